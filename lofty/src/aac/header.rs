@@ -6,6 +6,8 @@ use crate::mpeg::MpegVersion;
 
 use std::io::{Read, Seek, SeekFrom};
 
+use byteorder::ReadBytesExt;
+
 // Used to compare the headers up to the home bit.
 // If they aren't equal, something is broken.
 pub(super) const HEADER_MASK: u32 = 0xFFFF_FFE0;
@@ -122,3 +124,232 @@ impl ADTSHeader {
 		}))
 	}
 }
+
+/// The bitstream type of an ADIF stream
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AdifBitstreamType {
+	/// Constant bitrate
+	#[default]
+	Constant,
+	/// Variable bitrate
+	Variable,
+}
+
+#[derive(Clone)]
+pub(crate) struct ADIFHeader {
+	pub(crate) copyright: bool,
+	pub(crate) original: bool,
+	pub(crate) bitstream_type: AdifBitstreamType,
+	/// The bitrate in bits per second
+	///
+	/// For variable bitrate streams, this is the maximum bitrate.
+	pub(crate) bitrate: u32,
+	pub(crate) program_config_elements: Vec<ProgramConfigElement>,
+}
+
+impl ADIFHeader {
+	/// Reads an ADIF header, expecting the reader to be positioned after the "ADIF" identifier
+	pub(super) fn read<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read,
+	{
+		// https://wiki.multimedia.cx/index.php/ADIF
+		//
+		// Length (bits) 	Description
+		// 32 	            ADIF identifier, "ADIF"
+		// 1 	            Copyright ID present
+		// 72 	            Copyright ID, if present
+		// 1 	            Original/copy
+		// 1 	            Home
+		// 1 	            Bitstream type, 0 for constant rate and 1 for variable rate
+		// 23 	            Bitrate
+		// 4 	            Number of program config elements minus 1
+		//
+		// For each program config element:
+		// 20 	            ADIF buffer fullness, if the bitstream type is constant rate
+		// (variable)       Program config element
+		let mut reader = BitReader::new(reader);
+
+		let copyright = reader.read_bit()?;
+		if copyright {
+			// Skipping 72 bits
+			// Copyright ID (72)
+			for _ in 0..3 {
+				let _ = reader.read_bits(24)?;
+			}
+		}
+
+		let original = reader.read_bit()?;
+		let _home = reader.read_bit()?;
+
+		let bitstream_type = if reader.read_bit()? {
+			AdifBitstreamType::Variable
+		} else {
+			AdifBitstreamType::Constant
+		};
+
+		let bitrate = reader.read_bits(23)?;
+
+		let num_program_config_elements = reader.read_bits(4)? + 1;
+		let mut program_config_elements = Vec::with_capacity(num_program_config_elements as usize);
+		for _ in 0..num_program_config_elements {
+			if bitstream_type == AdifBitstreamType::Constant {
+				let _adif_buffer_fullness = reader.read_bits(20)?;
+			}
+
+			program_config_elements.push(ProgramConfigElement::read(&mut reader)?);
+		}
+
+		Ok(Self {
+			copyright,
+			original,
+			bitstream_type,
+			bitrate,
+			program_config_elements,
+		})
+	}
+}
+
+/// The parts of a program config element (PCE) that we care about
+#[derive(Copy, Clone)]
+pub(crate) struct ProgramConfigElement {
+	pub(crate) audio_object_ty: AudioObjectType,
+	pub(crate) sample_rate: u32,
+	pub(crate) channels: u8,
+}
+
+impl ProgramConfigElement {
+	fn read<R>(reader: &mut BitReader<'_, R>) -> Result<Self>
+	where
+		R: Read,
+	{
+		// ISO/IEC 14496-3, Table 4.2
+		let _element_instance_tag = reader.read_bits(4)?;
+
+		// The same as the ADTS profile, the audio object type minus 1
+		let audio_object_ty = match reader.read_bits(2)? + 1 {
+			1 => AudioObjectType::AacMain,
+			2 => AudioObjectType::AacLowComplexity,
+			3 => AudioObjectType::AacScalableSampleRate,
+			4 => AudioObjectType::AacLongTermPrediction,
+			_ => unreachable!(),
+		};
+
+		let sample_rate_idx = reader.read_bits(4)?;
+		let sample_rate = SAMPLE_RATES
+			.get(sample_rate_idx as usize)
+			.copied()
+			.unwrap_or_default();
+		if sample_rate == 0 {
+			decode_err!(@BAIL Aac, "File contains an invalid sample frequency index");
+		}
+
+		let num_front_channel_elements = reader.read_bits(4)?;
+		let num_side_channel_elements = reader.read_bits(4)?;
+		let num_back_channel_elements = reader.read_bits(4)?;
+		let num_lfe_channel_elements = reader.read_bits(2)?;
+		let num_assoc_data_elements = reader.read_bits(3)?;
+		let num_valid_cc_elements = reader.read_bits(4)?;
+
+		// Mono mixdown present (1)
+		//     Mono mixdown element number (4)
+		// Stereo mixdown present (1)
+		//     Stereo mixdown element number (4)
+		// Matrix mixdown index present (1)
+		//     Matrix mixdown index (2)
+		//     Pseudo surround enable (1)
+		for mixdown_len in [4, 4, 3] {
+			if reader.read_bit()? {
+				let _ = reader.read_bits(mixdown_len)?;
+			}
+		}
+
+		// Each channel element is either a single channel element (SCE), or a
+		// channel pair element (CPE)
+		let mut channels = 0;
+		for _ in
+			0..(num_front_channel_elements + num_side_channel_elements + num_back_channel_elements)
+		{
+			let is_cpe = reader.read_bit()?;
+			let _element_tag_select = reader.read_bits(4)?;
+
+			channels += if is_cpe { 2 } else { 1 };
+		}
+
+		// LFE elements are always a single channel
+		for _ in 0..num_lfe_channel_elements {
+			let _lfe_element_tag_select = reader.read_bits(4)?;
+			channels += 1;
+		}
+
+		for _ in 0..num_assoc_data_elements {
+			let _assoc_data_element_tag_select = reader.read_bits(4)?;
+		}
+
+		for _ in 0..num_valid_cc_elements {
+			// CC element is independently switched (1)
+			// Valid CC element tag select (4)
+			let _ = reader.read_bits(5)?;
+		}
+
+		reader.byte_align();
+
+		let comment_field_bytes = reader.read_bits(8)?;
+		for _ in 0..comment_field_bytes {
+			let _comment_field_data = reader.read_bits(8)?;
+		}
+
+		Ok(Self {
+			audio_object_ty,
+			sample_rate,
+			channels,
+		})
+	}
+}
+
+/// A simple MSB-first bit reader
+struct BitReader<'a, R> {
+	reader: &'a mut R,
+	current: u8,
+	remaining_bits: u8,
+}
+
+impl<'a, R> BitReader<'a, R>
+where
+	R: Read,
+{
+	fn new(reader: &'a mut R) -> Self {
+		Self {
+			reader,
+			current: 0,
+			remaining_bits: 0,
+		}
+	}
+
+	fn read_bit(&mut self) -> Result<bool> {
+		if self.remaining_bits == 0 {
+			self.current = self.reader.read_u8()?;
+			self.remaining_bits = 8;
+		}
+
+		self.remaining_bits -= 1;
+		Ok((self.current >> self.remaining_bits) & 1 == 1)
+	}
+
+	/// Reads up to 32 bits
+	fn read_bits(&mut self, count: u8) -> Result<u32> {
+		debug_assert!(count <= 32);
+
+		let mut value = 0;
+		for _ in 0..count {
+			value = (value << 1) | u32::from(self.read_bit()?);
+		}
+
+		Ok(value)
+	}
+
+	/// Discards any remaining bits in the current byte
+	fn byte_align(&mut self) {
+		self.remaining_bits = 0;
+	}
+}
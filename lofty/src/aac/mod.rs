@@ -1,6 +1,4 @@
-//! AAC (ADTS) specific items
-
-// TODO: Currently we only support ADTS, might want to look into ADIF in the future.
+//! AAC (ADTS and ADIF) specific items
 
 mod header;
 mod properties;
@@ -13,9 +11,10 @@ use lofty_attr::LoftyFile;
 
 // Exports
 
-pub use properties::AACProperties;
+pub use header::AdifBitstreamType;
+pub use properties::{AACProperties, AacFormat};
 
-/// An AAC (ADTS) file
+/// An AAC (ADTS or ADIF) file
 #[derive(LoftyFile, Default)]
 #[lofty(read_fn = "read::read_from")]
 #[lofty(internal_write_module_do_not_use_anywhere_else)]
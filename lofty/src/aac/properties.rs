@@ -1,13 +1,25 @@
-use crate::aac::header::ADTSHeader;
+use crate::aac::header::{ADIFHeader, ADTSHeader, AdifBitstreamType};
 use crate::mp4::AudioObjectType;
 use crate::mpeg::header::MpegVersion;
 use crate::properties::{ChannelMask, FileProperties};
 
 use std::time::Duration;
 
+/// The header format of an AAC stream
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AacFormat {
+	/// Audio Data Transport Stream, where each frame has its own header
+	#[default]
+	Adts,
+	/// Audio Data Interchange Format, where a single header is at the start of the stream
+	Adif,
+}
+
 /// An AAC file's audio properties
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct AACProperties {
+	pub(crate) format: AacFormat,
+	pub(crate) bitstream_type: Option<AdifBitstreamType>,
 	pub(crate) version: MpegVersion,
 	pub(crate) audio_object_type: AudioObjectType,
 	pub(crate) duration: Duration,
@@ -21,12 +33,26 @@ pub struct AACProperties {
 }
 
 impl AACProperties {
+	/// The header format of the stream
+	pub fn format(&self) -> AacFormat {
+		self.format
+	}
+
+	/// The bitstream type
+	///
+	/// This is only applicable to [`AacFormat::Adif`] streams.
+	pub fn bitstream_type(&self) -> Option<AdifBitstreamType> {
+		self.bitstream_type
+	}
+
 	/// MPEG version
 	///
 	/// The only possible variants are:
 	///
 	/// * [MpegVersion::V2]
 	/// * [MpegVersion::V4]
+	///
+	/// ADIF headers don't specify a version, so [MpegVersion::V4] is assumed.
 	pub fn version(&self) -> MpegVersion {
 		self.version
 	}
@@ -129,3 +155,41 @@ pub(super) fn read_properties(
 		properties.duration = Duration::from_millis((stream_len * 8) / u64::from(bitrate));
 	}
 }
+
+pub(super) fn read_adif_properties(
+	properties: &mut AACProperties,
+	header: &ADIFHeader,
+	stream_len: u64,
+) {
+	properties.format = AacFormat::Adif;
+	properties.bitstream_type = Some(header.bitstream_type);
+	properties.version = MpegVersion::V4;
+	properties.copyright = header.copyright;
+	properties.original = header.original;
+
+	// Only the first program is considered
+	if let Some(pce) = header.program_config_elements.first() {
+		properties.audio_object_type = pce.audio_object_ty;
+		properties.sample_rate = pce.sample_rate;
+		properties.channels = pce.channels;
+
+		match ChannelMask::from_mp4_channels(properties.channels) {
+			Some(mask) => properties.channel_mask = Some(mask),
+			None => {
+				log::warn!(
+					"Unable to create channel mask, invalid channel count: {}",
+					properties.channels
+				);
+			},
+		}
+	}
+
+	// ADIF streams have no frame headers, so the header bitrate is all we have to go on.
+	// For variable bitrate streams, this is the maximum bitrate, making the duration a lower bound.
+	let bitrate = header.bitrate;
+	if bitrate > 0 {
+		properties.audio_bitrate = bitrate / 1000;
+		properties.overall_bitrate = bitrate / 1000;
+		properties.duration = Duration::from_millis((stream_len * 8 * 1000) / u64::from(bitrate));
+	}
+}
@@ -1,4 +1,4 @@
-use super::header::{ADTSHeader, HEADER_MASK};
+use super::header::{ADIFHeader, ADTSHeader, HEADER_MASK};
 use super::AacFile;
 use crate::config::{ParseOptions, ParsingMode};
 use crate::error::Result;
@@ -23,6 +23,7 @@ where
 
 	let mut first_frame_header = None;
 	let mut first_frame_end = 0;
+	let mut adif_header = None;
 
 	// Skip any invalid padding
 	while reader.read_u8()? == 0 {}
@@ -53,7 +54,7 @@ where
 				stream_len = new_stream_len;
 
 				if parse_options.read_tags {
-					let id3v2 = parse_id3v2(reader, header, parse_options)?;
+					let id3v2 = parse_id3v2(reader, header, parse_options.clone())?;
 					if let Some(existing_tag) = &mut file.id3v2_tag {
 						log::warn!("Duplicate ID3v2 tag found, appending frames to previous tag");
 
@@ -81,6 +82,16 @@ where
 
 				continue;
 			},
+			// ADIF streams have a single header, with no frame headers to search for
+			[b'A', b'D', b'I', b'F'] => {
+				log::debug!("Found ADIF header");
+
+				if parse_options.read_properties {
+					adif_header = Some(ADIFHeader::read(reader)?);
+				}
+
+				break;
+			},
 			// Tags might be followed by junk bytes before the first ADTS frame begins
 			_ => {
 				log::debug!("Searching for first ADTS frame");
@@ -115,6 +126,11 @@ where
 		file.id3v1_tag = id3v1;
 	}
 
+	if let Some(adif_header) = adif_header {
+		super::properties::read_adif_properties(&mut file.properties, &adif_header, stream_len);
+		return Ok(file);
+	}
+
 	if parse_options.read_properties {
 		let Some(mut first_frame_header) = first_frame_header else {
 			// The search for sync bits was unsuccessful
@@ -75,7 +75,7 @@ macro_rules! impl_accessor {
 #[derive(Default, Debug, PartialEq, Eq, Clone)]
 #[tag(
 	description = "An `APE` tag",
-	supported_formats(Ape, Mpeg, Mpc, WavPack)
+	supported_formats(Ape, Mpeg, Mpc, Tta, WavPack)
 )]
 pub struct ApeTag {
 	/// Whether or not to mark the tag as read only
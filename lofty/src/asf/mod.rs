@@ -0,0 +1,31 @@
+//! ASF (Advanced Systems Format) specific items
+//!
+//! ## File notes
+//!
+//! ASF is the container used by Windows Media Audio (`.wma`) files. Metadata is stored in several
+//! objects within the file's header object, which are all merged into a single [`AsfTag`].
+//!
+//! Writing an [`AsfTag`] will rewrite the entire header object.
+
+pub(crate) mod object;
+mod properties;
+mod read;
+pub(crate) mod tag;
+pub(crate) mod write;
+
+use lofty_attr::LoftyFile;
+
+// Exports
+pub use properties::{AsfCodec, AsfProperties};
+pub use tag::{AsfAttribute, AsfAttributeValue, AsfTag};
+
+/// An ASF file
+#[derive(LoftyFile, Default)]
+#[lofty(read_fn = "read::read_from")]
+pub struct AsfFile {
+	/// An ASF tag
+	#[lofty(tag_type = "Asf")]
+	pub(crate) asf_tag: Option<AsfTag>,
+	/// The file's audio properties
+	pub(crate) properties: AsfProperties,
+}
@@ -0,0 +1,211 @@
+use crate::error::{ErrorKind, LoftyError, Result};
+use crate::macros::{err, try_vec};
+use crate::util::text::{utf16_decode_bytes, utf16_encode};
+
+use std::io::Read;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// A GUID, as stored in an ASF file
+pub(crate) type Guid = [u8; 16];
+
+/// Creates a [`Guid`] from its textual representation, `d1-d2-d3-d4`
+///
+/// The first three groups are stored little-endian, while the last is stored as-is.
+const fn guid(d1: u32, d2: u16, d3: u16, d4: [u8; 8]) -> Guid {
+	let d1 = d1.to_le_bytes();
+	let d2 = d2.to_le_bytes();
+	let d3 = d3.to_le_bytes();
+
+	[
+		d1[0], d1[1], d1[2], d1[3], d2[0], d2[1], d3[0], d3[1], d4[0], d4[1], d4[2], d4[3], d4[4],
+		d4[5], d4[6], d4[7],
+	]
+}
+
+// Top-level objects
+pub(crate) const HEADER_OBJECT: Guid = guid(
+	0x75B2_2630,
+	0x668E,
+	0x11CF,
+	[0xA6, 0xD9, 0x00, 0xAA, 0x00, 0x62, 0xCE, 0x6C],
+);
+
+// Header objects
+pub(crate) const FILE_PROPERTIES_OBJECT: Guid = guid(
+	0x8CAB_DCA1,
+	0xA947,
+	0x11CF,
+	[0x8E, 0xE4, 0x00, 0xC0, 0x0C, 0x20, 0x53, 0x65],
+);
+pub(crate) const STREAM_PROPERTIES_OBJECT: Guid = guid(
+	0xB7DC_0791,
+	0xA9B7,
+	0x11CF,
+	[0x8E, 0xE6, 0x00, 0xC0, 0x0C, 0x20, 0x53, 0x65],
+);
+pub(crate) const HEADER_EXTENSION_OBJECT: Guid = guid(
+	0x5FBF_03B5,
+	0xA92E,
+	0x11CF,
+	[0x8E, 0xE3, 0x00, 0xC0, 0x0C, 0x20, 0x53, 0x65],
+);
+pub(crate) const CONTENT_DESCRIPTION_OBJECT: Guid = guid(
+	0x75B2_2633,
+	0x668E,
+	0x11CF,
+	[0xA6, 0xD9, 0x00, 0xAA, 0x00, 0x62, 0xCE, 0x6C],
+);
+pub(crate) const EXTENDED_CONTENT_DESCRIPTION_OBJECT: Guid = guid(
+	0xD2D0_A440,
+	0xE307,
+	0x11D2,
+	[0x97, 0xF0, 0x00, 0xA0, 0xC9, 0x5E, 0xA8, 0x50],
+);
+pub(crate) const CONTENT_ENCRYPTION_OBJECT: Guid = guid(
+	0x2211_B3FB,
+	0xBD23,
+	0x11D2,
+	[0xB4, 0xB7, 0x00, 0xA0, 0xC9, 0x55, 0xFC, 0x6E],
+);
+pub(crate) const EXTENDED_CONTENT_ENCRYPTION_OBJECT: Guid = guid(
+	0x298A_E614,
+	0x2622,
+	0x4C17,
+	[0xB9, 0x35, 0xDA, 0xE0, 0x7E, 0xE9, 0x28, 0x9C],
+);
+
+// Header extension objects
+pub(crate) const HEADER_EXTENSION_RESERVED: Guid = guid(
+	0xABD3_D211,
+	0xA9BA,
+	0x11CF,
+	[0x8E, 0xE6, 0x00, 0xC0, 0x0C, 0x20, 0x53, 0x65],
+);
+pub(crate) const METADATA_OBJECT: Guid = guid(
+	0xC5F8_CBEA,
+	0x5BAF,
+	0x4877,
+	[0x84, 0x67, 0xAA, 0x8C, 0x44, 0xFA, 0x4C, 0xCA],
+);
+pub(crate) const METADATA_LIBRARY_OBJECT: Guid = guid(
+	0x4423_1C94,
+	0x9498,
+	0x49D1,
+	[0xA1, 0x41, 0x1D, 0x13, 0x4E, 0x45, 0x70, 0x54],
+);
+
+// Stream types
+pub(crate) const AUDIO_MEDIA: Guid = guid(
+	0xF869_9E40,
+	0x5B4D,
+	0x11CF,
+	[0xA8, 0xFD, 0x00, 0x80, 0x5F, 0x5C, 0x44, 0x2B],
+);
+
+/// The size of an object header (GUID + size)
+pub(crate) const OBJECT_HEADER_SIZE: u64 = 24;
+
+/// The size of the header object's header, which includes the object count and two reserved bytes
+pub(crate) const HEADER_OBJECT_HEADER_SIZE: u64 = OBJECT_HEADER_SIZE + 6;
+
+/// The header of an object, found at the start of *every* object in the file
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ObjectHeader {
+	pub(crate) guid: Guid,
+	/// The size of the **entire** object, including this header
+	pub(crate) size: u64,
+}
+
+impl ObjectHeader {
+	pub(crate) fn read<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read,
+	{
+		let mut guid = [0; 16];
+		reader.read_exact(&mut guid)?;
+
+		let size = reader.read_u64::<LittleEndian>()?;
+		if size < OBJECT_HEADER_SIZE {
+			err!(SizeMismatch);
+		}
+
+		Ok(Self { guid, size })
+	}
+
+	/// The size of the object's content, excluding this header
+	pub(crate) fn content_size(&self) -> u64 {
+		self.size - OBJECT_HEADER_SIZE
+	}
+}
+
+/// Iterates over a buffer of consecutive objects, yielding each object's GUID and content
+pub(crate) struct Objects<'a> {
+	content: &'a [u8],
+}
+
+impl<'a> Objects<'a> {
+	pub(crate) fn new(content: &'a [u8]) -> Self {
+		Self { content }
+	}
+}
+
+impl<'a> Iterator for Objects<'a> {
+	type Item = Result<(Guid, &'a [u8])>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if (self.content.len() as u64) < OBJECT_HEADER_SIZE {
+			return None;
+		}
+
+		let header = match ObjectHeader::read(&mut self.content) {
+			Ok(header) => header,
+			Err(e) => return Some(Err(e)),
+		};
+
+		let content_size = header.content_size();
+		if content_size > self.content.len() as u64 {
+			self.content = &[];
+			return Some(Err(LoftyError::new(ErrorKind::SizeMismatch)));
+		}
+
+		let (content, remaining) = self.content.split_at(content_size as usize);
+		self.content = remaining;
+
+		Some(Ok((header.guid, content)))
+	}
+}
+
+/// Read `len` bytes of content into a new buffer
+pub(crate) fn read_content<R>(reader: &mut R, len: u64) -> Result<Vec<u8>>
+where
+	R: Read,
+{
+	let mut content = try_vec![0; len as usize];
+	reader.read_exact(&mut content)?;
+
+	Ok(content)
+}
+
+/// Reads a UTF-16LE string of `len` bytes, trimming any null terminators
+pub(crate) fn read_utf16<R>(reader: &mut R, len: usize) -> Result<String>
+where
+	R: Read,
+{
+	let mut bytes = try_vec![0; len];
+	reader.read_exact(&mut bytes)?;
+
+	utf16_decode_bytes(&bytes, u16::from_le_bytes)
+}
+
+/// Encodes a null-terminated UTF-16LE string
+pub(crate) fn encode_utf16(text: &str) -> Vec<u8> {
+	utf16_encode(text, u16::to_le_bytes, false, true)
+}
+
+/// Writes an object with the given GUID and content
+pub(crate) fn write_object(writer: &mut Vec<u8>, guid: Guid, content: &[u8]) {
+	writer.extend(guid);
+	writer.extend((content.len() as u64 + OBJECT_HEADER_SIZE).to_le_bytes());
+	writer.extend(content);
+}
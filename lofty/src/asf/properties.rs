@@ -0,0 +1,184 @@
+use crate::error::Result;
+use crate::macros::decode_err;
+use crate::properties::FileProperties;
+
+use std::time::Duration;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// The codec of an ASF file's audio stream
+///
+/// This is determined from the format tag of the stream's `WAVEFORMATEX` structure.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum AsfCodec {
+	/// Windows Media Audio (version 1)
+	Wma1,
+	/// Windows Media Audio (version 2, also used for WMA 7, 8, and 9 Standard)
+	Wma2,
+	/// Windows Media Audio 9 Professional
+	WmaPro,
+	/// Windows Media Audio 9 Lossless
+	WmaLossless,
+	/// Windows Media Audio 9 Voice
+	WmaVoice,
+	/// An unknown format tag
+	#[default]
+	Unknown,
+}
+
+impl AsfCodec {
+	fn from_format_tag(format_tag: u16) -> Self {
+		match format_tag {
+			0x0160 => Self::Wma1,
+			0x0161 => Self::Wma2,
+			0x0162 => Self::WmaPro,
+			0x0163 => Self::WmaLossless,
+			0x000A => Self::WmaVoice,
+			_ => Self::Unknown,
+		}
+	}
+}
+
+/// An ASF file's audio properties
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct AsfProperties {
+	pub(crate) codec: AsfCodec,
+	pub(crate) format_tag: u16,
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) bit_depth: u8,
+	pub(crate) channels: u8,
+	pub(crate) is_encrypted: bool,
+}
+
+impl From<AsfProperties> for FileProperties {
+	fn from(input: AsfProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			bit_depth: Some(input.bit_depth),
+			channels: Some(input.channels),
+			channel_mask: None,
+		}
+	}
+}
+
+impl AsfProperties {
+	/// Duration of the audio
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn audio_bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Bits per sample
+	pub fn bit_depth(&self) -> u8 {
+		self.bit_depth
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// The codec of the audio stream
+	pub fn codec(&self) -> AsfCodec {
+		self.codec
+	}
+
+	/// The raw `WAVEFORMATEX` format tag of the audio stream
+	///
+	/// This is useful when [`AsfProperties::codec`] is [`AsfCodec::Unknown`].
+	pub fn format_tag(&self) -> u16 {
+		self.format_tag
+	}
+
+	/// Whether the file is protected with DRM
+	pub fn is_encrypted(&self) -> bool {
+		self.is_encrypted
+	}
+}
+
+/// Flag set in the File Properties Object if the file is being broadcast
+///
+/// In this case, the file size and durations are invalid.
+pub(super) const BROADCAST_FLAG: u32 = 0x01;
+
+pub(super) fn read_properties(
+	file_properties: &[u8],
+	stream_properties: Option<&[u8]>,
+	is_encrypted: bool,
+	file_length: u64,
+) -> Result<AsfProperties> {
+	let mut properties = AsfProperties {
+		is_encrypted,
+		..AsfProperties::default()
+	};
+
+	// Skip the file ID (16), file size (8), creation date (8), and data packets count (8)
+	if file_properties.len() < 80 {
+		decode_err!(@BAIL Asf, "File Properties Object is too small");
+	}
+
+	let reader = &mut &file_properties[40..];
+	// Both durations are stored in 100-nanosecond units, while preroll is stored in milliseconds
+	let play_duration = reader.read_u64::<LittleEndian>()?;
+	let _send_duration = reader.read_u64::<LittleEndian>()?;
+	let preroll = reader.read_u64::<LittleEndian>()?;
+	let flags = reader.read_u32::<LittleEndian>()?;
+
+	if flags & BROADCAST_FLAG == 0 {
+		let length = (play_duration / 10_000).saturating_sub(preroll);
+		properties.duration = Duration::from_millis(length);
+
+		if length > 0 {
+			properties.overall_bitrate = (file_length * 8 / length) as u32;
+		}
+	}
+
+	let Some(stream_properties) = stream_properties else {
+		log::warn!("No audio stream found, unable to read audio properties");
+		return Ok(properties);
+	};
+
+	// Skip the stream type (16), error correction type (16), time offset (8),
+	// type-specific data length (4), error correction data length (4), flags (2), and reserved (4)
+	if stream_properties.len() < 54 + 16 {
+		decode_err!(@BAIL Asf, "Stream Properties Object is too small");
+	}
+
+	// The type-specific data is a `WAVEFORMATEX` structure
+	let reader = &mut &stream_properties[54..];
+	properties.format_tag = reader.read_u16::<LittleEndian>()?;
+	properties.codec = AsfCodec::from_format_tag(properties.format_tag);
+	properties.channels = reader.read_u16::<LittleEndian>()? as u8;
+	properties.sample_rate = reader.read_u32::<LittleEndian>()?;
+
+	let bytes_per_second = reader.read_u32::<LittleEndian>()?;
+	// Equivalent to `bytes_per_second * 8 / 1000`, without the possibility of overflowing
+	properties.audio_bitrate = bytes_per_second / 125;
+
+	let _block_align = reader.read_u16::<LittleEndian>()?;
+	properties.bit_depth = reader.read_u16::<LittleEndian>()? as u8;
+
+	Ok(properties)
+}
@@ -0,0 +1,124 @@
+use super::object::{
+	read_content, ObjectHeader, Objects, AUDIO_MEDIA, CONTENT_DESCRIPTION_OBJECT,
+	CONTENT_ENCRYPTION_OBJECT, EXTENDED_CONTENT_DESCRIPTION_OBJECT,
+	EXTENDED_CONTENT_ENCRYPTION_OBJECT, FILE_PROPERTIES_OBJECT, HEADER_EXTENSION_OBJECT,
+	HEADER_OBJECT, HEADER_OBJECT_HEADER_SIZE, METADATA_LIBRARY_OBJECT, METADATA_OBJECT,
+	STREAM_PROPERTIES_OBJECT,
+};
+use super::properties::AsfProperties;
+use super::tag::read::{
+	read_content_description, read_extended_content_description, read_metadata,
+};
+use super::tag::AsfTag;
+use super::AsfFile;
+use crate::config::ParseOptions;
+use crate::error::Result;
+use crate::macros::{decode_err, err};
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// The size of the fields preceding the nested objects in the Header Extension Object
+const HEADER_EXTENSION_DATA_OFFSET: usize = 22;
+
+pub(super) fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<AsfFile>
+where
+	R: Read + Seek,
+{
+	let start = reader.stream_position()?;
+	let file_length = reader.seek(SeekFrom::End(0))?.saturating_sub(start);
+	reader.seek(SeekFrom::Start(start))?;
+
+	let header = ObjectHeader::read(reader)?;
+	if header.guid != HEADER_OBJECT {
+		decode_err!(@BAIL Asf, "File missing header object");
+	}
+
+	if header.size < HEADER_OBJECT_HEADER_SIZE || header.size > file_length {
+		decode_err!(@BAIL Asf, "Header object has an invalid size");
+	}
+
+	let object_count = reader.read_u32::<LittleEndian>()?;
+	// Two reserved bytes, 0x01 and 0x02
+	reader.seek(SeekFrom::Current(2))?;
+
+	log::debug!("File verified to be ASF, header object count: {object_count}");
+
+	let content = read_content(reader, header.size - HEADER_OBJECT_HEADER_SIZE)?;
+
+	let mut file_properties = None;
+	let mut stream_properties = None;
+	let mut is_encrypted = false;
+
+	let mut tag = AsfTag::default();
+
+	for object in Objects::new(&content) {
+		let (guid, object_content) = object?;
+		match guid {
+			FILE_PROPERTIES_OBJECT if parse_options.read_properties => {
+				file_properties = Some(object_content);
+			},
+			// We only care about the first audio stream
+			STREAM_PROPERTIES_OBJECT
+				if parse_options.read_properties
+					&& stream_properties.is_none()
+					&& object_content.starts_with(&AUDIO_MEDIA) =>
+			{
+				stream_properties = Some(object_content);
+			},
+			CONTENT_ENCRYPTION_OBJECT | EXTENDED_CONTENT_ENCRYPTION_OBJECT => is_encrypted = true,
+			CONTENT_DESCRIPTION_OBJECT if parse_options.read_tags => {
+				read_content_description(object_content, &mut tag)?;
+			},
+			EXTENDED_CONTENT_DESCRIPTION_OBJECT if parse_options.read_tags => {
+				read_extended_content_description(object_content, &mut tag)?;
+			},
+			HEADER_EXTENSION_OBJECT if parse_options.read_tags => {
+				read_header_extension(object_content, &mut tag)?;
+			},
+			_ => {},
+		}
+	}
+
+	let properties = if parse_options.read_properties {
+		let Some(file_properties) = file_properties else {
+			decode_err!(@BAIL Asf, "File missing File Properties Object");
+		};
+
+		super::properties::read_properties(
+			file_properties,
+			stream_properties,
+			is_encrypted,
+			file_length,
+		)?
+	} else {
+		AsfProperties::default()
+	};
+
+	Ok(AsfFile {
+		asf_tag: (!tag.attributes.is_empty()).then_some(tag),
+		properties,
+	})
+}
+
+fn read_header_extension(content: &[u8], tag: &mut AsfTag) -> Result<()> {
+	if content.len() < HEADER_EXTENSION_DATA_OFFSET {
+		decode_err!(@BAIL Asf, "Header Extension Object is too small");
+	}
+
+	let data_size = (&content[18..HEADER_EXTENSION_DATA_OFFSET]).read_u32::<LittleEndian>()?;
+	let Some(data) = content[HEADER_EXTENSION_DATA_OFFSET..].get(..data_size as usize) else {
+		err!(SizeMismatch);
+	};
+
+	for object in Objects::new(data) {
+		let (guid, object_content) = object?;
+		match guid {
+			METADATA_OBJECT | METADATA_LIBRARY_OBJECT => read_metadata(object_content, tag)?,
+			_ => {},
+		}
+	}
+
+	Ok(())
+}
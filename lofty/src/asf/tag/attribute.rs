@@ -0,0 +1,127 @@
+/// The value of an [`AsfAttribute`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AsfAttributeValue {
+	/// A UTF-16 string
+	String(String),
+	/// Arbitrary binary data, such as `WM/Picture`
+	Binary(Vec<u8>),
+	/// A boolean
+	Bool(bool),
+	/// A 32-bit unsigned integer
+	DWord(u32),
+	/// A 64-bit unsigned integer
+	QWord(u64),
+	/// A 16-bit unsigned integer
+	Word(u16),
+	/// A GUID
+	///
+	/// NOTE: These can only be stored in the Metadata Library Object
+	Guid([u8; 16]),
+}
+
+impl AsfAttributeValue {
+	pub(crate) fn data_type(&self) -> u16 {
+		match self {
+			Self::String(_) => 0,
+			Self::Binary(_) => 1,
+			Self::Bool(_) => 2,
+			Self::DWord(_) => 3,
+			Self::QWord(_) => 4,
+			Self::Word(_) => 5,
+			Self::Guid(_) => 6,
+		}
+	}
+
+	/// Returns the value as a string, if it is of type [`AsfAttributeValue::String`]
+	pub fn text(&self) -> Option<&str> {
+		match self {
+			Self::String(text) => Some(text),
+			_ => None,
+		}
+	}
+
+	/// Attempts to interpret the value as an integer
+	///
+	/// This will check any of the integer types, as well as parse [`AsfAttributeValue::String`].
+	pub fn number(&self) -> Option<u64> {
+		match self {
+			Self::DWord(value) => Some(u64::from(*value)),
+			Self::QWord(value) => Some(*value),
+			Self::Word(value) => Some(u64::from(*value)),
+			Self::String(text) => text.trim().parse().ok(),
+			_ => None,
+		}
+	}
+
+	/// Encodes the value
+	///
+	/// `wide_bool` determines whether booleans are written as 32-bit integers (Extended Content Description Object)
+	/// or 16-bit integers (Metadata and Metadata Library Objects).
+	pub(crate) fn encode(&self, wide_bool: bool) -> Vec<u8> {
+		match self {
+			Self::String(text) => crate::asf::object::encode_utf16(text),
+			Self::Binary(data) => data.clone(),
+			Self::Bool(value) if wide_bool => u32::from(*value).to_le_bytes().to_vec(),
+			Self::Bool(value) => u16::from(*value).to_le_bytes().to_vec(),
+			Self::DWord(value) => value.to_le_bytes().to_vec(),
+			Self::QWord(value) => value.to_le_bytes().to_vec(),
+			Self::Word(value) => value.to_le_bytes().to_vec(),
+			Self::Guid(guid) => guid.to_vec(),
+		}
+	}
+}
+
+/// An attribute in an [`AsfTag`](crate::asf::AsfTag)
+///
+/// An attribute will be stored in whichever object can hold it:
+///
+/// * The Content Description Object, for the `Title`, `Author`, `Copyright`, `Description`,
+///   and `Rating` attributes
+/// * The Extended Content Description Object, for attributes that apply to the entire file,
+///   with no language, and values smaller than 64 KiB
+/// * The Metadata Object, for attributes with no language
+/// * The Metadata Library Object, for everything else
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AsfAttribute {
+	/// The attribute name, such as `WM/AlbumTitle`
+	pub name: String,
+	/// The attribute value
+	pub value: AsfAttributeValue,
+	/// The stream that the attribute applies to, with 0 meaning the entire file
+	pub stream_number: u16,
+	/// An index into the file's language list, with 0 meaning no specific language
+	pub language_index: u16,
+}
+
+impl AsfAttribute {
+	/// Create a new `AsfAttribute` that applies to the entire file
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::asf::{AsfAttribute, AsfAttributeValue};
+	///
+	/// let attribute = AsfAttribute::new(
+	/// 	String::from("WM/AlbumTitle"),
+	/// 	AsfAttributeValue::String(String::from("Foo album")),
+	/// );
+	/// assert_eq!(attribute.stream_number, 0);
+	/// ```
+	pub fn new(name: String, value: AsfAttributeValue) -> Self {
+		Self {
+			name,
+			value,
+			stream_number: 0,
+			language_index: 0,
+		}
+	}
+
+	pub(crate) fn text(name: &str, value: String) -> Self {
+		Self::new(name.to_string(), AsfAttributeValue::String(value))
+	}
+
+	/// Whether the attribute applies to the entire file, with no specific language
+	pub(crate) fn is_global(&self) -> bool {
+		self.stream_number == 0 && self.language_index == 0
+	}
+}
@@ -0,0 +1,701 @@
+mod attribute;
+pub(crate) mod read;
+mod write;
+
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::picture::Picture;
+use crate::tag::{
+	try_parse_year, Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType,
+};
+use crate::util::flag_item;
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::borrow::Cow;
+use std::io::Write;
+
+use lofty_attr::tag;
+
+pub use attribute::{AsfAttribute, AsfAttributeValue};
+
+/// The attributes stored in the Content Description Object, in the order they appear
+pub(crate) const CONTENT_DESCRIPTION_NAMES: [&str; 5] =
+	["Title", "Author", "Copyright", "Description", "Rating"];
+
+/// The attribute name for pictures
+const PICTURE_ATTRIBUTE: &str = "WM/Picture";
+
+macro_rules! impl_accessor {
+	($($name:ident => $key:literal;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<Cow<'_, str>> {
+					self.get($key)
+						.and_then(|attribute| attribute.value.text())
+						.map(Cow::Borrowed)
+				}
+
+				fn [<set_ $name>](&mut self, value: String) {
+					self.insert(AsfAttribute::text($key, value))
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					self.remove($key);
+				}
+			)+
+		}
+	}
+}
+
+/// ## Item storage
+///
+/// ASF stores its metadata as a list of named [`AsfAttribute`]s, spread across multiple objects
+/// in the file's header. These objects are merged into a single tag, see [`AsfAttribute`] for
+/// details on where each attribute will be written.
+///
+/// Attribute names are case-sensitive, and a name may appear multiple times.
+///
+/// Pictures are stored in `WM/Picture` attributes, and can be converted with
+/// [`Picture::from_asf_bytes`](crate::picture::Picture::from_asf_bytes).
+///
+/// ## Conversions
+///
+/// ### To `Tag`
+///
+/// Any attribute that applies to the entire file, with no specific language, and an [`ItemKey`]
+/// mapping will be converted. Integer and boolean values will be converted to text, and
+/// `WM/Picture` attributes will be converted to [`Picture`]s.
+///
+/// All other attributes will be retained when using [`SplitTag`] and [`MergeTag`].
+///
+/// ### From `Tag`
+///
+/// Every [`ItemKey`] with a mapping will be converted to an [`AsfAttributeValue::String`],
+/// with the exception of:
+///
+/// * [`ItemKey::TrackNumber`] - stored as an [`AsfAttributeValue::DWord`]
+/// * [`ItemKey::FlagCompilation`] - stored as an [`AsfAttributeValue::Bool`]
+#[derive(Default, Debug, PartialEq, Eq, Clone)]
+#[tag(description = "An `ASF` tag", supported_formats(Asf))]
+pub struct AsfTag {
+	pub(crate) attributes: Vec<AsfAttribute>,
+}
+
+impl AsfTag {
+	/// Create a new empty `AsfTag`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::asf::AsfTag;
+	/// use lofty::tag::TagExt;
+	///
+	/// let asf_tag = AsfTag::new();
+	/// assert!(asf_tag.is_empty());
+	/// ```
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Get the first [`AsfAttribute`] with the given name
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::asf::AsfTag;
+	/// use lofty::tag::Accessor;
+	///
+	/// let mut asf_tag = AsfTag::new();
+	/// asf_tag.set_album(String::from("Foo album"));
+	///
+	/// // Get the album by its attribute name
+	/// let album = asf_tag.get("WM/AlbumTitle");
+	/// assert!(album.is_some());
+	/// ```
+	pub fn get(&self, name: &str) -> Option<&AsfAttribute> {
+		self.attributes.iter().find(|a| a.name == name)
+	}
+
+	/// Get all [`AsfAttribute`]s with the given name
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::asf::{AsfAttribute, AsfAttributeValue, AsfTag};
+	///
+	/// let mut asf_tag = AsfTag::new();
+	/// asf_tag.push(AsfAttribute::new(
+	/// 	String::from("WM/Genre"),
+	/// 	AsfAttributeValue::String(String::from("Rock")),
+	/// ));
+	/// asf_tag.push(AsfAttribute::new(
+	/// 	String::from("WM/Genre"),
+	/// 	AsfAttributeValue::String(String::from("Pop")),
+	/// ));
+	///
+	/// assert_eq!(asf_tag.get_all("WM/Genre").count(), 2);
+	/// ```
+	pub fn get_all<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a AsfAttribute> + 'a {
+		self.attributes.iter().filter(move |a| a.name == name)
+	}
+
+	/// Insert an [`AsfAttribute`]
+	///
+	/// This will remove any attribute with the same name prior to insertion.
+	/// To allow multiple values, see [`AsfTag::push`].
+	pub fn insert(&mut self, attribute: AsfAttribute) {
+		self.remove(&attribute.name);
+		self.attributes.push(attribute);
+	}
+
+	/// Append an [`AsfAttribute`], keeping any existing attributes with the same name
+	pub fn push(&mut self, attribute: AsfAttribute) {
+		self.attributes.push(attribute);
+	}
+
+	/// Remove all [`AsfAttribute`]s with the given name
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::asf::AsfTag;
+	/// use lofty::tag::Accessor;
+	///
+	/// let mut asf_tag = AsfTag::new();
+	/// asf_tag.set_title(String::from("Foo title"));
+	/// assert!(asf_tag.get("Title").is_some());
+	///
+	/// asf_tag.remove("Title");
+	/// assert!(asf_tag.get("Title").is_none());
+	/// ```
+	pub fn remove(&mut self, name: &str) {
+		self.attributes.retain(|a| a.name != name);
+	}
+
+	/// Returns all pictures stored in `WM/Picture` attributes
+	///
+	/// Any attributes that fail to parse will be skipped.
+	pub fn pictures(&self) -> impl Iterator<Item = Picture> + '_ {
+		self.get_all(PICTURE_ATTRIBUTE)
+			.filter_map(|attribute| match &attribute.value {
+				AsfAttributeValue::Binary(data) => Picture::from_asf_bytes(data).ok(),
+				_ => None,
+			})
+	}
+
+	/// Append a picture as a `WM/Picture` attribute
+	pub fn push_picture(&mut self, picture: &Picture) {
+		self.push(AsfAttribute::new(
+			String::from(PICTURE_ATTRIBUTE),
+			AsfAttributeValue::Binary(picture.as_asf_bytes()),
+		));
+	}
+
+	/// Remove all pictures
+	pub fn remove_pictures(&mut self) {
+		self.remove(PICTURE_ATTRIBUTE);
+	}
+
+	fn get_number(&self, name: &str) -> Option<u32> {
+		let value = self.get(name)?;
+		if let AsfAttributeValue::String(text) = &value.value {
+			// Some software will store number pairs, such as "1/2"
+			return text.split('/').next()?.trim().parse().ok();
+		}
+
+		value.value.number().and_then(|n| u32::try_from(n).ok())
+	}
+
+	fn insert_item(&mut self, item: TagItem) {
+		let Some(name) = item.key().map_key(TagType::Asf, true) else {
+			return;
+		};
+
+		let name = name.to_string();
+		let value = match item.consume() {
+			(ItemKey::TrackNumber, ItemValue::Text(text)) => match text.trim().parse() {
+				Ok(number) => AsfAttributeValue::DWord(number),
+				Err(_) => AsfAttributeValue::String(text),
+			},
+			(ItemKey::FlagCompilation, ItemValue::Text(text)) => {
+				let Some(flag) = flag_item(&text) else {
+					return;
+				};
+
+				AsfAttributeValue::Bool(flag)
+			},
+			(_, ItemValue::Text(text) | ItemValue::Locator(text)) => {
+				AsfAttributeValue::String(text)
+			},
+			(_, ItemValue::Binary(data)) => AsfAttributeValue::Binary(data),
+		};
+
+		self.push(AsfAttribute::new(name, value));
+	}
+}
+
+impl IntoIterator for AsfTag {
+	type Item = AsfAttribute;
+	type IntoIter = std::vec::IntoIter<Self::Item>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.attributes.into_iter()
+	}
+}
+
+impl<'a> IntoIterator for &'a AsfTag {
+	type Item = &'a AsfAttribute;
+	type IntoIter = std::slice::Iter<'a, AsfAttribute>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.attributes.iter()
+	}
+}
+
+impl Accessor for AsfTag {
+	impl_accessor!(
+		artist  => "Author";
+		title   => "Title";
+		album   => "WM/AlbumTitle";
+		genre   => "WM/Genre";
+		comment => "Description";
+	);
+
+	fn track(&self) -> Option<u32> {
+		self.get_number("WM/TrackNumber")
+	}
+
+	fn set_track(&mut self, value: u32) {
+		self.insert(AsfAttribute::new(
+			String::from("WM/TrackNumber"),
+			AsfAttributeValue::DWord(value),
+		));
+	}
+
+	fn remove_track(&mut self) {
+		self.remove("WM/TrackNumber");
+	}
+
+	fn track_total(&self) -> Option<u32> {
+		self.get_number("TotalTracks")
+	}
+
+	fn set_track_total(&mut self, value: u32) {
+		self.insert(AsfAttribute::text("TotalTracks", value.to_string()));
+	}
+
+	fn remove_track_total(&mut self) {
+		self.remove("TotalTracks");
+	}
+
+	fn disk(&self) -> Option<u32> {
+		self.get_number("WM/PartOfSet")
+	}
+
+	fn set_disk(&mut self, value: u32) {
+		self.insert(AsfAttribute::text("WM/PartOfSet", value.to_string()));
+	}
+
+	fn remove_disk(&mut self) {
+		self.remove("WM/PartOfSet");
+	}
+
+	fn disk_total(&self) -> Option<u32> {
+		self.get_number("TotalDiscs")
+	}
+
+	fn set_disk_total(&mut self, value: u32) {
+		self.insert(AsfAttribute::text("TotalDiscs", value.to_string()));
+	}
+
+	fn remove_disk_total(&mut self) {
+		self.remove("TotalDiscs");
+	}
+
+	fn year(&self) -> Option<u32> {
+		self.get("WM/Year")
+			.and_then(|attribute| attribute.value.text())
+			.and_then(try_parse_year)
+	}
+
+	fn set_year(&mut self, value: u32) {
+		self.insert(AsfAttribute::text("WM/Year", value.to_string()));
+	}
+
+	fn remove_year(&mut self) {
+		self.remove("WM/Year");
+	}
+}
+
+impl TagExt for AsfTag {
+	type Err = LoftyError;
+	type RefKey<'a> = &'a str;
+
+	#[inline]
+	fn tag_type(&self) -> TagType {
+		TagType::Asf
+	}
+
+	fn len(&self) -> usize {
+		self.attributes.len()
+	}
+
+	fn contains<'a>(&'a self, key: Self::RefKey<'a>) -> bool {
+		self.attributes.iter().any(|a| a.name == key)
+	}
+
+	fn is_empty(&self) -> bool {
+		self.attributes.is_empty()
+	}
+
+	/// Write an `ASF` tag to a file
+	///
+	/// This will rewrite the file's header object.
+	///
+	/// # Errors
+	///
+	/// * Attempting to write the tag to a format that does not support it
+	/// * An attribute is too large to be stored in any object
+	fn save_to<F>(
+		&self,
+		file: &mut F,
+		write_options: WriteOptions,
+	) -> std::result::Result<(), Self::Err>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		AsfTagRef {
+			attributes: self.attributes.iter(),
+		}
+		.write_to(file, write_options)
+	}
+
+	/// Dumps the tag to a writer
+	///
+	/// This will create a header object containing *only* the metadata objects.
+	///
+	/// # Errors
+	///
+	/// * [`std::io::Error`]
+	/// * An attribute is too large to be stored in any object
+	fn dump_to<W: Write>(
+		&self,
+		writer: &mut W,
+		write_options: WriteOptions,
+	) -> std::result::Result<(), Self::Err> {
+		AsfTagRef {
+			attributes: self.attributes.iter(),
+		}
+		.dump_to(writer, write_options)
+	}
+
+	fn clear(&mut self) {
+		self.attributes.clear();
+	}
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SplitTagRemainder(AsfTag);
+
+impl From<SplitTagRemainder> for AsfTag {
+	fn from(from: SplitTagRemainder) -> Self {
+		from.0
+	}
+}
+
+impl SplitTag for AsfTag {
+	type Remainder = SplitTagRemainder;
+
+	fn split_tag(mut self) -> (Self::Remainder, Tag) {
+		let mut tag = Tag::new(TagType::Asf);
+
+		for attribute in std::mem::take(&mut self.attributes) {
+			if !attribute.is_global() {
+				self.attributes.push(attribute);
+				continue;
+			}
+
+			if attribute.name == PICTURE_ATTRIBUTE {
+				if let AsfAttributeValue::Binary(data) = &attribute.value {
+					if let Ok(picture) = Picture::from_asf_bytes(data) {
+						tag.push_picture(picture);
+						continue;
+					}
+				}
+
+				self.attributes.push(attribute);
+				continue;
+			}
+
+			let key = ItemKey::from_key(TagType::Asf, &attribute.name);
+			let value = match attribute.value {
+				AsfAttributeValue::String(text) => ItemValue::Text(text),
+				AsfAttributeValue::Binary(ref data) if !matches!(key, ItemKey::Unknown(_)) => {
+					ItemValue::Binary(data.clone())
+				},
+				// Anything else can only be converted if there's a mapping, otherwise we would
+				// lose the value's type
+				AsfAttributeValue::DWord(_)
+				| AsfAttributeValue::QWord(_)
+				| AsfAttributeValue::Word(_)
+					if !matches!(key, ItemKey::Unknown(_)) =>
+				{
+					ItemValue::Text(attribute.value.number().unwrap_or_default().to_string())
+				},
+				AsfAttributeValue::Bool(flag) if !matches!(key, ItemKey::Unknown(_)) => {
+					ItemValue::Text(u8::from(flag).to_string())
+				},
+				_ => {
+					self.attributes.push(attribute);
+					continue;
+				},
+			};
+
+			tag.items.push(TagItem::new(key, value));
+		}
+
+		(SplitTagRemainder(self), tag)
+	}
+}
+
+impl MergeTag for SplitTagRemainder {
+	type Merged = AsfTag;
+
+	fn merge_tag(self, tag: Tag) -> Self::Merged {
+		let Self(mut merged) = self;
+
+		for item in tag.items {
+			merged.insert_item(item);
+		}
+
+		for picture in tag.pictures {
+			merged.push_picture(&picture);
+		}
+
+		merged
+	}
+}
+
+impl From<AsfTag> for Tag {
+	fn from(input: AsfTag) -> Self {
+		input.split_tag().1
+	}
+}
+
+impl From<Tag> for AsfTag {
+	fn from(input: Tag) -> Self {
+		SplitTagRemainder::default().merge_tag(input)
+	}
+}
+
+pub(crate) struct AsfTagRef<'a, I>
+where
+	I: Iterator<Item = &'a AsfAttribute>,
+{
+	pub(crate) attributes: I,
+}
+
+impl<'a, I> AsfTagRef<'a, I>
+where
+	I: Iterator<Item = &'a AsfAttribute>,
+{
+	pub(crate) fn write_to<F>(self, file: &mut F, write_options: WriteOptions) -> Result<()>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		write::write_to(file, self.attributes, write_options)
+	}
+
+	pub(crate) fn dump_to<W: Write>(
+		self,
+		writer: &mut W,
+		write_options: WriteOptions,
+	) -> Result<()> {
+		let header = write::create_header(self.attributes, write_options)?;
+		writer.write_all(&header)?;
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::asf::{AsfAttribute, AsfAttributeValue, AsfTag};
+	use crate::config::{ParseOptions, WriteOptions};
+	use crate::picture::{MimeType, Picture, PictureType};
+	use crate::prelude::*;
+	use crate::tag::{ItemValue, Tag, TagItem, TagType};
+
+	use std::io::Cursor;
+
+	fn read_tag(bytes: Vec<u8>) -> AsfTag {
+		crate::asf::read::read_from(
+			&mut Cursor::new(bytes),
+			ParseOptions::new().read_properties(false),
+		)
+		.unwrap()
+		.asf_tag
+		.unwrap()
+	}
+
+	#[test_log::test]
+	fn parse_asf() {
+		let tag = read_tag(crate::tag::utils::test_utils::read_path(
+			"tests/tags/assets/test.asf",
+		));
+
+		let expected = [
+			AsfAttribute::text("Title", String::from("Foo title")),
+			AsfAttribute::text("Author", String::from("Bar artist")),
+			AsfAttribute::text("Description", String::from("Qux comment")),
+			AsfAttribute::text("WM/AlbumTitle", String::from("Baz album")),
+			AsfAttribute::text("WM/Genre", String::from("Classical")),
+			AsfAttribute::new(String::from("WM/TrackNumber"), AsfAttributeValue::DWord(1)),
+			AsfAttribute {
+				name: String::from("WM/Lyrics"),
+				value: AsfAttributeValue::String(String::from("Quux lyrics")),
+				stream_number: 0,
+				language_index: 1,
+			},
+		];
+
+		assert_eq!(tag.len(), expected.len());
+		for attribute in &expected {
+			assert!(tag.attributes.contains(attribute), "{attribute:?}");
+		}
+	}
+
+	#[test_log::test]
+	fn asf_re_read() {
+		let parsed_tag = read_tag(crate::tag::utils::test_utils::read_path(
+			"tests/tags/assets/test.asf",
+		));
+
+		let mut writer = Vec::new();
+		parsed_tag
+			.dump_to(&mut writer, WriteOptions::default())
+			.unwrap();
+
+		let temp_parsed_tag = read_tag(writer);
+
+		assert_eq!(parsed_tag, temp_parsed_tag);
+	}
+
+	#[test_log::test]
+	fn asf_to_tag() {
+		let asf = read_tag(crate::tag::utils::test_utils::read_path(
+			"tests/tags/assets/test.asf",
+		));
+
+		let tag: Tag = asf.into();
+
+		crate::tag::utils::test_utils::verify_tag(&tag, true, true);
+
+		// Attributes with a language can't be converted
+		assert!(tag.get(&ItemKey::Lyrics).is_none());
+	}
+
+	#[test_log::test]
+	fn tag_to_asf() {
+		let tag = crate::tag::utils::test_utils::create_tag(TagType::Asf);
+
+		let asf_tag: AsfTag = tag.into();
+
+		assert_eq!(asf_tag.title().as_deref(), Some("Foo title"));
+		assert_eq!(asf_tag.artist().as_deref(), Some("Bar artist"));
+		assert_eq!(asf_tag.album().as_deref(), Some("Baz album"));
+		assert_eq!(asf_tag.comment().as_deref(), Some("Qux comment"));
+		assert_eq!(asf_tag.genre().as_deref(), Some("Classical"));
+		assert_eq!(
+			asf_tag.get("WM/TrackNumber").map(|a| &a.value),
+			Some(&AsfAttributeValue::DWord(1))
+		);
+	}
+
+	#[test_log::test]
+	fn split_merge_keeps_language_attributes() {
+		let asf = read_tag(crate::tag::utils::test_utils::read_path(
+			"tests/tags/assets/test.asf",
+		));
+
+		let (remainder, mut tag) = asf.split_tag();
+		tag.insert_text(ItemKey::Lyrics, String::from("Corge lyrics"));
+
+		let merged = remainder.merge_tag(tag);
+
+		let lyrics = merged.get_all("WM/Lyrics").collect::<Vec<_>>();
+		assert_eq!(lyrics.len(), 2);
+		assert!(lyrics
+			.iter()
+			.any(|a| a.language_index == 1 && a.value.text() == Some("Quux lyrics")));
+		assert!(lyrics
+			.iter()
+			.any(|a| a.language_index == 0 && a.value.text() == Some("Corge lyrics")));
+	}
+
+	#[test_log::test]
+	fn pictures() {
+		let picture = Picture::new_unchecked(
+			PictureType::CoverFront,
+			Some(MimeType::Png),
+			Some(String::from("Foo description")),
+			vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A],
+		);
+
+		let mut asf_tag = AsfTag::new();
+		asf_tag.push_picture(&picture);
+
+		assert_eq!(
+			asf_tag.pictures().collect::<Vec<_>>(),
+			vec![picture.clone()]
+		);
+
+		let tag: Tag = asf_tag.into();
+		assert_eq!(tag.pictures(), std::slice::from_ref(&picture));
+
+		let asf_tag: AsfTag = tag.into();
+		assert_eq!(
+			asf_tag.get("WM/Picture").map(|a| &a.value),
+			Some(&AsfAttributeValue::Binary(picture.as_asf_bytes()))
+		);
+	}
+
+	#[test_log::test]
+	fn integer_attributes_to_tag() {
+		let mut asf_tag = AsfTag::new();
+		asf_tag.set_track(5);
+		asf_tag.insert(AsfAttribute::new(
+			String::from("WM/IsCompilation"),
+			AsfAttributeValue::Bool(true),
+		));
+		// Unknown integer attributes will be kept in the remainder
+		asf_tag.insert(AsfAttribute::new(
+			String::from("WM/SharedUserRating"),
+			AsfAttributeValue::DWord(99),
+		));
+
+		let (remainder, tag) = asf_tag.split_tag();
+
+		assert_eq!(
+			tag.get(&ItemKey::TrackNumber).map(TagItem::value),
+			Some(&ItemValue::Text(String::from("5")))
+		);
+		assert_eq!(tag.get_string(&ItemKey::FlagCompilation), Some("1"));
+		assert!(remainder.0.get("WM/SharedUserRating").is_some());
+
+		let merged = remainder.merge_tag(tag);
+		assert_eq!(merged.track(), Some(5));
+		assert_eq!(
+			merged.get("WM/IsCompilation").map(|a| &a.value),
+			Some(&AsfAttributeValue::Bool(true))
+		);
+		assert_eq!(
+			merged.get("WM/SharedUserRating").map(|a| &a.value),
+			Some(&AsfAttributeValue::DWord(99))
+		);
+	}
+}
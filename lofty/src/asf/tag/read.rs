@@ -0,0 +1,111 @@
+use super::{AsfAttribute, AsfAttributeValue, AsfTag, CONTENT_DESCRIPTION_NAMES};
+use crate::asf::object::read_utf16;
+use crate::error::Result;
+use crate::macros::decode_err;
+
+use std::io::Read;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+pub(crate) fn read_content_description(mut content: &[u8], tag: &mut AsfTag) -> Result<()> {
+	let reader = &mut content;
+
+	let mut lengths = [0; CONTENT_DESCRIPTION_NAMES.len()];
+	for len in &mut lengths {
+		*len = reader.read_u16::<LittleEndian>()?;
+	}
+
+	for (name, len) in CONTENT_DESCRIPTION_NAMES.iter().zip(lengths) {
+		let value = read_utf16(reader, usize::from(len))?;
+		if !value.is_empty() {
+			tag.attributes.push(AsfAttribute::text(name, value));
+		}
+	}
+
+	Ok(())
+}
+
+pub(crate) fn read_extended_content_description(
+	mut content: &[u8],
+	tag: &mut AsfTag,
+) -> Result<()> {
+	let reader = &mut content;
+
+	let count = reader.read_u16::<LittleEndian>()?;
+	for _ in 0..count {
+		let name_len = reader.read_u16::<LittleEndian>()?;
+		let name = read_utf16(reader, usize::from(name_len))?;
+
+		let data_type = reader.read_u16::<LittleEndian>()?;
+		let value_len = reader.read_u16::<LittleEndian>()?;
+
+		if let Some(value) = read_value(reader, data_type, u32::from(value_len))? {
+			tag.attributes.push(AsfAttribute::new(name, value));
+		}
+	}
+
+	Ok(())
+}
+
+/// Reads either a Metadata Object or a Metadata Library Object
+///
+/// These objects are identical, other than the Metadata Object not supporting languages or GUID values.
+pub(crate) fn read_metadata(mut content: &[u8], tag: &mut AsfTag) -> Result<()> {
+	let reader = &mut content;
+
+	let count = reader.read_u16::<LittleEndian>()?;
+	for _ in 0..count {
+		// This is reserved in the Metadata Object, and should always be 0
+		let language_index = reader.read_u16::<LittleEndian>()?;
+		let stream_number = reader.read_u16::<LittleEndian>()?;
+		let name_len = reader.read_u16::<LittleEndian>()?;
+		let data_type = reader.read_u16::<LittleEndian>()?;
+		let value_len = reader.read_u32::<LittleEndian>()?;
+
+		let name = read_utf16(reader, usize::from(name_len))?;
+
+		if let Some(value) = read_value(reader, data_type, value_len)? {
+			tag.attributes.push(AsfAttribute {
+				name,
+				value,
+				stream_number,
+				language_index,
+			});
+		}
+	}
+
+	Ok(())
+}
+
+fn read_value(reader: &mut &[u8], data_type: u16, len: u32) -> Result<Option<AsfAttributeValue>> {
+	let len = len as usize;
+	if len > reader.len() {
+		decode_err!(@BAIL Asf, "Attribute value is larger than its object");
+	}
+
+	let (mut data, remaining) = reader.split_at(len);
+	*reader = remaining;
+
+	let value = match data_type {
+		0 => AsfAttributeValue::String(read_utf16(&mut data, len)?),
+		1 => AsfAttributeValue::Binary(data.to_vec()),
+		// Booleans are 32-bit in the Extended Content Description Object, and 16-bit elsewhere
+		2 => AsfAttributeValue::Bool(data.iter().any(|b| *b != 0)),
+		3 => AsfAttributeValue::DWord(data.read_u32::<LittleEndian>()?),
+		4 => AsfAttributeValue::QWord(data.read_u64::<LittleEndian>()?),
+		5 => AsfAttributeValue::Word(data.read_u16::<LittleEndian>()?),
+		6 => {
+			let mut guid = [0; 16];
+			data.read_exact(&mut guid)?;
+			AsfAttributeValue::Guid(guid)
+		},
+		_ => {
+			log::warn!(
+				"Encountered an attribute with an unknown data type ({data_type}), discarding"
+			);
+			return Ok(None);
+		},
+	};
+
+	Ok(Some(value))
+}
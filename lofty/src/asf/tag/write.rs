@@ -0,0 +1,362 @@
+use super::{AsfAttribute, AsfAttributeValue, CONTENT_DESCRIPTION_NAMES};
+use crate::asf::object::{
+	encode_utf16, read_content, write_object, Guid, ObjectHeader, Objects,
+	CONTENT_DESCRIPTION_OBJECT, EXTENDED_CONTENT_DESCRIPTION_OBJECT, FILE_PROPERTIES_OBJECT,
+	HEADER_EXTENSION_OBJECT, HEADER_EXTENSION_RESERVED, HEADER_OBJECT, HEADER_OBJECT_HEADER_SIZE,
+	METADATA_LIBRARY_OBJECT, METADATA_OBJECT, OBJECT_HEADER_SIZE,
+};
+use crate::asf::properties::BROADCAST_FLAG;
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::macros::{decode_err, err};
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::io::SeekFrom;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// The reserved bytes following the object count in the header object
+const HEADER_OBJECT_RESERVED: [u8; 2] = [0x01, 0x02];
+
+/// The size of the fields preceding the nested objects in the Header Extension Object
+const HEADER_EXTENSION_DATA_OFFSET: usize = 22;
+
+pub(super) fn write_to<'a, F, I>(
+	file: &mut F,
+	attributes: I,
+	_write_options: WriteOptions,
+) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+	I: Iterator<Item = &'a AsfAttribute>,
+{
+	file.rewind()?;
+
+	let header = ObjectHeader::read(file)?;
+	if header.guid != HEADER_OBJECT || header.size < HEADER_OBJECT_HEADER_SIZE {
+		err!(UnknownFormat);
+	}
+
+	let _object_count = file.read_u32::<LittleEndian>()?;
+	let mut reserved = [0; 2];
+	file.read_exact(&mut reserved)?;
+
+	let content = read_content(file, header.size - HEADER_OBJECT_HEADER_SIZE)?;
+
+	let metadata_objects = MetadataObjects::new(attributes)?;
+
+	let mut new_content = Vec::with_capacity(content.len());
+	let mut object_count = 0_u32;
+	let mut file_properties_pos = None;
+	let mut wrote_header_extension = false;
+
+	for object in Objects::new(&content) {
+		let (guid, object_content) = object?;
+		match guid {
+			// These are replaced entirely
+			CONTENT_DESCRIPTION_OBJECT | EXTENDED_CONTENT_DESCRIPTION_OBJECT => continue,
+			HEADER_EXTENSION_OBJECT if !wrote_header_extension => {
+				write_header_extension(&mut new_content, Some(object_content), &metadata_objects)?;
+				wrote_header_extension = true;
+			},
+			FILE_PROPERTIES_OBJECT => {
+				file_properties_pos = Some(new_content.len());
+				write_object(&mut new_content, guid, object_content);
+			},
+			_ => write_object(&mut new_content, guid, object_content),
+		}
+
+		object_count += 1;
+	}
+
+	if !wrote_header_extension && metadata_objects.needs_header_extension() {
+		write_header_extension(&mut new_content, None, &metadata_objects)?;
+		object_count += 1;
+	}
+
+	object_count += metadata_objects.write_top_level(&mut new_content);
+
+	let mut new_header = Vec::with_capacity(new_content.len() + HEADER_OBJECT_HEADER_SIZE as usize);
+	write_header_object(&mut new_header, object_count, reserved, &new_content);
+
+	file.seek(SeekFrom::Start(header.size))?;
+	let mut remaining = Vec::new();
+	file.read_to_end(&mut remaining)?;
+
+	// The File Properties Object stores the size of the entire file, which has now changed
+	if let Some(pos) = file_properties_pos {
+		let pos = HEADER_OBJECT_HEADER_SIZE as usize + pos + OBJECT_HEADER_SIZE as usize;
+		let file_size = (new_header.len() + remaining.len()) as u64;
+		update_file_size(&mut new_header[pos..], file_size)?;
+	}
+
+	log::debug!(
+		"Rewriting ASF header object, old size: {}, new size: {}",
+		header.size,
+		new_header.len()
+	);
+
+	file.rewind()?;
+	file.truncate(0)?;
+	file.write_all(&new_header)?;
+	file.write_all(&remaining)?;
+
+	Ok(())
+}
+
+/// Creates a header object containing only the metadata objects
+pub(super) fn create_header<'a, I>(attributes: I, _write_options: WriteOptions) -> Result<Vec<u8>>
+where
+	I: Iterator<Item = &'a AsfAttribute>,
+{
+	let metadata_objects = MetadataObjects::new(attributes)?;
+
+	let mut content = Vec::new();
+	let mut object_count = 0;
+
+	object_count += metadata_objects.write_top_level(&mut content);
+
+	if metadata_objects.needs_header_extension() {
+		write_header_extension(&mut content, None, &metadata_objects)?;
+		object_count += 1;
+	}
+
+	let mut header = Vec::with_capacity(content.len() + HEADER_OBJECT_HEADER_SIZE as usize);
+	write_header_object(&mut header, object_count, HEADER_OBJECT_RESERVED, &content);
+
+	Ok(header)
+}
+
+fn write_header_object(writer: &mut Vec<u8>, object_count: u32, reserved: [u8; 2], content: &[u8]) {
+	writer.extend(HEADER_OBJECT);
+	writer.extend((content.len() as u64 + HEADER_OBJECT_HEADER_SIZE).to_le_bytes());
+	writer.extend(object_count.to_le_bytes());
+	writer.extend(reserved);
+	writer.extend(content);
+}
+
+fn update_file_size(file_properties: &mut [u8], file_size: u64) -> Result<()> {
+	// File ID (16), file size (8), creation date (8), data packets count (8), play duration (8),
+	// send duration (8), preroll (8), flags (4)
+	if file_properties.len() < 68 {
+		decode_err!(@BAIL Asf, "File Properties Object is too small");
+	}
+
+	let flags = (&file_properties[64..68]).read_u32::<LittleEndian>()?;
+	if flags & BROADCAST_FLAG == 0 {
+		file_properties[16..24].copy_from_slice(&file_size.to_le_bytes());
+	}
+
+	Ok(())
+}
+
+/// Writes a Header Extension Object, replacing any existing metadata objects
+fn write_header_extension(
+	writer: &mut Vec<u8>,
+	existing: Option<&[u8]>,
+	metadata_objects: &MetadataObjects,
+) -> Result<()> {
+	let mut content = Vec::new();
+
+	match existing {
+		Some(existing) => {
+			if existing.len() < HEADER_EXTENSION_DATA_OFFSET {
+				decode_err!(@BAIL Asf, "Header Extension Object is too small");
+			}
+
+			// Reserved fields
+			content.extend(&existing[..18]);
+		},
+		None => {
+			content.extend(HEADER_EXTENSION_RESERVED);
+			content.extend(6_u16.to_le_bytes());
+		},
+	}
+
+	// Placeholder for the data size
+	content.extend([0; 4]);
+
+	if let Some(existing) = existing {
+		for object in Objects::new(&existing[HEADER_EXTENSION_DATA_OFFSET..]) {
+			let (guid, object_content) = object?;
+			match guid {
+				METADATA_OBJECT | METADATA_LIBRARY_OBJECT => {},
+				_ => write_object(&mut content, guid, object_content),
+			}
+		}
+	}
+
+	if let Some(metadata) = &metadata_objects.metadata {
+		content.extend(metadata);
+	}
+
+	if let Some(metadata_library) = &metadata_objects.metadata_library {
+		content.extend(metadata_library);
+	}
+
+	let data_size = (content.len() - HEADER_EXTENSION_DATA_OFFSET) as u32;
+	content[18..HEADER_EXTENSION_DATA_OFFSET].copy_from_slice(&data_size.to_le_bytes());
+
+	write_object(writer, HEADER_EXTENSION_OBJECT, &content);
+	Ok(())
+}
+
+/// A list of attributes in an object, prefixed with a 16-bit count
+#[derive(Default)]
+struct AttributeList {
+	count: u16,
+	content: Vec<u8>,
+}
+
+impl AttributeList {
+	fn push(&mut self) -> Result<&mut Vec<u8>> {
+		let Some(count) = self.count.checked_add(1) else {
+			err!(TooMuchData);
+		};
+
+		self.count = count;
+		Ok(&mut self.content)
+	}
+
+	fn into_object(self, guid: Guid) -> Option<Vec<u8>> {
+		if self.count == 0 {
+			return None;
+		}
+
+		let mut content = Vec::with_capacity(self.content.len() + 2);
+		content.extend(self.count.to_le_bytes());
+		content.extend(self.content);
+
+		let mut object = Vec::new();
+		write_object(&mut object, guid, &content);
+		Some(object)
+	}
+}
+
+/// All of the objects that can hold attributes, already encoded
+struct MetadataObjects {
+	content_description: Option<Vec<u8>>,
+	extended_content_description: Option<Vec<u8>>,
+	metadata: Option<Vec<u8>>,
+	metadata_library: Option<Vec<u8>>,
+}
+
+impl MetadataObjects {
+	fn new<'a, I>(attributes: I) -> Result<Self>
+	where
+		I: Iterator<Item = &'a AsfAttribute>,
+	{
+		let mut content_description: [Option<Vec<u8>>; CONTENT_DESCRIPTION_NAMES.len()] =
+			Default::default();
+		let mut extended_content_description = AttributeList::default();
+		let mut metadata = AttributeList::default();
+		let mut metadata_library = AttributeList::default();
+
+		for attribute in attributes {
+			let is_guid = matches!(attribute.value, AsfAttributeValue::Guid(_));
+
+			if let (true, AsfAttributeValue::String(text)) =
+				(attribute.is_global(), &attribute.value)
+			{
+				if let Some(pos) = CONTENT_DESCRIPTION_NAMES
+					.iter()
+					.position(|name| *name == attribute.name)
+				{
+					let encoded = encode_utf16(text);
+					if content_description[pos].is_none() && u16::try_from(encoded.len()).is_ok() {
+						content_description[pos] = Some(encoded);
+						continue;
+					}
+				}
+			}
+
+			let name = encode_utf16(&attribute.name);
+			let Ok(name_len) = u16::try_from(name.len()) else {
+				err!(TooMuchData);
+			};
+
+			let data_type = attribute.value.data_type().to_le_bytes();
+
+			if attribute.is_global() && !is_guid {
+				let value = attribute.value.encode(true);
+				if let Ok(value_len) = u16::try_from(value.len()) {
+					let writer = extended_content_description.push()?;
+					writer.extend(name_len.to_le_bytes());
+					writer.extend(name);
+					writer.extend(data_type);
+					writer.extend(value_len.to_le_bytes());
+					writer.extend(value);
+					continue;
+				}
+			}
+
+			let value = attribute.value.encode(false);
+			let Ok(value_len) = u32::try_from(value.len()) else {
+				err!(TooMuchData);
+			};
+
+			// The Metadata Object doesn't support languages or GUIDs
+			let writer = if attribute.language_index == 0 && !is_guid {
+				metadata.push()?
+			} else {
+				metadata_library.push()?
+			};
+
+			writer.extend(attribute.language_index.to_le_bytes());
+			writer.extend(attribute.stream_number.to_le_bytes());
+			writer.extend(name_len.to_le_bytes());
+			writer.extend(data_type);
+			writer.extend(value_len.to_le_bytes());
+			writer.extend(name);
+			writer.extend(value);
+		}
+
+		let content_description = if content_description.iter().any(Option::is_some) {
+			let mut content = Vec::new();
+			for value in &content_description {
+				content.extend((value.as_ref().map_or(0, Vec::len) as u16).to_le_bytes());
+			}
+
+			for value in content_description.into_iter().flatten() {
+				content.extend(value);
+			}
+
+			let mut object = Vec::new();
+			write_object(&mut object, CONTENT_DESCRIPTION_OBJECT, &content);
+			Some(object)
+		} else {
+			None
+		};
+
+		Ok(Self {
+			content_description,
+			extended_content_description: extended_content_description
+				.into_object(EXTENDED_CONTENT_DESCRIPTION_OBJECT),
+			metadata: metadata.into_object(METADATA_OBJECT),
+			metadata_library: metadata_library.into_object(METADATA_LIBRARY_OBJECT),
+		})
+	}
+
+	fn needs_header_extension(&self) -> bool {
+		self.metadata.is_some() || self.metadata_library.is_some()
+	}
+
+	/// Writes the objects that belong directly in the header object, returning the number written
+	fn write_top_level(&self, writer: &mut Vec<u8>) -> u32 {
+		let mut count = 0;
+		for object in [
+			&self.content_description,
+			&self.extended_content_description,
+		]
+		.into_iter()
+		.flatten()
+		{
+			writer.extend(object);
+			count += 1;
+		}
+
+		count
+	}
+}
@@ -0,0 +1,64 @@
+use crate::error::Result;
+use crate::macros::decode_err;
+
+use std::io::Read;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+/// The size of a chunk header (type + 64-bit size)
+pub(super) const CHUNK_HEADER_SIZE: u64 = 12;
+
+/// Verify the file header, leaving the reader at the first chunk
+pub(super) fn verify_caf<R>(reader: &mut R) -> Result<()>
+where
+	R: Read,
+{
+	let mut header = [0; 8];
+	reader.read_exact(&mut header)?;
+
+	if &header[..4] != b"caff" {
+		decode_err!(@BAIL Caf, "File missing \"caff\" header");
+	}
+
+	// Only version 1 exists
+	if header[4..6] != [0, 1] {
+		decode_err!(@BAIL Caf, "Encountered an unknown file version");
+	}
+
+	log::debug!("File verified to be CAF");
+	Ok(())
+}
+
+#[derive(Copy, Clone, Debug)]
+pub(super) struct ChunkHeader {
+	pub(super) fourcc: [u8; 4],
+	/// The size of the chunk's content
+	///
+	/// This is `None` for a `data` chunk with an unknown size, which can only
+	/// happen if it is the last chunk in the file.
+	pub(super) size: Option<u64>,
+}
+
+impl ChunkHeader {
+	/// Read the next chunk header, returning `None` at the end of the stream
+	pub(super) fn read<R>(reader: &mut R) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let mut fourcc = [0; 4];
+		match reader.read_exact(&mut fourcc) {
+			Ok(()) => {},
+			Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+			Err(e) => return Err(e.into()),
+		}
+
+		let size = reader.read_i64::<BigEndian>()?;
+		let size = match size {
+			-1 if &fourcc == b"data" => None,
+			0.. => Some(size as u64),
+			_ => decode_err!(@BAIL Caf, "Encountered a chunk with a negative size"),
+		};
+
+		Ok(Some(Self { fourcc, size }))
+	}
+}
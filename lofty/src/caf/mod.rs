@@ -0,0 +1,31 @@
+//! CAF (Core Audio Format) specific items
+//!
+//! ## File notes
+//!
+//! CAF files are made up of chunks with 64-bit sizes, following an 8 byte file header. The only
+//! metadata storage is the `info` chunk, a dictionary of string pairs, see [`CafInfo`].
+//!
+//! For VBR formats (such as AAC and ALAC), the duration is taken from the `pakt` chunk.
+
+mod chunk;
+mod properties;
+mod read;
+pub(crate) mod tag;
+pub(crate) mod write;
+
+use lofty_attr::LoftyFile;
+
+// Exports
+pub use properties::{CafCodec, CafProperties};
+pub use tag::CafInfo;
+
+/// A CAF file
+#[derive(LoftyFile, Default)]
+#[lofty(read_fn = "read::read_from")]
+pub struct CafFile {
+	/// The `info` chunk
+	#[lofty(tag_type = "CafInfo")]
+	pub(crate) info_tag: Option<CafInfo>,
+	/// The file's audio properties
+	pub(crate) properties: CafProperties,
+}
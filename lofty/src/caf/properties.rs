@@ -0,0 +1,293 @@
+use crate::config::ParsingMode;
+use crate::error::Result;
+use crate::macros::{decode_err, parse_mode_choice};
+use crate::mp4::AlacSpecificConfig;
+use crate::properties::FileProperties;
+
+use std::io::Read;
+use std::time::Duration;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+/// The audio codec of a CAF file
+#[allow(missing_docs)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum CafCodec {
+	#[default]
+	Unknown,
+	/// Linear PCM
+	Lpcm,
+	/// Apple IMA 4:1 ADPCM
+	Ima4,
+	/// Any MPEG-4 AAC variant
+	Aac,
+	Alac,
+	Mp3,
+	/// ITU-T G.711 µ-law
+	ULaw,
+	/// ITU-T G.711 A-law
+	ALaw,
+	Flac,
+	Opus,
+	Ac3,
+}
+
+impl CafCodec {
+	fn from_format_id(format_id: [u8; 4]) -> Self {
+		match &format_id {
+			b"lpcm" => Self::Lpcm,
+			b"ima4" => Self::Ima4,
+			b"aac " | b"aach" | b"aacp" | b"aacl" | b"aace" | b"aacf" | b"aacg" => Self::Aac,
+			b"alac" => Self::Alac,
+			b".mp3" => Self::Mp3,
+			b"ulaw" => Self::ULaw,
+			b"alaw" => Self::ALaw,
+			b"flac" => Self::Flac,
+			b"opus" => Self::Opus,
+			b"ac-3" => Self::Ac3,
+			_ => Self::Unknown,
+		}
+	}
+}
+
+/// A CAF file's audio properties
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct CafProperties {
+	pub(crate) codec: CafCodec,
+	pub(crate) format_id: [u8; 4],
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) bit_depth: Option<u8>,
+	pub(crate) channels: u8,
+	pub(crate) alac_config: Option<AlacSpecificConfig>,
+}
+
+impl From<CafProperties> for FileProperties {
+	fn from(input: CafProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			bit_depth: input.bit_depth,
+			channels: Some(input.channels),
+			channel_mask: None,
+		}
+	}
+}
+
+impl CafProperties {
+	/// Audio codec
+	pub fn codec(&self) -> CafCodec {
+		self.codec
+	}
+
+	/// The raw format ID from the `desc` chunk
+	///
+	/// This is useful for identifying a [`CafCodec::Unknown`] codec.
+	pub fn format_id(&self) -> [u8; 4] {
+		self.format_id
+	}
+
+	/// Duration of the audio
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn audio_bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Bits per sample
+	///
+	/// This is only available for uncompressed and lossless codecs.
+	pub fn bit_depth(&self) -> Option<u8> {
+		self.bit_depth
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// ALAC-specific information, from the `kuki` chunk
+	///
+	/// This is only applicable to files with the [`CafCodec::Alac`] codec.
+	pub fn alac_config(&self) -> Option<&AlacSpecificConfig> {
+		self.alac_config.as_ref()
+	}
+}
+
+/// The contents of the `desc` chunk
+pub(super) struct AudioDescription {
+	sample_rate: f64,
+	format_id: [u8; 4],
+	format_flags: u32,
+	bytes_per_packet: u32,
+	frames_per_packet: u32,
+	channels_per_frame: u32,
+	bits_per_channel: u32,
+}
+
+impl AudioDescription {
+	/// The size of the `desc` chunk content
+	pub(super) const SIZE: u64 = 32;
+
+	pub(super) fn read<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read,
+	{
+		let sample_rate = reader.read_f64::<BigEndian>()?;
+
+		let mut format_id = [0; 4];
+		reader.read_exact(&mut format_id)?;
+
+		Ok(Self {
+			sample_rate,
+			format_id,
+			format_flags: reader.read_u32::<BigEndian>()?,
+			bytes_per_packet: reader.read_u32::<BigEndian>()?,
+			frames_per_packet: reader.read_u32::<BigEndian>()?,
+			channels_per_frame: reader.read_u32::<BigEndian>()?,
+			bits_per_channel: reader.read_u32::<BigEndian>()?,
+		})
+	}
+}
+
+/// The header of the `pakt` chunk
+///
+/// The packet table itself is not needed.
+pub(super) struct PacketTableHeader {
+	number_packets: i64,
+	number_valid_frames: i64,
+	priming_frames: i32,
+	remainder_frames: i32,
+}
+
+impl PacketTableHeader {
+	/// The size of the `pakt` chunk header
+	pub(super) const SIZE: u64 = 24;
+
+	pub(super) fn read<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read,
+	{
+		Ok(Self {
+			number_packets: reader.read_i64::<BigEndian>()?,
+			number_valid_frames: reader.read_i64::<BigEndian>()?,
+			priming_frames: reader.read_i32::<BigEndian>()?,
+			remainder_frames: reader.read_i32::<BigEndian>()?,
+		})
+	}
+}
+
+pub(super) fn read_properties(
+	desc: &AudioDescription,
+	packet_table: Option<&PacketTableHeader>,
+	magic_cookie: Option<&[u8]>,
+	audio_data_size: u64,
+	file_length: u64,
+	parse_mode: ParsingMode,
+) -> Result<CafProperties> {
+	let codec = CafCodec::from_format_id(desc.format_id);
+
+	let mut properties = CafProperties {
+		codec,
+		format_id: desc.format_id,
+		sample_rate: desc.sample_rate.round() as u32,
+		channels: desc.channels_per_frame as u8,
+		..CafProperties::default()
+	};
+
+	if desc.bits_per_channel > 0 {
+		properties.bit_depth = Some(desc.bits_per_channel as u8);
+	}
+
+	if codec == CafCodec::Alac {
+		if let Some(config) = magic_cookie.and_then(alac_config) {
+			properties.bit_depth = Some(config.bit_depth);
+			properties.alac_config = Some(config);
+		} else {
+			// The source bit depth is also stored in the format flags
+			properties.bit_depth = match desc.format_flags {
+				1 => Some(16),
+				2 => Some(20),
+				3 => Some(24),
+				4 => Some(32),
+				_ => None,
+			};
+		}
+	}
+
+	if properties.sample_rate == 0 {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Caf, "Sample rate is 0"),
+			DEFAULT: return Ok(properties)
+		);
+	}
+
+	let total_frames = match packet_table {
+		// VBR formats need a packet table, which directly stores the number of frames
+		Some(packet_table) if packet_table.number_valid_frames > 0 => {
+			packet_table.number_valid_frames as u64
+		},
+		// Some encoders only fill out the packet count
+		Some(packet_table) if desc.frames_per_packet > 0 && packet_table.number_packets > 0 => {
+			let padding = u64::try_from(packet_table.priming_frames).unwrap_or(0)
+				+ u64::try_from(packet_table.remainder_frames).unwrap_or(0);
+
+			(packet_table.number_packets as u64)
+				.saturating_mul(u64::from(desc.frames_per_packet))
+				.saturating_sub(padding)
+		},
+		_ if desc.bytes_per_packet > 0 && desc.frames_per_packet > 0 => {
+			(audio_data_size / u64::from(desc.bytes_per_packet)) * u64::from(desc.frames_per_packet)
+		},
+		_ => {
+			log::warn!("Unable to determine the number of frames, no duration available");
+			return Ok(properties);
+		},
+	};
+
+	let length = u128::from(total_frames) * 1000 / u128::from(properties.sample_rate);
+	let length = u64::try_from(length).unwrap_or(u64::MAX);
+	if length > 0 {
+		properties.duration = Duration::from_millis(length);
+		properties.overall_bitrate = (file_length.saturating_mul(8) / length) as u32;
+		properties.audio_bitrate = (audio_data_size.saturating_mul(8) / length) as u32;
+	}
+
+	Ok(properties)
+}
+
+/// Extract the `ALACSpecificConfig` from the magic cookie
+///
+/// The config may be preceded by `frma` and `alac` atoms, like in MP4 files.
+fn alac_config(mut cookie: &[u8]) -> Option<AlacSpecificConfig> {
+	if cookie.len() >= 12 && &cookie[4..8] == b"frma" {
+		cookie = &cookie[12..];
+	}
+
+	// The `alac` atom has an additional 4 bytes for its version and flags
+	if cookie.len() >= 12 && &cookie[4..8] == b"alac" {
+		cookie = &cookie[12..];
+	}
+
+	AlacSpecificConfig::read(&mut cookie).ok()
+}
@@ -0,0 +1,98 @@
+use super::chunk::{verify_caf, ChunkHeader};
+use super::properties::{AudioDescription, PacketTableHeader};
+use super::tag::read::parse_info;
+use super::CafFile;
+use crate::config::ParseOptions;
+use crate::error::Result;
+use crate::macros::decode_err;
+use crate::util::io::SeekStreamLen;
+
+use std::io::{Read, Seek, SeekFrom};
+
+pub(super) fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<CafFile>
+where
+	R: Read + Seek,
+{
+	let file_length = reader.stream_len_hack()?;
+
+	verify_caf(reader)?;
+
+	let mut desc = None;
+	let mut packet_table = None;
+	let mut magic_cookie = None;
+	let mut audio_data_size = 0;
+
+	let mut file = CafFile::default();
+
+	while let Some(chunk) = ChunkHeader::read(reader)? {
+		let chunk_start = reader.stream_position()?;
+
+		let Some(size) = chunk.size else {
+			// A `data` chunk of unknown size extends to the end of the file
+			audio_data_size = file_length.saturating_sub(chunk_start);
+			break;
+		};
+
+		if chunk_start + size > file_length {
+			decode_err!(@BAIL Caf, "Chunk size exceeds the file length");
+		}
+
+		match &chunk.fourcc {
+			b"desc" => {
+				if size < AudioDescription::SIZE {
+					decode_err!(@BAIL Caf, "Audio description chunk is too small");
+				}
+
+				desc = Some(AudioDescription::read(reader)?);
+			},
+			b"pakt" if parse_options.read_properties => {
+				if size < PacketTableHeader::SIZE {
+					decode_err!(@BAIL Caf, "Packet table chunk is too small");
+				}
+
+				packet_table = Some(PacketTableHeader::read(reader)?);
+			},
+			b"kuki" if parse_options.read_properties => {
+				let mut content = Vec::new();
+				reader.by_ref().take(size).read_to_end(&mut content)?;
+
+				magic_cookie = Some(content);
+			},
+			b"info" if parse_options.read_tags => {
+				if file.info_tag.is_some() {
+					log::warn!("Encountered multiple `info` chunks, ignoring");
+				} else {
+					let mut content = Vec::new();
+					reader.by_ref().take(size).read_to_end(&mut content)?;
+
+					file.info_tag = Some(parse_info(&content, parse_options.parsing_mode)?);
+				}
+			},
+			b"data" => {
+				// Account for the edit count
+				audio_data_size = size.saturating_sub(4);
+			},
+			// `free` chunks, and anything else we don't care about
+			_ => {},
+		}
+
+		reader.seek(SeekFrom::Start(chunk_start + size))?;
+	}
+
+	let Some(desc) = desc else {
+		decode_err!(@BAIL Caf, "File missing audio description (`desc`) chunk");
+	};
+
+	if parse_options.read_properties {
+		file.properties = super::properties::read_properties(
+			&desc,
+			packet_table.as_ref(),
+			magic_cookie.as_deref(),
+			audio_data_size,
+			file_length,
+			parse_options.parsing_mode,
+		)?;
+	}
+
+	Ok(file)
+}
@@ -0,0 +1,435 @@
+pub(super) mod read;
+pub(crate) mod write;
+
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::tag::{
+	try_parse_year, Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType,
+};
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::borrow::Cow;
+use std::io::Write;
+
+use lofty_attr::tag;
+
+macro_rules! impl_accessor {
+	($($name:ident => $key:literal;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<Cow<'_, str>> {
+					self.get($key).map(Cow::Borrowed)
+				}
+
+				fn [<set_ $name>](&mut self, value: String) {
+					self.insert(String::from($key), value)
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					let _ = self.remove($key);
+				}
+			)+
+		}
+	}
+}
+
+/// A CAF `info` chunk
+///
+/// This is a dictionary of UTF-8 key/value pairs. Apple defines a set of common keys (such as
+/// `artist` and `title`), though any key is allowed.
+///
+/// ## Conversions
+///
+/// ### To `Tag`
+///
+/// All items will be converted to a [`TagItem`], with all unknown keys being stored with [`ItemKey::Unknown`].
+///
+/// ### From `Tag`
+///
+/// When converting a [`TagItem`], two conditions must be met:
+///
+/// * The [`TagItem`] has a value other than [`ItemValue::Binary`](crate::ItemValue::Binary)
+/// * It has a key that is not empty, and contains no null bytes
+#[derive(Default, Debug, PartialEq, Eq, Clone)]
+#[tag(description = "A CAF `info` chunk", supported_formats(Caf))]
+pub struct CafInfo {
+	/// A collection of key-value pairs
+	pub(crate) items: Vec<(String, String)>,
+}
+
+impl CafInfo {
+	/// Create a new empty `CafInfo`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::caf::CafInfo;
+	/// use lofty::tag::TagExt;
+	///
+	/// let caf_info_tag = CafInfo::new();
+	/// assert!(caf_info_tag.is_empty());
+	/// ```
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Get an item by key
+	pub fn get(&self, key: &str) -> Option<&str> {
+		self.items
+			.iter()
+			.find(|(k, _)| k.eq_ignore_ascii_case(key))
+			.map(|(_, v)| v.as_str())
+	}
+
+	/// Insert an item
+	///
+	/// NOTE: This will do nothing if `key` is invalid
+	///
+	/// This will case-insensitively replace any item with the same key
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::caf::CafInfo;
+	///
+	/// let mut caf_info_tag = CafInfo::new();
+	/// caf_info_tag.insert(String::from("artist"), String::from("Foo artist"));
+	///
+	/// assert_eq!(caf_info_tag.get("artist"), Some("Foo artist"));
+	/// ```
+	pub fn insert(&mut self, key: String, value: String) {
+		if read::verify_key(key.as_str()) {
+			self.items
+				.iter()
+				.position(|(k, _)| k.eq_ignore_ascii_case(key.as_str()))
+				.map(|p| self.items.remove(p));
+			self.items.push((key, value))
+		}
+	}
+
+	/// Remove an item by key
+	///
+	/// This will case-insensitively remove an item with the key, returning it
+	/// if it exists.
+	pub fn remove(&mut self, key: &str) -> Option<String> {
+		if let Some((_, value)) = self
+			.items
+			.iter()
+			.position(|(k, _)| k.eq_ignore_ascii_case(key))
+			.map(|p| self.items.remove(p))
+		{
+			return Some(value);
+		}
+
+		None
+	}
+}
+
+impl Accessor for CafInfo {
+	impl_accessor!(
+		artist  => "artist";
+		title   => "title";
+		album   => "album";
+		genre   => "genre";
+		comment => "comments";
+	);
+
+	fn track(&self) -> Option<u32> {
+		if let Some(item) = self.get("track number") {
+			return item.trim().parse::<u32>().ok();
+		}
+
+		None
+	}
+
+	fn set_track(&mut self, value: u32) {
+		self.insert(String::from("track number"), value.to_string());
+	}
+
+	fn remove_track(&mut self) {
+		let _ = self.remove("track number");
+	}
+
+	fn year(&self) -> Option<u32> {
+		if let Some(item) = self.get("year") {
+			return try_parse_year(item);
+		}
+
+		None
+	}
+
+	fn set_year(&mut self, value: u32) {
+		self.insert(String::from("year"), value.to_string());
+	}
+
+	fn remove_year(&mut self) {
+		let _ = self.remove("year");
+	}
+}
+
+impl IntoIterator for CafInfo {
+	type Item = (String, String);
+	type IntoIter = std::vec::IntoIter<Self::Item>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.items.into_iter()
+	}
+}
+
+impl<'a> IntoIterator for &'a CafInfo {
+	type Item = &'a (String, String);
+	type IntoIter = std::slice::Iter<'a, (String, String)>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.items.iter()
+	}
+}
+
+impl TagExt for CafInfo {
+	type Err = LoftyError;
+	type RefKey<'a> = &'a str;
+
+	#[inline]
+	fn tag_type(&self) -> TagType {
+		TagType::CafInfo
+	}
+
+	fn len(&self) -> usize {
+		self.items.len()
+	}
+
+	fn contains<'a>(&'a self, key: Self::RefKey<'a>) -> bool {
+		self.items
+			.iter()
+			.any(|(item_key, _)| item_key.eq_ignore_ascii_case(key))
+	}
+
+	fn is_empty(&self) -> bool {
+		self.items.is_empty()
+	}
+
+	fn save_to<F>(
+		&self,
+		file: &mut F,
+		write_options: WriteOptions,
+	) -> std::result::Result<(), Self::Err>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		CafInfoRef::new(self.items.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+			.write_to(file, write_options)
+	}
+
+	fn dump_to<W: Write>(
+		&self,
+		writer: &mut W,
+		write_options: WriteOptions,
+	) -> std::result::Result<(), Self::Err> {
+		CafInfoRef::new(self.items.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+			.dump_to(writer, write_options)
+	}
+
+	fn clear(&mut self) {
+		self.items.clear();
+	}
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SplitTagRemainder;
+
+impl SplitTag for CafInfo {
+	type Remainder = SplitTagRemainder;
+
+	fn split_tag(self) -> (Self::Remainder, Tag) {
+		(SplitTagRemainder, self.into())
+	}
+}
+
+impl MergeTag for SplitTagRemainder {
+	type Merged = CafInfo;
+
+	fn merge_tag(self, tag: Tag) -> Self::Merged {
+		tag.into()
+	}
+}
+
+impl From<CafInfo> for Tag {
+	fn from(input: CafInfo) -> Self {
+		let mut tag = Self::new(TagType::CafInfo);
+
+		for (k, v) in input.items {
+			let item_key = ItemKey::from_key(TagType::CafInfo, &k);
+
+			tag.items.push(TagItem::new(item_key, ItemValue::Text(v)));
+		}
+
+		tag
+	}
+}
+
+impl From<Tag> for CafInfo {
+	fn from(input: Tag) -> Self {
+		let mut caf_info = CafInfo::default();
+
+		for item in input.items {
+			if let ItemValue::Text(val) | ItemValue::Locator(val) = item.item_value {
+				match item.item_key {
+					ItemKey::Unknown(unknown) => {
+						if read::verify_key(&unknown) {
+							caf_info.items.push((unknown, val))
+						}
+					},
+					k => {
+						if let Some(key) = k.map_key(TagType::CafInfo, false) {
+							caf_info.items.push((key.to_string(), val))
+						}
+					},
+				}
+			}
+		}
+
+		caf_info
+	}
+}
+
+pub(crate) struct CafInfoRef<'a, I>
+where
+	I: Iterator<Item = (&'a str, &'a str)>,
+{
+	pub(crate) items: I,
+}
+
+impl<'a, I> CafInfoRef<'a, I>
+where
+	I: Iterator<Item = (&'a str, &'a str)>,
+{
+	pub(crate) fn new(items: I) -> CafInfoRef<'a, I> {
+		CafInfoRef { items }
+	}
+
+	pub(crate) fn write_to<F>(&mut self, file: &mut F, write_options: WriteOptions) -> Result<()>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		write::write_caf_info(file, self, write_options)
+	}
+
+	pub(crate) fn dump_to<W: Write>(
+		&mut self,
+		writer: &mut W,
+		_write_options: WriteOptions,
+	) -> Result<()> {
+		let mut temp = Vec::new();
+		write::create_caf_info(&mut self.items, &mut temp)?;
+
+		writer.write_all(&temp)?;
+
+		Ok(())
+	}
+}
+
+pub(crate) fn tagitems_into_caf_info<'a>(
+	items: impl IntoIterator<Item = &'a TagItem>,
+) -> impl Iterator<Item = (&'a str, &'a str)> {
+	items.into_iter().filter_map(|i| {
+		let item_key = i.key().map_key(TagType::CafInfo, true);
+
+		match (item_key, i.value()) {
+			(Some(key), ItemValue::Text(val) | ItemValue::Locator(val))
+				if read::verify_key(key) =>
+			{
+				Some((key, val.as_str()))
+			},
+			_ => None,
+		}
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::caf::CafInfo;
+	use crate::config::{ParsingMode, WriteOptions};
+	use crate::prelude::*;
+	use crate::tag::{Tag, TagType};
+
+	fn read_tag(bytes: &[u8]) -> CafInfo {
+		// Skip the chunk header
+		super::read::parse_info(&bytes[12..], ParsingMode::Strict).unwrap()
+	}
+
+	#[test_log::test]
+	fn parse_caf_info() {
+		let mut expected_tag = CafInfo::default();
+
+		expected_tag.insert(String::from("title"), String::from("Foo title"));
+		expected_tag.insert(String::from("artist"), String::from("Bar artist"));
+		expected_tag.insert(String::from("album"), String::from("Baz album"));
+		expected_tag.insert(String::from("comments"), String::from("Qux comment"));
+		expected_tag.insert(String::from("track number"), String::from("1"));
+		expected_tag.insert(String::from("year"), String::from("1984"));
+
+		let tag = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.caf_info");
+		let parsed_tag = read_tag(&tag);
+
+		assert_eq!(expected_tag, parsed_tag);
+	}
+
+	#[test_log::test]
+	fn caf_info_re_read() {
+		let tag = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.caf_info");
+		let parsed_tag = read_tag(&tag);
+
+		let mut writer = Vec::new();
+		parsed_tag
+			.dump_to(&mut writer, WriteOptions::default())
+			.unwrap();
+
+		assert_eq!(tag, writer);
+
+		let temp_parsed_tag = read_tag(&writer);
+		assert_eq!(parsed_tag, temp_parsed_tag);
+	}
+
+	#[test_log::test]
+	fn caf_info_entry_count_mismatch() {
+		let mut tag = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.caf_info");
+
+		// Claim one more entry than actually exists
+		tag[15] += 1;
+
+		assert!(super::read::parse_info(&tag[12..], ParsingMode::Strict).is_err());
+
+		let parsed_tag = super::read::parse_info(&tag[12..], ParsingMode::BestAttempt).unwrap();
+		assert_eq!(parsed_tag.len(), 6);
+	}
+
+	#[test_log::test]
+	fn caf_info_to_tag() {
+		let tag_bytes = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.caf_info");
+
+		let tag: Tag = read_tag(&tag_bytes).into();
+
+		crate::tag::utils::test_utils::verify_tag(&tag, true, false);
+		assert_eq!(tag.get_string(&ItemKey::Year), Some("1984"));
+	}
+
+	#[test_log::test]
+	fn tag_to_caf_info() {
+		let mut tag = crate::tag::utils::test_utils::create_tag(TagType::CafInfo);
+		tag.insert_text(ItemKey::InitialKey, String::from("C#m"));
+
+		let caf_info: CafInfo = tag.into();
+
+		assert_eq!(caf_info.get("title"), Some("Foo title"));
+		assert_eq!(caf_info.get("artist"), Some("Bar artist"));
+		assert_eq!(caf_info.get("album"), Some("Baz album"));
+		assert_eq!(caf_info.get("comments"), Some("Qux comment"));
+		assert_eq!(caf_info.get("track number"), Some("1"));
+		assert_eq!(caf_info.get("genre"), Some("Classical"));
+		assert_eq!(caf_info.get("key signature"), Some("C#m"));
+	}
+}
@@ -0,0 +1,56 @@
+use super::CafInfo;
+use crate::config::ParsingMode;
+use crate::error::Result;
+use crate::macros::{decode_err, parse_mode_choice};
+
+use byteorder::{BigEndian, ByteOrder};
+
+/// Parse the content of an `info` chunk
+///
+/// The content starts with the number of entries, followed by that many pairs of null-terminated
+/// UTF-8 strings.
+pub(in crate::caf) fn parse_info(content: &[u8], parse_mode: ParsingMode) -> Result<CafInfo> {
+	if content.len() < 4 {
+		decode_err!(@BAIL Caf, "Information chunk is too small");
+	}
+
+	let num_entries = BigEndian::read_u32(&content[..4]);
+	log::debug!("Reading CAF info chunk, entries: {num_entries}");
+
+	let mut strings = content[4..].split(|b| *b == 0);
+	let mut tag = CafInfo::default();
+
+	for _ in 0..num_entries {
+		let (Some(key), Some(value)) = (strings.next(), strings.next()) else {
+			parse_mode_choice!(
+				parse_mode,
+				STRICT: decode_err!(@BAIL Caf, "Information chunk has fewer entries than expected"),
+				DEFAULT: break
+			);
+		};
+
+		let (Ok(key), Ok(value)) = (std::str::from_utf8(key), std::str::from_utf8(value)) else {
+			parse_mode_choice!(
+				parse_mode,
+				STRICT: decode_err!(@BAIL Caf, "Information chunk contains invalid UTF-8"),
+				DEFAULT: continue
+			);
+		};
+
+		if !verify_key(key) {
+			parse_mode_choice!(
+				parse_mode,
+				STRICT: decode_err!(@BAIL Caf, "Information chunk contains an empty key"),
+				DEFAULT: continue
+			);
+		}
+
+		tag.items.push((key.to_owned(), value.to_owned()));
+	}
+
+	Ok(tag)
+}
+
+pub(super) fn verify_key(key: &str) -> bool {
+	!key.is_empty() && !key.contains('\0')
+}
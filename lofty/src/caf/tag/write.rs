@@ -0,0 +1,135 @@
+use super::CafInfoRef;
+use crate::caf::chunk::{verify_caf, ChunkHeader, CHUNK_HEADER_SIZE};
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::macros::err;
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::io::{Read, Seek, SeekFrom};
+use std::ops::Range;
+
+pub(in crate::caf) fn write_caf_info<'a, F, I>(
+	file: &mut F,
+	tag: &mut CafInfoRef<'a, I>,
+	_write_options: WriteOptions,
+) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+	I: Iterator<Item = (&'a str, &'a str)>,
+{
+	file.rewind()?;
+	verify_caf(file)?;
+
+	let mut info_chunk_bytes = Vec::new();
+	create_caf_info(&mut tag.items, &mut info_chunk_bytes)?;
+
+	let (info_chunks, data_chunk_start) = find_chunks(file)?;
+
+	file.rewind()?;
+	let mut file_bytes = Vec::new();
+	file.read_to_end(&mut file_bytes)?;
+
+	// The new chunk takes the place of the first `info` chunk. Otherwise, it has to go before the
+	// `data` chunk, since it may extend to the end of the file.
+	let insert_pos = match info_chunks.first() {
+		Some(range) => range.start,
+		None => data_chunk_start.unwrap_or(file_bytes.len() as u64),
+	} as usize;
+
+	// Remove in reverse, so the remaining ranges stay valid
+	for range in info_chunks.into_iter().rev() {
+		log::debug!(
+			"Removing existing CAF info chunk, size: {} bytes",
+			range.end - range.start
+		);
+		file_bytes.drain(range.start as usize..range.end as usize);
+	}
+
+	let _ = file_bytes.splice(insert_pos..insert_pos, info_chunk_bytes);
+
+	file.rewind()?;
+	file.truncate(0)?;
+	file.write_all(&file_bytes)?;
+
+	Ok(())
+}
+
+/// Find the position of all `info` chunks (including headers), and the start of the `data` chunk
+fn find_chunks<R>(reader: &mut R) -> Result<(Vec<Range<u64>>, Option<u64>)>
+where
+	R: Read + Seek,
+{
+	let mut info_chunks = Vec::new();
+	let mut data_chunk_start = None;
+
+	while let Some(chunk) = ChunkHeader::read(reader)? {
+		let chunk_start = reader.stream_position()? - CHUNK_HEADER_SIZE;
+
+		if &chunk.fourcc == b"data" && data_chunk_start.is_none() {
+			data_chunk_start = Some(chunk_start);
+		}
+
+		// A `data` chunk with an unknown size is always the last chunk
+		let Some(size) = chunk.size else {
+			break;
+		};
+
+		let chunk_end = reader.seek(SeekFrom::Current(size as i64))?;
+		if &chunk.fourcc == b"info" {
+			info_chunks.push(chunk_start..chunk_end);
+		}
+	}
+
+	Ok((info_chunks, data_chunk_start))
+}
+
+pub(super) fn create_caf_info(
+	items: &mut dyn Iterator<Item = (&str, &str)>,
+	bytes: &mut Vec<u8>,
+) -> Result<()> {
+	let mut items = items.peekable();
+
+	if items.peek().is_none() {
+		log::debug!("No items to write, removing CAF info chunk");
+		return Ok(());
+	}
+
+	let mut content = Vec::new();
+	let mut num_entries = 0_u32;
+
+	for (k, v) in items {
+		if v.is_empty() || !super::read::verify_key(k) {
+			continue;
+		}
+
+		// Values can't contain null bytes, so anything after one is discarded
+		let v = v.split('\0').next().unwrap_or_default();
+
+		content.extend(k.as_bytes());
+		content.push(0);
+		content.extend(v.as_bytes());
+		content.push(0);
+
+		num_entries += 1;
+	}
+
+	if num_entries == 0 {
+		log::debug!("No valid items to write, removing CAF info chunk");
+		return Ok(());
+	}
+
+	let Ok(size) = i64::try_from(content.len() + 4) else {
+		err!(TooMuchData);
+	};
+
+	log::debug!("Created CAF info chunk, size: {} bytes", size);
+
+	bytes.extend(b"info");
+	bytes.extend(size.to_be_bytes());
+	bytes.extend(num_entries.to_be_bytes());
+	bytes.extend(content);
+
+	Ok(())
+}
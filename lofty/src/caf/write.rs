@@ -0,0 +1,20 @@
+use super::tag::{tagitems_into_caf_info, CafInfoRef};
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::macros::err;
+use crate::tag::{Tag, TagType};
+use crate::util::io::{FileLike, Length, Truncate};
+
+pub(crate) fn write_to<F>(file: &mut F, tag: &Tag, write_options: WriteOptions) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	match tag.tag_type() {
+		TagType::CafInfo => {
+			CafInfoRef::new(tagitems_into_caf_info(tag.items())).write_to(file, write_options)
+		},
+		_ => err!(UnsupportedTag),
+	}
+}
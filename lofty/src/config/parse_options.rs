@@ -1,5 +1,10 @@
+use crate::id3::v2::cipher::FrameCiphersRef;
+use crate::id3::v2::FrameCiphers;
+
+use std::sync::Arc;
+
 /// Options to control how Lofty parses a file
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub struct ParseOptions {
 	pub(crate) read_properties: bool,
@@ -8,6 +13,7 @@ pub struct ParseOptions {
 	pub(crate) max_junk_bytes: usize,
 	pub(crate) read_cover_art: bool,
 	pub(crate) implicit_conversions: bool,
+	pub(crate) id3v2_frame_ciphers: Option<FrameCiphersRef>,
 }
 
 impl Default for ParseOptions {
@@ -23,6 +29,7 @@ impl Default for ParseOptions {
 	///     max_junk_bytes: 1024,
 	///     read_cover_art: true,
 	///     implicit_conversions: true,
+	///     id3v2_frame_ciphers: None,
 	/// }
 	/// ```
 	fn default() -> Self {
@@ -57,6 +64,7 @@ impl ParseOptions {
 			max_junk_bytes: Self::DEFAULT_MAX_JUNK_BYTES,
 			read_cover_art: true,
 			implicit_conversions: true,
+			id3v2_frame_ciphers: None,
 		}
 	}
 
@@ -72,7 +80,7 @@ impl ParseOptions {
 	/// ```
 	pub fn read_properties(&mut self, read_properties: bool) -> Self {
 		self.read_properties = read_properties;
-		*self
+		self.clone()
 	}
 
 	/// Whether or not to read the tags
@@ -87,7 +95,7 @@ impl ParseOptions {
 	/// ```
 	pub fn read_tags(&mut self, read_tags: bool) -> Self {
 		self.read_tags = read_tags;
-		*self
+		self.clone()
 	}
 
 	/// The parsing mode to use, see [`ParsingMode`] for details
@@ -102,7 +110,7 @@ impl ParseOptions {
 	/// ```
 	pub fn parsing_mode(&mut self, parsing_mode: ParsingMode) -> Self {
 		self.parsing_mode = parsing_mode;
-		*self
+		self.clone()
 	}
 
 	/// The maximum number of allowed junk bytes to search
@@ -120,7 +128,7 @@ impl ParseOptions {
 	/// ```
 	pub fn max_junk_bytes(&mut self, max_junk_bytes: usize) -> Self {
 		self.max_junk_bytes = max_junk_bytes;
-		*self
+		self.clone()
 	}
 
 	/// Whether or not to read cover art
@@ -135,7 +143,7 @@ impl ParseOptions {
 	/// ```
 	pub fn read_cover_art(&mut self, read_cover_art: bool) -> Self {
 		self.read_cover_art = read_cover_art;
-		*self
+		self.clone()
 	}
 
 	/// Whether or not to perform implicit conversions
@@ -160,7 +168,29 @@ impl ParseOptions {
 	///     the non-standard `COVERART` for [`Picture`](crate::picture::Picture)s.
 	pub fn implicit_conversions(&mut self, implicit_conversions: bool) -> Self {
 		self.implicit_conversions = implicit_conversions;
-		*self
+		self.clone()
+	}
+
+	/// The [`FrameCiphers`] used to decrypt `ID3v2` frames
+	///
+	/// By default, there are no ciphers, and encrypted frames are read as
+	/// [`Frame::Binary`](crate::id3::v2::Frame::Binary).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::id3::v2::FrameCiphers;
+	/// use std::sync::Arc;
+	///
+	/// let mut ciphers = FrameCiphers::new();
+	/// // ciphers.insert("mailto:foo@example.com", MyCipher);
+	///
+	/// let parsing_options = ParseOptions::new().id3v2_frame_ciphers(Arc::new(ciphers));
+	/// ```
+	pub fn id3v2_frame_ciphers(&mut self, id3v2_frame_ciphers: Arc<FrameCiphers>) -> Self {
+		self.id3v2_frame_ciphers = Some(FrameCiphersRef(id3v2_frame_ciphers));
+		self.clone()
 	}
 }
 
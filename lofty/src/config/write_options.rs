@@ -1,8 +1,13 @@
+use crate::id3::v2::cipher::FrameCiphersRef;
+use crate::id3::v2::FrameCiphers;
+
+use std::sync::Arc;
+
 /// Options to control how Lofty writes to a file
 ///
 /// This acts as a dumping ground for all sorts of format-specific settings. As such, this is best
 /// used as an application global config that gets set once.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub struct WriteOptions {
 	pub(crate) preferred_padding: Option<u32>,
@@ -10,6 +15,12 @@ pub struct WriteOptions {
 	pub(crate) respect_read_only: bool,
 	pub(crate) uppercase_id3v2_chunk: bool,
 	pub(crate) use_id3v23: bool,
+	pub(crate) use_id3v22: bool,
+	pub(crate) preserve_id3v2_version: bool,
+	pub(crate) id3v2_unsynchronisation: bool,
+	pub(crate) id3v2_footer: bool,
+	pub(crate) id3v2_crc: bool,
+	pub(crate) id3v2_frame_ciphers: Option<FrameCiphersRef>,
 }
 
 impl WriteOptions {
@@ -34,6 +45,12 @@ impl WriteOptions {
 			respect_read_only: true,
 			uppercase_id3v2_chunk: true,
 			use_id3v23: false,
+			use_id3v22: false,
+			preserve_id3v2_version: false,
+			id3v2_unsynchronisation: false,
+			id3v2_footer: false,
+			id3v2_crc: false,
+			id3v2_frame_ciphers: None,
 		}
 	}
 
@@ -175,7 +192,209 @@ impl WriteOptions {
 	/// ```
 	pub fn use_id3v23(&mut self, use_id3v23: bool) -> Self {
 		self.use_id3v23 = use_id3v23;
-		*self
+		self.clone()
+	}
+
+	/// Whether or not to use ID3v2.2 when saving [`TagType::Id3v2`](crate::tag::TagType::Id3v2)
+	/// or [`Id3v2Tag`](crate::id3::v2::Id3v2Tag)
+	///
+	/// This is only intended for legacy software and hardware that can't handle newer versions.
+	/// Frames are converted to their 3 character IDs, and any without an ID3v2.2 equivalent are
+	/// discarded, as are encrypted frames. ID3v2.2 has no extended header or footer, so
+	/// [`Id3v2TagFlags::crc`](crate::id3::v2::Id3v2TagFlags::crc),
+	/// [`Id3v2TagFlags::restrictions`](crate::id3::v2::Id3v2TagFlags::restrictions), and
+	/// [`Id3v2TagFlags::footer`](crate::id3::v2::Id3v2TagFlags::footer) are ignored.
+	///
+	/// NOTE: This takes precedence over [`WriteOptions::use_id3v23`]
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// // My old MP3 player only understands ID3v2.2 tags
+	/// let options = WriteOptions::new().use_id3v22(true);
+	/// id3v2_tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn use_id3v22(&mut self, use_id3v22: bool) -> Self {
+		self.use_id3v22 = use_id3v22;
+		self.clone()
+	}
+
+	/// Whether or not to save [`TagType::Id3v2`](crate::tag::TagType::Id3v2) and
+	/// [`Id3v2Tag`](crate::id3::v2::Id3v2Tag) in the version they were originally read in
+	///
+	/// See [`Id3v2Tag::original_version`](crate::id3::v2::Id3v2Tag::original_version). This allows
+	/// for editing files without migrating them to another version.
+	///
+	/// NOTES:
+	///
+	/// * This takes precedence over [`WriteOptions::use_id3v23`] and [`WriteOptions::use_id3v22`]
+	/// * Tags that weren't read from a file are considered ID3v2.4
+	/// * For a [`Tag`](crate::tag::Tag), the original version is only known if
+	///   [`GlobalOptions::preserve_format_specific_items`](crate::config::GlobalOptions::preserve_format_specific_items)
+	///   is enabled. Otherwise, it is considered ID3v2.4.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::prelude::*;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tagged_file = lofty::read_from_path("test.mp3")?;
+	///
+	/// if let Some(tag) = tagged_file.primary_tag_mut() {
+	/// 	tag.set_title(String::from("Foo title"));
+	/// }
+	///
+	/// // The ID3v2.3 tag in this file needs to stay ID3v2.3
+	/// let options = WriteOptions::new().preserve_id3v2_version(true);
+	/// tagged_file.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn preserve_id3v2_version(&mut self, preserve_id3v2_version: bool) -> Self {
+		self.preserve_id3v2_version = preserve_id3v2_version;
+		self.clone()
+	}
+
+	/// Whether or not to unsynchronise [`TagType::Id3v2`](crate::tag::TagType::Id3v2) and
+	/// [`Id3v2Tag`](crate::id3::v2::Id3v2Tag)
+	///
+	/// Unsynchronisation prevents any part of the tag from being mistaken for an MPEG frame sync,
+	/// which some older software and hardware require. See [`FrameFlags::unsynchronisation`](crate::id3::v2::FrameFlags::unsynchronisation)
+	/// for an explanation.
+	///
+	/// In ID3v2.2 and ID3v2.3, the entire tag is unsynchronised. In ID3v2.4, each frame is
+	/// unsynchronised individually.
+	///
+	/// NOTE: This is applied in addition to [`Id3v2TagFlags::unsynchronisation`](crate::id3::v2::Id3v2TagFlags::unsynchronisation)
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// // My playout system doesn't cope with false syncs in the tag
+	/// let options = WriteOptions::new().id3v2_unsynchronisation(true);
+	/// id3v2_tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn id3v2_unsynchronisation(mut self, id3v2_unsynchronisation: bool) -> Self {
+		self.id3v2_unsynchronisation = id3v2_unsynchronisation;
+		self
+	}
+
+	/// Whether or not to write a footer for [`TagType::Id3v2`](crate::tag::TagType::Id3v2) and
+	/// [`Id3v2Tag`](crate::id3::v2::Id3v2Tag)
+	///
+	/// A footer is a copy of the header at the end of the tag, which makes it possible to find
+	/// the tag when searching from the end of the file.
+	///
+	/// NOTES:
+	///
+	/// * Only ID3v2.4 supports footers, this has no effect for other versions
+	/// * Tags with a footer can't have padding, so [`WriteOptions::preferred_padding`] is ignored
+	/// * This is applied in addition to [`Id3v2TagFlags::footer`](crate::id3::v2::Id3v2TagFlags::footer)
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// let options = WriteOptions::new().id3v2_footer(true);
+	/// id3v2_tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn id3v2_footer(mut self, id3v2_footer: bool) -> Self {
+		self.id3v2_footer = id3v2_footer;
+		self
+	}
+
+	/// Whether or not to include a CRC-32 in the extended header of [`TagType::Id3v2`](crate::tag::TagType::Id3v2)
+	/// and [`Id3v2Tag`](crate::id3::v2::Id3v2Tag)
+	///
+	/// The CRC allows for verifying the integrity of the tag.
+	///
+	/// NOTES:
+	///
+	/// * ID3v2.2 has no extended header, this has no effect for it
+	/// * This is applied in addition to [`Id3v2TagFlags::crc`](crate::id3::v2::Id3v2TagFlags::crc)
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// let options = WriteOptions::new().id3v2_crc(true);
+	/// id3v2_tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn id3v2_crc(mut self, id3v2_crc: bool) -> Self {
+		self.id3v2_crc = id3v2_crc;
+		self
+	}
+
+	/// The [`FrameCiphers`] used to encrypt [`Id3v2Tag`](crate::id3::v2::Id3v2Tag) frames
+	///
+	/// Frames with an [`encryption`](crate::id3::v2::FrameFlags::encryption) method symbol that
+	/// has a cipher are encrypted when written. By default, there are no ciphers, and those frames
+	/// are written unencrypted, without the `encryption` flag.
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::WriteOptions;
+	/// use lofty::id3::v2::FrameCiphers;
+	/// use lofty::prelude::*;
+	/// use lofty::tag::{Tag, TagType};
+	/// use std::sync::Arc;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut ciphers = FrameCiphers::new();
+	/// // ciphers.insert("mailto:foo@example.com", MyCipher);
+	/// let ciphers = Arc::new(ciphers);
+	///
+	/// let mut id3v2_tag = Tag::new(TagType::Id3v2);
+	///
+	/// // ...
+	///
+	/// let options = WriteOptions::new().id3v2_frame_ciphers(ciphers);
+	/// id3v2_tag.save_to_path("test.mp3", options)?;
+	/// # Ok(()) }
+	/// ```
+	pub fn id3v2_frame_ciphers(mut self, id3v2_frame_ciphers: Arc<FrameCiphers>) -> Self {
+		self.id3v2_frame_ciphers = Some(FrameCiphersRef(id3v2_frame_ciphers));
+		self
 	}
 }
 
@@ -191,6 +410,12 @@ impl Default for WriteOptions {
 	///     respect_read_only: true,
 	///     uppercase_id3v2_chunk: true,
 	///     use_id3v23: false,
+	///     use_id3v22: false,
+	///     preserve_id3v2_version: false,
+	///     id3v2_unsynchronisation: false,
+	///     id3v2_footer: false,
+	///     id3v2_crc: false,
+	///     id3v2_frame_ciphers: None,
 	/// }
 	/// ```
 	fn default() -> Self {
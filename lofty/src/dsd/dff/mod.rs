@@ -0,0 +1,34 @@
+//! DSDIFF specific items
+//!
+//! ## File notes
+//!
+//! DSDIFF has a few chunks for storing basic metadata, see [`DffTextChunks`].
+//!
+//! Additionally, it is common for files to contain an unofficial `ID3 ` chunk, which
+//! will be read and written like any other `ID3v2` tag.
+mod properties;
+mod read;
+pub(crate) mod tag;
+
+use crate::id3::v2::tag::Id3v2Tag;
+
+use lofty_attr::LoftyFile;
+
+// Exports
+pub use properties::{DffCompression, DffProperties};
+pub use tag::{Comment, CommentType, DffTextChunks};
+
+/// A DSDIFF file
+#[derive(LoftyFile)]
+#[lofty(read_fn = "read::read_from")]
+#[lofty(internal_write_module_do_not_use_anywhere_else)]
+pub struct DffFile {
+	/// Any text chunks included in the file
+	#[lofty(tag_type = "DffText")]
+	pub(crate) text_chunks_tag: Option<DffTextChunks>,
+	/// An ID3v2 tag
+	#[lofty(tag_type = "Id3v2")]
+	pub(crate) id3v2_tag: Option<Id3v2Tag>,
+	/// The file's audio properties
+	pub(crate) properties: DffProperties,
+}
@@ -0,0 +1,222 @@
+use crate::error::Result;
+use crate::iff::chunk::Chunks;
+use crate::macros::decode_err;
+use crate::properties::{ChannelMask, FileProperties};
+
+use std::io::{Cursor, Read};
+use std::time::Duration;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+/// The compression used for a DSDIFF file's sound data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DffCompression {
+	/// Uncompressed DSD audio
+	#[default]
+	Dsd,
+	/// Direct Stream Transfer encoded audio
+	Dst,
+}
+
+/// A DSDIFF file's audio properties
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct DffProperties {
+	pub(crate) version: u32,
+	pub(crate) compression: DffCompression,
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) channels: u16,
+	pub(crate) channel_mask: Option<ChannelMask>,
+	pub(crate) sample_count: u64,
+}
+
+impl From<DffProperties> for FileProperties {
+	fn from(input: DffProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			// DSD is always 1-bit, provided we actually read the properties
+			bit_depth: (input.sample_rate > 0).then_some(1),
+			channels: u8::try_from(input.channels).ok(),
+			channel_mask: input.channel_mask,
+		}
+	}
+}
+
+impl DffProperties {
+	/// Duration of the audio
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn audio_bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u16 {
+		self.channels
+	}
+
+	/// Channel mask
+	pub fn channel_mask(&self) -> Option<ChannelMask> {
+		self.channel_mask
+	}
+
+	/// Total number of samples (per channel)
+	pub fn sample_count(&self) -> u64 {
+		self.sample_count
+	}
+
+	/// DSDIFF version, from the `FVER` chunk
+	pub fn version(&self) -> u32 {
+		self.version
+	}
+
+	/// The compression of the sound data
+	pub fn compression(&self) -> DffCompression {
+		self.compression
+	}
+}
+
+/// The contents of the `DST ` chunk that we care about
+pub(super) struct DstFrameInfo {
+	pub(super) frame_count: u32,
+	pub(super) frame_rate: u16,
+}
+
+/// The location of the sound data
+pub(super) enum SoundData {
+	Dsd { stream_len: u64 },
+	Dst { stream_len: u64, info: DstFrameInfo },
+}
+
+fn channel_mask_from_ids(ids: &[[u8; 4]]) -> Option<ChannelMask> {
+	let mut mask = ChannelMask(0);
+	for id in ids {
+		let channel = match id {
+			b"SLFT" | b"MLFT" => ChannelMask::FRONT_LEFT,
+			b"SRGT" | b"MRGT" => ChannelMask::FRONT_RIGHT,
+			b"LS  " => ChannelMask::BACK_LEFT,
+			b"RS  " => ChannelMask::BACK_RIGHT,
+			b"C   " => ChannelMask::FRONT_CENTER,
+			b"LFE " => ChannelMask::LOW_FREQUENCY,
+			_ => return None,
+		};
+
+		mask = mask | channel;
+	}
+
+	Some(mask)
+}
+
+pub(super) fn read_properties(
+	version: u32,
+	prop: &[u8],
+	sound_data: SoundData,
+	file_length: u64,
+) -> Result<DffProperties> {
+	let mut properties = DffProperties {
+		version,
+		..DffProperties::default()
+	};
+
+	let mut reader = Cursor::new(prop);
+	let mut chunks = Chunks::<BigEndian>::new_wide(prop.len() as u64);
+
+	let mut found_sample_rate = false;
+	let mut found_channels = false;
+	while chunks.next(&mut reader).is_ok() {
+		match &chunks.fourcc {
+			b"FS  " => {
+				let content = chunks.content(&mut reader)?;
+				chunks.correct_position(&mut reader)?;
+
+				properties.sample_rate = (&mut &*content).read_u32::<BigEndian>()?;
+				found_sample_rate = true;
+			},
+			b"CHNL" => {
+				let content = chunks.content(&mut reader)?;
+				chunks.correct_position(&mut reader)?;
+
+				let content = &mut &*content;
+				let channels = content.read_u16::<BigEndian>()?;
+
+				let mut ids = Vec::with_capacity(usize::from(channels));
+				for _ in 0..channels {
+					let mut id = [0; 4];
+					content.read_exact(&mut id)?;
+					ids.push(id);
+				}
+
+				properties.channels = channels;
+				properties.channel_mask = channel_mask_from_ids(&ids);
+				found_channels = true;
+			},
+			_ => chunks.skip(&mut reader)?,
+		}
+	}
+
+	if !found_sample_rate {
+		decode_err!(@BAIL Dff, "File missing \"FS  \" chunk");
+	}
+
+	if !found_channels {
+		decode_err!(@BAIL Dff, "File missing \"CHNL\" chunk");
+	}
+
+	if let SoundData::Dst { .. } = sound_data {
+		properties.compression = DffCompression::Dst;
+	}
+
+	if properties.sample_rate == 0 || properties.channels == 0 {
+		log::warn!("Sample rate or channel count is 0, unable to calculate properties");
+		return Ok(properties);
+	}
+
+	let sample_rate = u64::from(properties.sample_rate);
+	let stream_len = match sound_data {
+		SoundData::Dsd { stream_len } => {
+			properties.sample_count = stream_len.saturating_mul(8) / u64::from(properties.channels);
+			stream_len
+		},
+		SoundData::Dst { stream_len, info } => {
+			if info.frame_rate > 0 {
+				properties.sample_count =
+					u64::from(info.frame_count) * sample_rate / u64::from(info.frame_rate);
+			}
+			stream_len
+		},
+	};
+
+	let length = (u128::from(properties.sample_count) * 1000 / u128::from(sample_rate)) as u64;
+	properties.duration = Duration::from_millis(length);
+
+	if length > 0 {
+		properties.overall_bitrate = (file_length.saturating_mul(8) / length) as u32;
+
+		properties.audio_bitrate = match properties.compression {
+			// Every sample is 1 bit
+			DffCompression::Dsd => (sample_rate * u64::from(properties.channels) / 1000) as u32,
+			DffCompression::Dst => (stream_len.saturating_mul(8) / length) as u32,
+		};
+	}
+
+	Ok(properties)
+}
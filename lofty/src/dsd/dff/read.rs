@@ -0,0 +1,261 @@
+use super::properties::{DffProperties, DstFrameInfo, SoundData};
+use super::tag::{Comment, CommentType, DffTextChunks};
+use super::DffFile;
+use crate::config::ParseOptions;
+use crate::error::Result;
+use crate::id3::v2::tag::Id3v2Tag;
+use crate::iff::chunk::Chunks;
+use crate::macros::decode_err;
+use crate::tag::items::Timestamp;
+use crate::util::text::utf8_decode;
+
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+/// Verifies the `FRM8` header, returning the size of the form
+pub(super) fn verify_dff<R>(reader: &mut R) -> Result<u64>
+where
+	R: Read,
+{
+	let mut id = [0; 4];
+	reader.read_exact(&mut id)?;
+
+	if &id != b"FRM8" {
+		decode_err!(@BAIL Dff, "File missing \"FRM8\" chunk");
+	}
+
+	let form_size = reader.read_u64::<BigEndian>()?;
+
+	reader.read_exact(&mut id)?;
+	if &id != b"DSD " {
+		decode_err!(@BAIL Dff, "Found an invalid form type, expected \"DSD \"");
+	}
+
+	log::debug!("File verified to be DSDIFF");
+	Ok(form_size)
+}
+
+pub(super) fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<DffFile>
+where
+	R: Read + Seek,
+{
+	let start = reader.stream_position()?;
+	let file_length = reader.seek(SeekFrom::End(0))?.saturating_sub(start);
+	reader.seek(SeekFrom::Start(start))?;
+
+	let form_size = verify_dff(reader)?;
+	if form_size.saturating_add(12) != file_length {
+		log::warn!(
+			"File size mismatch, expected: {}, found: {}",
+			form_size.saturating_add(12),
+			file_length
+		);
+	}
+
+	let mut version = 0;
+	let mut prop = None;
+	let mut sound_data = None;
+
+	let mut text_chunks = DffTextChunks::default();
+	let mut id3v2_tag: Option<Id3v2Tag> = None;
+
+	let mut chunks = Chunks::<BigEndian>::new_wide(file_length.saturating_sub(16));
+
+	while chunks.next(reader).is_ok() {
+		match &chunks.fourcc {
+			b"FVER" => {
+				let content = chunks.content(reader)?;
+				chunks.correct_position(reader)?;
+
+				version = (&mut &*content).read_u32::<BigEndian>()?;
+			},
+			b"PROP" if parse_options.read_properties && prop.is_none() => {
+				let content = chunks.content(reader)?;
+				chunks.correct_position(reader)?;
+
+				if content.len() < 4 || &content[..4] != b"SND " {
+					log::warn!("Found a \"PROP\" chunk with an unknown type, skipping");
+					continue;
+				}
+
+				prop = Some(content);
+			},
+			b"DSD " if parse_options.read_properties => {
+				sound_data = Some(SoundData::Dsd {
+					stream_len: chunks.size,
+				});
+				chunks.skip(reader)?;
+			},
+			b"DST " if parse_options.read_properties => {
+				// The first sub-chunk is required to be `FRTE`
+				let mut frte = [0; 18];
+				reader.read_exact(&mut frte)?;
+				reader.seek(SeekFrom::Current(-18))?;
+
+				if &frte[..4] != b"FRTE" {
+					decode_err!(@BAIL Dff, "\"DST \" chunk missing \"FRTE\" chunk");
+				}
+
+				let content = &mut &frte[12..];
+				let info = DstFrameInfo {
+					frame_count: content.read_u32::<BigEndian>()?,
+					frame_rate: content.read_u16::<BigEndian>()?,
+				};
+
+				sound_data = Some(SoundData::Dst {
+					stream_len: chunks.size,
+					info,
+				});
+				chunks.skip(reader)?;
+			},
+			b"DIIN" if parse_options.read_tags => {
+				let content = chunks.content(reader)?;
+				chunks.correct_position(reader)?;
+
+				parse_diin(&content, &mut text_chunks)?;
+			},
+			b"COMT" if text_chunks.comments.is_none() && parse_options.read_tags => {
+				let content = chunks.content(reader)?;
+				chunks.correct_position(reader)?;
+
+				let comments = parse_comt(&mut &*content)?;
+				if !comments.is_empty() {
+					text_chunks.comments = Some(comments);
+				}
+			},
+			b"ID3 " | b"id3 " if parse_options.read_tags => {
+				let tag = chunks.id3_chunk(reader, parse_options.clone())?;
+				if let Some(existing_tag) = id3v2_tag.as_mut() {
+					log::warn!("Duplicate ID3v2 tag found, appending frames to previous tag");
+
+					// https://github.com/Serial-ATA/lofty-rs/issues/87
+					// Duplicate tags should have their frames appended to the previous
+					for frame in tag.frames {
+						existing_tag.insert(frame);
+					}
+					continue;
+				}
+				id3v2_tag = Some(tag);
+			},
+			_ => chunks.skip(reader)?,
+		}
+	}
+
+	let properties;
+	if parse_options.read_properties {
+		let Some(prop) = prop else {
+			decode_err!(@BAIL Dff, "File does not contain a \"PROP\" chunk");
+		};
+
+		let Some(sound_data) = sound_data else {
+			decode_err!(@BAIL Dff, "File does not contain a \"DSD \" or \"DST \" chunk");
+		};
+
+		properties =
+			super::properties::read_properties(version, &prop[4..], sound_data, file_length)?;
+	} else {
+		properties = DffProperties::default();
+	}
+
+	Ok(DffFile {
+		text_chunks_tag: match text_chunks {
+			DffTextChunks {
+				artist: None,
+				title: None,
+				comments: None,
+			} => None,
+			_ => Some(text_chunks),
+		},
+		id3v2_tag,
+		properties,
+	})
+}
+
+fn parse_diin(content: &[u8], text_chunks: &mut DffTextChunks) -> Result<()> {
+	let mut reader = Cursor::new(content);
+	let mut chunks = Chunks::<BigEndian>::new_wide(content.len() as u64);
+
+	while chunks.next(&mut reader).is_ok() {
+		match &chunks.fourcc {
+			b"DIAR" if text_chunks.artist.is_none() => {
+				text_chunks.artist = Some(read_text_sub_chunk(&mut chunks, &mut reader)?);
+			},
+			b"DITI" if text_chunks.title.is_none() => {
+				text_chunks.title = Some(read_text_sub_chunk(&mut chunks, &mut reader)?);
+			},
+			_ => chunks.skip(&mut reader)?,
+		}
+	}
+
+	Ok(())
+}
+
+/// Reads a `DIAR` or `DITI` chunk, which store a u32 length followed by the text
+fn read_text_sub_chunk(
+	chunks: &mut Chunks<BigEndian>,
+	reader: &mut Cursor<&[u8]>,
+) -> Result<String> {
+	let content = chunks.content(reader)?;
+	chunks.correct_position(reader)?;
+
+	let content = &mut &*content;
+	let len = content.read_u32::<BigEndian>()? as usize;
+	if len > content.len() {
+		decode_err!(@BAIL Dff, "Text chunk has an invalid length");
+	}
+
+	utf8_decode(content[..len].to_vec())
+}
+
+fn parse_comt(content: &mut &[u8]) -> Result<Vec<Comment>> {
+	if content.len() < 2 {
+		return Ok(Vec::new());
+	}
+
+	let num_comments = content.read_u16::<BigEndian>()?;
+
+	let mut comments = Vec::with_capacity(usize::from(num_comments));
+	for _ in 0..num_comments {
+		let year = content.read_u16::<BigEndian>()?;
+		let month = content.read_u8()?;
+		let day = content.read_u8()?;
+		let hour = content.read_u8()?;
+		let minute = content.read_u8()?;
+
+		let comment_type = CommentType::from_u16(content.read_u16::<BigEndian>()?);
+		let reference = content.read_u16::<BigEndian>()?;
+
+		let len = content.read_u32::<BigEndian>()? as usize;
+		if len > content.len() {
+			decode_err!(@BAIL Dff, "Comment has an invalid length");
+		}
+
+		let text = utf8_decode(content[..len].to_vec())?;
+		*content = &content[len..];
+
+		// Comments are padded to an even length
+		if len % 2 != 0 && !content.is_empty() {
+			*content = &content[1..];
+		}
+
+		// An empty timestamp is stored as all zeros
+		let timestamp = (year != 0).then_some(Timestamp {
+			year,
+			month: (month != 0).then_some(month),
+			day: (day != 0).then_some(day),
+			hour: Some(hour),
+			minute: Some(minute),
+			second: None,
+		});
+
+		comments.push(Comment {
+			timestamp,
+			comment_type,
+			reference,
+			text,
+		});
+	}
+
+	Ok(comments)
+}
@@ -0,0 +1,718 @@
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::iff::chunk::Chunks;
+use crate::macros::err;
+use crate::tag::items::Timestamp;
+use crate::tag::{Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType};
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::borrow::{Borrow, Cow};
+use std::io::{Cursor, Write};
+
+use byteorder::{BigEndian, WriteBytesExt};
+use lofty_attr::tag;
+
+/// The type of a DSDIFF [`Comment`]
+///
+/// The meaning of [`Comment::reference`] depends on the type.
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CommentType {
+	/// A general comment
+	///
+	/// The reference is unused, and should be 0.
+	#[default]
+	General,
+	/// A comment about a specific channel
+	///
+	/// The reference is the channel number, or 0 for all channels.
+	Channel,
+	/// A description of the sound source
+	///
+	/// The reference is one of:
+	///
+	/// * 0 - DSD recording
+	/// * 1 - Analogue recording
+	/// * 2 - PCM recording
+	SoundSource,
+	/// An entry in the file's history
+	///
+	/// The reference is one of:
+	///
+	/// * 0 - General remark
+	/// * 1 - Name of the operator
+	/// * 2 - Name or type of the creating machine
+	/// * 3 - Time zone information
+	/// * 4 - Revision of the file
+	FileHistory,
+	/// An unknown comment type
+	Other(u16),
+}
+
+impl CommentType {
+	/// Get a `CommentType` from a u16
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::dsd::dff::CommentType;
+	///
+	/// assert_eq!(CommentType::from_u16(3), CommentType::FileHistory);
+	/// assert_eq!(CommentType::from_u16(50), CommentType::Other(50));
+	/// ```
+	pub fn from_u16(value: u16) -> Self {
+		match value {
+			0 => Self::General,
+			1 => Self::Channel,
+			2 => Self::SoundSource,
+			3 => Self::FileHistory,
+			_ => Self::Other(value),
+		}
+	}
+
+	/// Get the u16 representation of a `CommentType`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::dsd::dff::CommentType;
+	///
+	/// assert_eq!(CommentType::FileHistory.as_u16(), 3);
+	/// ```
+	pub fn as_u16(self) -> u16 {
+		match self {
+			Self::General => 0,
+			Self::Channel => 1,
+			Self::SoundSource => 2,
+			Self::FileHistory => 3,
+			Self::Other(value) => value,
+		}
+	}
+}
+
+/// Represents a single comment in a DSDIFF `COMT` chunk
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct Comment {
+	/// The creation time of the comment
+	///
+	/// The seconds are not stored, and will be discarded when writing.
+	pub timestamp: Option<Timestamp>,
+	/// The type of comment
+	pub comment_type: CommentType,
+	/// A reference, dependent on the [`CommentType`]
+	pub reference: u16,
+	/// The comment itself
+	pub text: String,
+}
+
+impl Comment {
+	/// Create a new [`CommentType::General`] comment with no timestamp
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::dsd::dff::{Comment, CommentType};
+	///
+	/// let comment = Comment::new(String::from("Foo comment"));
+	/// assert_eq!(comment.comment_type, CommentType::General);
+	/// ```
+	pub fn new(text: String) -> Self {
+		Self {
+			text,
+			..Self::default()
+		}
+	}
+}
+
+/// ## Item storage
+///
+/// `DSDIFF` stores an artist and title in the `DIIN` (edited master information) chunk,
+/// and any number of comments in the `COMT` chunk.
+///
+/// ## Conversions
+///
+/// ### To `Tag`
+///
+/// * `artist` -> [`ItemKey::TrackArtist`]
+/// * `title` -> [`ItemKey::TrackTitle`]
+/// * `comments` -> [`ItemKey::Comment`]
+///
+/// Only [`CommentType::General`] comments will be converted, with their timestamps discarded.
+/// All other comments will be retained when using [`SplitTag`] and [`MergeTag`].
+///
+/// ### From `Tag`
+///
+/// All of the [`ItemKey`]s referenced in the conversion to [`Tag`] will be checked.
+///
+/// Every item with the key [`ItemKey::Comment`] will be stored as a [`CommentType::General`] comment.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[tag(description = "`DSDIFF` text chunks", supported_formats(Dff))]
+pub struct DffTextChunks {
+	/// The artist of the piece (`DIAR`)
+	pub artist: Option<String>,
+	/// The title of the piece (`DITI`)
+	pub title: Option<String>,
+	/// Comments (`COMT`)
+	pub comments: Option<Vec<Comment>>,
+}
+
+impl Accessor for DffTextChunks {
+	fn artist(&self) -> Option<Cow<'_, str>> {
+		self.artist.as_deref().map(Cow::Borrowed)
+	}
+	fn set_artist(&mut self, value: String) {
+		self.artist = Some(value)
+	}
+	fn remove_artist(&mut self) {
+		self.artist = None
+	}
+
+	fn title(&self) -> Option<Cow<'_, str>> {
+		self.title.as_deref().map(Cow::Borrowed)
+	}
+	fn set_title(&mut self, value: String) {
+		self.title = Some(value)
+	}
+	fn remove_title(&mut self) {
+		self.title = None
+	}
+
+	fn comment(&self) -> Option<Cow<'_, str>> {
+		self.general_comments()
+			.next()
+			.map(|c| Cow::Borrowed(c.text.as_str()))
+	}
+	fn set_comment(&mut self, value: String) {
+		self.remove_comment();
+		self.comments
+			.get_or_insert_with(Vec::new)
+			.insert(0, Comment::new(value));
+	}
+	fn remove_comment(&mut self) {
+		if let Some(comments) = &mut self.comments {
+			comments.retain(|c| c.comment_type != CommentType::General);
+			if comments.is_empty() {
+				self.comments = None;
+			}
+		}
+	}
+}
+
+impl DffTextChunks {
+	/// Create a new empty `DffTextChunks`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::dsd::dff::DffTextChunks;
+	/// use lofty::tag::TagExt;
+	///
+	/// let dff_tag = DffTextChunks::new();
+	/// assert!(dff_tag.is_empty());
+	/// ```
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	fn general_comments(&self) -> impl Iterator<Item = &Comment> {
+		self.comments
+			.iter()
+			.flatten()
+			.filter(|c| c.comment_type == CommentType::General)
+	}
+}
+
+impl TagExt for DffTextChunks {
+	type Err = LoftyError;
+	type RefKey<'a> = &'a ItemKey;
+
+	#[inline]
+	fn tag_type(&self) -> TagType {
+		TagType::DffText
+	}
+
+	fn len(&self) -> usize {
+		usize::from(self.artist.is_some())
+			+ usize::from(self.title.is_some())
+			+ self.comments.as_ref().map_or(0, Vec::len)
+	}
+
+	fn contains<'a>(&'a self, key: Self::RefKey<'a>) -> bool {
+		match key {
+			ItemKey::TrackArtist => self.artist.is_some(),
+			ItemKey::TrackTitle => self.title.is_some(),
+			ItemKey::Comment => self.general_comments().next().is_some(),
+			_ => false,
+		}
+	}
+
+	fn is_empty(&self) -> bool {
+		matches!(
+			self,
+			DffTextChunks {
+				artist: None,
+				title: None,
+				comments: None
+			}
+		)
+	}
+
+	fn save_to<F>(
+		&self,
+		file: &mut F,
+		write_options: WriteOptions,
+	) -> std::result::Result<(), Self::Err>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		DffTextChunksRef {
+			artist: self.artist.as_deref(),
+			title: self.title.as_deref(),
+			comments: self.comments.iter().flatten(),
+		}
+		.write_to(file, write_options)
+	}
+
+	fn dump_to<W: Write>(
+		&self,
+		writer: &mut W,
+		write_options: WriteOptions,
+	) -> std::result::Result<(), Self::Err> {
+		DffTextChunksRef {
+			artist: self.artist.as_deref(),
+			title: self.title.as_deref(),
+			comments: self.comments.iter().flatten(),
+		}
+		.dump_to(writer, write_options)
+	}
+
+	fn clear(&mut self) {
+		*self = Self::default();
+	}
+}
+
+/// Any comments that could not be converted to [`ItemKey::Comment`]
+#[derive(Debug, Clone, Default)]
+pub struct SplitTagRemainder(Vec<Comment>);
+
+impl SplitTag for DffTextChunks {
+	type Remainder = SplitTagRemainder;
+
+	fn split_tag(mut self) -> (Self::Remainder, Tag) {
+		let mut remainder = Vec::new();
+		if let Some(comments) = self.comments.take() {
+			let (general, other) = comments
+				.into_iter()
+				.partition(|c| c.comment_type == CommentType::General);
+
+			self.comments = Some(general);
+			remainder = other;
+		}
+
+		(SplitTagRemainder(remainder), self.into())
+	}
+}
+
+impl MergeTag for SplitTagRemainder {
+	type Merged = DffTextChunks;
+
+	fn merge_tag(self, tag: Tag) -> Self::Merged {
+		let mut merged: DffTextChunks = tag.into();
+		if !self.0.is_empty() {
+			merged.comments.get_or_insert_with(Vec::new).extend(self.0);
+		}
+
+		merged
+	}
+}
+
+impl From<DffTextChunks> for Tag {
+	fn from(input: DffTextChunks) -> Self {
+		let mut tag = Self::new(TagType::DffText);
+
+		if let Some(artist) = input.artist {
+			tag.items
+				.push(TagItem::new(ItemKey::TrackArtist, ItemValue::Text(artist)));
+		}
+
+		if let Some(title) = input.title {
+			tag.items
+				.push(TagItem::new(ItemKey::TrackTitle, ItemValue::Text(title)));
+		}
+
+		for comment in input.comments.into_iter().flatten() {
+			if comment.comment_type == CommentType::General {
+				tag.items.push(TagItem::new(
+					ItemKey::Comment,
+					ItemValue::Text(comment.text),
+				));
+			}
+		}
+
+		tag
+	}
+}
+
+impl From<Tag> for DffTextChunks {
+	fn from(mut input: Tag) -> Self {
+		let artist = input.take_strings(&ItemKey::TrackArtist).next();
+		let title = input.take_strings(&ItemKey::TrackTitle).next();
+		let comments = input
+			.take_strings(&ItemKey::Comment)
+			.map(Comment::new)
+			.collect::<Vec<_>>();
+
+		Self {
+			artist,
+			title,
+			comments: (!comments.is_empty()).then_some(comments),
+		}
+	}
+}
+
+pub(crate) fn tag_comments(tag: &Tag) -> impl Iterator<Item = Comment> + '_ {
+	tag.get_strings(&ItemKey::Comment)
+		.map(|text| Comment::new(text.to_owned()))
+}
+
+pub(crate) struct DffTextChunksRef<'a, C, CI>
+where
+	C: Borrow<Comment>,
+	CI: IntoIterator<Item = C>,
+{
+	pub artist: Option<&'a str>,
+	pub title: Option<&'a str>,
+	pub comments: CI,
+}
+
+impl<'a, C, CI> DffTextChunksRef<'a, C, CI>
+where
+	C: Borrow<Comment>,
+	CI: IntoIterator<Item = C>,
+{
+	pub(crate) fn write_to<F>(self, file: &mut F, _write_options: WriteOptions) -> Result<()>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		super::read::verify_dff(file)?;
+		let file_len = file.len()?.saturating_sub(16);
+
+		let mut chunks_remove = Vec::new();
+		let mut preserved_diin_chunks = Vec::new();
+
+		let mut chunks = Chunks::<BigEndian>::new_wide(file_len);
+
+		while chunks.next(file).is_ok() {
+			match &chunks.fourcc {
+				b"COMT" | b"DIIN" => {
+					let start = (file.stream_position()? - 12) as usize;
+					let mut end = start + 12 + chunks.size as usize;
+
+					if chunks.size % 2 != 0 {
+						end += 1
+					}
+
+					chunks_remove.push((start, end));
+
+					if &chunks.fourcc == b"DIIN" {
+						let content = chunks.content(file)?;
+						chunks.correct_position(file)?;
+
+						preserved_diin_chunks.extend(preserved_diin_sub_chunks(&content)?);
+						continue;
+					}
+				},
+				_ => {},
+			}
+
+			chunks.skip(file)?;
+		}
+
+		let (comt, mut diin) = Self::create_text_chunks(self)?;
+
+		let mut text_chunks = comt;
+		if !preserved_diin_chunks.is_empty() || !diin.is_empty() {
+			preserved_diin_chunks.append(&mut diin);
+			write_chunk(&mut text_chunks, *b"DIIN", &preserved_diin_chunks)?;
+		}
+
+		file.rewind()?;
+
+		let mut file_bytes = Vec::new();
+		file.read_to_end(&mut file_bytes)?;
+
+		if chunks_remove.is_empty() {
+			file_bytes.extend(text_chunks);
+		} else {
+			chunks_remove.sort_unstable();
+			chunks_remove.reverse();
+
+			let first = chunks_remove.pop().unwrap(); // Infallible
+
+			for (s, e) in &chunks_remove {
+				file_bytes.drain(*s..*e);
+			}
+
+			file_bytes.splice(first.0..first.1, text_chunks);
+		}
+
+		let total_size = ((file_bytes.len() - 12) as u64).to_be_bytes();
+		file_bytes.splice(4..12, total_size);
+
+		file.rewind()?;
+		file.truncate(0)?;
+		file.write_all(&file_bytes)?;
+
+		Ok(())
+	}
+
+	pub(crate) fn dump_to<W: Write>(
+		self,
+		writer: &mut W,
+		_write_options: WriteOptions,
+	) -> Result<()> {
+		let (comt, diin) = Self::create_text_chunks(self)?;
+
+		writer.write_all(&comt)?;
+		if !diin.is_empty() {
+			let mut diin_chunk = Vec::new();
+			write_chunk(&mut diin_chunk, *b"DIIN", &diin)?;
+			writer.write_all(&diin_chunk)?;
+		}
+
+		Ok(())
+	}
+
+	/// Creates the `COMT` chunk, and the contents of the `DIIN` chunk
+	fn create_text_chunks(self) -> Result<(Vec<u8>, Vec<u8>)> {
+		let mut comt = Vec::new();
+
+		let mut comments = self.comments.into_iter().peekable();
+		if comments.peek().is_some() {
+			let mut content = Cursor::new(vec![0; 2]);
+			content.set_position(2);
+
+			let mut count = 0_u16;
+			for comment in comments {
+				let comment = comment.borrow();
+
+				let Some(new_count) = count.checked_add(1) else {
+					err!(TooMuchData);
+				};
+				count = new_count;
+
+				let timestamp = comment.timestamp.unwrap_or_default();
+
+				content.write_u16::<BigEndian>(timestamp.year)?;
+				content.write_u8(timestamp.month.unwrap_or(0))?;
+				content.write_u8(timestamp.day.unwrap_or(0))?;
+				content.write_u8(timestamp.hour.unwrap_or(0))?;
+				content.write_u8(timestamp.minute.unwrap_or(0))?;
+				content.write_u16::<BigEndian>(comment.comment_type.as_u16())?;
+				content.write_u16::<BigEndian>(comment.reference)?;
+
+				let Ok(len) = u32::try_from(comment.text.len()) else {
+					err!(TooMuchData);
+				};
+
+				content.write_u32::<BigEndian>(len)?;
+				content.write_all(comment.text.as_bytes())?;
+
+				if len % 2 != 0 {
+					content.write_u8(0)?;
+				}
+			}
+
+			let mut content = content.into_inner();
+			content[..2].copy_from_slice(&count.to_be_bytes());
+
+			write_chunk(&mut comt, *b"COMT", &content)?;
+		}
+
+		let mut diin = Vec::new();
+		for (id, value) in [(*b"DIAR", self.artist), (*b"DITI", self.title)] {
+			let Some(value) = value else {
+				continue;
+			};
+
+			let Ok(len) = u32::try_from(value.len()) else {
+				err!(TooMuchData);
+			};
+
+			let mut content = Vec::with_capacity(value.len() + 4);
+			content.extend(len.to_be_bytes());
+			content.extend(value.as_bytes());
+
+			write_chunk(&mut diin, id, &content)?;
+		}
+
+		log::debug!(
+			"Created DSDIFF text chunks, size: {} bytes",
+			comt.len() + diin.len()
+		);
+
+		Ok((comt, diin))
+	}
+}
+
+/// Writes a chunk, padding it to an even boundary
+fn write_chunk(writer: &mut Vec<u8>, id: [u8; 4], content: &[u8]) -> Result<()> {
+	writer.extend(id);
+	writer.write_u64::<BigEndian>(content.len() as u64)?;
+	writer.extend(content);
+
+	if content.len() % 2 != 0 {
+		writer.push(0);
+	}
+
+	Ok(())
+}
+
+/// Get all sub-chunks of the `DIIN` chunk that aren't represented in [`DffTextChunks`]
+fn preserved_diin_sub_chunks(content: &[u8]) -> Result<Vec<u8>> {
+	let mut preserved = Vec::new();
+
+	let mut reader = Cursor::new(content);
+	let mut chunks = Chunks::<BigEndian>::new_wide(content.len() as u64);
+	while chunks.next(&mut reader).is_ok() {
+		match &chunks.fourcc {
+			b"DIAR" | b"DITI" => chunks.skip(&mut reader)?,
+			_ => {
+				let sub_chunk_content = chunks.content(&mut reader)?;
+				chunks.correct_position(&mut reader)?;
+
+				write_chunk(&mut preserved, chunks.fourcc, &sub_chunk_content)?;
+			},
+		}
+	}
+
+	Ok(preserved)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::config::{ParseOptions, WriteOptions};
+	use crate::dsd::dff::{Comment, CommentType, DffTextChunks};
+	use crate::prelude::*;
+	use crate::tag::items::Timestamp;
+	use crate::tag::{ItemValue, Tag, TagItem, TagType};
+
+	use std::io::Cursor;
+
+	fn expected() -> DffTextChunks {
+		DffTextChunks {
+			artist: Some(String::from("Bar artist")),
+			title: Some(String::from("Foo title")),
+			comments: Some(vec![
+				Comment {
+					timestamp: Some(Timestamp {
+						year: 2024,
+						month: Some(9),
+						day: Some(6),
+						hour: Some(14),
+						minute: Some(8),
+						second: None,
+					}),
+					comment_type: CommentType::General,
+					reference: 0,
+					text: String::from("Qux comment"),
+				},
+				Comment {
+					timestamp: None,
+					comment_type: CommentType::FileHistory,
+					reference: 1,
+					text: String::from("Quux operator"),
+				},
+			]),
+		}
+	}
+
+	fn read_tag(bytes: Vec<u8>) -> DffTextChunks {
+		super::super::read::read_from(
+			&mut Cursor::new(bytes),
+			ParseOptions::new().read_properties(false),
+		)
+		.unwrap()
+		.text_chunks_tag
+		.unwrap()
+	}
+
+	#[test_log::test]
+	fn parse_dff_text() {
+		let tag = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.dff_text");
+
+		assert_eq!(read_tag(tag), expected());
+	}
+
+	#[test_log::test]
+	fn dff_text_re_read() {
+		let tag = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.dff_text");
+		let parsed_tag = read_tag(tag);
+
+		// Create a fake DSDIFF signature
+		let mut writer = vec![b'F', b'R', b'M', b'8', 0, 0, 0, 0, 0, 0, 0, 0];
+		writer.extend(b"DSD ");
+		parsed_tag
+			.dump_to(&mut writer, WriteOptions::default())
+			.unwrap();
+
+		let temp_parsed_tag = read_tag(writer);
+
+		assert_eq!(parsed_tag, temp_parsed_tag);
+	}
+
+	#[test_log::test]
+	fn dff_text_to_tag() {
+		let tag_bytes = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.dff_text");
+
+		let tag: Tag = read_tag(tag_bytes).into();
+
+		assert_eq!(tag.get_string(&ItemKey::TrackTitle), Some("Foo title"));
+		assert_eq!(tag.get_string(&ItemKey::TrackArtist), Some("Bar artist"));
+
+		// Only general comments are converted
+		let mut comments = tag.get_strings(&ItemKey::Comment);
+		assert_eq!(comments.next(), Some("Qux comment"));
+		assert!(comments.next().is_none());
+	}
+
+	#[test_log::test]
+	fn tag_to_dff_text() {
+		let mut tag = Tag::new(TagType::DffText);
+		tag.insert_text(ItemKey::TrackTitle, String::from("Foo title"));
+		tag.insert_text(ItemKey::TrackArtist, String::from("Bar artist"));
+		tag.push_unchecked(TagItem::new(
+			ItemKey::Comment,
+			ItemValue::Text(String::from("Qux comment")),
+		));
+
+		let dff_text: DffTextChunks = tag.into();
+
+		assert_eq!(dff_text.title, Some(String::from("Foo title")));
+		assert_eq!(dff_text.artist, Some(String::from("Bar artist")));
+		assert_eq!(
+			dff_text.comments,
+			Some(vec![Comment::new(String::from("Qux comment"))])
+		);
+	}
+
+	#[test_log::test]
+	fn split_merge_keeps_other_comments() {
+		let tag_bytes = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.dff_text");
+		let dff_text = read_tag(tag_bytes);
+
+		let (remainder, mut tag) = dff_text.split_tag();
+		tag.insert_text(ItemKey::Comment, String::from("Corge comment"));
+
+		let merged = remainder.merge_tag(tag);
+
+		let comments = merged.comments.unwrap();
+		assert_eq!(comments.len(), 2);
+		assert_eq!(comments[0], Comment::new(String::from("Corge comment")));
+		assert_eq!(comments[1].comment_type, CommentType::FileHistory);
+		assert_eq!(comments[1].text, "Quux operator");
+	}
+}
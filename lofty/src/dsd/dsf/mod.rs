@@ -0,0 +1,28 @@
+//! DSF specific items
+//!
+//! ## File notes
+//!
+//! The only tag format supported by DSF is `ID3v2`. Unlike most formats, the tag is stored at the
+//! **end** of the file, with its location recorded in the `DSD ` chunk.
+
+mod properties;
+mod read;
+pub(crate) mod write;
+
+use crate::id3::v2::tag::Id3v2Tag;
+
+use lofty_attr::LoftyFile;
+
+// Exports
+pub use properties::DsfProperties;
+
+/// A DSF file
+#[derive(LoftyFile, Default)]
+#[lofty(read_fn = "read::read_from")]
+pub struct DsfFile {
+	/// An ID3v2 tag
+	#[lofty(tag_type = "Id3v2")]
+	pub(crate) id3v2_tag: Option<Id3v2Tag>,
+	/// The file's audio properties
+	pub(crate) properties: DsfProperties,
+}
@@ -0,0 +1,202 @@
+use crate::config::ParsingMode;
+use crate::error::Result;
+use crate::macros::{decode_err, parse_mode_choice};
+use crate::properties::{ChannelMask, FileProperties};
+
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// The size of the `fmt ` chunk, including its header
+const FMT_CHUNK_SIZE: u64 = 52;
+
+/// A DSF file's audio properties
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct DsfProperties {
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) bits_per_sample: u8,
+	pub(crate) channels: u8,
+	pub(crate) channel_mask: Option<ChannelMask>,
+	pub(crate) sample_count: u64,
+}
+
+impl From<DsfProperties> for FileProperties {
+	fn from(input: DsfProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			// DSD is always 1-bit, regardless of `bits_per_sample`, provided we actually read the properties
+			bit_depth: (input.sample_rate > 0).then_some(1),
+			channels: Some(input.channels),
+			channel_mask: input.channel_mask,
+		}
+	}
+}
+
+impl DsfProperties {
+	/// Duration of the audio
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn audio_bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Bits per sample
+	///
+	/// NOTE: DSD audio is always 1-bit. This is either 1 (LSB first) or 8 (MSB first), and only
+	///       describes the order of the bits within each byte.
+	pub fn bits_per_sample(&self) -> u8 {
+		self.bits_per_sample
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// Channel mask
+	pub fn channel_mask(&self) -> Option<ChannelMask> {
+		self.channel_mask
+	}
+
+	/// Total number of samples (per channel)
+	pub fn sample_count(&self) -> u64 {
+		self.sample_count
+	}
+}
+
+// See the "Channel Type" field of the `fmt ` chunk
+fn channel_mask_from_type(channel_type: u32) -> Option<ChannelMask> {
+	let mask = match channel_type {
+		1 => ChannelMask::mono(),
+		2 => ChannelMask::stereo(),
+		3 => ChannelMask::linear_surround(),
+		4 => {
+			ChannelMask::FRONT_LEFT
+				| ChannelMask::FRONT_RIGHT
+				| ChannelMask::BACK_LEFT
+				| ChannelMask::BACK_RIGHT
+		},
+		5 => ChannelMask::linear_surround() | ChannelMask::LOW_FREQUENCY,
+		6 => ChannelMask::linear_surround() | ChannelMask::BACK_LEFT | ChannelMask::BACK_RIGHT,
+		7 => {
+			ChannelMask::linear_surround()
+				| ChannelMask::LOW_FREQUENCY
+				| ChannelMask::BACK_LEFT
+				| ChannelMask::BACK_RIGHT
+		},
+		_ => return None,
+	};
+
+	Some(mask)
+}
+
+pub(super) fn read_properties<R>(
+	reader: &mut R,
+	file_length: u64,
+	parse_mode: ParsingMode,
+) -> Result<DsfProperties>
+where
+	R: Read + Seek,
+{
+	let mut id = [0; 4];
+	reader.read_exact(&mut id)?;
+
+	if &id != b"fmt " {
+		decode_err!(@BAIL Dsf, "File missing \"fmt \" chunk");
+	}
+
+	let chunk_size = reader.read_u64::<LittleEndian>()?;
+	if chunk_size < FMT_CHUNK_SIZE {
+		decode_err!(@BAIL Dsf, "Found an invalid \"fmt \" chunk size");
+	}
+
+	let format_version = reader.read_u32::<LittleEndian>()?;
+	if format_version != 1 {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Dsf, "Found an unknown format version"),
+			DEFAULT: log::warn!("Found an unknown format version: {format_version}")
+		);
+	}
+
+	// Format ID, the only defined value is 0 (DSD raw)
+	let _format_id = reader.read_u32::<LittleEndian>()?;
+
+	let channel_type = reader.read_u32::<LittleEndian>()?;
+	let channel_num = reader.read_u32::<LittleEndian>()?;
+	let sample_rate = reader.read_u32::<LittleEndian>()?;
+	let bits_per_sample = reader.read_u32::<LittleEndian>()?;
+	let sample_count = reader.read_u64::<LittleEndian>()?;
+
+	// Skip the block size per channel and reserved fields, along with any additional data
+	let Ok(remaining) = i64::try_from(chunk_size - FMT_CHUNK_SIZE + 8) else {
+		decode_err!(@BAIL Dsf, "Found an invalid \"fmt \" chunk size");
+	};
+
+	reader.seek(SeekFrom::Current(remaining))?;
+
+	let Ok(channels) = u8::try_from(channel_num) else {
+		decode_err!(@BAIL Dsf, "Found an invalid channel count");
+	};
+
+	if channels == 0 {
+		decode_err!(@BAIL Dsf, "File contains 0 channels");
+	}
+
+	if bits_per_sample != 1 && bits_per_sample != 8 {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Dsf, "Found an invalid bits per sample"),
+			DEFAULT: log::warn!("Found an invalid bits per sample: {bits_per_sample}")
+		);
+	}
+
+	let mut properties = DsfProperties {
+		sample_rate,
+		bits_per_sample: bits_per_sample as u8,
+		channels,
+		channel_mask: channel_mask_from_type(channel_type),
+		sample_count,
+		..DsfProperties::default()
+	};
+
+	if sample_rate == 0 {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Dsf, "Sample rate is 0"),
+			DEFAULT: return Ok(properties)
+		);
+	}
+
+	let length = (u128::from(sample_count) * 1000 / u128::from(sample_rate)) as u64;
+	properties.duration = Duration::from_millis(length);
+
+	// Every sample is 1 bit, regardless of `bits_per_sample`
+	properties.audio_bitrate = (u64::from(sample_rate) * u64::from(channels) / 1000) as u32;
+	if length > 0 {
+		properties.overall_bitrate = (file_length.saturating_mul(8) / length) as u32;
+	}
+
+	Ok(properties)
+}
@@ -0,0 +1,96 @@
+use super::DsfFile;
+use crate::config::ParseOptions;
+use crate::error::Result;
+use crate::id3::v2::header::Id3v2Header;
+use crate::id3::v2::read::parse_id3v2;
+use crate::macros::decode_err;
+
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// The size of the `DSD ` chunk, including its header
+pub(super) const DSD_CHUNK_SIZE: u64 = 28;
+
+/// The contents of the `DSD ` chunk
+pub(super) struct DsdChunk {
+	/// The total size of the file
+	pub(super) file_size: u64,
+	/// The offset of the metadata chunk, or 0 if there is none
+	pub(super) metadata_offset: u64,
+}
+
+pub(super) fn verify_dsf<R>(reader: &mut R) -> Result<DsdChunk>
+where
+	R: Read,
+{
+	let mut id = [0; 4];
+	reader.read_exact(&mut id)?;
+
+	if &id != b"DSD " {
+		decode_err!(@BAIL Dsf, "File missing \"DSD \" chunk");
+	}
+
+	let chunk_size = reader.read_u64::<LittleEndian>()?;
+	if chunk_size != DSD_CHUNK_SIZE {
+		decode_err!(@BAIL Dsf, "Found an invalid \"DSD \" chunk size");
+	}
+
+	let file_size = reader.read_u64::<LittleEndian>()?;
+	let metadata_offset = reader.read_u64::<LittleEndian>()?;
+
+	Ok(DsdChunk {
+		file_size,
+		metadata_offset,
+	})
+}
+
+pub(super) fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<DsfFile>
+where
+	R: Read + Seek,
+{
+	let start = reader.stream_position()?;
+	let file_length = reader.seek(SeekFrom::End(0))?.saturating_sub(start);
+	reader.seek(SeekFrom::Start(start))?;
+
+	let dsd_chunk = verify_dsf(reader)?;
+	if dsd_chunk.file_size != file_length {
+		log::warn!(
+			"File size mismatch, expected: {}, found: {}",
+			dsd_chunk.file_size,
+			file_length
+		);
+	}
+
+	let mut file = DsfFile::default();
+
+	if parse_options.read_properties {
+		file.properties =
+			super::properties::read_properties(reader, file_length, parse_options.parsing_mode)?;
+	}
+
+	if !parse_options.read_tags || dsd_chunk.metadata_offset == 0 {
+		return Ok(file);
+	}
+
+	if dsd_chunk.metadata_offset >= file_length {
+		decode_err!(@BAIL Dsf, "Metadata offset points past the end of the file");
+	}
+
+	reader.seek(SeekFrom::Start(start + dsd_chunk.metadata_offset))?;
+
+	let mut id = [0; 3];
+	reader.read_exact(&mut id)?;
+
+	if &id != b"ID3" {
+		log::warn!("Metadata chunk does not contain an ID3v2 tag, ignoring");
+		return Ok(file);
+	}
+
+	reader.seek(SeekFrom::Current(-3))?;
+
+	let header = Id3v2Header::parse(reader)?;
+	file.id3v2_tag = Some(parse_id3v2(reader, header, parse_options)?);
+
+	Ok(file)
+}
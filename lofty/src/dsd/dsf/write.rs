@@ -0,0 +1,84 @@
+use super::read::{verify_dsf, DSD_CHUNK_SIZE};
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::id3::v2::tag::{tag_frames, tag_original_version, Id3v2TagRef};
+use crate::id3::v2::Id3v2TagFlags;
+use crate::macros::{decode_err, err};
+use crate::tag::{Tag, TagType};
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::io::SeekFrom;
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+
+pub(crate) fn write_to<F>(file: &mut F, tag: &Tag, write_options: WriteOptions) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	match tag.tag_type() {
+		TagType::Id3v2 => Id3v2TagRef {
+			flags: Id3v2TagFlags::default(),
+			original_version: tag_original_version(tag),
+			frames: tag_frames(tag).peekable(),
+		}
+		.write_to(file, write_options),
+		_ => err!(UnsupportedTag),
+	}
+}
+
+/// Replace the metadata chunk of a DSF file with an already encoded `ID3v2` tag
+///
+/// An empty `tag` will remove the metadata chunk entirely.
+pub(crate) fn write_id3v2_to_dsf<F>(file: &mut F, tag: &[u8]) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	file.rewind()?;
+
+	let dsd_chunk = verify_dsf(file)?;
+
+	// The audio data ends the file, unless a metadata chunk is present. Either way,
+	// we need to find the end of the `data` chunk to place the new tag.
+	let mut audio_end = DSD_CHUNK_SIZE;
+	for expected_id in [b"fmt ", b"data"] {
+		file.seek(SeekFrom::Start(audio_end))?;
+
+		let mut id = [0; 4];
+		file.read_exact(&mut id)?;
+
+		if &id != expected_id {
+			decode_err!(@BAIL Dsf, "Encountered an unexpected chunk while searching for audio data");
+		}
+
+		let Some(end) = audio_end.checked_add(file.read_u64::<LittleEndian>()?) else {
+			err!(SizeMismatch);
+		};
+
+		audio_end = end;
+	}
+
+	if dsd_chunk.metadata_offset != 0 && dsd_chunk.metadata_offset < audio_end {
+		decode_err!(@BAIL Dsf, "Metadata chunk overlaps the audio data");
+	}
+
+	file.truncate(audio_end)?;
+	file.seek(SeekFrom::Start(audio_end))?;
+	file.write_all(tag)?;
+
+	let (file_size, metadata_offset) = if tag.is_empty() {
+		(audio_end, 0)
+	} else {
+		(audio_end + tag.len() as u64, audio_end)
+	};
+
+	// Skip the chunk ID and size
+	file.seek(SeekFrom::Start(12))?;
+	file.write_u64::<LittleEndian>(file_size)?;
+	file.write_u64::<LittleEndian>(metadata_offset)?;
+
+	Ok(())
+}
@@ -0,0 +1,3 @@
+//! DSD (Direct Stream Digital) specific items
+pub mod dff;
+pub mod dsf;
@@ -52,6 +52,8 @@ pub enum ErrorKind {
 	TextDecode(&'static str),
 	/// Arises when decoding OR encoding a problematic [`Timestamp`](crate::tag::items::Timestamp)
 	BadTimestamp(&'static str),
+	/// Arises when parsing invalid [LRC](crate::lyrics::Lrc) lyrics
+	BadLrc(&'static str),
 	/// Errors that arise while reading/writing ID3v2 tags
 	Id3v2(Id3v2Error),
 
@@ -121,6 +123,12 @@ pub enum Id3v2ErrorKind {
 	BadRva2ChannelType,
 	/// Arises when decoding a [`TimestampFormat`](crate::id3::v2::TimestampFormat) with an invalid type
 	BadTimestampFormat,
+	/// Arises when an [`AudioSeekPointIndexFrame`](crate::id3::v2::AudioSeekPointIndexFrame) has a size
+	/// other than 8 or 16 bits per index point
+	BadAspiIndexPointSize(u8),
+	/// Arises when an [`MpegLocationLookupTableFrame`](crate::id3::v2::MpegLocationLookupTableFrame) has
+	/// deviation sizes that are invalid, or too small to hold its references
+	BadMlltDeviationSize,
 
 	// Compression
 	#[cfg(feature = "id3v2_compression_support")]
@@ -131,7 +139,7 @@ pub enum Id3v2ErrorKind {
 	CompressedFrameEncountered,
 
 	// Writing
-	/// Arises when attempting to write an encrypted frame with an invalid encryption method symbol (must be <= 0x80)
+	/// Arises when attempting to write an encrypted frame with an invalid encryption method symbol (must be >= 0x80)
 	InvalidEncryptionMethodSymbol(u8),
 	/// Arises when attempting to write an invalid Frame (Bad `FrameId`/`FrameValue` pairing)
 	BadFrame(String, &'static str),
@@ -181,6 +189,14 @@ impl Display for Id3v2ErrorKind {
 				f,
 				"Encountered an invalid timestamp format in a synchronized frame"
 			),
+			Self::BadAspiIndexPointSize(bits) => write!(
+				f,
+				"Encountered an invalid index point size in ASPI frame ({bits} bits, expected 8 or \
+				 16)"
+			),
+			Self::BadMlltDeviationSize => {
+				write!(f, "Encountered invalid deviation sizes in MLLT frame")
+			},
 
 			// Compression
 			#[cfg(feature = "id3v2_compression_support")]
@@ -549,6 +565,7 @@ impl Display for LoftyError {
 			ErrorKind::BadTimestamp(message) => {
 				write!(f, "Encountered an invalid timestamp: {message}")
 			},
+			ErrorKind::BadLrc(message) => write!(f, "LRC: {message}"),
 			ErrorKind::Id3v2(ref id3v2_err) => write!(f, "{id3v2_err}"),
 			ErrorKind::BadAtom(message) => write!(f, "MP4 Atom: {message}"),
 			ErrorKind::AtomMismatch => write!(
@@ -13,13 +13,20 @@ pub enum FileType {
 	Aac,
 	Aiff,
 	Ape,
+	Asf,
+	Caf,
+	Dff,
+	Dsf,
 	Flac,
+	Matroska,
 	Mpeg,
 	Mp4,
 	Mpc,
+	OggFlac,
 	Opus,
 	Vorbis,
 	Speex,
+	Tta,
 	Wav,
 	WavPack,
 	Custom(&'static str),
@@ -28,12 +35,15 @@ pub enum FileType {
 impl FileType {
 	/// Returns the file type's "primary" [`TagType`], or the one most likely to be used in the target format
 	///
-	/// | [`FileType`]                      | [`TagType`]      |
-	/// |-----------------------------------|------------------|
-	/// | `Aac`, `Aiff`, `Mp3`, `Wav`       | `Id3v2`          |
-	/// | `Ape` , `Mpc`, `WavPack`          | `Ape`            |
-	/// | `Flac`, `Opus`, `Vorbis`, `Speex` | `VorbisComments` |
-	/// | `Mp4`                             | `Mp4Ilst`        |
+	/// | [`FileType`]                                        | [`TagType`]      |
+	/// |-----------------------------------------------------|------------------|
+	/// | `Aac`, `Aiff`, `Dff`, `Dsf`, `Mp3`, `Tta`, `Wav`    | `Id3v2`          |
+	/// | `Ape` , `Mpc`, `WavPack`                            | `Ape`            |
+	/// | `Flac`, `OggFlac`, `Opus`, `Vorbis`, `Speex`        | `VorbisComments` |
+	/// | `Mp4`                                               | `Mp4Ilst`        |
+	/// | `Asf`                                               | `Asf`            |
+	/// | `Caf`                                               | `CafInfo`        |
+	/// | `Matroska`                                          | `Matroska`       |
 	///
 	/// # Panics
 	///
@@ -50,12 +60,23 @@ impl FileType {
 	/// ```
 	pub fn primary_tag_type(&self) -> TagType {
 		match self {
-			FileType::Aac | FileType::Aiff | FileType::Mpeg | FileType::Wav => TagType::Id3v2,
+			FileType::Aac
+			| FileType::Aiff
+			| FileType::Dff
+			| FileType::Dsf
+			| FileType::Mpeg
+			| FileType::Tta
+			| FileType::Wav => TagType::Id3v2,
 			FileType::Ape | FileType::Mpc | FileType::WavPack => TagType::Ape,
-			FileType::Flac | FileType::Opus | FileType::Vorbis | FileType::Speex => {
-				TagType::VorbisComments
-			},
+			FileType::Flac
+			| FileType::OggFlac
+			| FileType::Opus
+			| FileType::Vorbis
+			| FileType::Speex => TagType::VorbisComments,
 			FileType::Mp4 => TagType::Mp4Ilst,
+			FileType::Asf => TagType::Asf,
+			FileType::Caf => TagType::CafInfo,
+			FileType::Matroska => TagType::Matroska,
 			FileType::Custom(c) => {
 				let resolver = crate::resolve::lookup_resolver(c);
 				resolver.primary_tag_type()
@@ -96,6 +117,11 @@ impl FileType {
 			TagType::VorbisComments => crate::ogg::VorbisComments::SUPPORTED_FORMATS.contains(self),
 			TagType::RiffInfo => crate::iff::wav::RiffInfoList::SUPPORTED_FORMATS.contains(self),
 			TagType::AiffText => crate::iff::aiff::AiffTextChunks::SUPPORTED_FORMATS.contains(self),
+			TagType::Bext => crate::iff::wav::BextChunk::SUPPORTED_FORMATS.contains(self),
+			TagType::DffText => crate::dsd::dff::DffTextChunks::SUPPORTED_FORMATS.contains(self),
+			TagType::Asf => crate::asf::AsfTag::SUPPORTED_FORMATS.contains(self),
+			TagType::CafInfo => crate::caf::CafInfo::SUPPORTED_FORMATS.contains(self),
+			TagType::Matroska => crate::matroska::MatroskaTag::SUPPORTED_FORMATS.contains(self),
 		}
 	}
 
@@ -131,15 +157,21 @@ impl FileType {
 			"aac" => Some(Self::Aac),
 			"ape" => Some(Self::Ape),
 			"aiff" | "aif" | "afc" | "aifc" => Some(Self::Aiff),
+			"wma" | "asf" => Some(Self::Asf),
+			"caf" => Some(Self::Caf),
+			"dff" => Some(Self::Dff),
+			"dsf" => Some(Self::Dsf),
 			"mp3" | "mp2" | "mp1" => Some(Self::Mpeg),
-			"wav" | "wave" => Some(Self::Wav),
+			"wav" | "wave" | "w64" | "rf64" | "bw64" => Some(Self::Wav),
 			"wv" => Some(Self::WavPack),
 			"opus" => Some(Self::Opus),
 			"flac" => Some(Self::Flac),
+			"mka" | "mkv" | "webm" => Some(Self::Matroska),
 			"ogg" => Some(Self::Vorbis),
 			"mp4" | "m4a" | "m4b" | "m4p" | "m4r" | "m4v" | "3gp" => Some(Self::Mp4),
 			"mpc" | "mp+" | "mpp" => Some(Self::Mpc),
 			"spx" => Some(Self::Speex),
+			"tta" => Some(Self::Tta),
 			_ => None,
 		}
 	}
@@ -237,6 +269,9 @@ impl FileType {
 		// Safe to index, since we return early on an empty buffer
 		match buf[0] {
 			77 if buf.starts_with(b"MAC") => Some(Self::Ape),
+			65 if buf.starts_with(b"ADIF") => Some(Self::Aac),
+			48 if buf.starts_with(&crate::asf::object::HEADER_OBJECT) => Some(Self::Asf),
+			26 if buf.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]) => Some(Self::Matroska),
 			255 if buf.len() >= 2 && verify_frame_sync([buf[0], buf[1]]) => {
 				// ADTS and MPEG frame headers are way too similar
 
@@ -271,6 +306,7 @@ impl FileType {
 
 				Some(Self::Mpeg)
 			},
+			68 if buf.starts_with(b"DSD ") => Some(Self::Dsf),
 			70 if buf.len() >= 12 && &buf[..4] == b"FORM" => {
 				let id = &buf[8..12];
 
@@ -280,6 +316,9 @@ impl FileType {
 
 				None
 			},
+			70 if buf.len() >= 16 && &buf[..4] == b"FRM8" && &buf[12..16] == b"DSD " => {
+				Some(Self::Dff)
+			},
 			79 if buf.len() >= 36 && &buf[..4] == b"OggS" => {
 				if &buf[29..35] == b"vorbis" {
 					return Some(Self::Vorbis);
@@ -287,18 +326,29 @@ impl FileType {
 					return Some(Self::Opus);
 				} else if &buf[28..36] == b"Speex   " {
 					return Some(Self::Speex);
+				} else if &buf[28..33] == b"\x7FFLAC" {
+					return Some(Self::OggFlac);
 				}
 
 				None
 			},
 			102 if buf.starts_with(b"fLaC") => Some(Self::Flac),
-			82 if buf.len() >= 12 && &buf[..4] == b"RIFF" => {
+			99 if buf.starts_with(b"caff") => Some(Self::Caf),
+			84 if buf.starts_with(b"TTA1") => Some(Self::Tta),
+			66 | 82 if buf.len() >= 12 && matches!(&buf[..4], b"RIFF" | b"RF64" | b"BW64") => {
 				if &buf[8..12] == b"WAVE" {
 					return Some(Self::Wav);
 				}
 
 				None
 			},
+			// Wave64 (`riff` GUID, followed by the `wave` GUID)
+			114 if buf.len() >= 28
+				&& buf[..16] == crate::iff::wav::container::WAVE64_RIFF_GUID
+				&& &buf[24..28] == b"wave" =>
+			{
+				Some(Self::Wav)
+			},
 			119 if buf.len() >= 4 && &buf[..4] == b"wvpk" => Some(Self::WavPack),
 			_ if buf.len() >= 8 && &buf[4..8] == b"ftyp" => Some(Self::Mp4),
 			_ if buf.starts_with(b"MPCK") || buf.starts_with(b"MP+") => Some(Self::Mpc),
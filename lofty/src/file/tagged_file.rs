@@ -434,7 +434,7 @@ impl AudioFile for TaggedFile {
 			// TODO: This is a temporary solution. Ideally we should probe once and use
 			//       the format-specific writing to avoid these rewinds.
 			file.rewind()?;
-			tag.save_to(file, write_options)?;
+			tag.save_to(file, write_options.clone())?;
 		}
 
 		Ok(())
@@ -7,11 +7,11 @@ use std::io::{Read, Seek, SeekFrom};
 
 use byteorder::{BigEndian, ReadBytesExt};
 
-pub(in crate::flac) const BLOCK_ID_STREAMINFO: u8 = 0;
-pub(in crate::flac) const BLOCK_ID_PADDING: u8 = 1;
-pub(in crate::flac) const BLOCK_ID_SEEKTABLE: u8 = 3;
-pub(in crate::flac) const BLOCK_ID_VORBIS_COMMENTS: u8 = 4;
-pub(in crate::flac) const BLOCK_ID_PICTURE: u8 = 6;
+pub(crate) const BLOCK_ID_STREAMINFO: u8 = 0;
+pub(crate) const BLOCK_ID_PADDING: u8 = 1;
+pub(crate) const BLOCK_ID_SEEKTABLE: u8 = 3;
+pub(crate) const BLOCK_ID_VORBIS_COMMENTS: u8 = 4;
+pub(crate) const BLOCK_ID_PICTURE: u8 = 6;
 
 const BLOCK_HEADER_SIZE: u64 = 4;
 
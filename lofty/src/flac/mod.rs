@@ -62,7 +62,7 @@ impl FlacFile {
 		LoftyError: From<<F as Length>::Error>,
 	{
 		if let Some(ref id3v2) = self.id3v2_tag {
-			id3v2.save_to(file, write_options)?;
+			id3v2.save_to(file, write_options.clone())?;
 			file.rewind()?;
 		}
 
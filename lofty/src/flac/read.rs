@@ -56,7 +56,7 @@ where
 
 		let reader = &mut &*content;
 
-		let id3v2 = parse_id3v2(reader, header, parse_options)?;
+		let id3v2 = parse_id3v2(reader, header, parse_options.clone())?;
 		flac_file.id3v2_tag = Some(id3v2);
 	}
 
@@ -102,7 +102,7 @@ where
 			let vorbis_comments = read_comments(
 				&mut &*block.content,
 				block.content.len() as u64,
-				parse_options,
+				parse_options.clone(),
 			)?;
 
 			flac_file.vorbis_comments_tag = Some(vorbis_comments);
@@ -25,7 +25,9 @@ where
 {
 	match tag.tag_type() {
 		TagType::VorbisComments => {
-			let (vendor, items, pictures) = crate::ogg::tag::create_vorbis_comments_ref(tag);
+			let extra_items = crate::ogg::tag::tag_extra_items(tag);
+			let (vendor, items, pictures) =
+				crate::ogg::tag::create_vorbis_comments_ref(tag, &extra_items);
 
 			let mut comments_ref = VorbisCommentsRef {
 				vendor: Cow::from(vendor),
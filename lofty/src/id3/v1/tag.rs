@@ -71,7 +71,7 @@ macro_rules! impl_accessor {
 #[derive(Default, Debug, PartialEq, Eq, Clone)]
 #[tag(
 	description = "An ID3v1 tag",
-	supported_formats(Aac, Ape, Mpeg, WavPack, read_only(Mpc))
+	supported_formats(Aac, Ape, Mpeg, Tta, WavPack, read_only(Mpc))
 )]
 pub struct Id3v1Tag {
 	/// Track title, 30 bytes max
@@ -0,0 +1,214 @@
+use crate::error::Result;
+use crate::id3::v2::{Frame, FrameFlags};
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+/// A cipher used to decrypt and encrypt `ID3v2` frames
+///
+/// Encrypted frames refer to their encryption method by a method symbol, which is assigned by an
+/// [`EncryptionMethodRegistrationFrame`](crate::id3::v2::EncryptionMethodRegistrationFrame)
+/// in the same tag. Ciphers are added to [`FrameCiphers`] under the owner identifier of that frame.
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::error::Result;
+/// use lofty::id3::v2::{FrameCipher, FrameCiphers};
+///
+/// struct XorCipher;
+///
+/// impl FrameCipher for XorCipher {
+/// 	fn decrypt(&self, encryption_data: &[u8], content: &[u8]) -> Result<Vec<u8>> {
+/// 		let key = encryption_data.first().copied().unwrap_or(0);
+/// 		Ok(content.iter().map(|b| b ^ key).collect())
+/// 	}
+///
+/// 	fn encrypt(&self, encryption_data: &[u8], content: &[u8]) -> Result<Vec<u8>> {
+/// 		self.decrypt(encryption_data, content)
+/// 	}
+/// }
+///
+/// let mut ciphers = FrameCiphers::new();
+/// ciphers.insert("mailto:foo@example.com", XorCipher);
+/// ```
+pub trait FrameCipher: Send + Sync {
+	/// Decrypt the content of a frame
+	///
+	/// `encryption_data` is the [`encryption_data`](crate::id3::v2::EncryptionMethodRegistrationFrame::encryption_data)
+	/// of the registration frame.
+	///
+	/// # Errors
+	///
+	/// Any error will be propagated to the caller, failing the read.
+	fn decrypt(&self, encryption_data: &[u8], content: &[u8]) -> Result<Vec<u8>>;
+
+	/// Encrypt the content of a frame
+	///
+	/// `encryption_data` is the [`encryption_data`](crate::id3::v2::EncryptionMethodRegistrationFrame::encryption_data)
+	/// of the registration frame.
+	///
+	/// # Errors
+	///
+	/// Any error will be propagated to the caller, failing the write.
+	fn encrypt(&self, encryption_data: &[u8], content: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// A set of [`FrameCipher`]s, keyed by the owner identifier of their encryption method
+///
+/// The owner is matched against the [`owner`](crate::id3::v2::EncryptionMethodRegistrationFrame::owner)
+/// of the `ENCR` frames in a tag. Any frames using the method symbol of a matching `ENCR` frame
+/// will be decrypted when read, and encrypted when written.
+///
+/// These are used with [`ParseOptions::id3v2_frame_ciphers`](crate::config::ParseOptions::id3v2_frame_ciphers)
+/// and [`WriteOptions::id3v2_frame_ciphers`](crate::config::WriteOptions::id3v2_frame_ciphers).
+///
+/// NOTE: Encrypted frames that can't be decrypted, or that don't have a specific frame type, are
+///       kept as [`BinaryFrame`](crate::id3::v2::BinaryFrame)s holding the encrypted content.
+///       These are always written as-is.
+#[derive(Default)]
+pub struct FrameCiphers {
+	ciphers: HashMap<String, Arc<dyn FrameCipher>>,
+}
+
+impl FrameCiphers {
+	/// Create an empty `FrameCiphers`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::FrameCiphers;
+	///
+	/// let ciphers = FrameCiphers::new();
+	/// assert!(ciphers.is_empty());
+	/// ```
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Add a [`FrameCipher`] for an encryption method
+	///
+	/// This replaces any cipher already added for `owner`.
+	pub fn insert<C>(&mut self, owner: impl Into<String>, cipher: C)
+	where
+		C: FrameCipher + 'static,
+	{
+		self.ciphers.insert(owner.into(), Arc::new(cipher));
+	}
+
+	/// Remove the [`FrameCipher`] for an encryption method
+	///
+	/// This returns `true` if a cipher was added for `owner`.
+	pub fn remove(&mut self, owner: &str) -> bool {
+		self.ciphers.remove(owner).is_some()
+	}
+
+	/// Whether there are no [`FrameCipher`]s
+	pub fn is_empty(&self) -> bool {
+		self.ciphers.is_empty()
+	}
+}
+
+impl Debug for FrameCiphers {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		f.debug_set().entries(self.ciphers.keys()).finish()
+	}
+}
+
+/// The shared [`FrameCiphers`] of a [`ParseOptions`](crate::config::ParseOptions) or
+/// [`WriteOptions`](crate::config::WriteOptions)
+///
+/// These are compared by identity, as ciphers can't be compared.
+#[derive(Clone)]
+pub(crate) struct FrameCiphersRef(pub(crate) Arc<FrameCiphers>);
+
+impl PartialEq for FrameCiphersRef {
+	fn eq(&self, other: &Self) -> bool {
+		Arc::ptr_eq(&self.0, &other.0)
+	}
+}
+
+impl Eq for FrameCiphersRef {}
+
+impl Debug for FrameCiphersRef {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+/// The ciphers for the encryption methods of a single tag, keyed by method symbol
+#[derive(Default)]
+pub(crate) struct EncryptionMethods {
+	methods: HashMap<u8, (Arc<dyn FrameCipher>, Vec<u8>)>,
+}
+
+impl EncryptionMethods {
+	/// Match the tag's `ENCR` frames against the provided ciphers
+	pub(crate) fn new<'a, 'f: 'a>(
+		frames: impl IntoIterator<Item = &'a Frame<'f>>,
+		ciphers: Option<&FrameCiphersRef>,
+	) -> Self {
+		let mut methods = HashMap::new();
+		let Some(FrameCiphersRef(ciphers)) = ciphers else {
+			return Self { methods };
+		};
+
+		for frame in frames {
+			let Frame::EncryptionMethodRegistration(encr) = frame else {
+				continue;
+			};
+
+			match ciphers.ciphers.get(&encr.owner) {
+				Some(cipher) => {
+					methods.insert(
+						encr.method_symbol,
+						(Arc::clone(cipher), encr.encryption_data.clone()),
+					);
+				},
+				None => log::debug!(
+					"No cipher provided for encryption method \"{}\" (symbol: {})",
+					encr.owner,
+					encr.method_symbol
+				),
+			}
+		}
+
+		Self { methods }
+	}
+
+	/// Decrypt the content of a frame
+	///
+	/// This returns `None` if there is no cipher for the method symbol.
+	pub(crate) fn decrypt(&self, method_symbol: u8, content: &[u8]) -> Option<Result<Vec<u8>>> {
+		let (cipher, encryption_data) = self.methods.get(&method_symbol)?;
+		Some(cipher.decrypt(encryption_data, content))
+	}
+
+	/// Encrypt the content of a frame, if it has a cipher for its method symbol
+	///
+	/// The flags are updated to describe the written content. Without a cipher, the content
+	/// is written unencrypted, so the encryption flag is cleared.
+	pub(crate) fn encrypt(&self, flags: &mut FrameFlags, content: Vec<u8>) -> Result<Vec<u8>> {
+		let Some(method_symbol) = flags.encryption else {
+			return Ok(content);
+		};
+
+		let Some((cipher, encryption_data)) = self.methods.get(&method_symbol) else {
+			log::debug!(
+				"No cipher provided for encryption method symbol {method_symbol}, writing frame \
+				 unencrypted"
+			);
+
+			flags.encryption = None;
+			flags.data_length_indicator = None;
+			return Ok(content);
+		};
+
+		// The content is never compressed when written
+		flags.compression = false;
+		flags.data_length_indicator = Some(content.len() as u32);
+
+		cipher.encrypt(encryption_data, &content)
+	}
+}
@@ -2,10 +2,14 @@ use crate::config::ParsingMode;
 use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
 use crate::id3::v2::header::Id3v2Version;
 use crate::id3::v2::items::{
-	AttachedPictureFrame, CommentFrame, EventTimingCodesFrame, ExtendedTextFrame, ExtendedUrlFrame,
-	KeyValueFrame, OwnershipFrame, PopularimeterFrame, PrivateFrame, RelativeVolumeAdjustmentFrame,
-	TextInformationFrame, TimestampFrame, UniqueFileIdentifierFrame, UnsynchronizedTextFrame,
-	UrlLinkFrame,
+	AttachedPictureFrame, AudioEncryptionFrame, AudioSeekPointIndexFrame, ChapterFrame,
+	CommentFrame, CommercialFrame, EncryptionMethodRegistrationFrame, EqualisationFrame,
+	EventTimingCodesFrame, ExtendedTextFrame, ExtendedUrlFrame, GeneralEncapsulatedObject,
+	GroupIdentificationRegistrationFrame, KeyValueFrame, LinkedInformationFrame,
+	MpegLocationLookupTableFrame, OwnershipFrame, PopularimeterFrame, PositionSynchronisationFrame,
+	PrivateFrame, RecommendedBufferSizeFrame, RelativeVolumeAdjustmentFrame, SeekFrame,
+	SignatureFrame, SynchronizedTextFrame, TableOfContentsFrame, TextInformationFrame,
+	TimestampFrame, UniqueFileIdentifierFrame, UnsynchronizedTextFrame, UrlLinkFrame,
 };
 use crate::id3::v2::{BinaryFrame, Frame, FrameFlags, FrameId};
 use crate::macros::err;
@@ -34,10 +38,39 @@ pub(super) fn parse_content<R: Read>(
 		"USLT" => UnsynchronizedTextFrame::parse(reader, flags, version)?.map(Frame::UnsynchronizedText),
 		"TIPL" | "TMCL" => KeyValueFrame::parse(reader, id, flags, version)?.map(Frame::KeyValue),
 		"UFID" => UniqueFileIdentifierFrame::parse(reader, flags, parse_mode)?.map(Frame::UniqueFileIdentifier),
+		// The older volume adjustment and equalisation frames were upgraded, but their content differs
+		"RVA2" if version != Id3v2Version::V4 => RelativeVolumeAdjustmentFrame::parse_rvad(reader, flags)?.map(Frame::RelativeVolumeAdjustment),
 		"RVA2" => RelativeVolumeAdjustmentFrame::parse(reader, flags, parse_mode)?.map(Frame::RelativeVolumeAdjustment),
+		"EQU2" if version != Id3v2Version::V4 => EqualisationFrame::parse_equa(reader, flags).map(Frame::Equalisation),
+		"EQU2" => EqualisationFrame::parse(reader, flags)?.map(Frame::Equalisation),
 		"OWNE" => OwnershipFrame::parse(reader, flags)?.map(Frame::Ownership),
 		"ETCO" => EventTimingCodesFrame::parse(reader, flags)?.map(Frame::EventTimingCodes),
 		"PRIV" => PrivateFrame::parse(reader, flags)?.map(Frame::Private),
+		"CHAP" => ChapterFrame::parse(reader, flags, version, parse_mode)?.map(Frame::Chapter),
+		"COMR" => CommercialFrame::parse(reader, flags)?.map(Frame::Commercial),
+		"AENC" => AudioEncryptionFrame::parse(reader, flags)?.map(Frame::AudioEncryption),
+		"LINK" => LinkedInformationFrame::parse(reader, flags, version)?.map(Frame::LinkedInformation),
+		"SIGN" => SignatureFrame::parse(reader, flags)?.map(Frame::Signature),
+		"ENCR" => EncryptionMethodRegistrationFrame::parse(reader, flags)?.map(Frame::EncryptionMethodRegistration),
+		"GRID" => GroupIdentificationRegistrationFrame::parse(reader, flags)?.map(Frame::GroupIdentificationRegistration),
+		"SEEK" => SeekFrame::parse(reader, flags)?.map(Frame::Seek),
+		"ASPI" => AudioSeekPointIndexFrame::parse(reader, flags)?.map(Frame::AudioSeekPointIndex),
+		"RBUF" => RecommendedBufferSizeFrame::parse(reader, flags)?.map(Frame::RecommendedBufferSize),
+		"MLLT" => MpegLocationLookupTableFrame::parse(reader, flags)?.map(Frame::MpegLocationLookupTable),
+		"POSS" => PositionSynchronisationFrame::parse(reader, flags)?.map(Frame::PositionSynchronisation),
+		"CTOC" => TableOfContentsFrame::parse(reader, flags, version, parse_mode)?.map(Frame::TableOfContents),
+		"SYLT" => {
+			let mut content = Vec::new();
+			reader.read_to_end(&mut content)?;
+
+			Some(Frame::SynchronizedText(SynchronizedTextFrame::parse(&content, flags)?))
+		},
+		"GEOB" => {
+			let mut content = Vec::new();
+			reader.read_to_end(&mut content)?;
+
+			Some(Frame::EncapsulatedObject(GeneralEncapsulatedObject::parse(&content, flags)?))
+		},
 		"TDEN" | "TDOR" | "TDRC" | "TDRL" | "TDTG" => TimestampFrame::parse(reader, id, flags, parse_mode)?.map(Frame::Timestamp),
 		i if i.starts_with('T') => TextInformationFrame::parse(reader, id, flags, version)?.map(Frame::Text),
 		// Apple proprietary frames
@@ -45,7 +78,7 @@ pub(super) fn parse_content<R: Read>(
 		"WFED" | "GRP1" | "MVNM" | "MVIN" => TextInformationFrame::parse(reader, id, flags, version)?.map(Frame::Text),
 		i if i.starts_with('W') => UrlLinkFrame::parse(reader, id, flags)?.map(Frame::Url),
 		"POPM" => Some(Frame::Popularimeter(PopularimeterFrame::parse(reader, flags)?)),
-		// SYLT, GEOB, and any unknown frames
+		// Any unknown frames
 		_ => {
 			Some(Frame::Binary(BinaryFrame::parse(reader, id, flags)?))
 		},
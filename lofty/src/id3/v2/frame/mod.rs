@@ -5,9 +5,14 @@ pub(super) mod read;
 
 use super::header::Id3v2Version;
 use super::items::{
-	AttachedPictureFrame, BinaryFrame, CommentFrame, EventTimingCodesFrame, ExtendedTextFrame,
-	ExtendedUrlFrame, KeyValueFrame, OwnershipFrame, PopularimeterFrame, PrivateFrame,
-	RelativeVolumeAdjustmentFrame, TextInformationFrame, TimestampFrame, UniqueFileIdentifierFrame,
+	AttachedPictureFrame, AudioEncryptionFrame, AudioSeekPointIndexFrame, BinaryFrame,
+	ChapterFrame, CommentFrame, CommercialFrame, EncryptionMethodRegistrationFrame,
+	EqualisationFrame, EventTimingCodesFrame, ExtendedTextFrame, ExtendedUrlFrame,
+	GeneralEncapsulatedObject, GroupIdentificationRegistrationFrame, KeyValueFrame,
+	LinkedInformationFrame, MpegLocationLookupTableFrame, OwnershipFrame, PopularimeterFrame,
+	PositionSynchronisationFrame, PrivateFrame, RecommendedBufferSizeFrame,
+	RelativeVolumeAdjustmentFrame, SeekFrame, SignatureFrame, SynchronizedTextFrame,
+	TableOfContentsFrame, TextInformationFrame, TimestampFrame, UniqueFileIdentifierFrame,
 	UnsynchronizedTextFrame, UrlLinkFrame,
 };
 use crate::error::Result;
@@ -97,7 +102,10 @@ define_frames! {
 	///
 	/// `ID3v2.2` frame IDs are 3 characters. When reading these tags, [`upgrade_v2`](crate::id3::v2::upgrade_v2) is used, which has a list of all of the common IDs
 	/// that have a mapping to `ID3v2.4`. Any ID that fails to be converted will be stored as [`FrameId::Outdated`], and it must be manually
-	/// upgraded before it can be written. **Lofty** will not write `ID3v2.2` tags.
+	/// upgraded before it can be written.
+	///
+	/// `ID3v2.2` tags can be written with [`WriteOptions::use_id3v22`](crate::config::WriteOptions::use_id3v22), in which case frames are
+	/// converted back to their 3 character IDs. Frames without an `ID3v2.2` equivalent are discarded.
 	///
 	/// ### ID3v2.3
 	///
@@ -124,7 +132,7 @@ define_frames! {
 		Popularimeter(PopularimeterFrame<'a>),
 		/// Represents an "IPLS" or "TPIL" frame
 		KeyValue(KeyValueFrame<'a>),
-		/// Represents an "RVA2" frame
+		/// Represents an "RVA2" or "RVAD" frame
 		RelativeVolumeAdjustment(RelativeVolumeAdjustmentFrame<'a>),
 		/// Unique file identifier
 		UniqueFileIdentifier(UniqueFileIdentifierFrame<'a>),
@@ -136,12 +144,44 @@ define_frames! {
 		Private(PrivateFrame<'a>),
 		/// Represents a timestamp for the "TDEN", "TDOR", "TDRC", "TDRL", and "TDTG" frames
 		Timestamp(TimestampFrame<'a>),
+		/// Represents a "CHAP" frame
+		Chapter(ChapterFrame<'a>),
+		/// Represents a "CTOC" frame
+		TableOfContents(TableOfContentsFrame<'a>),
+		/// Represents a "SYLT" frame
+		SynchronizedText(SynchronizedTextFrame<'a>),
+		/// Represents a "GEOB" frame
+		EncapsulatedObject(GeneralEncapsulatedObject<'a>),
+		/// Represents a "COMR" frame
+		Commercial(CommercialFrame<'a>),
+		/// Represents an "AENC" frame
+		AudioEncryption(AudioEncryptionFrame<'a>),
+		/// Represents a "LINK" frame
+		LinkedInformation(LinkedInformationFrame<'a>),
+		/// Represents a "SIGN" frame
+		Signature(SignatureFrame<'a>),
+		/// Represents an "ENCR" frame
+		EncryptionMethodRegistration(EncryptionMethodRegistrationFrame<'a>),
+		/// Represents a "GRID" frame
+		GroupIdentificationRegistration(GroupIdentificationRegistrationFrame<'a>),
+		/// Represents a "SEEK" frame
+		Seek(SeekFrame<'a>),
+		/// Represents an "ASPI" frame
+		AudioSeekPointIndex(AudioSeekPointIndexFrame<'a>),
+		/// Represents an "RBUF" frame
+		RecommendedBufferSize(RecommendedBufferSizeFrame<'a>),
+		/// Represents an "EQU2" or "EQUA" frame
+		Equalisation(EqualisationFrame<'a>),
+		/// Represents an "MLLT" frame
+		MpegLocationLookupTable(MpegLocationLookupTableFrame<'a>),
+		/// Represents a "POSS" frame
+		PositionSynchronisation(PositionSynchronisationFrame<'a>),
 		/// Binary data
 		///
 		/// NOTES:
 		///
-		/// * This is used for rare frames, such as GEOB, SYLT, and ATXT to skip additional unnecessary work.
-		///   See [`GeneralEncapsulatedObject::parse`](crate::id3::v2::GeneralEncapsulatedObject::parse), [`SynchronizedText::parse`](crate::id3::v2::SynchronizedTextFrame::parse), and [`AudioTextFrame::parse`](crate::id3::v2::AudioTextFrame::parse) respectively
+		/// * This is used for rare frames, such as ATXT to skip additional unnecessary work.
+		///   See [`AudioTextFrame::parse`](crate::id3::v2::AudioTextFrame::parse)
 		/// * This is used for **all** frames with an ID of [`FrameId::Outdated`]
 		/// * This is used for unknown frames
 		Binary(BinaryFrame<'a>),
@@ -181,11 +221,27 @@ impl<'a> Frame<'a> {
 			Frame::UniqueFileIdentifier(ufid) => ufid.identifier.is_empty(),
 			Frame::EventTimingCodes(event_timing) => event_timing.events.is_empty(),
 			Frame::Private(private) => private.private_data.is_empty(),
+			Frame::SynchronizedText(sync_text) => sync_text.content.is_empty(),
+			Frame::EncapsulatedObject(encapsulated_object) => encapsulated_object.data.is_empty(),
+			Frame::Signature(signature) => signature.signature.is_empty(),
 			Frame::Binary(binary) => binary.data.is_empty(),
 			Frame::Popularimeter(_)
 			| Frame::RelativeVolumeAdjustment(_)
 			| Frame::Ownership(_)
-			| Frame::Timestamp(_) => {
+			| Frame::Timestamp(_)
+			| Frame::Chapter(_)
+			| Frame::TableOfContents(_)
+			| Frame::Commercial(_)
+			| Frame::AudioEncryption(_)
+			| Frame::LinkedInformation(_)
+			| Frame::EncryptionMethodRegistration(_)
+			| Frame::GroupIdentificationRegistration(_)
+			| Frame::Seek(_)
+			| Frame::AudioSeekPointIndex(_)
+			| Frame::RecommendedBufferSize(_)
+			| Frame::Equalisation(_)
+			| Frame::MpegLocationLookupTable(_)
+			| Frame::PositionSynchronisation(_) => {
 				// Undefined.
 				return None;
 			},
@@ -195,7 +251,10 @@ impl<'a> Frame<'a> {
 }
 
 impl<'a> Frame<'a> {
-	pub(super) fn as_bytes(&self, is_id3v23: bool) -> Result<Vec<u8>> {
+	pub(super) fn as_bytes(&self, version: Id3v2Version) -> Result<Vec<u8>> {
+		// ID3v2.2 has the same text encoding restrictions as ID3v2.3
+		let is_id3v23 = version != Id3v2Version::V4;
+
 		Ok(match self {
 			Frame::Comment(comment) => comment.as_bytes(is_id3v23)?,
 			Frame::UnsynchronizedText(lf) => lf.as_bytes(is_id3v23)?,
@@ -203,15 +262,7 @@ impl<'a> Frame<'a> {
 			Frame::UserText(content) => content.as_bytes(is_id3v23),
 			Frame::UserUrl(content) => content.as_bytes(is_id3v23),
 			Frame::Url(link) => link.as_bytes(),
-			Frame::Picture(attached_picture) => {
-				let version = if is_id3v23 {
-					Id3v2Version::V3
-				} else {
-					Id3v2Version::V4
-				};
-
-				attached_picture.as_bytes(version)?
-			},
+			Frame::Picture(attached_picture) => attached_picture.as_bytes(version)?,
 			Frame::Popularimeter(popularimeter) => popularimeter.as_bytes()?,
 			Frame::KeyValue(content) => content.as_bytes(is_id3v23),
 			Frame::RelativeVolumeAdjustment(frame) => frame.as_bytes(),
@@ -220,6 +271,22 @@ impl<'a> Frame<'a> {
 			Frame::EventTimingCodes(frame) => frame.as_bytes(),
 			Frame::Private(frame) => frame.as_bytes()?,
 			Frame::Timestamp(frame) => frame.as_bytes(is_id3v23)?,
+			Frame::Chapter(frame) => frame.as_bytes(is_id3v23)?,
+			Frame::TableOfContents(frame) => frame.as_bytes(is_id3v23)?,
+			Frame::SynchronizedText(frame) => frame.as_bytes(is_id3v23)?,
+			Frame::EncapsulatedObject(frame) => frame.as_bytes(is_id3v23),
+			Frame::Commercial(frame) => frame.as_bytes(is_id3v23)?,
+			Frame::AudioEncryption(frame) => frame.as_bytes(),
+			Frame::LinkedInformation(frame) => frame.as_bytes(version)?,
+			Frame::Signature(frame) => frame.as_bytes(),
+			Frame::EncryptionMethodRegistration(frame) => frame.as_bytes(),
+			Frame::GroupIdentificationRegistration(frame) => frame.as_bytes(),
+			Frame::Seek(frame) => frame.as_bytes(),
+			Frame::AudioSeekPointIndex(frame) => frame.as_bytes()?,
+			Frame::RecommendedBufferSize(frame) => frame.as_bytes()?,
+			Frame::Equalisation(frame) => frame.as_bytes(),
+			Frame::MpegLocationLookupTable(frame) => frame.as_bytes()?,
+			Frame::PositionSynchronisation(frame) => frame.as_bytes(),
 			Frame::Binary(frame) => frame.as_bytes(),
 		})
 	}
@@ -242,6 +309,22 @@ impl<'a> Frame<'a> {
 			Frame::EventTimingCodes(_) => "EventTimingCodes",
 			Frame::Private(_) => "Private",
 			Frame::Timestamp(_) => "Timestamp",
+			Frame::Chapter(_) => "Chapter",
+			Frame::TableOfContents(_) => "TableOfContents",
+			Frame::SynchronizedText(_) => "SynchronizedText",
+			Frame::EncapsulatedObject(_) => "EncapsulatedObject",
+			Frame::Commercial(_) => "Commercial",
+			Frame::AudioEncryption(_) => "AudioEncryption",
+			Frame::LinkedInformation(_) => "LinkedInformation",
+			Frame::Signature(_) => "Signature",
+			Frame::EncryptionMethodRegistration(_) => "EncryptionMethodRegistration",
+			Frame::GroupIdentificationRegistration(_) => "GroupIdentificationRegistration",
+			Frame::Seek(_) => "Seek",
+			Frame::AudioSeekPointIndex(_) => "AudioSeekPointIndex",
+			Frame::RecommendedBufferSize(_) => "RecommendedBufferSize",
+			Frame::Equalisation(_) => "Equalisation",
+			Frame::MpegLocationLookupTable(_) => "MpegLocationLookupTable",
+			Frame::PositionSynchronisation(_) => "PositionSynchronisation",
 			Frame::Binary(_) => "Binary",
 		}
 	}
@@ -264,12 +347,17 @@ pub struct FrameFlags {
 	/// Frame is zlib compressed
 	///
 	/// It is **required** `data_length_indicator` be set if this is set.
+	///
+	/// NOTE: Frames are never compressed when written, so this only has an effect on frames that
+	///       are still encrypted.
 	pub compression: bool,
 	/// Frame encryption method symbol
 	///
-	/// NOTE: Since the encryption method is unknown, lofty cannot do anything with these frames
+	/// The method is registered by an [`EncryptionMethodRegistrationFrame`](crate::id3::v2::EncryptionMethodRegistrationFrame)
+	/// with the same symbol. Unless a [`FrameCipher`](crate::id3::v2::FrameCipher) is provided for
+	/// it, lofty cannot do anything with these frames, and they are read as [`Frame::Binary`].
 	///
-	/// The encryption method symbol **must** be > 0x80.
+	/// The encryption method symbol **must** be >= 0x80.
 	pub encryption: Option<u8>,
 	/// Frame is unsynchronised
 	///
@@ -277,15 +365,18 @@ pub struct FrameFlags {
 	/// with the MPEG frame header, which is often identified by its "frame sync" (11 set bits).
 	/// It is preferred an ID3v2 tag is either *completely* unsynchronised or not unsynchronised at all.
 	///
-	/// NOTE: While unsynchronized data is read, for the sake of simplicity, this flag has no effect when
-	/// writing. There isn't much reason to write unsynchronized data.
-	pub unsynchronisation: bool, /* TODO: Maybe? This doesn't seem very useful, and it is wasted effort if one forgets to make this false when writing. */
+	/// NOTE: Frame level unsynchronisation only exists in ID3v2.4. When writing ID3v2.2 and ID3v2.3
+	/// tags, this has no effect, use [`Id3v2TagFlags::unsynchronisation`](crate::id3::v2::Id3v2TagFlags::unsynchronisation)
+	/// or [`WriteOptions::id3v2_unsynchronisation`](crate::config::WriteOptions::id3v2_unsynchronisation)
+	/// instead.
+	pub unsynchronisation: bool,
 	/// Frame has a data length indicator
 	///
 	/// The data length indicator is the size of the frame if the flags were all zeroed out.
 	/// This is usually used in combination with `compression` and `encryption` (depending on encryption method).
 	///
-	/// If using `encryption`, the final size must be added.
+	/// If using `encryption` in ID3v2.4, the final size must be added. This is done automatically
+	/// for frames encrypted with a [`FrameCipher`](crate::id3::v2::FrameCipher).
 	pub data_length_indicator: Option<u32>,
 }
 
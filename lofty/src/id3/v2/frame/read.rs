@@ -2,17 +2,21 @@ use super::header::parse::{parse_header, parse_v2_header};
 use super::Frame;
 use crate::config::{ParseOptions, ParsingMode};
 use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::cipher::EncryptionMethods;
 use crate::id3::v2::frame::content::parse_content;
 use crate::id3::v2::header::Id3v2Version;
 use crate::id3::v2::tag::ATTACHED_PICTURE_ID;
 use crate::id3::v2::util::synchsafe::{SynchsafeInteger, UnsynchronizedStream};
 use crate::id3::v2::{BinaryFrame, FrameFlags, FrameHeader, FrameId};
 use crate::macros::try_vec;
+use crate::util::alloc::VecFallibleCapacity;
 
 use std::io::Read;
 
 use byteorder::{BigEndian, ReadBytesExt};
 
+// `Frame` is large, but this is only ever a short-lived return value
+#[allow(clippy::large_enum_variant)]
 pub(crate) enum ParsedFrame<'a> {
 	Next(Frame<'a>),
 	Skip,
@@ -23,6 +27,7 @@ impl<'a> ParsedFrame<'a> {
 	pub(crate) fn read<R>(
 		reader: &mut R,
 		version: Id3v2Version,
+		tag_unsynchronised: bool,
 		parse_options: ParseOptions,
 	) -> Result<Self>
 	where
@@ -33,8 +38,8 @@ impl<'a> ParsedFrame<'a> {
 		// The header will be upgraded to ID3v2.4 past this point, so they can all be treated the same
 		let parse_header_result = match version {
 			Id3v2Version::V2 => parse_v2_header(reader, &mut size),
-			Id3v2Version::V3 => parse_header(reader, &mut size, false, parse_options),
-			Id3v2Version::V4 => parse_header(reader, &mut size, true, parse_options),
+			Id3v2Version::V3 => parse_header(reader, &mut size, false, parse_options.clone()),
+			Id3v2Version::V4 => parse_header(reader, &mut size, true, parse_options.clone()),
 		};
 		let (id, mut flags) = match parse_header_result {
 			Ok(None) => {
@@ -72,145 +77,173 @@ impl<'a> ParsedFrame<'a> {
 			return Ok(Self::Skip);
 		}
 
-		// Get the encryption method symbol
-		if let Some(enc) = flags.encryption.as_mut() {
-			log::trace!("Reading encryption method symbol");
-
-			if size < 1 {
-				return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
-			}
+		// ID3v2.4 tags with the unsynchronisation flag set have all of their frames unsynchronised
+		if tag_unsynchronised {
+			flags.unsynchronisation = true;
+		}
 
-			*enc = reader.read_u8()?;
-			size -= 1;
+		// Unsynchronisation applies to everything following the frame header, including the
+		// additional data
+		if flags.unsynchronisation {
+			let mut content = try_vec![0; size as usize];
+			reader.read_exact(&mut content)?;
+
+			let mut synchronised = Vec::try_with_capacity_stable(content.len())?;
+			UnsynchronizedStream::new(&content[..]).read_to_end(&mut synchronised)?;
+
+			return read_frame_data(
+				&mut &synchronised[..],
+				synchronised.len() as u32,
+				id,
+				flags,
+				version,
+				parse_options,
+			);
 		}
 
-		// Get the group identifier
-		if let Some(group) = flags.grouping_identity.as_mut() {
-			log::trace!("Reading group identifier");
+		read_frame_data(reader, size, id, flags, version, parse_options)
+	}
+}
 
-			if size < 1 {
-				return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
-			}
+fn read_frame_data<R>(
+	reader: &mut R,
+	mut size: u32,
+	id: FrameId<'static>,
+	mut flags: FrameFlags,
+	version: Id3v2Version,
+	parse_options: ParseOptions,
+) -> Result<ParsedFrame<'static>>
+where
+	R: Read,
+{
+	// The additional data follows the frame header in the same order as the flags:
+	//
+	// * ID3v2.3: decompressed size, encryption method symbol, group identifier
+	// * ID3v2.4: group identifier, encryption method symbol, data length indicator
+	if version == Id3v2Version::V3 {
+		read_data_length_indicator(reader, &mut size, &mut flags, version)?;
+	} else {
+		read_additional_byte(reader, &mut size, flags.grouping_identity.as_mut())?;
+	}
 
-			*group = reader.read_u8()?;
-			size -= 1;
-		}
+	read_additional_byte(reader, &mut size, flags.encryption.as_mut())?;
 
-		// Get the real data length
-		if flags.data_length_indicator.is_some() || flags.compression {
-			log::trace!("Reading data length indicator");
+	if version == Id3v2Version::V3 {
+		read_additional_byte(reader, &mut size, flags.grouping_identity.as_mut())?;
+	} else {
+		read_data_length_indicator(reader, &mut size, &mut flags, version)?;
+	}
 
-			if size < 4 {
-				return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
-			}
+	// Frames must have at least 1 byte, *after* all of the additional data flags can provide
+	if size == 0 {
+		return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+	}
 
-			// For some reason, no one can follow the spec, so while a data length indicator is *written*
-			// the flag **isn't always set**
-			let len = reader.read_u32::<BigEndian>()?.unsynch();
-			flags.data_length_indicator = Some(len);
-			size -= 4;
-		}
+	// Restrict the reader to the frame content
+	let mut reader = reader.take(u64::from(size));
 
-		// Frames must have at least 1 byte, *after* all of the additional data flags can provide
-		if size == 0 {
-			return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
-		}
-
-		// Restrict the reader to the frame content
-		let mut reader = reader.take(u64::from(size));
+	// Encrypted content can only be decompressed after it is decrypted, which has to wait
+	// until all of the ENCR frames are read. See `decrypt_frame`.
+	if flags.encryption.is_some() && parse_options.id3v2_frame_ciphers.is_some() {
+		return read_encrypted(&mut reader, size, id, flags);
+	}
 
-		// It seems like the flags are applied in the order:
+	// It seems like the flags are applied in the order:
+	//
+	// compression -> encryption
+	//
+	// Unsynchronisation has already been handled at this point
+	match flags {
+		// Possible combinations:
 		//
-		// unsynchronization -> compression -> encryption
+		// * compressed + encrypted
+		// * compressed
+		FrameFlags {
+			compression: true, ..
+		} => {
+			let mut compression_reader = handle_compression(reader)?;
+
+			if flags.encryption.is_some() {
+				return handle_encryption(&mut compression_reader, size, id, flags, version);
+			}
+
+			parse_frame(
+				&mut compression_reader,
+				size,
+				id,
+				flags,
+				version,
+				parse_options.parsing_mode,
+			)
+		},
+		// Possible combinations:
 		//
-		// Which all have their own needs, so this gets a little messy...
-		match flags {
-			// Possible combinations:
-			//
-			// * unsynchronized + compressed + encrypted
-			// * unsynchronized + compressed
-			// * unsynchronized + encrypted
-			// * unsynchronized
-			FrameFlags {
-				unsynchronisation: true,
-				..
-			} => {
-				let mut unsynchronized_reader = UnsynchronizedStream::new(reader);
-
-				if flags.compression {
-					let mut compression_reader = handle_compression(unsynchronized_reader)?;
-
-					if flags.encryption.is_some() {
-						return handle_encryption(&mut compression_reader, size, id, flags);
-					}
-
-					return parse_frame(
-						&mut compression_reader,
-						size,
-						id,
-						flags,
-						version,
-						parse_options.parsing_mode,
-					);
-				}
+		// * encrypted
+		FrameFlags {
+			encryption: Some(_),
+			..
+		} => handle_encryption(&mut reader, size, id, flags, version),
+		// Everything else that doesn't have special flags
+		_ => parse_frame(
+			&mut reader,
+			size,
+			id,
+			flags,
+			version,
+			parse_options.parsing_mode,
+		),
+	}
+}
 
-				if flags.encryption.is_some() {
-					return handle_encryption(&mut unsynchronized_reader, size, id, flags);
-				}
+// Reads the encryption method symbol or group identifier
+fn read_additional_byte<R>(reader: &mut R, size: &mut u32, byte: Option<&mut u8>) -> Result<()>
+where
+	R: Read,
+{
+	let Some(byte) = byte else {
+		return Ok(());
+	};
+
+	if *size < 1 {
+		return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+	}
 
-				return parse_frame(
-					&mut unsynchronized_reader,
-					size,
-					id,
-					flags,
-					version,
-					parse_options.parsing_mode,
-				);
-			},
-			// Possible combinations:
-			//
-			// * compressed + encrypted
-			// * compressed
-			FrameFlags {
-				compression: true, ..
-			} => {
-				let mut compression_reader = handle_compression(reader)?;
-
-				if flags.encryption.is_some() {
-					return handle_encryption(&mut compression_reader, size, id, flags);
-				}
+	*byte = reader.read_u8()?;
+	*size -= 1;
 
-				return parse_frame(
-					&mut compression_reader,
-					size,
-					id,
-					flags,
-					version,
-					parse_options.parsing_mode,
-				);
-			},
-			// Possible combinations:
-			//
-			// * encrypted
-			FrameFlags {
-				encryption: Some(_),
-				..
-			} => {
-				return handle_encryption(&mut reader, size, id, flags);
-			},
-			// Everything else that doesn't have special flags
-			_ => {
-				return parse_frame(
-					&mut reader,
-					size,
-					id,
-					flags,
-					version,
-					parse_options.parsing_mode,
-				);
-			},
-		}
+	Ok(())
+}
+
+fn read_data_length_indicator<R>(
+	reader: &mut R,
+	size: &mut u32,
+	flags: &mut FrameFlags,
+	version: Id3v2Version,
+) -> Result<()>
+where
+	R: Read,
+{
+	if flags.data_length_indicator.is_none() && !flags.compression {
+		return Ok(());
+	}
+
+	log::trace!("Reading data length indicator");
+
+	if *size < 4 {
+		return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
 	}
+
+	// For some reason, no one can follow the spec, so while a data length indicator is *written*
+	// the flag **isn't always set**
+	let mut len = reader.read_u32::<BigEndian>()?;
+	if version == Id3v2Version::V4 {
+		len = len.unsynch();
+	}
+
+	flags.data_length_indicator = Some(len);
+	*size -= 4;
+
+	Ok(())
 }
 
 #[cfg(feature = "id3v2_compression_support")]
@@ -230,8 +263,10 @@ fn handle_encryption<R: Read>(
 	size: u32,
 	id: FrameId<'static>,
 	flags: FrameFlags,
+	version: Id3v2Version,
 ) -> Result<ParsedFrame<'static>> {
-	if flags.data_length_indicator.is_none() {
+	// ID3v2.3 only has a data length indicator (the "decompressed size") for compressed frames
+	if version == Id3v2Version::V4 && flags.data_length_indicator.is_none() {
 		return Err(Id3v2Error::new(Id3v2ErrorKind::MissingDataLengthIndicator).into());
 	}
 
@@ -247,6 +282,72 @@ fn handle_encryption<R: Read>(
 	Ok(ParsedFrame::Next(encrypted_frame))
 }
 
+fn read_encrypted<R: Read>(
+	reader: &mut R,
+	size: u32,
+	id: FrameId<'static>,
+	flags: FrameFlags,
+) -> Result<ParsedFrame<'static>> {
+	let mut content = Vec::try_with_capacity_stable(size as usize)?;
+	reader.read_to_end(&mut content)?;
+
+	Ok(ParsedFrame::Next(Frame::Binary(BinaryFrame {
+		header: FrameHeader::new(id, flags),
+		data: content,
+	})))
+}
+
+/// Decrypt a frame read by [`ParsedFrame::read`], if there is a cipher for its encryption method
+///
+/// Frames that decrypt to a [`BinaryFrame`] are left encrypted, as they would otherwise be
+/// indistinguishable from frames that still need decrypting when written.
+pub(crate) fn decrypt_frame(
+	frame: &mut Frame<'static>,
+	ciphers: &EncryptionMethods,
+	version: Id3v2Version,
+	parse_mode: ParsingMode,
+) -> Result<()> {
+	let Frame::Binary(BinaryFrame { header, data }) = frame else {
+		return Ok(());
+	};
+
+	let Some(method_symbol) = header.flags.encryption else {
+		return Ok(());
+	};
+
+	let Some(content) = ciphers.decrypt(method_symbol, data) else {
+		return Ok(());
+	};
+
+	let content = content?;
+	let flags = header.flags;
+	let id = header.id.clone();
+
+	let decrypted = if flags.compression {
+		parse_content(
+			&mut handle_compression(&content[..])?,
+			id,
+			flags,
+			version,
+			parse_mode,
+		)?
+	} else {
+		parse_content(&mut &content[..], id, flags, version, parse_mode)?
+	};
+
+	match decrypted {
+		Some(Frame::Binary(_)) | None => {
+			log::debug!(
+				"Decrypted frame \"{}\" has no specific type, leaving it encrypted",
+				frame.id()
+			);
+		},
+		Some(decrypted) => *frame = decrypted,
+	}
+
+	Ok(())
+}
+
 fn parse_frame<R: Read>(
 	reader: &mut R,
 	size: u32,
@@ -23,16 +23,25 @@ pub enum Id3v2Version {
 #[allow(clippy::struct_excessive_bools)]
 pub struct Id3v2TagFlags {
 	/// Whether or not all frames are unsynchronised. See [`FrameFlags::unsynchronisation`](crate::id3::v2::FrameFlags::unsynchronisation)
+	///
+	/// This is applied if the tag is written. In ID3v2.2 and ID3v2.3, the entire tag is
+	/// unsynchronised, whereas in ID3v2.4, each frame is unsynchronised individually.
+	///
+	/// See also [`WriteOptions::id3v2_unsynchronisation`](crate::config::WriteOptions::id3v2_unsynchronisation)
 	pub unsynchronisation: bool,
 	/// Indicates if the tag is in an experimental stage
 	pub experimental: bool,
 	/// Indicates that the tag includes a footer
 	///
-	/// A footer will be created if the tag is written
+	/// A footer will be created if the tag is written as ID3v2.4, the only version to support it.
+	///
+	/// See also [`WriteOptions::id3v2_footer`](crate::config::WriteOptions::id3v2_footer)
 	pub footer: bool,
 	/// Whether or not to include a CRC-32 in the extended header
 	///
-	/// This is calculated if the tag is written
+	/// This is calculated if the tag is written as ID3v2.3 or ID3v2.4.
+	///
+	/// See also [`WriteOptions::id3v2_crc`](crate::config::WriteOptions::id3v2_crc)
 	pub crc: bool,
 	/// Restrictions on the tag, written in the extended header
 	///
@@ -64,16 +73,16 @@ impl Id3v2TagFlags {
 
 	/// Get the **ID3v2.3** byte representation of the flags
 	///
-	/// NOTE: This does not include the extended header flags
+	/// NOTE: This does not include the extended header flags, and ID3v2.3 has no footer
 	pub fn as_id3v23_byte(&self) -> u8 {
 		let mut byte = 0;
 
-		if self.experimental {
-			byte |= 0x40;
+		if self.unsynchronisation {
+			byte |= 0x80;
 		}
 
-		if self.footer {
-			byte |= 0x10;
+		if self.experimental {
+			byte |= 0x20;
 		}
 
 		byte
@@ -140,7 +149,10 @@ impl Id3v2Header {
 		let extended_header =
 			(version == Id3v2Version::V4 || version == Id3v2Version::V3) && flags & 0x40 == 0x40;
 
-		if extended_header {
+		if extended_header && version == Id3v2Version::V3 {
+			extended_size =
+				parse_id3v23_extended_header(bytes, &mut flags_parsed, flags & 0x80 == 0x80)?;
+		} else if extended_header {
 			extended_size = bytes.read_u32::<BigEndian>()?.unsynch();
 
 			if extended_size < 6 {
@@ -183,7 +195,91 @@ impl Id3v2Header {
 	}
 
 	/// The total size of the tag, including the header, footer, and extended header
+	///
+	/// NOTE: `size` already includes the extended header
 	pub(crate) fn full_tag_size(&self) -> u32 {
-		self.size + 10 + self.extended_size + if self.flags.footer { 10 } else { 0 }
+		self.size + 10 + if self.flags.footer { 10 } else { 0 }
+	}
+}
+
+// The ID3v2.3 extended header is laid out differently from the ID3v2.4 one:
+//
+// Size (4, not synchsafe, excluding itself)
+// Flags (2)
+// Padding size (4)
+// Followed by the CRC (4), if present
+//
+// It is also subject to tag-wide unsynchronisation, so this returns the number of bytes
+// actually consumed.
+fn parse_id3v23_extended_header<R>(
+	bytes: &mut R,
+	flags: &mut Id3v2TagFlags,
+	unsynchronised: bool,
+) -> Result<u32>
+where
+	R: Read,
+{
+	let mut reader = ExtendedHeaderReader {
+		reader: bytes,
+		unsynchronised,
+		encountered_ff: false,
+		consumed: 0,
+	};
+
+	let size = reader.read_u32::<BigEndian>()?;
+	if size < 6 {
+		return Err(Id3v2Error::new(Id3v2ErrorKind::BadExtendedHeaderSize).into());
+	}
+
+	let extended_flags = reader.read_u16::<BigEndian>()?;
+	let _padding_size = reader.read_u32::<BigEndian>()?;
+
+	let mut remaining = size - 6;
+	if extended_flags & 0x8000 == 0x8000 && remaining >= 4 {
+		flags.crc = true;
+
+		// We don't care about the existing CRC
+		let _crc = reader.read_u32::<BigEndian>()?;
+		remaining -= 4;
+	}
+
+	std::io::copy(
+		&mut (&mut reader).take(u64::from(remaining)),
+		&mut std::io::sink(),
+	)?;
+
+	Ok(reader.consumed)
+}
+
+// Reads a single byte at a time, so no more than the extended header is consumed
+struct ExtendedHeaderReader<'a, R> {
+	reader: &'a mut R,
+	unsynchronised: bool,
+	encountered_ff: bool,
+	consumed: u32,
+}
+
+impl<R: Read> Read for ExtendedHeaderReader<'_, R> {
+	fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+		let Some(dest) = buf.first_mut() else {
+			return Ok(0);
+		};
+
+		loop {
+			let mut byte = [0];
+			if self.reader.read(&mut byte)? == 0 {
+				return Ok(0);
+			}
+
+			self.consumed += 1;
+
+			let encountered_ff = std::mem::replace(&mut self.encountered_ff, byte[0] == 0xFF);
+			if self.unsynchronised && encountered_ff && byte[0] == 0 {
+				continue;
+			}
+
+			*dest = byte[0];
+			return Ok(1);
+		}
 	}
 }
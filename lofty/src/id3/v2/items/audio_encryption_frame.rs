@@ -0,0 +1,151 @@
+use crate::error::Result;
+use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
+use crate::util::text::{decode_text, encode_text, TextDecodeOptions, TextEncoding};
+
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("AENC"));
+
+/// An `ID3v2` audio encryption frame
+///
+/// This frame indicates that the audio stream is encrypted, and by whom. It can also describe an
+/// unencrypted preview of the audio.
+///
+/// There may only be one audio encryption frame per [`owner`](Self::owner), so the `PartialEq` and
+/// `Hash` implementations depend solely on it.
+#[derive(Clone, Debug, Eq)]
+pub struct AudioEncryptionFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// A URL containing an email address, or a link to a location where an email can be found,
+	/// that belongs to the organisation responsible for the encryption
+	pub owner: String,
+	/// The start of the unencrypted preview, in MPEG frames
+	pub preview_start: u16,
+	/// The length of the unencrypted preview, in MPEG frames
+	///
+	/// A length of `0` means there is no preview.
+	pub preview_length: u16,
+	/// Data required for decryption, defined by the owner
+	pub encryption_info: Vec<u8>,
+}
+
+impl<'a> PartialEq for AudioEncryptionFrame<'a> {
+	fn eq(&self, other: &Self) -> bool {
+		self.owner == other.owner
+	}
+}
+
+impl<'a> Hash for AudioEncryptionFrame<'a> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.owner.hash(state);
+	}
+}
+
+impl<'a> AudioEncryptionFrame<'a> {
+	/// Create a new [`AudioEncryptionFrame`]
+	pub fn new(
+		owner: String,
+		preview_start: u16,
+		preview_length: u16,
+		encryption_info: Vec<u8>,
+	) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			owner,
+			preview_start,
+			preview_length,
+			encryption_info,
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read an [`AudioEncryptionFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * Not enough data
+	pub fn parse<R>(reader: &mut R, frame_flags: FrameFlags) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let Ok(owner) = decode_text(
+			reader,
+			TextDecodeOptions::new()
+				.encoding(TextEncoding::Latin1)
+				.terminated(true),
+		) else {
+			return Ok(None);
+		};
+
+		let preview_start = reader.read_u16::<BigEndian>()?;
+		let preview_length = reader.read_u16::<BigEndian>()?;
+
+		let mut encryption_info = Vec::new();
+		reader.read_to_end(&mut encryption_info)?;
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(AudioEncryptionFrame {
+			header,
+			owner: owner.content,
+			preview_start,
+			preview_length,
+			encryption_info,
+		}))
+	}
+
+	/// Convert an [`AudioEncryptionFrame`] to a byte vec
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut content = encode_text(&self.owner, TextEncoding::Latin1, true);
+		content.extend(self.preview_start.to_be_bytes());
+		content.extend(self.preview_length.to_be_bytes());
+		content.extend_from_slice(&self.encryption_info);
+
+		content
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{AudioEncryptionFrame, FrameFlags};
+
+	#[test_log::test]
+	fn aenc_re_read() {
+		let frame = AudioEncryptionFrame::new(String::from("foo@bar.com"), 10, 250, vec![1, 2, 3]);
+
+		let encoded = frame.as_bytes();
+		assert_eq!(
+			encoded,
+			b"foo@bar.com\x00\x00\x0A\x00\xFA\x01\x02\x03".to_vec()
+		);
+
+		let parsed = AudioEncryptionFrame::parse(&mut &encoded[..], FrameFlags::default())
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed.owner, frame.owner);
+		assert_eq!(parsed.preview_start, 10);
+		assert_eq!(parsed.preview_length, 250);
+		assert_eq!(parsed.encryption_info, frame.encryption_info);
+	}
+}
@@ -0,0 +1,186 @@
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
+use crate::macros::err;
+
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::io::Read;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("ASPI"));
+
+/// An `ID3v2` audio seek point index frame
+///
+/// This frame is a list of seek points for audio formats that are difficult to seek in, such as
+/// VBR MPEG audio. The audio is split into equally sized parts, and each index point stores where
+/// its part starts.
+///
+/// The byte offset of the `i`th point, relative to [`indexed_data_start`](Self::indexed_data_start), is:
+///
+/// `fractions[i] / 2^bits_per_index_point * indexed_data_length`
+///
+/// NOTE: This frame is only available in ID3v2.4, and will be discarded when writing ID3v2.3 tags.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct AudioSeekPointIndexFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// The byte offset of the indexed data from the beginning of the file
+	pub indexed_data_start: u32,
+	/// The length of the indexed data, in bytes
+	pub indexed_data_length: u32,
+	/// The size of each index point, must be 8 or 16
+	pub bits_per_index_point: u8,
+	/// The index points, as fractions of [`indexed_data_length`](Self::indexed_data_length)
+	pub fractions: Vec<u16>,
+}
+
+impl<'a> AudioSeekPointIndexFrame<'a> {
+	/// Create a new [`AudioSeekPointIndexFrame`]
+	///
+	/// This will use 16 bits per index point.
+	pub fn new(indexed_data_start: u32, indexed_data_length: u32, fractions: Vec<u16>) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			indexed_data_start,
+			indexed_data_length,
+			bits_per_index_point: 16,
+			fractions,
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read an [`AudioSeekPointIndexFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * Bad index point size (See [`Id3v2ErrorKind::BadAspiIndexPointSize`])
+	/// * Not enough data
+	pub fn parse<R>(reader: &mut R, frame_flags: FrameFlags) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let indexed_data_start = reader.read_u32::<BigEndian>()?;
+		let indexed_data_length = reader.read_u32::<BigEndian>()?;
+		let index_point_count = reader.read_u16::<BigEndian>()?;
+		let bits_per_index_point = reader.read_u8()?;
+
+		let mut fractions = Vec::with_capacity(usize::from(index_point_count));
+		for _ in 0..index_point_count {
+			let fraction = match bits_per_index_point {
+				8 => u16::from(reader.read_u8()?),
+				16 => reader.read_u16::<BigEndian>()?,
+				_ => {
+					return Err(Id3v2Error::new(Id3v2ErrorKind::BadAspiIndexPointSize(
+						bits_per_index_point,
+					))
+					.into())
+				},
+			};
+
+			fractions.push(fraction);
+		}
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(AudioSeekPointIndexFrame {
+			header,
+			indexed_data_start,
+			indexed_data_length,
+			bits_per_index_point,
+			fractions,
+		}))
+	}
+
+	/// Convert an [`AudioSeekPointIndexFrame`] to a byte vec
+	///
+	/// # Errors
+	///
+	/// * There are more than [`u16::MAX`] index points
+	/// * `bits_per_index_point` is not 8 or 16, or is 8 with a fraction that doesn't fit
+	pub fn as_bytes(&self) -> Result<Vec<u8>> {
+		let Ok(index_point_count) = u16::try_from(self.fractions.len()) else {
+			err!(TooMuchData);
+		};
+
+		let bad_index_point_size = || -> crate::error::LoftyError {
+			Id3v2Error::new(Id3v2ErrorKind::BadAspiIndexPointSize(
+				self.bits_per_index_point,
+			))
+			.into()
+		};
+
+		let mut content = Vec::with_capacity(11 + (self.fractions.len() * 2));
+		content.extend(self.indexed_data_start.to_be_bytes());
+		content.extend(self.indexed_data_length.to_be_bytes());
+		content.extend(index_point_count.to_be_bytes());
+		content.push(self.bits_per_index_point);
+
+		for fraction in &self.fractions {
+			match self.bits_per_index_point {
+				8 => content.push(u8::try_from(*fraction).map_err(|_| bad_index_point_size())?),
+				16 => content.extend(fraction.to_be_bytes()),
+				_ => return Err(bad_index_point_size()),
+			}
+		}
+
+		Ok(content)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{AudioSeekPointIndexFrame, FrameFlags};
+
+	#[test_log::test]
+	fn aspi_re_read() {
+		let mut frame = AudioSeekPointIndexFrame::new(0, 8192, vec![0, 0x4000, 0x8000, 0xC000]);
+
+		let encoded = frame.as_bytes().unwrap();
+		assert_eq!(encoded.len(), 11 + 8);
+		assert_eq!(
+			AudioSeekPointIndexFrame::parse(&mut &encoded[..], FrameFlags::default())
+				.unwrap()
+				.unwrap(),
+			frame
+		);
+
+		frame.bits_per_index_point = 8;
+		frame.fractions = vec![0, 0x40, 0x80, 0xC0];
+
+		let encoded = frame.as_bytes().unwrap();
+		assert_eq!(&encoded[10..], &[8, 0, 0x40, 0x80, 0xC0]);
+		assert_eq!(
+			AudioSeekPointIndexFrame::parse(&mut &encoded[..], FrameFlags::default())
+				.unwrap()
+				.unwrap(),
+			frame
+		);
+	}
+
+	#[test_log::test]
+	fn aspi_bad_index_point_size() {
+		let mut frame = AudioSeekPointIndexFrame::new(0, 8192, vec![0x100]);
+
+		frame.bits_per_index_point = 8;
+		assert!(frame.as_bytes().is_err());
+
+		frame.bits_per_index_point = 12;
+		assert!(frame.as_bytes().is_err());
+	}
+}
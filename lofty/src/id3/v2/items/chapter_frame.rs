@@ -0,0 +1,307 @@
+use crate::config::{ParseOptions, ParsingMode};
+use crate::error::Result;
+use crate::id3::v2::cipher::EncryptionMethods;
+use crate::id3::v2::frame::read::ParsedFrame;
+use crate::id3::v2::header::Id3v2Version;
+use crate::id3::v2::write::frame::{create_items, create_items_v3};
+use crate::id3::v2::{Frame, FrameFlags, FrameHeader, FrameId};
+use crate::util::text::{decode_text, encode_text, TextDecodeOptions, TextEncoding};
+
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("CHAP"));
+
+/// A byte offset of `0xFFFFFFFF` means that the offset should be ignored
+const NO_BYTE_OFFSET: u32 = u32::MAX;
+
+/// An `ID3v2` chapter frame
+///
+/// This frame describes a single chapter of the audio. Its title, URL, image, etc. are stored in
+/// the embedded [`sub_frames`](Self::sub_frames), typically a `TIT2` frame for the title.
+///
+/// Chapters are referenced by their element ID from a [`TableOfContentsFrame`](crate::id3::v2::TableOfContentsFrame).
+#[derive(Clone, Debug, Eq)]
+pub struct ChapterFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// A unique identifier for the chapter within the tag
+	pub element_id: String,
+	/// The start time of the chapter, in milliseconds
+	pub start_time: u32,
+	/// The end time of the chapter, in milliseconds
+	pub end_time: u32,
+	/// The byte offset of the first audio frame of the chapter
+	pub start_offset: Option<u32>,
+	/// The byte offset of the first audio frame following the chapter
+	pub end_offset: Option<u32>,
+	/// Frames describing the chapter
+	pub sub_frames: Vec<Frame<'a>>,
+}
+
+impl<'a> PartialEq for ChapterFrame<'a> {
+	fn eq(&self, other: &Self) -> bool {
+		self.element_id == other.element_id
+	}
+}
+
+impl<'a> Hash for ChapterFrame<'a> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.element_id.hash(state);
+	}
+}
+
+impl<'a> ChapterFrame<'a> {
+	/// Create a new [`ChapterFrame`]
+	///
+	/// This will have no byte offsets or sub-frames.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{ChapterFrame, Frame, FrameId, TextInformationFrame};
+	/// use lofty::TextEncoding;
+	/// use std::borrow::Cow;
+	///
+	/// let mut chapter = ChapterFrame::new(String::from("chp0"), 0, 30_000);
+	/// chapter
+	/// 	.sub_frames
+	/// 	.push(Frame::Text(TextInformationFrame::new(
+	/// 		FrameId::Valid(Cow::Borrowed("TIT2")),
+	/// 		TextEncoding::UTF8,
+	/// 		String::from("Intro"),
+	/// 	)));
+	///
+	/// assert_eq!(chapter.element_id, "chp0");
+	/// ```
+	pub fn new(element_id: String, start_time: u32, end_time: u32) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			element_id,
+			start_time,
+			end_time,
+			start_offset: None,
+			end_offset: None,
+			sub_frames: Vec::new(),
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read a [`ChapterFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * Failure to read from `reader`
+	/// * A sub-frame is invalid (only in [`ParsingMode::Strict`])
+	pub fn parse<R>(
+		reader: &mut R,
+		frame_flags: FrameFlags,
+		version: Id3v2Version,
+		parse_mode: ParsingMode,
+	) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let Ok(element_id) = read_element_id(reader) else {
+			return Ok(None);
+		};
+
+		let start_time = reader.read_u32::<BigEndian>()?;
+		let end_time = reader.read_u32::<BigEndian>()?;
+		let start_offset = reader.read_u32::<BigEndian>()?;
+		let end_offset = reader.read_u32::<BigEndian>()?;
+
+		let sub_frames = read_sub_frames(reader, version, parse_mode)?;
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(ChapterFrame {
+			header,
+			element_id,
+			start_time,
+			end_time,
+			start_offset: (start_offset != NO_BYTE_OFFSET).then_some(start_offset),
+			end_offset: (end_offset != NO_BYTE_OFFSET).then_some(end_offset),
+			sub_frames,
+		}))
+	}
+
+	/// Convert a [`ChapterFrame`] to a byte vec
+	///
+	/// NOTE: The sub-frames are written with the same rules as the rest of the tag, meaning
+	///       frames not supported in ID3v2.3 will be converted or discarded.
+	///
+	/// # Errors
+	///
+	/// * A sub-frame could not be written
+	pub fn as_bytes(&self, is_id3v23: bool) -> Result<Vec<u8>> {
+		let mut content = encode_text(&self.element_id, TextEncoding::Latin1, true);
+
+		content.write_u32::<BigEndian>(self.start_time)?;
+		content.write_u32::<BigEndian>(self.end_time)?;
+		content.write_u32::<BigEndian>(self.start_offset.unwrap_or(NO_BYTE_OFFSET))?;
+		content.write_u32::<BigEndian>(self.end_offset.unwrap_or(NO_BYTE_OFFSET))?;
+
+		write_sub_frames(&mut content, &self.sub_frames, is_id3v23)?;
+
+		Ok(content)
+	}
+}
+
+pub(super) fn read_element_id<R>(reader: &mut R) -> Result<String>
+where
+	R: Read,
+{
+	let element_id = decode_text(
+		reader,
+		TextDecodeOptions::new()
+			.encoding(TextEncoding::Latin1)
+			.terminated(true),
+	)?;
+
+	Ok(element_id.content)
+}
+
+/// Read the sub-frames embedded in a `CHAP` or `CTOC` frame
+pub(super) fn read_sub_frames<R>(
+	reader: &mut R,
+	version: Id3v2Version,
+	parse_mode: ParsingMode,
+) -> Result<Vec<Frame<'static>>>
+where
+	R: Read,
+{
+	// The sub-frames need to be read into a buffer, otherwise `ParsedFrame::read` would recurse
+	// with an ever-growing reader type
+	let mut content = Vec::new();
+	reader.read_to_end(&mut content)?;
+
+	let parse_options = ParseOptions::new().parsing_mode(parse_mode);
+
+	let mut sub_frames = Vec::new();
+	let mut reader = &content[..];
+	loop {
+		match ParsedFrame::read(&mut reader, version, false, parse_options.clone())? {
+			ParsedFrame::Next(frame) => sub_frames.push(frame),
+			ParsedFrame::Skip => {},
+			ParsedFrame::Eof => break,
+		}
+	}
+
+	Ok(sub_frames)
+}
+
+pub(super) fn write_sub_frames(
+	content: &mut Vec<u8>,
+	sub_frames: &[Frame<'_>],
+	is_id3v23: bool,
+) -> Result<()> {
+	let mut sub_frames = sub_frames.iter().filter_map(Frame::as_opt_ref);
+
+	if is_id3v23 {
+		create_items_v3(content, &mut sub_frames, &EncryptionMethods::default())
+	} else {
+		create_items(
+			content,
+			&mut sub_frames,
+			&EncryptionMethods::default(),
+			false,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::config::ParsingMode;
+	use crate::id3::v2::header::Id3v2Version;
+	use crate::id3::v2::{ChapterFrame, Frame, FrameFlags};
+
+	use std::borrow::Cow;
+
+	fn expected(title: String) -> ChapterFrame<'static> {
+		let mut chapter = ChapterFrame::new(String::from("chp0"), 1500, 30_000);
+		chapter.end_offset = Some(48_000);
+		chapter
+			.sub_frames
+			.push(Frame::text(Cow::Borrowed("TIT2"), title));
+
+		chapter
+	}
+
+	fn re_read(chapter: &ChapterFrame<'_>, version: Id3v2Version) -> ChapterFrame<'static> {
+		let encoded = chapter.as_bytes(version == Id3v2Version::V3).unwrap();
+
+		ChapterFrame::parse(
+			&mut &encoded[..],
+			FrameFlags::default(),
+			version,
+			ParsingMode::Strict,
+		)
+		.unwrap()
+		.unwrap()
+	}
+
+	#[test_log::test]
+	fn chap_re_read() {
+		// ID3v2.3 frame sizes aren't synchsafe, which only makes a difference at >= 128 bytes
+		for title in [String::from("Foo title"), "a".repeat(200)] {
+			for version in [Id3v2Version::V3, Id3v2Version::V4] {
+				let chapter = expected(title.clone());
+				let parsed = re_read(&chapter, version);
+
+				assert_eq!(parsed.element_id, chapter.element_id);
+				assert_eq!(parsed.start_time, chapter.start_time);
+				assert_eq!(parsed.end_time, chapter.end_time);
+				assert_eq!(parsed.start_offset, None);
+				assert_eq!(parsed.end_offset, Some(48_000));
+
+				let [Frame::Text(parsed_title)] = &parsed.sub_frames[..] else {
+					panic!("Expected a single text frame, got: {:?}", parsed.sub_frames);
+				};
+
+				assert_eq!(parsed_title.header.id.as_str(), "TIT2");
+				assert_eq!(parsed_title.value, title);
+			}
+		}
+	}
+
+	#[test_log::test]
+	fn chap_nested_sub_frames() {
+		let mut chapter = expected(String::from("Foo title"));
+
+		let mut nested = ChapterFrame::new(String::from("chp1"), 0, 1500);
+		nested.sub_frames.push(Frame::text(
+			Cow::Borrowed("TIT2"),
+			String::from("Bar title"),
+		));
+		chapter.sub_frames.push(Frame::Chapter(nested));
+
+		let parsed = re_read(&chapter, Id3v2Version::V4);
+
+		let Some(Frame::Chapter(parsed_nested)) = parsed.sub_frames.get(1) else {
+			panic!("Expected a nested chapter frame");
+		};
+
+		assert_eq!(parsed_nested.element_id, "chp1");
+		assert_eq!(parsed_nested.end_time, 1500);
+		assert_eq!(parsed_nested.sub_frames.len(), 1);
+	}
+}
@@ -0,0 +1,298 @@
+use crate::error::{ErrorKind, Id3v2Error, Id3v2ErrorKind, LoftyError, Result};
+use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
+use crate::picture::{MimeType, Picture, PictureType};
+use crate::util::text::{
+	decode_text, encode_text, utf8_decode_str, TextDecodeOptions, TextEncoding,
+};
+
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::io::Read;
+
+use byteorder::ReadBytesExt;
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("COMR"));
+
+/// How the item in a [`CommercialFrame`] is delivered
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+#[allow(missing_docs)]
+pub enum ReceivedAs {
+	Other = 0,
+	StandardCdAlbum = 1,
+	CompressedAudioOnCd = 2,
+	FileOverInternet = 3,
+	StreamOverInternet = 4,
+	NoteSheets = 5,
+	NoteSheetsInBook = 6,
+	MusicOnOtherMedia = 7,
+	NonMusicalMerchandise = 8,
+}
+
+impl ReceivedAs {
+	/// Get a [`ReceivedAs`] from a `u8`
+	///
+	/// NOTE: Unknown values map to [`ReceivedAs::Other`]
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::ReceivedAs;
+	///
+	/// assert_eq!(ReceivedAs::from_u8(3), ReceivedAs::FileOverInternet);
+	///
+	/// // The valid range is 0..=8
+	/// assert_eq!(ReceivedAs::from_u8(10), ReceivedAs::Other);
+	/// ```
+	pub fn from_u8(byte: u8) -> Self {
+		match byte {
+			1 => Self::StandardCdAlbum,
+			2 => Self::CompressedAudioOnCd,
+			3 => Self::FileOverInternet,
+			4 => Self::StreamOverInternet,
+			5 => Self::NoteSheets,
+			6 => Self::NoteSheetsInBook,
+			7 => Self::MusicOnOtherMedia,
+			8 => Self::NonMusicalMerchandise,
+			_ => Self::Other,
+		}
+	}
+}
+
+/// An `ID3v2` commercial frame
+///
+/// This frame describes an offer to buy the audio, or related items.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CommercialFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// The encoding of the seller and description strings
+	pub encoding: TextEncoding,
+	/// The price of the item
+	///
+	/// Each price is made up of a three character ISO 4217 alphabetic currency code followed by
+	/// the price as a numerical string, using "." as the decimal separator. Multiple prices are
+	/// separated by "/", for example "USD10.00/SEK90.00".
+	pub price: String,
+	/// The date the prices are valid until, as an 8 character date string (YYYYMMDD)
+	pub valid_until: String,
+	/// A URL to contact the seller
+	pub contact_url: String,
+	/// How the item is delivered
+	pub received_as: ReceivedAs,
+	/// The name of the seller
+	pub seller: String,
+	/// A description of the item
+	pub description: String,
+	/// The seller's logo
+	///
+	/// Only the MIME type and data of the picture are stored. The spec only allows for
+	/// [`MimeType::Png`] and [`MimeType::Jpeg`].
+	pub seller_logo: Option<Picture>,
+}
+
+impl<'a> CommercialFrame<'a> {
+	/// Create a new [`CommercialFrame`]
+	///
+	/// This will have no contact URL, seller, description, or logo.
+	pub fn new(
+		encoding: TextEncoding,
+		price: String,
+		valid_until: String,
+		received_as: ReceivedAs,
+	) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			encoding,
+			price,
+			valid_until,
+			contact_url: String::new(),
+			received_as,
+			seller: String::new(),
+			description: String::new(),
+			seller_logo: None,
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read a [`CommercialFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * Invalid text encoding
+	/// * Not enough data
+	pub fn parse<R>(reader: &mut R, frame_flags: FrameFlags) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let Ok(encoding_byte) = reader.read_u8() else {
+			return Ok(None);
+		};
+
+		let encoding = TextEncoding::from_u8(encoding_byte)
+			.ok_or_else(|| LoftyError::new(ErrorKind::TextDecode("Found invalid encoding")))?;
+
+		let latin1_terminated = TextDecodeOptions::new()
+			.encoding(TextEncoding::Latin1)
+			.terminated(true);
+
+		let price = decode_text(reader, latin1_terminated)?.content;
+
+		let mut date_bytes = [0u8; 8];
+		reader.read_exact(&mut date_bytes)?;
+		let valid_until = utf8_decode_str(&date_bytes)?.to_owned();
+
+		let contact_url = decode_text(reader, latin1_terminated)?.content;
+		let received_as = ReceivedAs::from_u8(reader.read_u8()?);
+
+		let text_options = TextDecodeOptions::new().encoding(encoding).terminated(true);
+		let seller = decode_text(reader, text_options)?.content;
+		let description = decode_text(reader, text_options)?.content;
+
+		// The logo is optional, and is only present if there's anything left
+		let mut logo_bytes = Vec::new();
+		reader.read_to_end(&mut logo_bytes)?;
+
+		let mut seller_logo = None;
+		if !logo_bytes.is_empty() {
+			let logo_reader = &mut &logo_bytes[..];
+
+			let mime_type = decode_text(logo_reader, latin1_terminated)?.content;
+			let mime_type = (!mime_type.is_empty()).then(|| MimeType::from_str(&mime_type));
+
+			seller_logo = Some(Picture::new_unchecked(
+				PictureType::Other,
+				mime_type,
+				None,
+				logo_reader.to_vec(),
+			));
+		}
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(CommercialFrame {
+			header,
+			encoding,
+			price,
+			valid_until,
+			contact_url,
+			received_as,
+			seller,
+			description,
+			seller_logo,
+		}))
+	}
+
+	/// Convert a [`CommercialFrame`] to a byte vec
+	///
+	/// NOTE: The caller must verify that the `price` and `contact_url` fields are valid Latin-1
+	///       encoded strings
+	///
+	/// # Errors
+	///
+	/// * `valid_until` is not at least 8 characters (it will be truncated if greater)
+	pub fn as_bytes(&self, is_id3v23: bool) -> Result<Vec<u8>> {
+		let mut encoding = self.encoding;
+		if is_id3v23 {
+			encoding = encoding.to_id3v23();
+		}
+
+		let mut bytes = vec![encoding as u8];
+
+		bytes.extend(encode_text(&self.price, TextEncoding::Latin1, true));
+		if self.valid_until.len() < 8 {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+		}
+
+		bytes.extend(self.valid_until.as_bytes().iter().take(8));
+		bytes.extend(encode_text(&self.contact_url, TextEncoding::Latin1, true));
+		bytes.push(self.received_as as u8);
+		bytes.extend(encode_text(&self.seller, encoding, true));
+		bytes.extend(encode_text(&self.description, encoding, true));
+
+		if let Some(seller_logo) = &self.seller_logo {
+			bytes.extend(encode_text(
+				seller_logo.mime_str(),
+				TextEncoding::Latin1,
+				true,
+			));
+			bytes.extend_from_slice(seller_logo.data());
+		}
+
+		Ok(bytes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{CommercialFrame, FrameFlags, ReceivedAs};
+	use crate::picture::{MimeType, Picture, PictureType};
+	use crate::TextEncoding;
+
+	fn expected() -> CommercialFrame<'static> {
+		let mut frame = CommercialFrame::new(
+			TextEncoding::UTF8,
+			String::from("USD10.00/SEK90.00"),
+			String::from("20301231"),
+			ReceivedAs::FileOverInternet,
+		);
+		frame.contact_url = String::from("http://example.com");
+		frame.seller = String::from("Foo seller");
+		frame.description = String::from("Foo description");
+
+		frame
+	}
+
+	#[test_log::test]
+	fn comr_re_read() {
+		let frame = expected();
+
+		let encoded = frame.as_bytes(false).unwrap();
+		let parsed = CommercialFrame::parse(&mut &encoded[..], FrameFlags::default())
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, frame);
+	}
+
+	#[test_log::test]
+	fn comr_seller_logo() {
+		let mut frame = expected();
+		frame.seller_logo = Some(Picture::new_unchecked(
+			PictureType::Other,
+			Some(MimeType::Png),
+			None,
+			vec![1, 2, 3],
+		));
+
+		let encoded = frame.as_bytes(false).unwrap();
+		assert!(encoded.ends_with(b"image/png\x00\x01\x02\x03"));
+
+		let parsed = CommercialFrame::parse(&mut &encoded[..], FrameFlags::default())
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, frame);
+	}
+
+	#[test_log::test]
+	fn comr_encode_id3v23() {
+		let encoded = expected().as_bytes(true).unwrap();
+		assert_eq!(encoded[0], TextEncoding::UTF16 as u8);
+	}
+}
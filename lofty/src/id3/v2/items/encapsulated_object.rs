@@ -2,12 +2,20 @@ use crate::error::{ErrorKind, Id3v2Error, Id3v2ErrorKind, LoftyError, Result};
 use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
 use crate::util::text::{decode_text, encode_text, TextDecodeOptions, TextEncoding};
 
+use std::hash::{Hash, Hasher};
 use std::io::{Cursor, Read};
 
 const FRAME_ID: FrameId<'static> = FrameId::Valid(std::borrow::Cow::Borrowed("GEOB"));
 
 /// Allows for encapsulation of any file type inside an ID3v2 tag
-#[derive(PartialEq, Clone, Debug, Eq, Hash)]
+///
+/// This is used in the `GEOB` frame, where the frames are told apart by their
+/// [`descriptor`](Self::descriptor). This means for each `GeneralEncapsulatedObject` in the tag,
+/// the descriptor must be unique.
+///
+/// Applications such as DJ software commonly use these to store their own data, identified by the
+/// descriptor.
+#[derive(Clone, Debug, Eq)]
 pub struct GeneralEncapsulatedObject<'a> {
 	pub(crate) header: FrameHeader<'a>,
 	/// The text encoding of `file_name` and `description`
@@ -22,6 +30,18 @@ pub struct GeneralEncapsulatedObject<'a> {
 	pub data: Vec<u8>,
 }
 
+impl<'a> PartialEq for GeneralEncapsulatedObject<'a> {
+	fn eq(&self, other: &Self) -> bool {
+		self.descriptor_or_empty() == other.descriptor_or_empty()
+	}
+}
+
+impl<'a> Hash for GeneralEncapsulatedObject<'a> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.descriptor_or_empty().hash(state);
+	}
+}
+
 impl<'a> GeneralEncapsulatedObject<'a> {
 	/// Create a new [`GeneralEncapsulatedObject`]
 	pub fn new(
@@ -57,6 +77,11 @@ impl<'a> GeneralEncapsulatedObject<'a> {
 		self.header.flags = flags;
 	}
 
+	/// The descriptor, with a missing descriptor being empty
+	pub(crate) fn descriptor_or_empty(&self) -> &str {
+		self.descriptor.as_deref().unwrap_or_default()
+	}
+
 	/// Read a [`GeneralEncapsulatedObject`] from a slice
 	///
 	/// NOTE: This expects the frame header to have already been skipped
@@ -103,8 +128,13 @@ impl<'a> GeneralEncapsulatedObject<'a> {
 	/// Convert a [`GeneralEncapsulatedObject`] into an ID3v2 GEOB frame byte Vec
 	///
 	/// NOTE: This does not include a frame header
-	pub fn as_bytes(&self) -> Vec<u8> {
-		let encoding = self.encoding;
+	///
+	/// NOTE: If `is_id3v23` is `true`, the encoding will be converted to one supported by ID3v2.3
+	pub fn as_bytes(&self, is_id3v23: bool) -> Vec<u8> {
+		let mut encoding = self.encoding;
+		if is_id3v23 {
+			encoding = encoding.to_id3v23();
+		}
 
 		let mut bytes = vec![encoding as u8];
 
@@ -152,14 +182,19 @@ mod tests {
 
 		let parsed_geob = GeneralEncapsulatedObject::parse(&cont, FrameFlags::default()).unwrap();
 
-		assert_eq!(parsed_geob, expected);
+		// `PartialEq` only compares the descriptors
+		assert_eq!(parsed_geob.encoding, expected.encoding);
+		assert_eq!(parsed_geob.mime_type, expected.mime_type);
+		assert_eq!(parsed_geob.file_name, expected.file_name);
+		assert_eq!(parsed_geob.descriptor, expected.descriptor);
+		assert_eq!(parsed_geob.data, expected.data);
 	}
 
 	#[test_log::test]
 	fn geob_encode() {
 		let to_encode = expected();
 
-		let encoded = to_encode.as_bytes();
+		let encoded = to_encode.as_bytes(false);
 
 		let expected_bytes =
 			crate::tag::utils::test_utils::read_path("tests/tags/assets/id3v2/test.geob");
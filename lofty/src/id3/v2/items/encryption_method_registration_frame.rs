@@ -0,0 +1,139 @@
+use crate::error::Result;
+use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
+use crate::util::text::{decode_text, encode_text, TextDecodeOptions, TextEncoding};
+
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+
+use byteorder::ReadBytesExt;
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("ENCR"));
+
+/// An `ID3v2` encryption method registration frame
+///
+/// This frame registers the encryption method used by frames with the
+/// [`encryption`](crate::id3::v2::FrameFlags::encryption) flag set, which refer to it by its
+/// [`method_symbol`](Self::method_symbol).
+///
+/// There may only be one registration per method symbol, so the `PartialEq` and `Hash`
+/// implementations depend solely on it.
+#[derive(Clone, Debug, Eq)]
+pub struct EncryptionMethodRegistrationFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// A URL containing an email address, or a link to a location where an email can be found,
+	/// that belongs to the organisation responsible for the encryption method
+	pub owner: String,
+	/// The symbol used by encrypted frames to refer to this method
+	///
+	/// Values below `0x80` are reserved.
+	pub method_symbol: u8,
+	/// Data specific to the encryption method, defined by the owner
+	pub encryption_data: Vec<u8>,
+}
+
+impl<'a> PartialEq for EncryptionMethodRegistrationFrame<'a> {
+	fn eq(&self, other: &Self) -> bool {
+		self.method_symbol == other.method_symbol
+	}
+}
+
+impl<'a> Hash for EncryptionMethodRegistrationFrame<'a> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.method_symbol.hash(state);
+	}
+}
+
+impl<'a> EncryptionMethodRegistrationFrame<'a> {
+	/// Create a new [`EncryptionMethodRegistrationFrame`]
+	pub fn new(owner: String, method_symbol: u8, encryption_data: Vec<u8>) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			owner,
+			method_symbol,
+			encryption_data,
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read an [`EncryptionMethodRegistrationFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * Not enough data
+	pub fn parse<R>(reader: &mut R, frame_flags: FrameFlags) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let Ok(owner) = decode_text(
+			reader,
+			TextDecodeOptions::new()
+				.encoding(TextEncoding::Latin1)
+				.terminated(true),
+		) else {
+			return Ok(None);
+		};
+
+		let method_symbol = reader.read_u8()?;
+
+		let mut encryption_data = Vec::new();
+		reader.read_to_end(&mut encryption_data)?;
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(EncryptionMethodRegistrationFrame {
+			header,
+			owner: owner.content,
+			method_symbol,
+			encryption_data,
+		}))
+	}
+
+	/// Convert an [`EncryptionMethodRegistrationFrame`] to a byte vec
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut content = encode_text(&self.owner, TextEncoding::Latin1, true);
+		content.push(self.method_symbol);
+		content.extend_from_slice(&self.encryption_data);
+
+		content
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{EncryptionMethodRegistrationFrame, FrameFlags};
+
+	#[test_log::test]
+	fn encr_re_read() {
+		let frame =
+			EncryptionMethodRegistrationFrame::new(String::from("foo@bar.com"), 0x81, vec![1, 2]);
+
+		let encoded = frame.as_bytes();
+		assert_eq!(encoded, b"foo@bar.com\x00\x81\x01\x02".to_vec());
+
+		let parsed =
+			EncryptionMethodRegistrationFrame::parse(&mut &encoded[..], FrameFlags::default())
+				.unwrap()
+				.unwrap();
+
+		assert_eq!(parsed.owner, frame.owner);
+		assert_eq!(parsed.method_symbol, 0x81);
+		assert_eq!(parsed.encryption_data, frame.encryption_data);
+	}
+}
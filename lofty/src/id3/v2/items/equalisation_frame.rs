@@ -0,0 +1,348 @@
+use super::relative_volume_adjustment_frame::{
+	db_to_id3v23_adjustment, id3v23_adjustment_to_db, read_id3v23_adjustment,
+	ID3V23_ADJUSTMENT_BITS,
+};
+use crate::error::Result;
+use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
+use crate::util::text::{decode_text, encode_text, TextDecodeOptions, TextEncoding};
+
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("EQU2"));
+
+/// How to interpolate between the points of an [`EqualisationFrame`]
+#[repr(u8)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub enum InterpolationMethod {
+	/// No interpolation, an adjustment applies from its frequency up to the next point
+	Band = 0,
+	/// The adjustment is interpolated linearly between points
+	Linear = 1,
+}
+
+impl InterpolationMethod {
+	/// Get an [`InterpolationMethod`] from a `u8`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::InterpolationMethod;
+	///
+	/// assert_eq!(
+	/// 	InterpolationMethod::from_u8(1),
+	/// 	Some(InterpolationMethod::Linear)
+	/// );
+	///
+	/// // The valid range is 0..=1
+	/// assert_eq!(InterpolationMethod::from_u8(2), None);
+	/// ```
+	pub fn from_u8(byte: u8) -> Option<Self> {
+		match byte {
+			0 => Some(Self::Band),
+			1 => Some(Self::Linear),
+			_ => None,
+		}
+	}
+}
+
+/// A single adjustment point in an [`EqualisationFrame`]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
+pub struct AdjustmentPoint {
+	/// The frequency, in units of 1/2 Hz
+	pub frequency: u16,
+	/// A fixed point decibel value representing (adjustment*512), giving +/- 64 dB with a precision of 0.001953125 dB.
+	pub volume_adjustment: i16,
+}
+
+/// An `ID3v2` EQU2 frame
+///
+/// There may only be one frame per [`identification`](Self::identification), so the `PartialEq`
+/// and `Hash` implementations depend solely on it.
+///
+/// ## ID3v2.3
+///
+/// ID3v2.3 tags use the `EQUA` frame instead, which is converted to and from this frame. `EQUA`
+/// frames don't have an identification or interpolation method, and only allow for one frame per
+/// tag, so only the first `EQU2` frame will be written.
+///
+/// `EQUA` adjustments are unitless. They are treated as a fraction of the largest value they can
+/// hold, where a full increment doubles the volume, and a full decrement mutes it.
+#[derive(Clone, Debug, Eq)]
+pub struct EqualisationFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// How to interpolate between the adjustment points
+	pub interpolation_method: InterpolationMethod,
+	/// The identifier used to identify the situation and/or device where this adjustment should apply
+	pub identification: String,
+	/// The adjustment points, ordered by frequency
+	pub adjustment_points: Vec<AdjustmentPoint>,
+}
+
+impl<'a> PartialEq for EqualisationFrame<'a> {
+	fn eq(&self, other: &Self) -> bool {
+		self.identification == other.identification
+	}
+}
+
+impl<'a> Hash for EqualisationFrame<'a> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.identification.hash(state);
+	}
+}
+
+impl<'a> EqualisationFrame<'a> {
+	/// Create a new [`EqualisationFrame`]
+	pub fn new(
+		interpolation_method: InterpolationMethod,
+		identification: String,
+		adjustment_points: Vec<AdjustmentPoint>,
+	) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			interpolation_method,
+			identification,
+			adjustment_points,
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read an [`EqualisationFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// NOTE: An unknown interpolation method will be read as [`InterpolationMethod::Band`]
+	///
+	/// # Errors
+	///
+	/// * Failure to read from `reader`
+	pub fn parse<R>(reader: &mut R, frame_flags: FrameFlags) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let Ok(interpolation_method_byte) = reader.read_u8() else {
+			return Ok(None);
+		};
+
+		let interpolation_method = InterpolationMethod::from_u8(interpolation_method_byte)
+			.unwrap_or_else(|| {
+				log::warn!(
+					"Encountered an unknown EQU2 interpolation method: {}",
+					interpolation_method_byte
+				);
+				InterpolationMethod::Band
+			});
+
+		let identification = decode_text(
+			reader,
+			TextDecodeOptions::new()
+				.encoding(TextEncoding::Latin1)
+				.terminated(true),
+		)?
+		.content;
+
+		let mut adjustment_points = Vec::new();
+		while let (Ok(frequency), Ok(volume_adjustment)) = (
+			reader.read_u16::<BigEndian>(),
+			reader.read_i16::<BigEndian>(),
+		) {
+			adjustment_points.push(AdjustmentPoint {
+				frequency,
+				volume_adjustment,
+			});
+		}
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(EqualisationFrame {
+			header,
+			interpolation_method,
+			identification,
+			adjustment_points,
+		}))
+	}
+
+	/// Read an [`EqualisationFrame`] from an ID3v2.3 `EQUA` frame
+	///
+	/// The frequencies are converted from Hz, and the adjustments to decibels.
+	pub(crate) fn parse_equa<R>(reader: &mut R, frame_flags: FrameFlags) -> Option<Self>
+	where
+		R: Read,
+	{
+		let Ok(adjustment_bits) = reader.read_u8() else {
+			return None;
+		};
+
+		if adjustment_bits == 0 || adjustment_bits > 64 {
+			log::warn!(
+				"Encountered an unsupported EQUA adjustment size ({} bits), discarding",
+				adjustment_bits
+			);
+			return None;
+		}
+
+		let mut adjustment_bytes = [0; 8];
+		let adjustment_bytes = &mut adjustment_bytes[..usize::from(adjustment_bits.div_ceil(8))];
+
+		let mut adjustment_points = Vec::new();
+		while let Ok(frequency) = reader.read_u16::<BigEndian>() {
+			if reader.read_exact(adjustment_bytes).is_err() {
+				break;
+			}
+
+			// The most significant bit is the increment/decrement flag, with the remaining 15
+			// bits being the frequency in Hz
+			let increment = frequency & 0x8000 == 0x8000;
+			let frequency = (frequency & 0x7FFF) * 2;
+
+			let adjustment = read_id3v23_adjustment(adjustment_bytes);
+			adjustment_points.push(AdjustmentPoint {
+				frequency,
+				volume_adjustment: id3v23_adjustment_to_db(increment, adjustment, adjustment_bits),
+			});
+		}
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Some(EqualisationFrame {
+			header,
+			interpolation_method: InterpolationMethod::Band,
+			identification: String::new(),
+			adjustment_points,
+		})
+	}
+
+	/// Convert an [`EqualisationFrame`] to a byte vec
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut content = vec![self.interpolation_method as u8];
+		content.extend(encode_text(
+			&self.identification,
+			TextEncoding::Latin1,
+			true,
+		));
+
+		for point in &self.adjustment_points {
+			content.extend(point.frequency.to_be_bytes());
+			content.extend(point.volume_adjustment.to_be_bytes());
+		}
+
+		content
+	}
+
+	/// Convert an [`EqualisationFrame`] to the content of an ID3v2.3 `EQUA` frame
+	///
+	/// Frequencies that end up the same once rounded to a whole Hz are only written once.
+	pub(crate) fn as_equa_bytes(&self) -> Vec<u8> {
+		let mut points = self.adjustment_points.clone();
+		points.sort_by_key(|point| point.frequency);
+
+		let mut content = vec![ID3V23_ADJUSTMENT_BITS];
+
+		let mut last_frequency = None;
+		for point in points {
+			let frequency = point.frequency.div_ceil(2).min(0x7FFF);
+			if last_frequency == Some(frequency) {
+				continue;
+			}
+
+			last_frequency = Some(frequency);
+
+			let (increment, adjustment) = db_to_id3v23_adjustment(point.volume_adjustment);
+
+			let mut frequency_field = frequency;
+			if increment {
+				frequency_field |= 0x8000;
+			}
+
+			content.extend(frequency_field.to_be_bytes());
+			content.extend(adjustment.to_be_bytes());
+		}
+
+		content
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{AdjustmentPoint, EqualisationFrame, FrameFlags, InterpolationMethod};
+
+	fn expected() -> EqualisationFrame<'static> {
+		EqualisationFrame::new(
+			InterpolationMethod::Linear,
+			String::from("Foo"),
+			vec![
+				AdjustmentPoint {
+					frequency: 200,
+					volume_adjustment: -1024,
+				},
+				AdjustmentPoint {
+					frequency: 2000,
+					volume_adjustment: 2048,
+				},
+			],
+		)
+	}
+
+	#[test_log::test]
+	fn equ2_re_read() {
+		let frame = expected();
+
+		let encoded = frame.as_bytes();
+		assert_eq!(
+			encoded,
+			b"\x01Foo\x00\x00\xC8\xFC\x00\x07\xD0\x08\x00".to_vec()
+		);
+
+		let parsed = EqualisationFrame::parse(&mut &encoded[..], FrameFlags::default())
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed.interpolation_method, frame.interpolation_method);
+		assert_eq!(parsed.identification, frame.identification);
+		assert_eq!(parsed.adjustment_points, frame.adjustment_points);
+	}
+
+	#[test_log::test]
+	fn equa_conversion() {
+		let frame = expected();
+
+		let encoded = frame.as_equa_bytes();
+
+		// 100 Hz, decrement, then 1000 Hz, increment
+		assert_eq!(encoded[0], 16);
+		assert_eq!(&encoded[1..3], &[0x00, 0x64]);
+		assert_eq!(&encoded[5..7], &[0x83, 0xE8]);
+
+		let parsed =
+			EqualisationFrame::parse_equa(&mut &encoded[..], FrameFlags::default()).unwrap();
+
+		assert_eq!(parsed.interpolation_method, InterpolationMethod::Band);
+		assert!(parsed.identification.is_empty());
+		assert_eq!(parsed.adjustment_points.len(), 2);
+
+		for (parsed, expected) in parsed
+			.adjustment_points
+			.iter()
+			.zip(&frame.adjustment_points)
+		{
+			assert_eq!(parsed.frequency, expected.frequency);
+			assert!((parsed.volume_adjustment - expected.volume_adjustment).abs() <= 1);
+		}
+	}
+}
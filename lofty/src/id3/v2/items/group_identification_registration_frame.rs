@@ -0,0 +1,138 @@
+use crate::error::Result;
+use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
+use crate::util::text::{decode_text, encode_text, TextDecodeOptions, TextEncoding};
+
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+
+use byteorder::ReadBytesExt;
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("GRID"));
+
+/// An `ID3v2` group identification registration frame
+///
+/// This frame registers a group of frames, which refer to it through their
+/// [`grouping_identity`](crate::id3::v2::FrameFlags::grouping_identity) flag.
+///
+/// There may only be one registration per group symbol, so the `PartialEq` and `Hash`
+/// implementations depend solely on it.
+#[derive(Clone, Debug, Eq)]
+pub struct GroupIdentificationRegistrationFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// A URL containing an email address, or a link to a location where an email can be found,
+	/// that belongs to the organisation responsible for the grouping
+	pub owner: String,
+	/// The symbol used by grouped frames to refer to this group
+	///
+	/// Values below `0x80` are reserved.
+	pub group_symbol: u8,
+	/// Data specific to the group, defined by the owner
+	pub group_data: Vec<u8>,
+}
+
+impl<'a> PartialEq for GroupIdentificationRegistrationFrame<'a> {
+	fn eq(&self, other: &Self) -> bool {
+		self.group_symbol == other.group_symbol
+	}
+}
+
+impl<'a> Hash for GroupIdentificationRegistrationFrame<'a> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.group_symbol.hash(state);
+	}
+}
+
+impl<'a> GroupIdentificationRegistrationFrame<'a> {
+	/// Create a new [`GroupIdentificationRegistrationFrame`]
+	pub fn new(owner: String, group_symbol: u8, group_data: Vec<u8>) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			owner,
+			group_symbol,
+			group_data,
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read a [`GroupIdentificationRegistrationFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * Not enough data
+	pub fn parse<R>(reader: &mut R, frame_flags: FrameFlags) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let Ok(owner) = decode_text(
+			reader,
+			TextDecodeOptions::new()
+				.encoding(TextEncoding::Latin1)
+				.terminated(true),
+		) else {
+			return Ok(None);
+		};
+
+		let group_symbol = reader.read_u8()?;
+
+		let mut group_data = Vec::new();
+		reader.read_to_end(&mut group_data)?;
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(GroupIdentificationRegistrationFrame {
+			header,
+			owner: owner.content,
+			group_symbol,
+			group_data,
+		}))
+	}
+
+	/// Convert a [`GroupIdentificationRegistrationFrame`] to a byte vec
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut content = encode_text(&self.owner, TextEncoding::Latin1, true);
+		content.push(self.group_symbol);
+		content.extend_from_slice(&self.group_data);
+
+		content
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{FrameFlags, GroupIdentificationRegistrationFrame};
+
+	#[test_log::test]
+	fn grid_re_read() {
+		let frame =
+			GroupIdentificationRegistrationFrame::new(String::from("foo@bar.com"), 0x90, vec![]);
+
+		let encoded = frame.as_bytes();
+		assert_eq!(encoded, b"foo@bar.com\x00\x90".to_vec());
+
+		let parsed =
+			GroupIdentificationRegistrationFrame::parse(&mut &encoded[..], FrameFlags::default())
+				.unwrap()
+				.unwrap();
+
+		assert_eq!(parsed.owner, frame.owner);
+		assert_eq!(parsed.group_symbol, 0x90);
+		assert!(parsed.group_data.is_empty());
+	}
+}
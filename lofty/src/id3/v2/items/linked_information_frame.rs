@@ -0,0 +1,243 @@
+use crate::error::{Id3v2Error, Id3v2ErrorKind, LoftyError, Result};
+use crate::id3::v2::header::Id3v2Version;
+use crate::id3::v2::util::upgrade::{downgrade_v2, upgrade_v2};
+use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
+use crate::util::text::{
+	decode_text, encode_text, utf8_decode_str, TextDecodeOptions, TextEncoding,
+};
+
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::io::Read;
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("LINK"));
+
+/// An `ID3v2` linked information frame
+///
+/// This frame is used to keep information that is duplicated across files in one place, by
+/// pointing to a frame in another file (or tag) through a URL.
+///
+/// NOTE: The linked frame ID is upgraded when reading `ID3v2.2` tags, see [`upgrade_v2`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct LinkedInformationFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// The ID of the linked frame
+	pub linked_frame_id: FrameId<'a>,
+	/// The URL of the file containing the linked frame
+	pub url: String,
+	/// Additional data identifying the linked frame
+	///
+	/// For example, a linked `COMM` frame would be identified by its language and description.
+	pub additional_data: Vec<String>,
+}
+
+impl<'a> LinkedInformationFrame<'a> {
+	/// Create a new [`LinkedInformationFrame`]
+	///
+	/// This will have no additional data.
+	pub fn new(linked_frame_id: FrameId<'a>, url: String) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			linked_frame_id,
+			url,
+			additional_data: Vec::new(),
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read a [`LinkedInformationFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * The linked frame ID is invalid
+	/// * Not enough data
+	pub fn parse<R>(
+		reader: &mut R,
+		frame_flags: FrameFlags,
+		version: Id3v2Version,
+	) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		// ID3v2.2 uses 3 character frame IDs. The ID3v2.3 spec also lists 3 bytes here, but
+		// that is a mistake, it uses 4 like everywhere else.
+		let id_len = if version == Id3v2Version::V2 { 3 } else { 4 };
+
+		let mut id_bytes = [0; 4];
+		if reader.read_exact(&mut id_bytes[..id_len]).is_err() {
+			return Ok(None);
+		}
+
+		let id_bytes = &id_bytes[..id_len];
+		let id_str = utf8_decode_str(id_bytes)
+			.map_err(|_| Id3v2Error::new(Id3v2ErrorKind::BadFrameId(id_bytes.to_vec())))?;
+
+		let linked_frame_id = match upgrade_v2(id_str) {
+			Some(upgraded) if version == Id3v2Version::V2 => FrameId::new(upgraded)?,
+			_ => FrameId::new(id_str.to_owned())?,
+		};
+
+		let url = decode_text(
+			reader,
+			TextDecodeOptions::new()
+				.encoding(TextEncoding::Latin1)
+				.terminated(true),
+		)?
+		.content;
+
+		let mut additional_data_bytes = Vec::new();
+		reader.read_to_end(&mut additional_data_bytes)?;
+
+		let mut additional_data = Vec::new();
+		if !additional_data_bytes.is_empty() {
+			// The strings may or may not be terminated
+			if additional_data_bytes.last() == Some(&0) {
+				additional_data_bytes.pop();
+			}
+
+			for data in additional_data_bytes.split(|&b| b == 0) {
+				let data = decode_text(
+					&mut &data[..],
+					TextDecodeOptions::new().encoding(TextEncoding::Latin1),
+				)?;
+				additional_data.push(data.content);
+			}
+		}
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(LinkedInformationFrame {
+			header,
+			linked_frame_id,
+			url,
+			additional_data,
+		}))
+	}
+
+	/// Convert a [`LinkedInformationFrame`] to a byte vec
+	///
+	/// NOTE: For `ID3v2.2`, the linked frame ID is downgraded to its 3 character equivalent
+	///
+	/// # Errors
+	///
+	/// * `linked_frame_id` is [`FrameId::Outdated`]
+	///
+	/// ID3v2.2:
+	///
+	/// * `linked_frame_id` has no `ID3v2.2` equivalent
+	pub fn as_bytes(&self, version: Id3v2Version) -> Result<Vec<u8>> {
+		let bad_frame_id = || -> LoftyError {
+			Id3v2Error::new(Id3v2ErrorKind::BadFrameId(
+				self.linked_frame_id.as_str().as_bytes().to_vec(),
+			))
+			.into()
+		};
+
+		let FrameId::Valid(linked_frame_id) = &self.linked_frame_id else {
+			return Err(bad_frame_id());
+		};
+
+		let linked_frame_id = if version == Id3v2Version::V2 {
+			downgrade_v2(linked_frame_id).ok_or_else(bad_frame_id)?
+		} else {
+			linked_frame_id
+		};
+
+		let mut content = linked_frame_id.as_bytes().to_vec();
+		content.extend(encode_text(&self.url, TextEncoding::Latin1, true));
+
+		for (i, data) in self.additional_data.iter().enumerate() {
+			if i > 0 {
+				content.push(0);
+			}
+
+			content.extend(encode_text(data, TextEncoding::Latin1, false));
+		}
+
+		Ok(content)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::header::Id3v2Version;
+	use crate::id3::v2::{FrameFlags, FrameId, LinkedInformationFrame};
+
+	#[test_log::test]
+	fn link_re_read() {
+		let mut frame = LinkedInformationFrame::new(
+			FrameId::new("COMM").unwrap(),
+			String::from("http://example.com/foo.mp3"),
+		);
+		frame.additional_data = vec![String::from("eng"), String::from("Foo description")];
+
+		let encoded = frame.as_bytes(Id3v2Version::V4).unwrap();
+		assert_eq!(
+			encoded,
+			b"COMMhttp://example.com/foo.mp3\x00eng\x00Foo description".to_vec()
+		);
+
+		let parsed = LinkedInformationFrame::parse(
+			&mut &encoded[..],
+			FrameFlags::default(),
+			Id3v2Version::V4,
+		)
+		.unwrap()
+		.unwrap();
+
+		assert_eq!(parsed, frame);
+	}
+
+	#[test_log::test]
+	fn link_upgrade_v2_frame_id() {
+		let content = b"TT2http://example.com/foo.mp3\x00";
+
+		let parsed = LinkedInformationFrame::parse(
+			&mut &content[..],
+			FrameFlags::default(),
+			Id3v2Version::V2,
+		)
+		.unwrap()
+		.unwrap();
+
+		assert_eq!(parsed.linked_frame_id, FrameId::new("TIT2").unwrap());
+		assert!(parsed.additional_data.is_empty());
+
+		// And back again
+		assert_eq!(parsed.as_bytes(Id3v2Version::V2).unwrap(), content.to_vec());
+	}
+
+	#[test_log::test]
+	fn link_bad_frame_id() {
+		let frame = LinkedInformationFrame::new(
+			FrameId::new("XXX").unwrap(),
+			String::from("http://example.com/foo.mp3"),
+		);
+
+		assert!(frame.as_bytes(Id3v2Version::V4).is_err());
+
+		// No ID3v2.2 equivalent
+		let frame = LinkedInformationFrame::new(
+			FrameId::new("CHAP").unwrap(),
+			String::from("http://example.com/foo.mp3"),
+		);
+
+		assert!(frame.as_bytes(Id3v2Version::V2).is_err());
+	}
+}
@@ -1,38 +1,66 @@
 mod attached_picture_frame;
+mod audio_encryption_frame;
+mod audio_seek_point_index_frame;
 mod audio_text_frame;
 mod binary_frame;
+mod chapter_frame;
+mod commercial_frame;
 mod encapsulated_object;
+mod encryption_method_registration_frame;
+mod equalisation_frame;
 mod event_timing_codes_frame;
 mod extended_text_frame;
 mod extended_url_frame;
+mod group_identification_registration_frame;
 mod key_value_frame;
 pub(in crate::id3::v2) mod language_frame;
+mod linked_information_frame;
+mod mpeg_location_lookup_table_frame;
 mod ownership_frame;
 mod popularimeter;
+mod position_synchronisation_frame;
 mod private_frame;
+mod recommended_buffer_size_frame;
 mod relative_volume_adjustment_frame;
+mod seek_frame;
+mod signature_frame;
 mod sync_text;
+mod table_of_contents_frame;
 mod text_information_frame;
 mod timestamp_frame;
 mod unique_file_identifier;
 mod url_link_frame;
 
 pub use attached_picture_frame::AttachedPictureFrame;
+pub use audio_encryption_frame::AudioEncryptionFrame;
+pub use audio_seek_point_index_frame::AudioSeekPointIndexFrame;
 pub use audio_text_frame::{scramble, AudioTextFrame, AudioTextFrameFlags};
 pub use binary_frame::BinaryFrame;
+pub use chapter_frame::ChapterFrame;
+pub use commercial_frame::{CommercialFrame, ReceivedAs};
 pub use encapsulated_object::GeneralEncapsulatedObject;
+pub use encryption_method_registration_frame::EncryptionMethodRegistrationFrame;
+pub use equalisation_frame::{AdjustmentPoint, EqualisationFrame, InterpolationMethod};
 pub use event_timing_codes_frame::{Event, EventTimingCodesFrame, EventType};
 pub use extended_text_frame::ExtendedTextFrame;
 pub use extended_url_frame::ExtendedUrlFrame;
+pub use group_identification_registration_frame::GroupIdentificationRegistrationFrame;
 pub use key_value_frame::KeyValueFrame;
 pub use language_frame::{CommentFrame, UnsynchronizedTextFrame};
+pub use linked_information_frame::LinkedInformationFrame;
+pub use mpeg_location_lookup_table_frame::{MpegLocationLookupTableFrame, MpegLocationReference};
 pub use ownership_frame::OwnershipFrame;
 pub use popularimeter::PopularimeterFrame;
+pub use position_synchronisation_frame::PositionSynchronisationFrame;
 pub use private_frame::PrivateFrame;
+pub use recommended_buffer_size_frame::RecommendedBufferSizeFrame;
 pub use relative_volume_adjustment_frame::{
 	ChannelInformation, ChannelType, RelativeVolumeAdjustmentFrame,
 };
+pub use seek_frame::SeekFrame;
+pub use signature_frame::SignatureFrame;
 pub use sync_text::{SyncTextContentType, SynchronizedTextFrame, TimestampFormat};
+pub use table_of_contents_frame::TableOfContentsFrame;
 pub use text_information_frame::TextInformationFrame;
 pub use timestamp_frame::TimestampFrame;
 pub use unique_file_identifier::UniqueFileIdentifierFrame;
@@ -0,0 +1,286 @@
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
+use crate::macros::err;
+
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::io::Read;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("MLLT"));
+
+// The deviations are stored in `u32`s
+const MAX_DEVIATION_BITS: u8 = 32;
+
+/// A single reference in an [`MpegLocationLookupTableFrame`]
+///
+/// The deviations are the difference between the actual position of the reference, and the
+/// position described by the frame's `*_between_reference` fields.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MpegLocationReference {
+	/// The deviation in bytes
+	pub bytes_deviation: u32,
+	/// The deviation in milliseconds
+	pub milliseconds_deviation: u32,
+}
+
+/// An `ID3v2` MPEG location lookup table frame
+///
+/// This frame is a table of references into MPEG audio, used to speed up seeking. Every
+/// reference is [`frames_between_reference`](Self::frames_between_reference) MPEG frames apart,
+/// with its actual position described by a deviation from the expected one.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MpegLocationLookupTableFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// The number of MPEG frames between each reference
+	pub frames_between_reference: u16,
+	/// The expected number of bytes between each reference
+	///
+	/// This is stored in 24 bits, so it can't exceed `0xFFFFFF`.
+	pub bytes_between_reference: u32,
+	/// The expected number of milliseconds between each reference
+	///
+	/// This is stored in 24 bits, so it can't exceed `0xFFFFFF`.
+	pub milliseconds_between_reference: u32,
+	/// The number of bits used to store [`MpegLocationReference::bytes_deviation`]
+	pub bits_for_bytes_deviation: u8,
+	/// The number of bits used to store [`MpegLocationReference::milliseconds_deviation`]
+	pub bits_for_milliseconds_deviation: u8,
+	/// The references
+	pub references: Vec<MpegLocationReference>,
+}
+
+impl<'a> MpegLocationLookupTableFrame<'a> {
+	/// Create a new [`MpegLocationLookupTableFrame`]
+	///
+	/// This will have no references, with 8 bits for each deviation.
+	pub fn new(
+		frames_between_reference: u16,
+		bytes_between_reference: u32,
+		milliseconds_between_reference: u32,
+	) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			frames_between_reference,
+			bytes_between_reference,
+			milliseconds_between_reference,
+			bits_for_bytes_deviation: 8,
+			bits_for_milliseconds_deviation: 8,
+			references: Vec::new(),
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read an [`MpegLocationLookupTableFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * Either deviation is larger than 32 bits (See [`Id3v2ErrorKind::BadMlltDeviationSize`])
+	/// * Not enough data
+	pub fn parse<R>(reader: &mut R, frame_flags: FrameFlags) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let frames_between_reference = reader.read_u16::<BigEndian>()?;
+		let bytes_between_reference = reader.read_u24::<BigEndian>()?;
+		let milliseconds_between_reference = reader.read_u24::<BigEndian>()?;
+		let bits_for_bytes_deviation = reader.read_u8()?;
+		let bits_for_milliseconds_deviation = reader.read_u8()?;
+
+		if bits_for_bytes_deviation > MAX_DEVIATION_BITS
+			|| bits_for_milliseconds_deviation > MAX_DEVIATION_BITS
+		{
+			return Err(Id3v2Error::new(Id3v2ErrorKind::BadMlltDeviationSize).into());
+		}
+
+		let mut reference_bytes = Vec::new();
+		reader.read_to_end(&mut reference_bytes)?;
+
+		let bits_per_reference =
+			usize::from(bits_for_bytes_deviation) + usize::from(bits_for_milliseconds_deviation);
+
+		let mut references = Vec::new();
+		if bits_per_reference > 0 {
+			let mut reader = BitReader::new(&reference_bytes);
+			for _ in 0..(reference_bytes.len() * 8) / bits_per_reference {
+				references.push(MpegLocationReference {
+					bytes_deviation: reader.read(bits_for_bytes_deviation),
+					milliseconds_deviation: reader.read(bits_for_milliseconds_deviation),
+				});
+			}
+		}
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(MpegLocationLookupTableFrame {
+			header,
+			frames_between_reference,
+			bytes_between_reference,
+			milliseconds_between_reference,
+			bits_for_bytes_deviation,
+			bits_for_milliseconds_deviation,
+			references,
+		}))
+	}
+
+	/// Convert an [`MpegLocationLookupTableFrame`] to a byte vec
+	///
+	/// # Errors
+	///
+	/// * `bytes_between_reference` or `milliseconds_between_reference` doesn't fit in 24 bits
+	/// * Either deviation is larger than 32 bits, or a reference doesn't fit in it
+	///   (See [`Id3v2ErrorKind::BadMlltDeviationSize`])
+	pub fn as_bytes(&self) -> Result<Vec<u8>> {
+		if self.bytes_between_reference > 0xFF_FFFF
+			|| self.milliseconds_between_reference > 0xFF_FFFF
+		{
+			err!(TooMuchData);
+		}
+
+		if self.bits_for_bytes_deviation > MAX_DEVIATION_BITS
+			|| self.bits_for_milliseconds_deviation > MAX_DEVIATION_BITS
+		{
+			return Err(Id3v2Error::new(Id3v2ErrorKind::BadMlltDeviationSize).into());
+		}
+
+		let mut content = Vec::new();
+		content.extend(self.frames_between_reference.to_be_bytes());
+		content.extend(&self.bytes_between_reference.to_be_bytes()[1..]);
+		content.extend(&self.milliseconds_between_reference.to_be_bytes()[1..]);
+		content.push(self.bits_for_bytes_deviation);
+		content.push(self.bits_for_milliseconds_deviation);
+
+		let mut writer = BitWriter::new(content);
+		for reference in &self.references {
+			writer.write(reference.bytes_deviation, self.bits_for_bytes_deviation)?;
+			writer.write(
+				reference.milliseconds_deviation,
+				self.bits_for_milliseconds_deviation,
+			)?;
+		}
+
+		Ok(writer.bytes)
+	}
+}
+
+struct BitReader<'a> {
+	bytes: &'a [u8],
+	bit_position: usize,
+}
+
+impl<'a> BitReader<'a> {
+	fn new(bytes: &'a [u8]) -> Self {
+		Self {
+			bytes,
+			bit_position: 0,
+		}
+	}
+
+	// The caller must ensure there are enough bits remaining
+	fn read(&mut self, bits: u8) -> u32 {
+		let mut value = 0;
+		for _ in 0..bits {
+			let byte = self.bytes[self.bit_position / 8];
+			let bit = (byte >> (7 - (self.bit_position % 8))) & 1;
+
+			value = (value << 1) | u32::from(bit);
+			self.bit_position += 1;
+		}
+
+		value
+	}
+}
+
+struct BitWriter {
+	bytes: Vec<u8>,
+	bit_position: usize,
+}
+
+impl BitWriter {
+	fn new(bytes: Vec<u8>) -> Self {
+		Self {
+			bytes,
+			bit_position: 0,
+		}
+	}
+
+	fn write(&mut self, value: u32, bits: u8) -> Result<()> {
+		if u64::from(value) >> bits != 0 {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::BadMlltDeviationSize).into());
+		}
+
+		for i in (0..bits).rev() {
+			if self.bit_position % 8 == 0 {
+				self.bytes.push(0);
+			}
+
+			if (value >> i) & 1 == 1 {
+				// Guaranteed to have at least one byte at this point
+				*self.bytes.last_mut().unwrap() |= 0x80 >> (self.bit_position % 8);
+			}
+
+			self.bit_position += 1;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{FrameFlags, MpegLocationLookupTableFrame, MpegLocationReference};
+
+	#[test_log::test]
+	fn mllt_re_read() {
+		let mut frame = MpegLocationLookupTableFrame::new(10, 4180, 261);
+		frame.bits_for_bytes_deviation = 12;
+		frame.bits_for_milliseconds_deviation = 4;
+		frame.references = vec![
+			MpegLocationReference {
+				bytes_deviation: 0xABC,
+				milliseconds_deviation: 0xD,
+			},
+			MpegLocationReference {
+				bytes_deviation: 1,
+				milliseconds_deviation: 2,
+			},
+		];
+
+		let encoded = frame.as_bytes().unwrap();
+		assert_eq!(&encoded[10..], &[0xAB, 0xCD, 0x00, 0x12]);
+
+		let parsed = MpegLocationLookupTableFrame::parse(&mut &encoded[..], FrameFlags::default())
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, frame);
+	}
+
+	#[test_log::test]
+	fn mllt_deviation_too_large() {
+		let mut frame = MpegLocationLookupTableFrame::new(10, 4180, 261);
+		frame.references.push(MpegLocationReference {
+			bytes_deviation: 0x100,
+			milliseconds_deviation: 0,
+		});
+
+		assert!(frame.as_bytes().is_err());
+	}
+}
@@ -0,0 +1,113 @@
+use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::{FrameFlags, FrameHeader, FrameId, TimestampFormat};
+
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::io::Read;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("POSS"));
+
+/// An `ID3v2` position synchronisation frame
+///
+/// This frame is used in streams, to tell where in the audio the listener started receiving,
+/// and therefore how other synchronised frames (such as `SYLT` or `ETCO`) line up.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct PositionSynchronisationFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// The unit of [`position`](Self::position)
+	pub timestamp_format: TimestampFormat,
+	/// The position in the audio where the tag was received
+	pub position: u32,
+}
+
+impl<'a> PositionSynchronisationFrame<'a> {
+	/// Create a new [`PositionSynchronisationFrame`]
+	pub fn new(timestamp_format: TimestampFormat, position: u32) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			timestamp_format,
+			position,
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read a [`PositionSynchronisationFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * Invalid timestamp format
+	/// * Not enough data
+	pub fn parse<R>(reader: &mut R, frame_flags: FrameFlags) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let Ok(timestamp_format_byte) = reader.read_u8() else {
+			return Ok(None);
+		};
+
+		let timestamp_format = TimestampFormat::from_u8(timestamp_format_byte)
+			.ok_or_else(|| Id3v2Error::new(Id3v2ErrorKind::BadTimestampFormat))?;
+		let position = reader.read_u32::<BigEndian>()?;
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(PositionSynchronisationFrame {
+			header,
+			timestamp_format,
+			position,
+		}))
+	}
+
+	/// Convert a [`PositionSynchronisationFrame`] to a byte vec
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut content = vec![self.timestamp_format as u8];
+		content.extend(self.position.to_be_bytes());
+
+		content
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{FrameFlags, PositionSynchronisationFrame, TimestampFormat};
+
+	#[test_log::test]
+	fn poss_re_read() {
+		let frame = PositionSynchronisationFrame::new(TimestampFormat::MS, 60_000);
+
+		let encoded = frame.as_bytes();
+		assert_eq!(encoded, [2, 0, 0, 0xEA, 0x60]);
+
+		let parsed = PositionSynchronisationFrame::parse(&mut &encoded[..], FrameFlags::default())
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, frame);
+	}
+
+	#[test_log::test]
+	fn poss_bad_timestamp_format() {
+		let content = [3, 0, 0, 0, 1];
+		assert!(
+			PositionSynchronisationFrame::parse(&mut &content[..], FrameFlags::default()).is_err()
+		);
+	}
+}
@@ -0,0 +1,144 @@
+use crate::error::Result;
+use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
+use crate::macros::err;
+
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::io::Read;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("RBUF"));
+
+const FLAG_EMBEDDED_INFO: u8 = 0x01;
+
+/// An `ID3v2` recommended buffer size frame
+///
+/// This frame is used in streams, where the server may send tags in the middle of the audio.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RecommendedBufferSizeFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// The recommended buffer size, in bytes
+	///
+	/// This is stored in 24 bits, so it can't exceed `0xFFFFFF`.
+	pub buffer_size: u32,
+	/// Whether a tag larger than [`buffer_size`](Self::buffer_size) may occur in the stream
+	pub embedded_info: bool,
+	/// The number of bytes from the end of this tag to the start of the next one
+	pub offset_to_next_tag: Option<u32>,
+}
+
+impl<'a> RecommendedBufferSizeFrame<'a> {
+	/// Create a new [`RecommendedBufferSizeFrame`]
+	///
+	/// This will have no `embedded_info` flag or offset to the next tag.
+	pub fn new(buffer_size: u32) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			buffer_size,
+			embedded_info: false,
+			offset_to_next_tag: None,
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read a [`RecommendedBufferSizeFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * Not enough data
+	pub fn parse<R>(reader: &mut R, frame_flags: FrameFlags) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let buffer_size = reader.read_u24::<BigEndian>()?;
+		let flags = reader.read_u8()?;
+		let offset_to_next_tag = reader.read_u32::<BigEndian>().ok();
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(RecommendedBufferSizeFrame {
+			header,
+			buffer_size,
+			embedded_info: flags & FLAG_EMBEDDED_INFO == FLAG_EMBEDDED_INFO,
+			offset_to_next_tag,
+		}))
+	}
+
+	/// Convert a [`RecommendedBufferSizeFrame`] to a byte vec
+	///
+	/// # Errors
+	///
+	/// * `buffer_size` doesn't fit in 24 bits
+	pub fn as_bytes(&self) -> Result<Vec<u8>> {
+		if self.buffer_size > 0xFF_FFFF {
+			err!(TooMuchData);
+		}
+
+		let mut content = self.buffer_size.to_be_bytes()[1..].to_vec();
+		content.push(if self.embedded_info {
+			FLAG_EMBEDDED_INFO
+		} else {
+			0
+		});
+
+		if let Some(offset_to_next_tag) = self.offset_to_next_tag {
+			content.extend(offset_to_next_tag.to_be_bytes());
+		}
+
+		Ok(content)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{FrameFlags, RecommendedBufferSizeFrame};
+
+	#[test_log::test]
+	fn rbuf_re_read() {
+		let mut frame = RecommendedBufferSizeFrame::new(0x01_0000);
+		frame.embedded_info = true;
+
+		let encoded = frame.as_bytes().unwrap();
+		assert_eq!(encoded, [1, 0, 0, 1]);
+		assert_eq!(
+			RecommendedBufferSizeFrame::parse(&mut &encoded[..], FrameFlags::default())
+				.unwrap()
+				.unwrap(),
+			frame
+		);
+
+		frame.offset_to_next_tag = Some(512);
+
+		let encoded = frame.as_bytes().unwrap();
+		assert_eq!(encoded, [1, 0, 0, 1, 0, 0, 2, 0]);
+		assert_eq!(
+			RecommendedBufferSizeFrame::parse(&mut &encoded[..], FrameFlags::default())
+				.unwrap()
+				.unwrap(),
+			frame
+		);
+	}
+
+	#[test_log::test]
+	fn rbuf_buffer_size_too_large() {
+		let frame = RecommendedBufferSizeFrame::new(0x0100_0000);
+		assert!(frame.as_bytes().is_err());
+	}
+}
@@ -13,6 +13,24 @@ use byteorder::{BigEndian, ReadBytesExt};
 
 const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("RVA2"));
 
+/// The size used when writing ID3v2.3 `RVAD` and `EQUA` adjustments
+pub(super) const ID3V23_ADJUSTMENT_BITS: u8 = 16;
+
+// The channels of an `RVAD` frame, in order, along with their increment/decrement flag.
+// The adjustments come in pairs, each followed by a pair of peaks (with the exception of the
+// centre and bass channels, which are alone).
+const RVAD_CHANNELS: [(ChannelType, u8); 6] = [
+	(ChannelType::FrontRight, 0x01),
+	(ChannelType::FrontLeft, 0x02),
+	(ChannelType::BackRight, 0x04),
+	(ChannelType::BackLeft, 0x08),
+	(ChannelType::FrontCentre, 0x10),
+	(ChannelType::Subwoofer, 0x20),
+];
+
+// The (adjustment, peak) field indices for each of `RVAD_CHANNELS`
+const RVAD_FIELD_INDICES: [(usize, usize); 6] = [(0, 2), (1, 3), (4, 6), (5, 7), (8, 9), (10, 11)];
+
 /// A channel identifier used in the RVA2 frame
 #[repr(u8)]
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug, Hash)]
@@ -81,6 +99,16 @@ pub struct ChannelInformation {
 /// An `ID3v2` RVA2 frame
 ///
 /// NOTE: The `Eq` and `Hash` implementations depend solely on the `identification` field.
+///
+/// ## ID3v2.3
+///
+/// ID3v2.3 tags use the `RVAD` frame instead, which is converted to and from this frame. `RVAD`
+/// frames don't have an identification, and only allow for one frame per tag, so only the first
+/// `RVA2` frame will be written. They also can't describe the master volume, so it is applied to
+/// the front left and right channels if they aren't present.
+///
+/// `RVAD` adjustments are unitless. They are treated as a fraction of the largest value they can
+/// hold, where a full increment doubles the volume, and a full decrement mutes it.
 #[derive(Clone, Debug, Eq)]
 pub struct RelativeVolumeAdjustmentFrame<'a> {
 	pub(crate) header: FrameHeader<'a>,
@@ -193,6 +221,80 @@ impl<'a> RelativeVolumeAdjustmentFrame<'a> {
 		}))
 	}
 
+	/// Read a [`RelativeVolumeAdjustmentFrame`] from an ID3v2.3 `RVAD` frame
+	///
+	/// The adjustments are converted to decibels, with the peaks kept as-is.
+	pub(crate) fn parse_rvad<R>(reader: &mut R, frame_flags: FrameFlags) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let Ok(increment_flags) = reader.read_u8() else {
+			return Ok(None);
+		};
+
+		let bits = reader.read_u8()?;
+		if bits == 0 || bits > 64 {
+			log::warn!(
+				"Encountered an unsupported RVAD adjustment size ({} bits), discarding",
+				bits
+			);
+			return Ok(None);
+		}
+
+		let mut content = Vec::new();
+		reader.read_to_end(&mut content)?;
+
+		let fields = content
+			.chunks_exact(usize::from(bits.div_ceil(8)))
+			.collect::<Vec<_>>();
+
+		// The front right and left channels are required
+		if fields.len() < 2 {
+			return Err(Id3v2Error::new(Id3v2ErrorKind::BadFrameLength).into());
+		}
+
+		let mut channels = HashMap::new();
+		for ((channel_type, flag), (adjustment_index, peak_index)) in
+			RVAD_CHANNELS.into_iter().zip(RVAD_FIELD_INDICES)
+		{
+			let Some(adjustment) = fields.get(adjustment_index) else {
+				break;
+			};
+
+			let volume_adjustment = id3v23_adjustment_to_db(
+				increment_flags & flag == flag,
+				read_id3v23_adjustment(adjustment),
+				bits,
+			);
+
+			let mut bits_representing_peak = 0;
+			let mut peak_volume = None;
+			if let Some(peak) = fields.get(peak_index) {
+				if read_id3v23_adjustment(peak) != 0 {
+					bits_representing_peak = bits;
+					peak_volume = Some(peak.to_vec());
+				}
+			}
+
+			channels.insert(
+				channel_type,
+				ChannelInformation {
+					channel_type,
+					volume_adjustment,
+					bits_representing_peak,
+					peak_volume,
+				},
+			);
+		}
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(Self {
+			header,
+			identification: String::new(),
+			channels,
+		}))
+	}
+
 	/// Convert a [`RelativeVolumeAdjustmentFrame`] to a byte vec
 	pub fn as_bytes(&self) -> Vec<u8> {
 		let mut content = Vec::new();
@@ -234,6 +336,124 @@ impl<'a> RelativeVolumeAdjustmentFrame<'a> {
 
 		content
 	}
+
+	/// Convert a [`RelativeVolumeAdjustmentFrame`] to the content of an ID3v2.3 `RVAD` frame
+	///
+	/// This will return `None` if none of the channels can be represented in `RVAD`.
+	pub(crate) fn as_rvad_bytes(&self) -> Option<Vec<u8>> {
+		let channel = |channel_type| {
+			let info = self.channels.get(&channel_type);
+			match channel_type {
+				ChannelType::FrontRight | ChannelType::FrontLeft => {
+					info.or_else(|| self.channels.get(&ChannelType::MasterVolume))
+				},
+				_ => info,
+			}
+		};
+
+		let channels = RVAD_CHANNELS.map(|(channel_type, _)| channel(channel_type));
+		let last_present = channels.iter().rposition(Option::is_some)?;
+
+		// The fields can only be omitted from the end
+		let field_count = match last_present {
+			0 | 1 => 4,
+			2 | 3 => 8,
+			4 => 10,
+			_ => 12,
+		};
+
+		let mut increment_flags = 0;
+		let mut fields = [0u16; 12];
+		for ((info, (_, flag)), (adjustment_index, peak_index)) in
+			channels.iter().zip(RVAD_CHANNELS).zip(RVAD_FIELD_INDICES)
+		{
+			let Some(info) = info else {
+				continue;
+			};
+
+			let (increment, adjustment) = db_to_id3v23_adjustment(info.volume_adjustment);
+			if increment {
+				increment_flags |= flag;
+			}
+
+			fields[adjustment_index] = adjustment;
+
+			if let Some(peak) = &info.peak_volume {
+				fields[peak_index] = rescale_peak(peak, info.bits_representing_peak);
+			}
+		}
+
+		let mut content = vec![increment_flags, ID3V23_ADJUSTMENT_BITS];
+		for field in &fields[..field_count] {
+			content.extend(field.to_be_bytes());
+		}
+
+		Some(content)
+	}
+}
+
+/// Read an unsigned ID3v2.3 `RVAD` or `EQUA` field of up to 8 bytes
+pub(super) fn read_id3v23_adjustment(bytes: &[u8]) -> u64 {
+	bytes
+		.iter()
+		.fold(0, |value, byte| (value << 8) | u64::from(*byte))
+}
+
+/// Convert an ID3v2.3 `RVAD` or `EQUA` adjustment of `bits` bits to an `RVA2`/`EQU2` adjustment
+///
+/// See [`RelativeVolumeAdjustmentFrame`] for how these are interpreted.
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+pub(super) fn id3v23_adjustment_to_db(increment: bool, adjustment: u64, bits: u8) -> i16 {
+	let max = (u128::from(1u8) << bits) - 1;
+	let fraction = (adjustment as f64 / max as f64).min(1.0);
+
+	let factor = if increment {
+		1.0 + fraction
+	} else {
+		1.0 - fraction
+	};
+
+	// A full decrement is -inf dB, which will saturate
+	(20.0 * factor.log10() * 512.0).round() as i16
+}
+
+/// Convert an `RVA2`/`EQU2` adjustment to a 16-bit ID3v2.3 adjustment, and whether it is an increment
+///
+/// Increments beyond doubling the volume (~6 dB) can't be represented, and will saturate.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(super) fn db_to_id3v23_adjustment(volume_adjustment: i16) -> (bool, u16) {
+	let factor = 10f64.powf(f64::from(volume_adjustment) / 512.0 / 20.0);
+	let fraction = (factor - 1.0).abs().min(1.0);
+
+	(
+		volume_adjustment >= 0,
+		(fraction * f64::from(u16::MAX)).round() as u16,
+	)
+}
+
+// Rescales an `RVA2` peak to the 16 bits used when writing `RVAD` frames
+#[allow(clippy::cast_possible_truncation)]
+fn rescale_peak(peak: &[u8], bits_representing_peak: u8) -> u16 {
+	if bits_representing_peak == 0 {
+		return 0;
+	}
+
+	// Only the most significant 64 bits are needed
+	let peak_byte_count = usize::from(bits_representing_peak.div_ceil(8));
+	let peak = &peak[..peak.len().min(peak_byte_count)];
+	let dropped_bits = peak.len().saturating_sub(8) * 8;
+	let value = read_id3v23_adjustment(&peak[..peak.len().min(8)]);
+
+	let bits = u32::from(bits_representing_peak) - dropped_bits as u32;
+	let target_bits = u32::from(ID3V23_ADJUSTMENT_BITS);
+
+	let rescaled = if bits > target_bits {
+		value >> (bits - target_bits)
+	} else {
+		value << (target_bits - bits)
+	};
+
+	u16::try_from(rescaled).unwrap_or(u16::MAX)
 }
 
 #[cfg(test)]
@@ -352,4 +572,86 @@ mod tests {
 
 		assert!(needles.is_empty());
 	}
+
+	#[test_log::test]
+	fn rvad_conversion() {
+		let mut channels = HashMap::new();
+		channels.insert(
+			ChannelType::MasterVolume,
+			ChannelInformation {
+				channel_type: ChannelType::MasterVolume,
+				volume_adjustment: -1024,
+				bits_representing_peak: 8,
+				peak_volume: Some(vec![0x80]),
+			},
+		);
+
+		let mut frame = RelativeVolumeAdjustmentFrame::new(String::from("Foo"), channels);
+
+		let encoded = frame.as_rvad_bytes().unwrap();
+
+		// The master volume is applied to the front channels, which are both decrements
+		assert_eq!(&encoded[..2], &[0, 16]);
+		assert_eq!(encoded.len(), 2 + (4 * 2));
+
+		// The 8-bit peaks are rescaled to 16 bits
+		assert_eq!(&encoded[6..], &[0x80, 0, 0x80, 0]);
+
+		let parsed =
+			RelativeVolumeAdjustmentFrame::parse_rvad(&mut &encoded[..], FrameFlags::default())
+				.unwrap()
+				.unwrap();
+
+		assert!(parsed.identification.is_empty());
+		assert_eq!(parsed.channels.len(), 2);
+		for channel_type in [ChannelType::FrontRight, ChannelType::FrontLeft] {
+			let info = &parsed.channels[&channel_type];
+			assert!((info.volume_adjustment + 1024).abs() <= 1);
+			assert_eq!(info.bits_representing_peak, 16);
+			assert_eq!(info.peak_volume.as_deref(), Some(&[0x80, 0][..]));
+		}
+
+		// The bass channel comes last, so every other field has to be written
+		frame.channels.insert(
+			ChannelType::Subwoofer,
+			ChannelInformation {
+				channel_type: ChannelType::Subwoofer,
+				volume_adjustment: 512,
+				bits_representing_peak: 0,
+				peak_volume: None,
+			},
+		);
+
+		let encoded = frame.as_rvad_bytes().unwrap();
+		assert_eq!(&encoded[..2], &[0x20, 16]);
+		assert_eq!(encoded.len(), 2 + (12 * 2));
+
+		let parsed =
+			RelativeVolumeAdjustmentFrame::parse_rvad(&mut &encoded[..], FrameFlags::default())
+				.unwrap()
+				.unwrap();
+
+		assert_eq!(parsed.channels.len(), 6);
+
+		let bass = &parsed.channels[&ChannelType::Subwoofer];
+		assert!((bass.volume_adjustment - 512).abs() <= 1);
+		assert_eq!(bass.peak_volume, None);
+	}
+
+	#[test_log::test]
+	fn rvad_unrepresentable_channels() {
+		let mut channels = HashMap::new();
+		channels.insert(
+			ChannelType::BackCentre,
+			ChannelInformation {
+				channel_type: ChannelType::BackCentre,
+				volume_adjustment: 512,
+				bits_representing_peak: 0,
+				peak_volume: None,
+			},
+		);
+
+		let frame = RelativeVolumeAdjustmentFrame::new(String::new(), channels);
+		assert!(frame.as_rvad_bytes().is_none());
+	}
 }
@@ -0,0 +1,93 @@
+use crate::error::Result;
+use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
+
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::io::Read;
+
+use byteorder::{BigEndian, ReadBytesExt};
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("SEEK"));
+
+/// An `ID3v2` seek frame
+///
+/// This frame indicates where other tags in the file can be found, relative to the end of the
+/// tag it's in.
+///
+/// NOTE: This frame is only available in ID3v2.4, and will be discarded when writing ID3v2.3 tags.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SeekFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// The minimum number of bytes from the end of this tag to the start of the next one
+	pub minimum_offset_to_next_tag: u32,
+}
+
+impl<'a> SeekFrame<'a> {
+	/// Create a new [`SeekFrame`]
+	pub fn new(minimum_offset_to_next_tag: u32) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			minimum_offset_to_next_tag,
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read a [`SeekFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * Not enough data
+	pub fn parse<R>(reader: &mut R, frame_flags: FrameFlags) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let minimum_offset_to_next_tag = reader.read_u32::<BigEndian>()?;
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(SeekFrame {
+			header,
+			minimum_offset_to_next_tag,
+		}))
+	}
+
+	/// Convert a [`SeekFrame`] to a byte vec
+	pub fn as_bytes(&self) -> Vec<u8> {
+		self.minimum_offset_to_next_tag.to_be_bytes().to_vec()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{FrameFlags, SeekFrame};
+
+	#[test_log::test]
+	fn seek_re_read() {
+		let frame = SeekFrame::new(1024);
+
+		let encoded = frame.as_bytes();
+		assert_eq!(encoded, [0, 0, 4, 0]);
+
+		let parsed = SeekFrame::parse(&mut &encoded[..], FrameFlags::default())
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, frame);
+	}
+}
@@ -0,0 +1,106 @@
+use crate::error::Result;
+use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
+
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::io::Read;
+
+use byteorder::ReadBytesExt;
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("SIGN"));
+
+/// An `ID3v2` signature frame
+///
+/// This frame holds a signature for a group of frames, registered with a
+/// [`GroupIdentificationRegistrationFrame`](crate::id3::v2::GroupIdentificationRegistrationFrame).
+///
+/// NOTE: This frame is only available in ID3v2.4, and will be discarded when writing ID3v2.3 tags.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct SignatureFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// The symbol of the group of frames that is signed
+	pub group_symbol: u8,
+	/// The signature
+	pub signature: Vec<u8>,
+}
+
+impl<'a> SignatureFrame<'a> {
+	/// Create a new [`SignatureFrame`]
+	pub fn new(group_symbol: u8, signature: Vec<u8>) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			group_symbol,
+			signature,
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read a [`SignatureFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * Failure to read from `reader`
+	pub fn parse<R>(reader: &mut R, frame_flags: FrameFlags) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let Ok(group_symbol) = reader.read_u8() else {
+			return Ok(None);
+		};
+
+		let mut signature = Vec::new();
+		reader.read_to_end(&mut signature)?;
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(SignatureFrame {
+			header,
+			group_symbol,
+			signature,
+		}))
+	}
+
+	/// Convert a [`SignatureFrame`] to a byte vec
+	pub fn as_bytes(&self) -> Vec<u8> {
+		let mut content = Vec::with_capacity(self.signature.len() + 1);
+		content.push(self.group_symbol);
+		content.extend_from_slice(&self.signature);
+
+		content
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::id3::v2::{FrameFlags, SignatureFrame};
+
+	#[test_log::test]
+	fn sign_re_read() {
+		let frame = SignatureFrame::new(0x90, vec![0xDE, 0xAD, 0xBE, 0xEF]);
+
+		let encoded = frame.as_bytes();
+		assert_eq!(encoded, [0x90, 0xDE, 0xAD, 0xBE, 0xEF]);
+
+		let parsed = SignatureFrame::parse(&mut &encoded[..], FrameFlags::default())
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, frame);
+	}
+}
@@ -1,6 +1,7 @@
 use crate::error::{ErrorKind, Id3v2Error, Id3v2ErrorKind, LoftyError, Result};
 use crate::id3::v2::{FrameFlags, FrameHeader, FrameId};
 use crate::macros::err;
+use crate::tag::items::{SyncedLyrics, SyncedLyricsLine, SyncedLyricsWord};
 use crate::util::text::{
 	decode_text, encode_text, read_to_terminator, utf16_decode_bytes, TextDecodeOptions,
 	TextEncoding,
@@ -8,6 +9,7 @@ use crate::util::text::{
 
 use std::borrow::Cow;
 use std::io::{Cursor, Read, Seek, SeekFrom, Write};
+use std::time::Duration;
 
 use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 
@@ -32,6 +34,20 @@ impl TimestampFormat {
 			_ => None,
 		}
 	}
+
+	/// Convert a timestamp in this format to a [`Duration`]
+	///
+	/// `mpeg_frame_duration` is only used for [`TimestampFormat::MPEG`], where it is required.
+	fn to_duration(
+		self,
+		timestamp: u32,
+		mpeg_frame_duration: Option<Duration>,
+	) -> Option<Duration> {
+		match self {
+			Self::MS => Some(Duration::from_millis(u64::from(timestamp))),
+			Self::MPEG => mpeg_frame_duration.map(|frame_duration| frame_duration * timestamp),
+		}
+	}
 }
 
 /// The type of text stored in a [`SynchronizedTextFrame`]
@@ -159,13 +175,14 @@ impl<'a> SynchronizedTextFrame<'a> {
 
 		// It's possible for the description to be the only string with a BOM
 		// To be safe, we change the encoding to the concrete variant determined from the description
+		//
+		// An empty description may not have a BOM, in which case we keep the default
 		if encoding == TextEncoding::UTF16 {
-			endianness = match cursor.get_ref()[..=1] {
-				[0xFF, 0xFE] => u16::from_le_bytes,
-				[0xFE, 0xFF] => u16::from_be_bytes,
-				// Since the description was already read, we can assume the BOM was valid
-				_ => unreachable!(),
-			};
+			match cursor.get_ref().get(..2) {
+				Some([0xFF, 0xFE]) => endianness = u16::from_le_bytes,
+				Some([0xFE, 0xFF]) => endianness = u16::from_be_bytes,
+				_ => {},
+			}
 		}
 
 		let mut pos = 0;
@@ -186,7 +203,10 @@ impl<'a> SynchronizedTextFrame<'a> {
 
 					// Encountered text that doesn't include a BOM
 					if bom != [0xFF, 0xFE] && bom != [0xFE, 0xFF] {
-						let (raw_text, _) = read_to_terminator(&mut cursor, TextEncoding::UTF16);
+						let (raw_text, terminator_len) =
+							read_to_terminator(&mut cursor, TextEncoding::UTF16);
+						pos += (raw_text.len() + terminator_len) as u64;
+
 						return utf16_decode_bytes(&raw_text, endianness)
 							.map_err(|_| Id3v2Error::new(Id3v2ErrorKind::BadSyncText).into());
 					}
@@ -222,17 +242,170 @@ impl<'a> SynchronizedTextFrame<'a> {
 		})
 	}
 
+	/// Create a [`SynchronizedTextFrame`] from [`SyncedLyrics`]
+	///
+	/// The frame will use [`TimestampFormat::MS`] and [`SyncTextContentType::Lyrics`], with a
+	/// UTF-8 encoding. Timestamps that don't fit in a `u32` will be clamped.
+	///
+	/// If any line has [word timing](SyncedLyricsLine::words), each word will be stored as a
+	/// separate entry, with every line starting with a line break.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{SynchronizedTextFrame, TimestampFormat};
+	/// use lofty::tag::items::{SyncedLyrics, SyncedLyricsLine};
+	/// use std::time::Duration;
+	///
+	/// let lyrics = SyncedLyrics::new(vec![SyncedLyricsLine::new(
+	/// 	Duration::from_millis(1500),
+	/// 	String::from("Foo"),
+	/// )]);
+	///
+	/// let frame = SynchronizedTextFrame::from_synced_lyrics(&lyrics);
+	/// assert_eq!(frame.timestamp_format, TimestampFormat::MS);
+	/// assert_eq!(frame.content, vec![(1500, String::from("Foo"))]);
+	/// ```
+	pub fn from_synced_lyrics(lyrics: &SyncedLyrics) -> Self {
+		fn to_millis(timestamp: Duration) -> u32 {
+			u32::try_from(timestamp.as_millis()).unwrap_or(u32::MAX)
+		}
+
+		let has_word_timing = lyrics.lines.iter().any(|line| !line.words.is_empty());
+
+		let mut content = Vec::with_capacity(lyrics.lines.len());
+		for line in &lyrics.lines {
+			if !has_word_timing {
+				content.push((to_millis(line.timestamp), line.text.clone()));
+				continue;
+			}
+
+			if line.words.is_empty() {
+				content.push((to_millis(line.timestamp), format!("\n{}", line.text)));
+				continue;
+			}
+
+			for (idx, word) in line.words.iter().enumerate() {
+				let text = if idx == 0 {
+					format!("\n{}", word.text)
+				} else {
+					word.text.clone()
+				};
+
+				content.push((to_millis(word.timestamp), text));
+			}
+		}
+
+		Self::new(
+			TextEncoding::UTF8,
+			lyrics.language,
+			TimestampFormat::MS,
+			SyncTextContentType::Lyrics,
+			lyrics.description.clone(),
+			content,
+		)
+	}
+
+	/// Convert the frame to [`SyncedLyrics`]
+	///
+	/// Timestamps in [`TimestampFormat::MPEG`] are counts of MPEG frames, so they can only be
+	/// converted when `mpeg_frame_duration` is provided. For example, an MPEG-1 Layer III frame
+	/// holds 1152 samples, so at 44.1 kHz it lasts roughly 26 ms. `mpeg_frame_duration` is
+	/// ignored for [`TimestampFormat::MS`].
+	///
+	/// If only some entries start with a line break, the entries are treated as syllables, and are
+	/// grouped into lines with [word timing](SyncedLyricsLine::words).
+	///
+	/// NOTE: The content type isn't checked, any [`SyncTextContentType`] will be converted.
+	///
+	/// This will return `None` if the timestamps are in MPEG frames and no `mpeg_frame_duration`
+	/// was provided.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{SyncTextContentType, SynchronizedTextFrame, TimestampFormat};
+	/// use lofty::TextEncoding;
+	/// use std::time::Duration;
+	///
+	/// let frame = SynchronizedTextFrame::new(
+	/// 	TextEncoding::UTF8,
+	/// 	*b"eng",
+	/// 	TimestampFormat::MPEG,
+	/// 	SyncTextContentType::Lyrics,
+	/// 	None,
+	/// 	vec![(100, String::from("Foo"))],
+	/// );
+	///
+	/// assert!(frame.to_synced_lyrics(None).is_none());
+	///
+	/// let lyrics = frame
+	/// 	.to_synced_lyrics(Some(Duration::from_millis(26)))
+	/// 	.unwrap();
+	/// assert_eq!(lyrics.lines[0].timestamp, Duration::from_millis(2600));
+	/// ```
+	pub fn to_synced_lyrics(&self, mpeg_frame_duration: Option<Duration>) -> Option<SyncedLyrics> {
+		let starts_line = |text: &str| text.starts_with(['\n', '\r']);
+
+		// Entries may be individual syllables, in which case only those starting with a line break
+		// start a new line. Otherwise, every entry is its own line.
+		let is_syllables = self.content.iter().any(|(_, text)| starts_line(text))
+			&& self.content.iter().any(|(_, text)| !starts_line(text));
+
+		let mut lines: Vec<SyncedLyricsLine> = Vec::with_capacity(self.content.len());
+		for (timestamp, text) in &self.content {
+			let timestamp = self
+				.timestamp_format
+				.to_duration(*timestamp, mpeg_frame_duration)?;
+
+			if !is_syllables {
+				lines.push(SyncedLyricsLine::new(timestamp, text.clone()));
+				continue;
+			}
+
+			let word =
+				SyncedLyricsWord::new(timestamp, text.trim_start_matches(['\n', '\r']).to_string());
+			match lines.last_mut() {
+				Some(line) if !starts_line(text) => {
+					line.text.push_str(&word.text);
+					line.words.push(word);
+				},
+				_ => lines.push(SyncedLyricsLine::from_words(vec![word])),
+			}
+		}
+
+		// A single word is just the line itself
+		for line in &mut lines {
+			if line.words.len() == 1 {
+				line.words.clear();
+			}
+		}
+
+		Some(SyncedLyrics {
+			language: self.language,
+			description: self.description.clone(),
+			lines,
+		})
+	}
+
 	/// Convert a [`SynchronizedTextFrame`] to an ID3v2 SYLT frame byte Vec
 	///
 	/// NOTE: This does not include the frame header
 	///
+	/// NOTE: If `is_id3v23` is `true`, the encoding will be converted to one supported by ID3v2.3
+	///
 	/// # Errors
 	///
 	/// * `content`'s length > [`u32::MAX`]
 	/// * `language` is not exactly 3 bytes
 	/// * `language` contains invalid characters (Only `'a'..='z'` and `'A'..='Z'` allowed)
-	pub fn as_bytes(&self) -> Result<Vec<u8>> {
-		let mut data = vec![self.encoding as u8];
+	pub fn as_bytes(&self, is_id3v23: bool) -> Result<Vec<u8>> {
+		let mut encoding = self.encoding;
+		if is_id3v23 {
+			encoding = encoding.to_id3v23();
+		}
+
+		let mut data = vec![encoding as u8];
 
 		if self.language.len() == 3 && self.language.iter().all(u8::is_ascii_alphabetic) {
 			data.write_all(&self.language)?;
@@ -240,13 +413,13 @@ impl<'a> SynchronizedTextFrame<'a> {
 			data.write_u8(self.content_type as u8)?;
 
 			if let Some(description) = &self.description {
-				data.write_all(&encode_text(description, self.encoding, true))?;
+				data.write_all(&encode_text(description, encoding, true))?;
 			} else {
 				data.write_u8(0)?;
 			}
 
 			for (time, ref text) in &self.content {
-				data.write_all(&encode_text(text, self.encoding, true))?;
+				data.write_all(&encode_text(text, encoding, true))?;
 				data.write_u32::<BigEndian>(*time)?;
 			}
 
@@ -266,8 +439,11 @@ mod tests {
 	use crate::id3::v2::{
 		FrameFlags, FrameHeader, SyncTextContentType, SynchronizedTextFrame, TimestampFormat,
 	};
+	use crate::tag::items::{SyncedLyrics, SyncedLyricsLine, SyncedLyricsWord};
 	use crate::util::text::TextEncoding;
 
+	use std::time::Duration;
+
 	fn expected(encoding: TextEncoding) -> SynchronizedTextFrame<'static> {
 		SynchronizedTextFrame {
 			header: FrameHeader::new(super::FRAME_ID, FrameFlags::default()),
@@ -295,9 +471,23 @@ mod tests {
 		assert_eq!(parsed_sylt, expected(TextEncoding::Latin1));
 	}
 
+	#[test_log::test]
+	fn sylt_decode_utf16_empty_description_without_bom() {
+		let cont = [
+			1, b'e', b'n', b'g', 2, 1, // Header
+			0, 0, // Empty description, without a BOM
+			b'F', 0, b'o', 0, b'o', 0, 0, 0, 0, 0, 0, 10, // "Foo" at 10ms, without a BOM
+		];
+
+		let parsed_sylt = SynchronizedTextFrame::parse(&cont, FrameFlags::default()).unwrap();
+
+		assert_eq!(parsed_sylt.description, None);
+		assert_eq!(parsed_sylt.content, vec![(10, String::from("Foo"))]);
+	}
+
 	#[test_log::test]
 	fn sylt_encode() {
-		let encoded = expected(TextEncoding::Latin1).as_bytes().unwrap();
+		let encoded = expected(TextEncoding::Latin1).as_bytes(false).unwrap();
 
 		let expected_bytes =
 			crate::tag::utils::test_utils::read_path("tests/tags/assets/id3v2/test.sylt");
@@ -317,11 +507,71 @@ mod tests {
 
 	#[test_log::test]
 	fn sylt_encode_utf_16() {
-		let encoded = expected(TextEncoding::UTF16).as_bytes().unwrap();
+		let encoded = expected(TextEncoding::UTF16).as_bytes(false).unwrap();
 
 		let expected_bytes =
 			crate::tag::utils::test_utils::read_path("tests/tags/assets/id3v2/test_utf16.sylt");
 
 		assert_eq!(encoded, expected_bytes);
 	}
+
+	#[test_log::test]
+	fn sylt_encode_id3v23() {
+		let encoded = expected(TextEncoding::UTF8).as_bytes(true).unwrap();
+		assert_eq!(encoded[0], TextEncoding::UTF16 as u8);
+
+		let parsed = SynchronizedTextFrame::parse(&encoded, FrameFlags::default()).unwrap();
+		assert_eq!(parsed, expected(TextEncoding::UTF16));
+	}
+
+	#[test_log::test]
+	fn sylt_synced_lyrics_round_trip() {
+		let frame = expected(TextEncoding::UTF8);
+
+		let lyrics = frame.to_synced_lyrics(None).unwrap();
+		assert_eq!(lyrics.language, *b"eng");
+		assert_eq!(lyrics.description.as_deref(), Some("Test Sync Text"));
+		assert_eq!(
+			lyrics.lines[4],
+			SyncedLyricsLine::new(Duration::from_secs(1938), String::from("\nCorrectly"))
+		);
+
+		assert_eq!(SynchronizedTextFrame::from_synced_lyrics(&lyrics), frame);
+	}
+
+	#[test_log::test]
+	fn sylt_mpeg_frames_to_synced_lyrics() {
+		let mut frame = expected(TextEncoding::UTF8);
+		frame.timestamp_format = TimestampFormat::MPEG;
+
+		assert_eq!(frame.to_synced_lyrics(None), None);
+
+		let SyncedLyrics { lines, .. } = frame
+			.to_synced_lyrics(Some(Duration::from_millis(20)))
+			.unwrap();
+		assert_eq!(lines[1].timestamp, Duration::from_secs(200));
+	}
+
+	#[test_log::test]
+	fn sylt_synced_lyrics_words() {
+		let lyrics = SyncedLyrics::new(vec![
+			SyncedLyricsLine::from_words(vec![
+				SyncedLyricsWord::new(Duration::from_secs(1), String::from("Foo ")),
+				SyncedLyricsWord::new(Duration::from_millis(1500), String::from("bar")),
+			]),
+			SyncedLyricsLine::new(Duration::from_secs(3), String::from("Baz")),
+		]);
+
+		let frame = SynchronizedTextFrame::from_synced_lyrics(&lyrics);
+		assert_eq!(
+			frame.content,
+			vec![
+				(1000, String::from("\nFoo ")),
+				(1500, String::from("bar")),
+				(3000, String::from("\nBaz")),
+			]
+		);
+
+		assert_eq!(frame.to_synced_lyrics(None), Some(lyrics));
+	}
 }
@@ -0,0 +1,226 @@
+use super::chapter_frame::{read_element_id, read_sub_frames, write_sub_frames};
+use crate::config::ParsingMode;
+use crate::error::Result;
+use crate::id3::v2::header::Id3v2Version;
+use crate::id3::v2::{Frame, FrameFlags, FrameHeader, FrameId};
+use crate::macros::err;
+use crate::util::text::{encode_text, TextEncoding};
+
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+
+use byteorder::ReadBytesExt;
+
+const FRAME_ID: FrameId<'static> = FrameId::Valid(Cow::Borrowed("CTOC"));
+
+const FLAG_ORDERED: u8 = 0x01;
+const FLAG_TOP_LEVEL: u8 = 0x02;
+
+/// An `ID3v2` table of contents frame
+///
+/// This frame lists the element IDs of [`ChapterFrame`](crate::id3::v2::ChapterFrame)s, or of
+/// other tables of contents, allowing for a hierarchy of chapters. Like chapters, it can have
+/// [`sub_frames`](Self::sub_frames) describing it, such as a `TIT2` frame for its title.
+#[derive(Clone, Debug, Eq)]
+pub struct TableOfContentsFrame<'a> {
+	pub(crate) header: FrameHeader<'a>,
+	/// A unique identifier for the table of contents within the tag
+	pub element_id: String,
+	/// Whether this is the root of the hierarchy
+	///
+	/// There should only be one top-level table of contents in a tag.
+	pub top_level: bool,
+	/// Whether the child elements are in order
+	pub ordered: bool,
+	/// The element IDs of the child chapters and tables of contents
+	pub child_element_ids: Vec<String>,
+	/// Frames describing the table of contents
+	pub sub_frames: Vec<Frame<'a>>,
+}
+
+impl<'a> PartialEq for TableOfContentsFrame<'a> {
+	fn eq(&self, other: &Self) -> bool {
+		self.element_id == other.element_id
+	}
+}
+
+impl<'a> Hash for TableOfContentsFrame<'a> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.element_id.hash(state);
+	}
+}
+
+impl<'a> TableOfContentsFrame<'a> {
+	/// Create a new [`TableOfContentsFrame`]
+	///
+	/// This will not be top-level or ordered, and will have no sub-frames.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::TableOfContentsFrame;
+	///
+	/// let mut toc = TableOfContentsFrame::new(
+	/// 	String::from("toc"),
+	/// 	vec![String::from("chp0"), String::from("chp1")],
+	/// );
+	/// toc.top_level = true;
+	/// toc.ordered = true;
+	///
+	/// assert_eq!(toc.child_element_ids.len(), 2);
+	/// ```
+	pub fn new(element_id: String, child_element_ids: Vec<String>) -> Self {
+		let header = FrameHeader::new(FRAME_ID, FrameFlags::default());
+		Self {
+			header,
+			element_id,
+			top_level: false,
+			ordered: false,
+			child_element_ids,
+			sub_frames: Vec::new(),
+		}
+	}
+
+	/// Get the ID for the frame
+	pub fn id(&self) -> FrameId<'_> {
+		FRAME_ID
+	}
+
+	/// Get the flags for the frame
+	pub fn flags(&self) -> FrameFlags {
+		self.header.flags
+	}
+
+	/// Set the flags for the frame
+	pub fn set_flags(&mut self, flags: FrameFlags) {
+		self.header.flags = flags;
+	}
+
+	/// Read a [`TableOfContentsFrame`]
+	///
+	/// NOTE: This expects the frame header to have already been skipped
+	///
+	/// # Errors
+	///
+	/// * Failure to read from `reader`
+	/// * A sub-frame is invalid (only in [`ParsingMode::Strict`])
+	pub fn parse<R>(
+		reader: &mut R,
+		frame_flags: FrameFlags,
+		version: Id3v2Version,
+		parse_mode: ParsingMode,
+	) -> Result<Option<Self>>
+	where
+		R: Read,
+	{
+		let Ok(element_id) = read_element_id(reader) else {
+			return Ok(None);
+		};
+
+		let flags = reader.read_u8()?;
+		let entry_count = reader.read_u8()?;
+
+		let mut child_element_ids = Vec::with_capacity(usize::from(entry_count));
+		for _ in 0..entry_count {
+			child_element_ids.push(read_element_id(reader)?);
+		}
+
+		let sub_frames = read_sub_frames(reader, version, parse_mode)?;
+
+		let header = FrameHeader::new(FRAME_ID, frame_flags);
+		Ok(Some(TableOfContentsFrame {
+			header,
+			element_id,
+			top_level: flags & FLAG_TOP_LEVEL == FLAG_TOP_LEVEL,
+			ordered: flags & FLAG_ORDERED == FLAG_ORDERED,
+			child_element_ids,
+			sub_frames,
+		}))
+	}
+
+	/// Convert a [`TableOfContentsFrame`] to a byte vec
+	///
+	/// NOTE: The sub-frames are written with the same rules as the rest of the tag, meaning
+	///       frames not supported in ID3v2.3 will be converted or discarded.
+	///
+	/// # Errors
+	///
+	/// * There are more than 255 child elements
+	/// * A sub-frame could not be written
+	pub fn as_bytes(&self, is_id3v23: bool) -> Result<Vec<u8>> {
+		let Ok(entry_count) = u8::try_from(self.child_element_ids.len()) else {
+			err!(TooMuchData);
+		};
+
+		let mut content = encode_text(&self.element_id, TextEncoding::Latin1, true);
+
+		let mut flags = 0;
+		if self.top_level {
+			flags |= FLAG_TOP_LEVEL;
+		}
+
+		if self.ordered {
+			flags |= FLAG_ORDERED;
+		}
+
+		content.push(flags);
+		content.push(entry_count);
+
+		for child_element_id in &self.child_element_ids {
+			content.extend(encode_text(child_element_id, TextEncoding::Latin1, true));
+		}
+
+		write_sub_frames(&mut content, &self.sub_frames, is_id3v23)?;
+
+		Ok(content)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::config::ParsingMode;
+	use crate::id3::v2::header::Id3v2Version;
+	use crate::id3::v2::{Frame, FrameFlags, TableOfContentsFrame};
+
+	use std::borrow::Cow;
+
+	#[test_log::test]
+	fn ctoc_re_read() {
+		let mut toc = TableOfContentsFrame::new(
+			String::from("toc"),
+			vec![String::from("chp0"), String::from("chp1")],
+		);
+		toc.top_level = true;
+		toc.sub_frames.push(Frame::text(
+			Cow::Borrowed("TIT2"),
+			String::from("Foo title"),
+		));
+
+		let encoded = toc.as_bytes(false).unwrap();
+		assert_eq!(&encoded[..6], b"toc\x00\x02\x02");
+
+		let parsed = TableOfContentsFrame::parse(
+			&mut &encoded[..],
+			FrameFlags::default(),
+			Id3v2Version::V4,
+			ParsingMode::Strict,
+		)
+		.unwrap()
+		.unwrap();
+
+		assert_eq!(parsed.element_id, toc.element_id);
+		assert!(parsed.top_level);
+		assert!(!parsed.ordered);
+		assert_eq!(parsed.child_element_ids, toc.child_element_ids);
+		assert_eq!(parsed.sub_frames, toc.sub_frames);
+	}
+
+	#[test_log::test]
+	fn ctoc_too_many_children() {
+		let children = (0..256).map(|i| format!("chp{i}")).collect();
+		let toc = TableOfContentsFrame::new(String::from("toc"), children);
+
+		assert!(toc.as_bytes(false).is_err());
+	}
+}
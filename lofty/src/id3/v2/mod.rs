@@ -7,6 +7,7 @@
 //! * [`Id3v2Tag`]
 //! * [`Frame`]
 
+pub(crate) mod cipher;
 mod frame;
 pub(crate) mod header;
 mod items;
@@ -18,6 +19,7 @@ pub(crate) mod write;
 
 // Exports
 
+pub use cipher::{FrameCipher, FrameCiphers};
 pub use header::{Id3v2TagFlags, Id3v2Version};
 pub use util::upgrade::{upgrade_v2, upgrade_v3};
 
@@ -1,4 +1,5 @@
-use super::frame::read::ParsedFrame;
+use super::cipher::EncryptionMethods;
+use super::frame::read::{decrypt_frame, ParsedFrame};
 use super::header::Id3v2Header;
 use super::tag::Id3v2Tag;
 use crate::config::ParseOptions;
@@ -27,22 +28,24 @@ where
 	let mut tag_bytes = bytes.take(u64::from(header.size - header.extended_size));
 
 	let mut ret;
-	if header.flags.unsynchronisation {
+	if header.flags.unsynchronisation && header.version != Id3v2Version::V4 {
 		// Unsynchronize the entire tag
+		//
+		// ID3v2.4 unsynchronises each frame individually instead
 		let mut unsynchronized_reader = UnsynchronizedStream::new(tag_bytes);
-		ret = read_all_frames_into_tag(&mut unsynchronized_reader, header, parse_options)?;
+		ret = read_all_frames_into_tag(&mut unsynchronized_reader, header, parse_options.clone())?;
 
 		// Get the `Take` back from the `UnsynchronizedStream`
 		tag_bytes = unsynchronized_reader.into_inner();
 	} else {
-		ret = read_all_frames_into_tag(&mut tag_bytes, header, parse_options)?;
+		ret = read_all_frames_into_tag(&mut tag_bytes, header, parse_options.clone())?;
 	};
 
 	// Throw away the rest of the tag (padding, bad frames)
 	std::io::copy(&mut tag_bytes, &mut std::io::sink())?;
 
-	// Construct TDRC frame from TYER, TDAT, and TIME frames
-	if parse_options.implicit_conversions && header.version == Id3v2Version::V3 {
+	// Construct TDRC frame from TYER, TDAT, and TIME frames (TYE, TDA, and TIM in ID3v2.2)
+	if parse_options.implicit_conversions && header.version != Id3v2Version::V4 {
 		construct_tdrc_from_v3(&mut ret);
 	}
 
@@ -142,8 +145,15 @@ where
 	tag.original_version = header.version;
 	tag.set_flags(header.flags);
 
+	let tag_unsynchronised = header.flags.unsynchronisation && header.version == Id3v2Version::V4;
+
 	loop {
-		match ParsedFrame::read(reader, header.version, parse_options)? {
+		match ParsedFrame::read(
+			reader,
+			header.version,
+			tag_unsynchronised,
+			parse_options.clone(),
+		)? {
 			ParsedFrame::Next(frame) => {
 				let frame_value_is_empty = frame.is_empty();
 				if let Some(replaced_frame) = tag.insert(frame) {
@@ -174,6 +184,12 @@ where
 		}
 	}
 
+	// The ENCR frames can appear anywhere in the tag, so decryption has to wait until the end
+	let ciphers = EncryptionMethods::new(&tag.frames, parse_options.id3v2_frame_ciphers.as_ref());
+	for frame in &mut tag.frames {
+		decrypt_frame(frame, &ciphers, header.version, parse_options.parsing_mode)?;
+	}
+
 	Ok(tag)
 }
 
@@ -1,3 +1,4 @@
+mod chapters;
 #[cfg(test)]
 mod tests;
 
@@ -8,7 +9,8 @@ use crate::error::{LoftyError, Result};
 use crate::id3::v1::GENRES;
 use crate::id3::v2::frame::{FrameRef, MUSICBRAINZ_UFID_OWNER};
 use crate::id3::v2::items::{
-	AttachedPictureFrame, CommentFrame, ExtendedTextFrame, ExtendedUrlFrame, TextInformationFrame,
+	AttachedPictureFrame, CommentFrame, ExtendedTextFrame, ExtendedUrlFrame,
+	GeneralEncapsulatedObject, SyncTextContentType, SynchronizedTextFrame, TextInformationFrame,
 	UniqueFileIdentifierFrame, UnsynchronizedTextFrame, UrlLinkFrame,
 };
 use crate::id3::v2::util::mappings::TIPL_MAPPINGS;
@@ -101,18 +103,11 @@ macro_rules! impl_accessor {
 /// otherwise [`ItemKey::Unknown`] will be used.
 /// * Frames that require a language (COMM/USLT) - With ID3v2 being the only format that allows for language-specific items, this information is not retained.
 /// * POPM - These frames will be stored as a raw [`ItemValue::Binary`] value under the [`ItemKey::Popularimeter`] key.
-///
-/// ## Special Frames
-///
-/// ID3v2 has `GEOB` and `SYLT` frames, which are not parsed by default, instead storing them as [`FrameType::Binary`].
-/// They can easily be parsed with [`GeneralEncapsulatedObject::parse`](crate::id3::v2::GeneralEncapsulatedObject::parse)
-/// and [`SynchronizedText::parse`](crate::id3::v2::SynchronizedTextFrame::parse) respectively, and converted back to binary with
-/// [`GeneralEncapsulatedObject::as_bytes`](crate::id3::v2::GeneralEncapsulatedObject::as_bytes) and
-/// [`SynchronizedText::as_bytes`](crate::id3::v2::SynchronizedTextFrame::as_bytes) for writing.
+/// * SYLT - Frames containing lyrics will be stored as [`SyncedLyrics`](crate::tag::items::SyncedLyrics), unless their timestamps are in MPEG frames.
 #[derive(PartialEq, Eq, Debug, Clone)]
 #[tag(
 	description = "An `ID3v2` tag",
-	supported_formats(Aac, Aiff, Mpeg, Wav, read_only(Ape, Flac, Mpc))
+	supported_formats(Aac, Aiff, Dff, Dsf, Mpeg, Tta, Wav, read_only(Ape, Flac, Mpc))
 )]
 pub struct Id3v2Tag {
 	flags: Id3v2TagFlags,
@@ -507,6 +502,188 @@ impl Id3v2Tag {
 		})
 	}
 
+	/// Returns all `SYLT` frames
+	pub fn synchronized_text(&self) -> impl Iterator<Item = &SynchronizedTextFrame<'_>> + Clone {
+		self.frames.iter().filter_map(|f| match f {
+			Frame::SynchronizedText(val) => Some(val),
+			_ => None,
+		})
+	}
+
+	/// Gets the `SYLT` frame with the given language and description
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{Id3v2Tag, SyncTextContentType, SynchronizedTextFrame, TimestampFormat};
+	/// use lofty::TextEncoding;
+	///
+	/// let mut tag = Id3v2Tag::new();
+	///
+	/// let _ = tag.insert_synchronized_text(SynchronizedTextFrame::new(
+	/// 	TextEncoding::UTF8,
+	/// 	*b"eng",
+	/// 	TimestampFormat::MS,
+	/// 	SyncTextContentType::Lyrics,
+	/// 	None,
+	/// 	vec![(0, String::from("Foo"))],
+	/// ));
+	///
+	/// let sync_text = tag.get_synchronized_text(*b"eng", None).unwrap();
+	/// assert_eq!(sync_text.content[0].1, "Foo");
+	///
+	/// assert!(tag.get_synchronized_text(*b"deu", None).is_none());
+	/// ```
+	pub fn get_synchronized_text(
+		&self,
+		language: Lang,
+		description: Option<&str>,
+	) -> Option<&SynchronizedTextFrame<'_>> {
+		self.synchronized_text().find(|sync_text| {
+			sync_text.language == language && sync_text.description.as_deref() == description
+		})
+	}
+
+	/// Inserts a `SYLT` frame
+	///
+	/// `SYLT` frames are identified by their language and description, so this will replace any
+	/// frame with the same language and description, returning it.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{Id3v2Tag, SyncTextContentType, SynchronizedTextFrame, TimestampFormat};
+	/// use lofty::TextEncoding;
+	///
+	/// let mut tag = Id3v2Tag::new();
+	///
+	/// let new_frame = |text: &str| {
+	/// 	SynchronizedTextFrame::new(
+	/// 		TextEncoding::UTF8,
+	/// 		*b"eng",
+	/// 		TimestampFormat::MS,
+	/// 		SyncTextContentType::Lyrics,
+	/// 		Some(String::from("Verse")),
+	/// 		vec![(0, String::from(text))],
+	/// 	)
+	/// };
+	///
+	/// assert!(tag.insert_synchronized_text(new_frame("Foo")).is_none());
+	///
+	/// // Same language and description, the old frame is replaced
+	/// assert!(tag.insert_synchronized_text(new_frame("Bar")).is_some());
+	/// assert_eq!(tag.synchronized_text().count(), 1);
+	/// ```
+	pub fn insert_synchronized_text(
+		&mut self,
+		frame: SynchronizedTextFrame<'static>,
+	) -> Option<Frame<'static>> {
+		let replaced = self
+			.frames
+			.iter()
+			.position(|f| {
+				matches!(f, Frame::SynchronizedText(sync_text)
+					if sync_text.language == frame.language && sync_text.description == frame.description)
+			})
+			.map(|pos| self.frames.remove(pos));
+
+		self.frames.push(Frame::SynchronizedText(frame));
+		replaced
+	}
+
+	/// Returns all `GEOB` frames
+	pub fn encapsulated_objects(
+		&self,
+	) -> impl Iterator<Item = &GeneralEncapsulatedObject<'_>> + Clone {
+		self.frames.iter().filter_map(|f| match f {
+			Frame::EncapsulatedObject(val) => Some(val),
+			_ => None,
+		})
+	}
+
+	/// Gets a `GEOB` frame by its descriptor
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{GeneralEncapsulatedObject, Id3v2Tag};
+	/// use lofty::TextEncoding;
+	///
+	/// let mut tag = Id3v2Tag::new();
+	///
+	/// let _ = tag.insert_encapsulated_object(GeneralEncapsulatedObject::new(
+	/// 	TextEncoding::Latin1,
+	/// 	Some(String::from("application/octet-stream")),
+	/// 	None,
+	/// 	Some(String::from("Serato Markers2")),
+	/// 	vec![1, 2, 3],
+	/// ));
+	///
+	/// let object = tag.get_encapsulated_object("Serato Markers2").unwrap();
+	/// assert_eq!(object.data, &[1, 2, 3]);
+	/// ```
+	pub fn get_encapsulated_object(
+		&self,
+		descriptor: &str,
+	) -> Option<&GeneralEncapsulatedObject<'_>> {
+		self.encapsulated_objects()
+			.find(|object| object.descriptor_or_empty() == descriptor)
+	}
+
+	/// Inserts a `GEOB` frame
+	///
+	/// `GEOB` frames are identified by their descriptor, so this will replace any frame with the
+	/// same descriptor, returning it.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::id3::v2::{Frame, GeneralEncapsulatedObject, Id3v2Tag};
+	/// use lofty::TextEncoding;
+	///
+	/// let mut tag = Id3v2Tag::new();
+	///
+	/// let new_object = |data: Vec<u8>| {
+	/// 	GeneralEncapsulatedObject::new(
+	/// 		TextEncoding::Latin1,
+	/// 		None,
+	/// 		None,
+	/// 		Some(String::from("Serato Markers2")),
+	/// 		data,
+	/// 	)
+	/// };
+	///
+	/// assert!(tag.insert_encapsulated_object(new_object(vec![1])).is_none());
+	///
+	/// // Same descriptor, the old frame is replaced
+	/// let Some(Frame::EncapsulatedObject(replaced)) =
+	/// 	tag.insert_encapsulated_object(new_object(vec![2]))
+	/// else {
+	/// 	unreachable!()
+	/// };
+	/// assert_eq!(replaced.data, &[1]);
+	/// assert_eq!(tag.encapsulated_objects().count(), 1);
+	/// ```
+	pub fn insert_encapsulated_object(
+		&mut self,
+		object: GeneralEncapsulatedObject<'static>,
+	) -> Option<Frame<'static>> {
+		self.insert(Frame::EncapsulatedObject(object))
+	}
+
+	/// Removes a `GEOB` frame by its descriptor
+	///
+	/// This will return the matching frame.
+	pub fn remove_encapsulated_object(&mut self, descriptor: &str) -> Option<Frame<'static>> {
+		self.frames
+			.iter()
+			.position(|frame| {
+				matches!(frame, Frame::EncapsulatedObject(object)
+					if object.descriptor_or_empty() == descriptor)
+			})
+			.map(|pos| self.frames.remove(pos))
+	}
+
 	/// Returns all `COMM` frames with an empty content descriptor
 	pub fn comments(&self) -> impl Iterator<Item = &CommentFrame<'_>> {
 		self.frames.iter().filter_map(|frame| {
@@ -913,6 +1090,7 @@ impl TagExt for Id3v2Tag {
 	{
 		Id3v2TagRef {
 			flags: self.flags,
+			original_version: self.original_version,
 			frames: self.frames.iter().filter_map(Frame::as_opt_ref).peekable(),
 		}
 		.write_to(file, write_options)
@@ -931,6 +1109,7 @@ impl TagExt for Id3v2Tag {
 	) -> std::result::Result<(), Self::Err> {
 		Id3v2TagRef {
 			flags: self.flags,
+			original_version: self.original_version,
 			frames: self.frames.iter().filter_map(Frame::as_opt_ref).peekable(),
 		}
 		.dump_to(writer, write_options)
@@ -1226,6 +1405,15 @@ fn handle_tag_split(tag: &mut Tag, frame: &mut Frame<'_>) -> bool {
 
 			return FRAME_CONSUMED;
 		},
+		Frame::SynchronizedText(sync_text) if sync_text.content_type == SyncTextContentType::Lyrics => {
+			// Timestamps in MPEG frames can't be converted without the audio properties
+			let Some(lyrics) = sync_text.to_synced_lyrics(None) else {
+				return FRAME_RETAINED;
+			};
+
+			tag.synced_lyrics.push(lyrics);
+			return FRAME_CONSUMED;
+		},
 
 		Frame::Binary(_)
 		| Frame::UserText(_)
@@ -1235,7 +1423,23 @@ fn handle_tag_split(tag: &mut Tag, frame: &mut Frame<'_>) -> bool {
 		| Frame::Ownership(_)
 		| Frame::EventTimingCodes(_)
 		| Frame::Popularimeter(_)
-		| Frame::Private(_) => {
+		| Frame::Private(_)
+		| Frame::Chapter(_)
+		| Frame::TableOfContents(_)
+		| Frame::SynchronizedText(_)
+		| Frame::EncapsulatedObject(_)
+		| Frame::Commercial(_)
+		| Frame::AudioEncryption(_)
+		| Frame::LinkedInformation(_)
+		| Frame::Signature(_)
+		| Frame::EncryptionMethodRegistration(_)
+		| Frame::GroupIdentificationRegistration(_)
+		| Frame::Seek(_)
+		| Frame::AudioSeekPointIndex(_)
+		| Frame::RecommendedBufferSize(_)
+		| Frame::Equalisation(_)
+		| Frame::MpegLocationLookupTable(_)
+		| Frame::PositionSynchronisation(_) => {
 			return FRAME_RETAINED; // Keep unsupported frame
 		},
 	}
@@ -1247,6 +1451,8 @@ impl SplitTag for Id3v2Tag {
 	fn split_tag(mut self) -> (Self::Remainder, Tag) {
 		let mut tag = Tag::new(TagType::Id3v2);
 
+		tag.chapters = chapters::take_chapters(&mut self.frames);
+
 		self.frames
 			.retain_mut(|frame| handle_tag_split(&mut tag, frame));
 
@@ -1582,6 +1788,14 @@ impl MergeTag for SplitTagRemainder {
 			}
 		}
 
+		for frame in chapters::chapter_frames(&tag.chapters) {
+			merged.insert(frame);
+		}
+
+		for lyrics in &tag.synced_lyrics {
+			merged.insert_synchronized_text(SynchronizedTextFrame::from_synced_lyrics(lyrics));
+		}
+
 		merged
 	}
 }
@@ -1590,7 +1804,10 @@ impl From<Id3v2Tag> for Tag {
 	fn from(input: Id3v2Tag) -> Self {
 		let (remainder, mut tag) = input.split_tag();
 
-		if unsafe { global_options().preserve_format_specific_items } && remainder.0.len() > 0 {
+		// The companion tag also keeps track of the original version, for `WriteOptions::preserve_id3v2_version`
+		if unsafe { global_options().preserve_format_specific_items }
+			&& (remainder.0.len() > 0 || remainder.0.original_version != Id3v2Version::V4)
+		{
 			tag.companion_tag = Some(CompanionTag::Id3v2(remainder.0));
 		}
 
@@ -1612,6 +1829,7 @@ impl From<Tag> for Id3v2Tag {
 
 pub(crate) struct Id3v2TagRef<'a, I: Iterator<Item = FrameRef<'a>> + 'a> {
 	pub(crate) flags: Id3v2TagFlags,
+	pub(crate) original_version: Id3v2Version,
 	pub(crate) frames: Peekable<I>,
 }
 
@@ -1619,11 +1837,23 @@ impl<'a> Id3v2TagRef<'a, std::iter::Empty<FrameRef<'a>>> {
 	pub(crate) fn empty() -> Self {
 		Self {
 			flags: Id3v2TagFlags::default(),
+			original_version: Id3v2Version::V4,
 			frames: std::iter::empty().peekable(),
 		}
 	}
 }
 
+// Get the original version of the `Id3v2Tag` a Tag was converted from for Id3v2TagRef::new
+//
+// This is only known if the `Id3v2Tag` was kept around as a companion tag, otherwise
+// the tag is treated as ID3v2.4, same as a new `Id3v2Tag`.
+pub(crate) fn tag_original_version(tag: &Tag) -> Id3v2Version {
+	match &tag.companion_tag {
+		Some(CompanionTag::Id3v2(companion)) => companion.original_version,
+		_ => Id3v2Version::V4,
+	}
+}
+
 // Create an iterator of FrameRef from a Tag's items for Id3v2TagRef::new
 pub(crate) fn tag_frames(tag: &Tag) -> impl Iterator<Item = FrameRef<'_>> {
 	#[derive(Clone)]
@@ -1692,7 +1922,17 @@ pub(crate) fn tag_frames(tag: &Tag) -> impl Iterator<Item = FrameRef<'_>> {
 		))))
 	});
 
-	items.chain(pictures)
+	let chapters = chapters::chapter_frames(tag.chapters())
+		.into_iter()
+		.map(|frame| FrameRef(Cow::Owned(frame)));
+
+	let synced_lyrics = tag.synced_lyrics().iter().map(|lyrics| {
+		FrameRef(Cow::Owned(Frame::SynchronizedText(
+			SynchronizedTextFrame::from_synced_lyrics(lyrics),
+		)))
+	});
+
+	items.chain(pictures).chain(chapters).chain(synced_lyrics)
 }
 
 impl<'a, I: Iterator<Item = FrameRef<'a>> + 'a> Id3v2TagRef<'a, I> {
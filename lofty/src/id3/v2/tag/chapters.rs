@@ -0,0 +1,202 @@
+//! Conversions between `CHAP`/`CTOC` frames and [`Chapter`]s
+
+use crate::id3::v2::items::{AttachedPictureFrame, ExtendedUrlFrame};
+use crate::id3::v2::{ChapterFrame, Frame, TableOfContentsFrame};
+use crate::tag::items::{chapter_ends, Chapter};
+use crate::util::text::TextEncoding;
+
+use std::borrow::Cow;
+use std::time::Duration;
+
+/// The element ID of the table of contents we create
+const TOP_LEVEL_ELEMENT_ID: &str = "toc";
+
+/// Remove all `CHAP` and `CTOC` frames, converting them to [`Chapter`]s
+///
+/// The chapters will be in the order of the top-level `CTOC` frame if there is one, otherwise they
+/// will be ordered by their start times. The frames are left untouched if there are no `CHAP` frames.
+pub(super) fn take_chapters(frames: &mut Vec<Frame<'static>>) -> Vec<Chapter> {
+	if !frames
+		.iter()
+		.any(|frame| matches!(frame, Frame::Chapter(_)))
+	{
+		return Vec::new();
+	}
+
+	let mut chapters = Vec::new();
+	let mut tables_of_contents = Vec::new();
+
+	frames.retain_mut(|frame| match frame {
+		Frame::Chapter(chapter) => {
+			let element_id = std::mem::take(&mut chapter.element_id);
+			chapters.push((element_id, convert_chapter_frame(chapter)));
+			false
+		},
+		Frame::TableOfContents(toc) => {
+			tables_of_contents.push((toc.top_level, std::mem::take(&mut toc.child_element_ids)));
+			false
+		},
+		_ => true,
+	});
+
+	chapters.sort_by_key(|(_, chapter)| chapter.start);
+
+	let Some((_, children)) = tables_of_contents
+		.iter()
+		.find(|(top_level, _)| *top_level)
+		.or(tables_of_contents.first())
+	else {
+		return chapters.into_iter().map(|(_, chapter)| chapter).collect();
+	};
+
+	// Chapters that aren't referenced by the table of contents are kept at the end
+	let mut ordered = Vec::with_capacity(chapters.len());
+	for child in children {
+		if let Some(pos) = chapters.iter().position(|(id, _)| id == child) {
+			ordered.push(chapters.remove(pos).1);
+		}
+	}
+
+	ordered.extend(chapters.into_iter().map(|(_, chapter)| chapter));
+	ordered
+}
+
+fn convert_chapter_frame(chapter_frame: &mut ChapterFrame<'static>) -> Chapter {
+	let start = chapter_frame.start_time;
+	let end = chapter_frame.end_time;
+
+	let mut chapter = Chapter::new(Duration::from_millis(u64::from(start)), None);
+	if end >= start {
+		chapter.end = Some(Duration::from_millis(u64::from(end)));
+	}
+
+	for sub_frame in chapter_frame.sub_frames.drain(..) {
+		match sub_frame {
+			Frame::Text(text) if text.header.id.as_str() == "TIT2" => {
+				chapter.title = Some(text.value);
+			},
+			Frame::UserUrl(url) => chapter.url = Some(url.content),
+			Frame::Url(url) if chapter.url.is_none() => chapter.url = Some(url.content),
+			Frame::Picture(picture) => chapter.image = Some(picture.picture),
+			_ => {},
+		}
+	}
+
+	chapter
+}
+
+/// Create a `CHAP` frame for every chapter, as well as a top-level `CTOC` frame
+pub(crate) fn chapter_frames(chapters: &[Chapter]) -> Vec<Frame<'static>> {
+	if chapters.is_empty() {
+		return Vec::new();
+	}
+
+	let mut frames = Vec::with_capacity(chapters.len() + 1);
+	let mut element_ids = Vec::with_capacity(chapters.len());
+
+	for (idx, (chapter, end)) in chapters.iter().zip(chapter_ends(chapters)).enumerate() {
+		let element_id = format!("chp{idx}");
+
+		let mut chapter_frame = ChapterFrame::new(
+			element_id.clone(),
+			duration_to_millis(chapter.start),
+			duration_to_millis(end),
+		);
+
+		if let Some(title) = &chapter.title {
+			chapter_frame
+				.sub_frames
+				.push(Frame::text(Cow::Borrowed("TIT2"), title.clone()));
+		}
+
+		if let Some(url) = &chapter.url {
+			chapter_frame
+				.sub_frames
+				.push(Frame::UserUrl(ExtendedUrlFrame::new(
+					TextEncoding::UTF8,
+					String::new(),
+					url.clone(),
+				)));
+		}
+
+		if let Some(image) = &chapter.image {
+			chapter_frame
+				.sub_frames
+				.push(Frame::Picture(AttachedPictureFrame::new(
+					TextEncoding::UTF8,
+					image.clone(),
+				)));
+		}
+
+		frames.push(Frame::Chapter(chapter_frame));
+		element_ids.push(element_id);
+	}
+
+	if element_ids.len() > usize::from(u8::MAX) {
+		log::warn!(
+			"Too many chapters for the table of contents, only the first {} will be referenced",
+			u8::MAX
+		);
+		element_ids.truncate(usize::from(u8::MAX));
+	}
+
+	let mut toc = TableOfContentsFrame::new(String::from(TOP_LEVEL_ELEMENT_ID), element_ids);
+	toc.top_level = true;
+	toc.ordered = true;
+
+	frames.push(Frame::TableOfContents(toc));
+	frames
+}
+
+fn duration_to_millis(duration: Duration) -> u32 {
+	u32::try_from(duration.as_millis()).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{chapter_frames, take_chapters};
+	use crate::picture::{MimeType, Picture, PictureType};
+	use crate::tag::items::Chapter;
+
+	use std::time::Duration;
+
+	#[test_log::test]
+	fn chapters_re_read() {
+		let mut first = Chapter::new(Duration::ZERO, Some(String::from("Intro")));
+		first.url = Some(String::from("https://example.com"));
+		first.image = Some(Picture::new_unchecked(
+			PictureType::Other,
+			Some(MimeType::Png),
+			None,
+			vec![0; 512],
+		));
+
+		let mut second = Chapter::new(Duration::from_millis(62_500), Some(String::from("Outro")));
+		second.end = Some(Duration::from_secs(150));
+
+		let mut frames = chapter_frames(&[first.clone(), second.clone()]);
+		assert_eq!(frames.len(), 3);
+
+		// The end of the first chapter is filled in
+		first.end = Some(second.start);
+
+		let chapters = take_chapters(&mut frames);
+		assert!(frames.is_empty());
+		assert_eq!(chapters, vec![first, second]);
+	}
+
+	#[test_log::test]
+	fn chapters_follow_table_of_contents() {
+		// The table of contents doesn't need to be ordered by start time
+		let chapters = [
+			Chapter::new(Duration::from_secs(10), Some(String::from("First"))),
+			Chapter::new(Duration::ZERO, Some(String::from("Second"))),
+		];
+
+		let mut frames = chapter_frames(&chapters);
+
+		let read_chapters = take_chapters(&mut frames);
+		assert_eq!(read_chapters[0].title.as_deref(), Some("First"));
+		assert_eq!(read_chapters[1].title.as_deref(), Some("Second"));
+	}
+}
@@ -2,16 +2,23 @@ use crate::config::{ParseOptions, ParsingMode};
 use crate::id3::v2::header::Id3v2Header;
 use crate::id3::v2::items::PopularimeterFrame;
 use crate::id3::v2::util::pairs::DEFAULT_NUMBER_IN_PAIR;
+use crate::id3::v2::util::synchsafe::SynchsafeInteger;
 use crate::id3::v2::{
-	ChannelInformation, ChannelType, RelativeVolumeAdjustmentFrame, TimestampFrame,
+	AdjustmentPoint, AudioSeekPointIndexFrame, ChannelInformation, ChannelType, ChapterFrame,
+	EncryptionMethodRegistrationFrame, EqualisationFrame, FrameCipher, FrameCiphers, FrameFlags,
+	GeneralEncapsulatedObject, InterpolationMethod, LinkedInformationFrame, PrivateFrame,
+	RelativeVolumeAdjustmentFrame, SeekFrame, SignatureFrame, SyncTextContentType,
+	TableOfContentsFrame, TimestampFormat, TimestampFrame,
 };
 use crate::picture::MimeType;
-use crate::tag::items::{Timestamp, ENGLISH};
+use crate::tag::items::{SyncedLyrics, SyncedLyricsLine, Timestamp, ENGLISH};
 use crate::tag::utils::test_utils::read_path;
 
 use super::*;
 
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 
 const COMMENT_FRAME_ID: &str = "COMM";
 
@@ -279,9 +286,14 @@ fn id3v23_full() {
 
 #[test_log::test]
 fn id3v22_full() {
-	let tag = create_full_test_tag(Id3v2Version::V2);
-	let parsed_tag = read_tag("tests/tags/assets/id3v2/test_full.id3v22");
+	let mut tag = create_full_test_tag(Id3v2Version::V2);
+	let mut parsed_tag = read_tag("tests/tags/assets/id3v2/test_full.id3v22");
 
+	// Tags may change order after being read, due to the TDRC conversion
+	tag.frames.sort_by_key(|frame| frame.id_str().to_string());
+	parsed_tag
+		.frames
+		.sort_by_key(|frame| frame.id_str().to_string());
 	assert_eq!(tag, parsed_tag);
 }
 
@@ -1381,24 +1393,71 @@ fn skip_reading_cover_art() {
 fn remove_id3v24_frames_on_id3v23_save() {
 	let mut tag = Id3v2Tag::new();
 
-	tag.insert(Frame::RelativeVolumeAdjustment(
-		RelativeVolumeAdjustmentFrame::new(
-			String::from("Foo RVA"),
+	tag.insert(Frame::Seek(SeekFrame::new(1024)));
+	tag.insert(Frame::Signature(SignatureFrame::new(0x90, vec![1, 2, 3])));
+	tag.insert(Frame::AudioSeekPointIndex(AudioSeekPointIndexFrame::new(
+		0,
+		8192,
+		vec![0, 0x8000],
+	)));
+
+	let tag_re_read = dump_and_re_read(&tag, WriteOptions::default().use_id3v23(true));
+
+	assert_eq!(tag_re_read.frames.len(), 0);
+}
+
+#[test_log::test]
+fn convert_rva2_and_equ2_on_id3v23_save() {
+	let mut tag = Id3v2Tag::new();
+
+	let rva2 = |identification: &str, volume_adjustment| {
+		Frame::RelativeVolumeAdjustment(RelativeVolumeAdjustmentFrame::new(
+			String::from(identification),
 			HashMap::from([(
 				ChannelType::MasterVolume,
 				ChannelInformation {
 					channel_type: ChannelType::MasterVolume,
-					volume_adjustment: 30,
+					volume_adjustment,
 					bits_representing_peak: 0,
 					peak_volume: None,
 				},
 			)]),
-		),
-	));
+		))
+	};
+
+	tag.insert(rva2("Foo RVA", 512));
+	tag.insert(rva2("Bar RVA", -512));
+	tag.insert(Frame::Equalisation(EqualisationFrame::new(
+		InterpolationMethod::Linear,
+		String::from("Foo EQ"),
+		vec![AdjustmentPoint {
+			frequency: 2000,
+			volume_adjustment: -1024,
+		}],
+	)));
 
 	let tag_re_read = dump_and_re_read(&tag, WriteOptions::default().use_id3v23(true));
 
-	assert_eq!(tag_re_read.frames.len(), 0);
+	// Only one RVAD frame is allowed in ID3v2.3, the second RVA2 frame is discarded
+	assert_eq!(tag_re_read.frames.len(), 2);
+
+	let Some(Frame::RelativeVolumeAdjustment(rva2)) =
+		tag_re_read.get(&FrameId::Valid(Cow::Borrowed("RVA2")))
+	else {
+		panic!("Expected an RVA2 frame");
+	};
+	assert!(rva2.identification.is_empty());
+	for channel_type in [ChannelType::FrontRight, ChannelType::FrontLeft] {
+		assert!((rva2.channels[&channel_type].volume_adjustment - 512).abs() <= 1);
+	}
+
+	let Some(Frame::Equalisation(equ2)) = tag_re_read.get(&FrameId::Valid(Cow::Borrowed("EQU2")))
+	else {
+		panic!("Expected an EQU2 frame");
+	};
+	assert_eq!(equ2.adjustment_points.len(), 1);
+	assert_eq!(equ2.adjustment_points[0].frequency, 2000);
+	assert!((equ2.adjustment_points[0].volume_adjustment + 1024).abs() <= 1);
 }
 
 #[test_log::test]
@@ -1583,3 +1642,839 @@ fn ensure_frame_skipping_within_bounds() {
 		)))
 	);
 }
+
+#[test_log::test]
+fn chapter_frames_id3v23_round_trip() {
+	let mut tag = Id3v2Tag::new();
+
+	// Long enough for the sub-frame size to differ between synchsafe and regular integers
+	let title = "a".repeat(200);
+
+	let mut chapter = ChapterFrame::new(String::from("chp0"), 0, 5000);
+	chapter
+		.sub_frames
+		.push(Frame::text(Cow::Borrowed("TIT2"), title.clone()));
+	tag.insert(Frame::Chapter(chapter));
+
+	let mut toc = TableOfContentsFrame::new(String::from("toc"), vec![String::from("chp0")]);
+	toc.top_level = true;
+	tag.insert(Frame::TableOfContents(toc));
+
+	for write_options in [
+		WriteOptions::default(),
+		WriteOptions::default().use_id3v23(true),
+	] {
+		let tag_re_read = dump_and_re_read(&tag, write_options);
+		assert_eq!(tag_re_read.len(), 2);
+
+		let Some(Frame::Chapter(chapter)) = tag_re_read.get(&FrameId::Valid(Cow::Borrowed("CHAP")))
+		else {
+			panic!("Expected a ChapterFrame");
+		};
+
+		assert_eq!(chapter.end_time, 5000);
+		let [Frame::Text(chapter_title)] = &chapter.sub_frames[..] else {
+			panic!("Expected a single TextInformationFrame");
+		};
+		assert_eq!(chapter_title.value, title);
+
+		let (_, generic_tag) = tag_re_read.split_tag();
+		assert_eq!(generic_tag.chapters().len(), 1);
+		assert_eq!(
+			generic_tag.chapters()[0].title.as_deref(),
+			Some(title.as_str())
+		);
+	}
+}
+
+#[test_log::test]
+fn synchronized_text_round_trip() {
+	let mut tag = Id3v2Tag::new();
+
+	let lyrics_frame = SynchronizedTextFrame::new(
+		TextEncoding::UTF8,
+		ENGLISH,
+		TimestampFormat::MS,
+		SyncTextContentType::Lyrics,
+		Some(String::from("Foo description")),
+		vec![(0, String::from("Foo")), (1500, String::from("Bar"))],
+	);
+
+	let mut mpeg_frame = lyrics_frame.clone();
+	mpeg_frame.timestamp_format = TimestampFormat::MPEG;
+	mpeg_frame.description = Some(String::from("MPEG frames"));
+
+	let mut events_frame = lyrics_frame.clone();
+	events_frame.content_type = SyncTextContentType::Events;
+	events_frame.description = Some(String::from("Events"));
+
+	for frame in [&lyrics_frame, &mpeg_frame, &events_frame] {
+		assert!(tag.insert_synchronized_text(frame.clone()).is_none());
+	}
+
+	for write_options in [
+		WriteOptions::default(),
+		WriteOptions::default().use_id3v23(true),
+	] {
+		let tag_re_read = dump_and_re_read(&tag, write_options);
+		assert_eq!(tag_re_read.synchronized_text().count(), 3);
+
+		let parsed = tag_re_read
+			.get_synchronized_text(ENGLISH, Some("Foo description"))
+			.unwrap();
+		assert_eq!(parsed.content, lyrics_frame.content);
+
+		// Only the lyrics with timestamps in milliseconds can be converted
+		let (remainder, generic_tag) = tag_re_read.split_tag();
+		assert_eq!(
+			generic_tag.synced_lyrics(),
+			&[SyncedLyrics {
+				language: ENGLISH,
+				description: Some(String::from("Foo description")),
+				lines: vec![
+					SyncedLyricsLine::new(Duration::ZERO, String::from("Foo")),
+					SyncedLyricsLine::new(Duration::from_millis(1500), String::from("Bar")),
+				],
+			}]
+		);
+		assert_eq!(remainder.synchronized_text().count(), 2);
+
+		let merged = remainder.merge_tag(generic_tag);
+		assert_eq!(merged.synchronized_text().count(), 3);
+		assert_eq!(
+			merged.get_synchronized_text(ENGLISH, Some("Foo description")),
+			Some(&lyrics_frame)
+		);
+	}
+}
+
+#[test_log::test]
+fn synced_lyrics_from_generic_tag() {
+	let mut tag = Tag::new(TagType::Id3v2);
+	tag.push_synced_lyrics(SyncedLyrics::new(vec![SyncedLyricsLine::new(
+		Duration::from_secs(5),
+		String::from("Foo"),
+	)]));
+
+	let id3v2: Id3v2Tag = tag.into();
+
+	let sync_text = id3v2.get_synchronized_text(UNKNOWN_LANGUAGE, None).unwrap();
+	assert_eq!(sync_text.timestamp_format, TimestampFormat::MS);
+	assert_eq!(sync_text.content_type, SyncTextContentType::Lyrics);
+	assert_eq!(sync_text.content, vec![(5000, String::from("Foo"))]);
+
+	let generic_tag: Tag = id3v2.into();
+	assert_eq!(generic_tag.synced_lyrics().len(), 1);
+}
+
+#[test_log::test]
+fn encapsulated_object_round_trip() {
+	let mut tag = Id3v2Tag::new();
+
+	let new_object = |descriptor: &str, data: Vec<u8>| {
+		GeneralEncapsulatedObject::new(
+			TextEncoding::UTF16,
+			Some(String::from("application/octet-stream")),
+			None,
+			Some(String::from(descriptor)),
+			data,
+		)
+	};
+
+	assert!(tag
+		.insert_encapsulated_object(new_object("Serato Markers2", vec![1, 2, 3]))
+		.is_none());
+	assert!(tag
+		.insert_encapsulated_object(new_object("Serato BeatGrid", vec![4, 5, 6]))
+		.is_none());
+
+	// Replaces the existing frame with the same descriptor
+	let Some(Frame::EncapsulatedObject(replaced)) =
+		tag.insert_encapsulated_object(new_object("Serato Markers2", vec![7, 8, 9]))
+	else {
+		panic!("Expected the old GEOB frame to be replaced");
+	};
+	assert_eq!(replaced.data, &[1, 2, 3]);
+
+	for write_options in [
+		WriteOptions::default(),
+		WriteOptions::default().use_id3v23(true),
+	] {
+		let mut tag_re_read = dump_and_re_read(&tag, write_options);
+		assert_eq!(tag_re_read.encapsulated_objects().count(), 2);
+
+		let markers = tag_re_read
+			.get_encapsulated_object("Serato Markers2")
+			.unwrap();
+		assert_eq!(
+			markers.mime_type.as_deref(),
+			Some("application/octet-stream")
+		);
+		assert_eq!(markers.data, &[7, 8, 9]);
+
+		// GEOB frames aren't converted, so they have to survive a round trip through `Tag`
+		let (remainder, generic_tag) = tag_re_read.clone().split_tag();
+		let merged: Id3v2Tag = remainder.merge_tag(generic_tag);
+		assert_eq!(merged.encapsulated_objects().count(), 2);
+
+		assert!(tag_re_read
+			.remove_encapsulated_object("Serato BeatGrid")
+			.is_some());
+		assert!(tag_re_read
+			.get_encapsulated_object("Serato BeatGrid")
+			.is_none());
+	}
+}
+
+#[test_log::test]
+fn id3v22_full_round_trip() {
+	let mut tag = create_full_test_tag(Id3v2Version::V2);
+
+	let mut bytes = Vec::new();
+	tag.dump_to(&mut bytes, WriteOptions::default().use_id3v22(true))
+		.unwrap();
+
+	// Version 2, rev 0, no flags
+	assert_eq!(&bytes[..6], b"ID3\x02\x00\x00");
+
+	let mut parsed_tag = read_tag_with_options(
+		&bytes,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	);
+
+	// Tags may change order after being read, due to the TDRC conversion
+	tag.frames.sort_by_key(|frame| frame.id_str().to_string());
+	parsed_tag
+		.frames
+		.sort_by_key(|frame| frame.id_str().to_string());
+	assert_eq!(tag, parsed_tag);
+}
+
+#[test_log::test]
+fn convert_frames_on_id3v22_save() {
+	let mut tag = Id3v2Tag::new();
+
+	// Split into TYE, TDA, and TIM
+	tag.insert(Frame::Timestamp(TimestampFrame::new(
+		FrameId::Valid(Cow::Borrowed("TDRC")),
+		TextEncoding::UTF8,
+		Timestamp {
+			year: 2024,
+			month: Some(6),
+			day: Some(3),
+			hour: Some(14),
+			minute: Some(8),
+			second: None,
+		},
+	)));
+
+	// Merged into IPL
+	tag.insert(Frame::KeyValue(KeyValueFrame::new(
+		FrameId::Valid(Cow::Borrowed("TMCL")),
+		TextEncoding::UTF8,
+		vec![(String::from("Guitar"), String::from("Foo"))],
+	)));
+
+	// The linked frame ID is downgraded as well
+	tag.insert(Frame::LinkedInformation(LinkedInformationFrame::new(
+		FrameId::Valid(Cow::Borrowed("TIT2")),
+		String::from("http://example.com/foo.mp3"),
+	)));
+
+	// No ID3v2.2 equivalents
+	tag.insert(Frame::Private(PrivateFrame::new(
+		String::from("Foo owner"),
+		vec![1, 2, 3],
+	)));
+	tag.insert(Frame::Timestamp(TimestampFrame::new(
+		FrameId::Valid(Cow::Borrowed("TDEN")),
+		TextEncoding::UTF8,
+		Timestamp {
+			year: 2024,
+			..Timestamp::default()
+		},
+	)));
+
+	let tag_re_read = dump_and_re_read(&tag, WriteOptions::default().use_id3v22(true));
+	assert_eq!(tag_re_read.frames.len(), 3);
+
+	let Some(Frame::Timestamp(tdrc)) = tag_re_read.get(&FrameId::Valid(Cow::Borrowed("TDRC")))
+	else {
+		panic!("Expected a TDRC frame");
+	};
+	assert_eq!(tdrc.encoding, TextEncoding::UTF16);
+	assert_eq!(
+		tdrc.timestamp,
+		Timestamp {
+			year: 2024,
+			month: Some(6),
+			day: Some(3),
+			hour: Some(14),
+			minute: Some(8),
+			second: None,
+		}
+	);
+
+	let Some(Frame::KeyValue(tipl)) = tag_re_read.get(&FrameId::Valid(Cow::Borrowed("TIPL")))
+	else {
+		panic!("Expected a TIPL frame");
+	};
+	assert_eq!(
+		tipl.key_value_pairs,
+		vec![(String::from("Guitar"), String::from("Foo"))]
+	);
+
+	let Some(Frame::LinkedInformation(link)) =
+		tag_re_read.get(&FrameId::Valid(Cow::Borrowed("LINK")))
+	else {
+		panic!("Expected a LINK frame");
+	};
+	assert_eq!(link.linked_frame_id, FrameId::Valid(Cow::Borrowed("TIT2")));
+}
+
+#[test_log::test]
+fn preserve_original_version_on_save() {
+	for (path, version, version_byte) in [
+		(
+			"tests/tags/assets/id3v2/test_full.id3v22",
+			Id3v2Version::V2,
+			2,
+		),
+		(
+			"tests/tags/assets/id3v2/test_full.id3v23",
+			Id3v2Version::V3,
+			3,
+		),
+		(
+			"tests/tags/assets/id3v2/test_full.id3v24",
+			Id3v2Version::V4,
+			4,
+		),
+	] {
+		let tag = read_tag(path);
+		assert_eq!(tag.original_version(), version);
+
+		// Takes precedence over `use_id3v23`
+		let write_options = WriteOptions::default()
+			.use_id3v23(true)
+			.preserve_id3v2_version(true);
+
+		let mut bytes = Vec::new();
+		tag.dump_to(&mut bytes, write_options.clone()).unwrap();
+		assert_eq!(bytes[3], version_byte);
+
+		let tag_re_read = read_tag_with_options(
+			&bytes,
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		);
+		assert_eq!(tag_re_read.original_version(), version);
+
+		// The version is kept through a conversion to `Tag`
+		let generic_tag: Tag = tag.into();
+
+		let mut bytes = Vec::new();
+		generic_tag.dump_to(&mut bytes, write_options).unwrap();
+		assert_eq!(bytes[3], version_byte);
+	}
+
+	// New tags are written as ID3v2.4
+	let mut tag = Id3v2Tag::new();
+	tag.set_title(String::from("Foo title"));
+
+	let mut bytes = Vec::new();
+	tag.dump_to(
+		&mut bytes,
+		WriteOptions::default().preserve_id3v2_version(true),
+	)
+	.unwrap();
+	assert_eq!(bytes[3], 4);
+}
+
+struct XorCipher;
+
+impl FrameCipher for XorCipher {
+	fn decrypt(&self, encryption_data: &[u8], content: &[u8]) -> Result<Vec<u8>> {
+		Ok(content.iter().map(|b| b ^ encryption_data[0]).collect())
+	}
+
+	fn encrypt(&self, encryption_data: &[u8], content: &[u8]) -> Result<Vec<u8>> {
+		self.decrypt(encryption_data, content)
+	}
+}
+
+#[test_log::test]
+fn encrypted_frames() {
+	const OWNER: &str = "mailto:encrypted_frames@example.com";
+	const UNREGISTERED_OWNER: &str = "mailto:unregistered@example.com";
+
+	let mut ciphers = FrameCiphers::new();
+	ciphers.insert(OWNER, XorCipher);
+	let ciphers = Arc::new(ciphers);
+
+	let mut tag = Id3v2Tag::new();
+	tag.insert(Frame::EncryptionMethodRegistration(
+		EncryptionMethodRegistrationFrame::new(String::from(OWNER), 0x80, vec![0x5A]),
+	));
+	tag.insert(Frame::EncryptionMethodRegistration(
+		EncryptionMethodRegistrationFrame::new(String::from(UNREGISTERED_OWNER), 0x81, vec![0]),
+	));
+
+	let mut title = Frame::Text(TextInformationFrame::new(
+		FrameId::Valid(Cow::Borrowed("TIT2")),
+		TextEncoding::Latin1,
+		String::from("Foo title"),
+	));
+	title.set_flags(FrameFlags {
+		encryption: Some(0x80),
+		grouping_identity: Some(1),
+		..FrameFlags::default()
+	});
+	tag.insert(title);
+
+	// Already encrypted with a method that has no cipher
+	let mut binary = Frame::Binary(BinaryFrame::new(
+		FrameId::Valid(Cow::Borrowed("XXXX")),
+		vec![1, 2, 3],
+	));
+	binary.set_flags(FrameFlags {
+		encryption: Some(0x81),
+		data_length_indicator: Some(3),
+		..FrameFlags::default()
+	});
+	tag.insert(binary);
+
+	for write_options in [
+		WriteOptions::default(),
+		WriteOptions::default().use_id3v23(true),
+	] {
+		let mut bytes = Vec::new();
+		tag.dump_to(
+			&mut bytes,
+			write_options.id3v2_frame_ciphers(Arc::clone(&ciphers)),
+		)
+		.unwrap();
+
+		// The title is never written in plain text
+		assert!(!bytes.windows(9).any(|w| w == b"Foo title"));
+
+		// Without the ciphers, the title is left encrypted
+		let tag_re_read = read_tag_with_options(
+			&bytes,
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		);
+		assert!(tag_re_read.title().is_none());
+		assert!(matches!(
+			tag_re_read.get(&FrameId::Valid(Cow::Borrowed("TIT2"))),
+			Some(Frame::Binary(_))
+		));
+
+		let tag_re_read = read_tag_with_options(
+			&bytes,
+			ParseOptions::new()
+				.parsing_mode(ParsingMode::Strict)
+				.id3v2_frame_ciphers(Arc::clone(&ciphers)),
+		);
+
+		assert_eq!(tag_re_read.title().as_deref(), Some("Foo title"));
+
+		let title = tag_re_read
+			.get(&FrameId::Valid(Cow::Borrowed("TIT2")))
+			.unwrap();
+		assert_eq!(title.flags().encryption, Some(0x80));
+		assert_eq!(title.flags().grouping_identity, Some(1));
+
+		let Some(Frame::Binary(binary)) = tag_re_read.get(&FrameId::Valid(Cow::Borrowed("XXXX")))
+		else {
+			panic!("Expected an encrypted binary frame");
+		};
+		assert_eq!(binary.data, vec![1, 2, 3]);
+		assert_eq!(binary.flags().encryption, Some(0x81));
+	}
+}
+
+#[test_log::test]
+fn decrypted_frames_written_without_ciphers() {
+	const OWNER: &str = "mailto:decrypted_frames@example.com";
+
+	let mut ciphers = FrameCiphers::new();
+	ciphers.insert(OWNER, XorCipher);
+	let ciphers = Arc::new(ciphers);
+
+	let mut tag = Id3v2Tag::new();
+	tag.insert(Frame::EncryptionMethodRegistration(
+		EncryptionMethodRegistrationFrame::new(String::from(OWNER), 0x80, vec![0x5A]),
+	));
+
+	let mut title = Frame::Text(TextInformationFrame::new(
+		FrameId::Valid(Cow::Borrowed("TIT2")),
+		TextEncoding::Latin1,
+		String::from("Secret title"),
+	));
+	title.set_flags(FrameFlags {
+		encryption: Some(0x80),
+		..FrameFlags::default()
+	});
+	tag.insert(title);
+
+	for write_options in [
+		WriteOptions::default(),
+		WriteOptions::default().use_id3v23(true),
+	] {
+		let mut bytes = Vec::new();
+		tag.dump_to(
+			&mut bytes,
+			write_options
+				.clone()
+				.id3v2_frame_ciphers(Arc::clone(&ciphers)),
+		)
+		.unwrap();
+
+		let decrypted_tag = read_tag_with_options(
+			&bytes,
+			ParseOptions::new()
+				.parsing_mode(ParsingMode::Strict)
+				.id3v2_frame_ciphers(Arc::clone(&ciphers)),
+		);
+		assert_eq!(decrypted_tag.title().as_deref(), Some("Secret title"));
+
+		// Saving without the ciphers writes the title unencrypted, so it can't stay flagged
+		let mut bytes = Vec::new();
+		decrypted_tag.dump_to(&mut bytes, write_options).unwrap();
+		assert!(bytes.windows(12).any(|w| w == b"Secret title"));
+
+		for parse_options in [
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+			ParseOptions::new()
+				.parsing_mode(ParsingMode::Strict)
+				.id3v2_frame_ciphers(Arc::clone(&ciphers)),
+		] {
+			let tag_re_read = read_tag_with_options(&bytes, parse_options);
+			assert_eq!(tag_re_read.title().as_deref(), Some("Secret title"));
+
+			let Some(Frame::Text(title)) = tag_re_read.get(&FrameId::Valid(Cow::Borrowed("TIT2")))
+			else {
+				panic!("Expected a plain text frame");
+			};
+			assert_eq!(title.flags().encryption, None);
+			assert_eq!(title.flags().data_length_indicator, None);
+		}
+	}
+}
+
+#[test_log::test]
+fn encrypted_frame_bad_method_symbol() {
+	let mut tag = Id3v2Tag::new();
+
+	let mut binary = Frame::Binary(BinaryFrame::new(
+		FrameId::Valid(Cow::Borrowed("XXXX")),
+		vec![1, 2, 3],
+	));
+	binary.set_flags(FrameFlags {
+		encryption: Some(0x7F),
+		data_length_indicator: Some(3),
+		..FrameFlags::default()
+	});
+	tag.insert(binary);
+
+	assert!(tag
+		.dump_to(&mut Vec::<u8>::new(), WriteOptions::default())
+		.is_err());
+}
+
+fn assert_no_false_syncs(content: &[u8]) {
+	for window in content.windows(2) {
+		assert!(
+			!(window[0] == 0xFF && window[1] >= 0xE0),
+			"found a false sync in {content:?}"
+		);
+	}
+}
+
+#[test_log::test]
+fn unsynchronisation_on_save() {
+	let mut tag = Id3v2Tag::new();
+	// Contains 0xFF 0xE0 in Latin-1, and UTF-16 has its BOM
+	tag.set_title(String::from("Foo \u{FF}\u{E0} title"));
+	tag.insert(Frame::Private(PrivateFrame::new(
+		String::from("Foo owner"),
+		vec![0xFF, 0xE0, 0xFF, 0x00, 0xFF, 0xFF, 0xF1, 0xFF],
+	)));
+
+	for (write_options, version) in [
+		(WriteOptions::default().use_id3v22(true), Id3v2Version::V2),
+		(WriteOptions::default().use_id3v23(true), Id3v2Version::V3),
+		(WriteOptions::default(), Id3v2Version::V4),
+	] {
+		let write_options = write_options.id3v2_unsynchronisation(true).id3v2_crc(true);
+
+		let mut bytes = Vec::new();
+		tag.dump_to(&mut bytes, write_options).unwrap();
+
+		// Unsynchronisation flag
+		assert_eq!(bytes[5] & 0x80, 0x80);
+		assert_no_false_syncs(&bytes[10..]);
+
+		let tag_re_read = read_tag_with_options(
+			&bytes,
+			ParseOptions::new().parsing_mode(ParsingMode::Strict),
+		);
+
+		assert_eq!(tag_re_read.original_version(), version);
+		assert!(tag_re_read.flags().unsynchronisation);
+		assert_eq!(tag_re_read.flags().crc, version != Id3v2Version::V2);
+		assert_eq!(
+			tag_re_read.title().as_deref(),
+			Some("Foo \u{FF}\u{E0} title")
+		);
+
+		// PRIV has no ID3v2.2 equivalent
+		if version != Id3v2Version::V2 {
+			let Some(Frame::Private(private)) =
+				tag_re_read.get(&FrameId::Valid(Cow::Borrowed("PRIV")))
+			else {
+				panic!("Expected a PRIV frame");
+			};
+			assert_eq!(
+				private.private_data,
+				vec![0xFF, 0xE0, 0xFF, 0x00, 0xFF, 0xFF, 0xF1, 0xFF]
+			);
+		}
+
+		// ID3v2.4 unsynchronises each frame
+		if version == Id3v2Version::V4 {
+			for frame in &tag_re_read.frames {
+				assert!(frame.flags().unsynchronisation);
+			}
+		}
+	}
+}
+
+#[test_log::test]
+fn footer_on_save() {
+	let mut tag = Id3v2Tag::new();
+	tag.set_title(String::from("Foo title"));
+
+	let mut bytes = Vec::new();
+	tag.dump_to(&mut bytes, WriteOptions::default().id3v2_footer(true))
+		.unwrap();
+
+	// Footer flag, and no padding
+	assert_eq!(bytes[5], 0x10);
+	assert_eq!(&bytes[bytes.len() - 10..bytes.len() - 7], b"3DI");
+	assert_eq!(&bytes[bytes.len() - 7..], &bytes[3..10]);
+
+	let size = u32::from_be_bytes(bytes[6..10].try_into().unwrap()).unsynch();
+	assert_eq!(bytes.len(), 10 + size as usize + 10);
+
+	let tag_re_read = read_tag_with_options(
+		&bytes,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	);
+	assert!(tag_re_read.flags().footer);
+	assert_eq!(tag_re_read.title().as_deref(), Some("Foo title"));
+
+	// ID3v2.3 has no footer
+	let mut bytes = Vec::new();
+	tag.dump_to(
+		&mut bytes,
+		WriteOptions::default().use_id3v23(true).id3v2_footer(true),
+	)
+	.unwrap();
+
+	assert_eq!(bytes[5], 0);
+	assert_ne!(&bytes[bytes.len() - 10..bytes.len() - 7], b"3DI");
+}
+
+#[test_log::test]
+fn encrypted_frame_no_cipher() {
+	let mut tag = Id3v2Tag::new();
+
+	let mut binary = Frame::Binary(BinaryFrame::new(
+		FrameId::Valid(Cow::Borrowed("XXXX")),
+		vec![1, 2, 3],
+	));
+	binary.set_flags(FrameFlags {
+		encryption: Some(0x82),
+		data_length_indicator: Some(3),
+		..FrameFlags::default()
+	});
+	tag.insert(binary);
+
+	for write_options in [
+		WriteOptions::default(),
+		WriteOptions::default().use_id3v23(true),
+	] {
+		let tag_re_read = dump_and_re_read(&tag, write_options);
+
+		let Some(Frame::Binary(binary)) = tag_re_read.get(&FrameId::Valid(Cow::Borrowed("XXXX")))
+		else {
+			panic!("Expected an encrypted binary frame");
+		};
+		assert_eq!(binary.data, vec![1, 2, 3]);
+		assert_eq!(binary.flags().encryption, Some(0x82));
+	}
+}
+
+#[test_log::test]
+fn frame_additional_data_order() {
+	let mut tag = Id3v2Tag::new();
+
+	let mut binary = Frame::Binary(BinaryFrame::new(
+		FrameId::Valid(Cow::Borrowed("XXXX")),
+		vec![1, 2, 3],
+	));
+	binary.set_flags(FrameFlags {
+		encryption: Some(0x82),
+		grouping_identity: Some(7),
+		data_length_indicator: Some(3),
+		..FrameFlags::default()
+	});
+	tag.insert(binary);
+
+	// ID3v2.4: group identifier, encryption method symbol, data length indicator
+	let mut bytes = Vec::new();
+	tag.dump_to(&mut bytes, WriteOptions::default()).unwrap();
+	assert_eq!(&bytes[20..29], &[7, 0x82, 0, 0, 0, 3, 1, 2, 3]);
+
+	// ID3v2.3: (decompressed size, only when compressed), encryption method symbol, group identifier
+	let mut bytes = Vec::new();
+	tag.dump_to(&mut bytes, WriteOptions::default().use_id3v23(true))
+		.unwrap();
+	assert_eq!(&bytes[20..25], &[0x82, 7, 1, 2, 3]);
+
+	for write_options in [
+		WriteOptions::default(),
+		WriteOptions::default().use_id3v23(true),
+	] {
+		let tag_re_read = dump_and_re_read(&tag, write_options);
+		let frame = tag_re_read
+			.get(&FrameId::Valid(Cow::Borrowed("XXXX")))
+			.unwrap();
+		assert_eq!(frame.flags().encryption, Some(0x82));
+		assert_eq!(frame.flags().grouping_identity, Some(7));
+	}
+}
+
+#[test_log::test]
+fn read_id3v23_extended_header() {
+	let mut bytes = Vec::new();
+	bytes.extend(b"ID3\x03\x00\x40\x00\x00\x00\x28");
+
+	// Extended header: size (excluding itself), flags (CRC), padding size, CRC
+	bytes.extend(&[0, 0, 0, 10, 0x80, 0, 0, 0, 0, 6, 0xDE, 0xAD, 0xBE, 0xEF]);
+
+	bytes.extend(b"TIT2\x00\x00\x00\x0A\x00\x00\x00Foo title");
+	bytes.extend(&[0; 6]);
+
+	// Anything after the tag
+	bytes.extend(b"junk");
+
+	let mut reader = &bytes[..];
+	let header = Id3v2Header::parse(&mut reader).unwrap();
+	assert_eq!(header.version, Id3v2Version::V3);
+	assert!(header.flags.crc);
+	assert_eq!(header.size, 40);
+	assert_eq!(header.extended_size, 14);
+
+	// The extended header is part of the tag size
+	assert_eq!(header.full_tag_size() as usize, bytes.len() - 4);
+
+	let tag = crate::id3::v2::read::parse_id3v2(
+		&mut reader,
+		header,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	)
+	.unwrap();
+	assert_eq!(tag.title().as_deref(), Some("Foo title"));
+	assert_eq!(reader, b"junk");
+}
+
+#[test_log::test]
+fn read_id3v24_extended_header() {
+	let mut bytes = Vec::new();
+	bytes.extend(b"ID3\x04\x00\x40\x00\x00\x00\x28");
+
+	// Extended header: size (including itself), number of flag bytes, flags (CRC, restrictions),
+	// CRC, restrictions
+	bytes.extend(&[0, 0, 0, 14, 1, 0x30, 5, 0, 0, 0, 0, 0, 1, 0]);
+
+	bytes.extend(b"TIT2\x00\x00\x00\x0A\x00\x00\x03Foo title");
+	bytes.extend(&[0; 6]);
+
+	// Anything after the tag
+	bytes.extend(b"junk");
+
+	let mut reader = &bytes[..];
+	let header = Id3v2Header::parse(&mut reader).unwrap();
+	assert_eq!(header.version, Id3v2Version::V4);
+	assert!(header.flags.crc);
+	assert!(header.flags.restrictions.is_some());
+	assert_eq!(header.size, 40);
+	assert_eq!(header.extended_size, 14);
+
+	// The extended header is part of the tag size
+	assert_eq!(header.full_tag_size() as usize, bytes.len() - 4);
+
+	let tag = crate::id3::v2::read::parse_id3v2(
+		&mut reader,
+		header,
+		ParseOptions::new().parsing_mode(ParsingMode::Strict),
+	)
+	.unwrap();
+	assert_eq!(tag.title().as_deref(), Some("Foo title"));
+	assert_eq!(reader, b"junk");
+}
+
+#[test_log::test]
+fn id3v23_header_flags() {
+	let flags = Id3v2TagFlags {
+		unsynchronisation: true,
+		experimental: true,
+		footer: true,
+		..Id3v2TagFlags::default()
+	};
+
+	// No footer in ID3v2.3, and the experimental flag doesn't overlap the extended header flag
+	assert_eq!(flags.as_id3v23_byte(), 0xA0);
+
+	let mut tag = Id3v2Tag::new();
+	tag.set_title(String::from("Foo title"));
+	tag.set_flags(Id3v2TagFlags {
+		experimental: true,
+		..Id3v2TagFlags::default()
+	});
+
+	let mut bytes = Vec::new();
+	tag.dump_to(&mut bytes, WriteOptions::default().use_id3v23(true))
+		.unwrap();
+	assert_eq!(bytes[5], 0x20);
+
+	let mut reader = &bytes[..];
+	let header = Id3v2Header::parse(&mut reader).unwrap();
+	assert!(header.flags.experimental);
+	assert_eq!(header.extended_size, 0);
+}
+
+#[test_log::test]
+fn read_utf16_sylt_without_description_bom() {
+	let sylt = [
+		1, b'e', b'n', b'g', 2, 1, // Header
+		0, 0, // Empty description, without a BOM
+		b'F', 0, b'o', 0, b'o', 0, 0, 0, 0, 0, 0, 10, // "Foo" at 10ms, without a BOM
+	];
+
+	let mut bytes = b"ID3\x04\x00\x00\x00\x00\x00".to_vec();
+	bytes.push(10 + sylt.len() as u8);
+	bytes.extend(b"SYLT\x00\x00\x00");
+	bytes.push(sylt.len() as u8);
+	bytes.extend(&[0, 0]);
+	bytes.extend(&sylt);
+
+	let tag = read_tag_with_options(&bytes, ParseOptions::new());
+
+	let Some(Frame::SynchronizedText(sylt)) = tag.get(&FrameId::Valid(Cow::Borrowed("SYLT")))
+	else {
+		panic!("Expected a SYLT frame");
+	};
+	assert_eq!(sylt.content, vec![(10, String::from("Foo"))]);
+}
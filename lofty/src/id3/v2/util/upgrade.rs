@@ -2,7 +2,11 @@
 
 use std::collections::HashMap;
 
-/// Upgrade an ID3v2.2 key to an ID3v2.4 key
+/// Upgrade an ID3v2.2 key to an ID3v2.3 or ID3v2.4 key
+///
+/// Keys are upgraded to their ID3v2.4 equivalents where possible. The `TDA`, `TIM`, `TRD`, and `TSI`
+/// keys have no ID3v2.4 equivalent, so they are upgraded to the ID3v2.3-only `TDAT`, `TIME`, `TRDA`,
+/// and `TSIZ` keys respectively.
 ///
 /// # Examples
 ///
@@ -13,6 +17,9 @@ use std::collections::HashMap;
 /// let new_title = upgrade_v2(old_title);
 ///
 /// assert_eq!(new_title, Some("TIT2"));
+///
+/// // Only valid in ID3v2.3
+/// assert_eq!(upgrade_v2("TDA"), Some("TDAT"));
 /// ```
 pub fn upgrade_v2(key: &str) -> Option<&'static str> {
 	v2keys().get(key).copied()
@@ -34,6 +41,16 @@ pub fn upgrade_v3(key: &str) -> Option<&'static str> {
 	v3keys().get(key).copied()
 }
 
+/// Downgrade an ID3v2.3 or ID3v2.4 key to an ID3v2.2 key
+///
+/// This is the reverse of [`upgrade_v2`], with ID3v2.3 keys being upgraded first.
+pub(crate) fn downgrade_v2(key: &str) -> Option<&'static str> {
+	let keys = v2keys_reversed();
+	keys.get(key)
+		.or_else(|| keys.get(upgrade_v3(key)?))
+		.copied()
+}
+
 macro_rules! gen_upgrades {
     (V2 => [$($($v2_key:literal)|* => $id3v24_from_v2:literal),+]; V3 => [$($($v3_key:literal)|* => $id3v24_from_v3:literal),+]) => {
 		use std::sync::OnceLock;
@@ -51,6 +68,19 @@ macro_rules! gen_upgrades {
 			})
 		}
 
+		fn v2keys_reversed() -> &'static HashMap<&'static str, &'static str> {
+			static INSTANCE: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+			INSTANCE.get_or_init(|| {
+				let mut map = HashMap::new();
+				$(
+					$(
+						map.entry($id3v24_from_v2).or_insert($v2_key);
+					)+
+				)+
+				map
+			})
+		}
+
 		fn v3keys() -> &'static HashMap<&'static str, &'static str> {
 			static INSTANCE: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
 			INSTANCE.get_or_init(|| {
@@ -67,21 +97,24 @@ macro_rules! gen_upgrades {
 }
 
 gen_upgrades!(
-	// ID3v2.2 => ID3v2.4
+	// ID3v2.2 => ID3v2.4 (or ID3v2.3, for frames that were removed in ID3v2.4)
 	V2 => [
 		// Standard frames
 		"BUF" => "RBUF",
 		"CNT" => "PCNT",
 		"COM" => "COMM",
 		"CRA" => "AENC",
+		"EQU" => "EQU2",
 		"ETC" => "ETCO",
 		"GEO" => "GEOB",
 		"IPL" => "TIPL",
+		"LNK" => "LINK",
 		"MCI" => "MCDI",
 		"MLL" => "MLLT",
 		"PIC" => "APIC",
 		"POP" => "POPM",
 		"REV" => "RVRB",
+		"RVA" => "RVA2",
 		"SLT" => "SYLT",
 		"STC" => "SYTC",
 		"TAL" => "TALB",
@@ -90,14 +123,16 @@ gen_upgrades!(
 		"TCO" => "TCON",
 		"TCP" => "TCMP",
 		"TCR" => "TCOP",
+		"TDA" => "TDAT",
 		"TDY" => "TDLY",
 		"TEN" => "TENC",
 		"TFT" => "TFLT",
+		"TIM" => "TIME",
 		"TKE" => "TKEY",
 		"TLA" => "TLAN",
 		"TLE" => "TLEN",
 		"TMT" => "TMED",
-		"TOA" => "TOAL",
+		"TOA" => "TOPE",
 		"TOF" => "TOFN",
 		"TOL" => "TOLY",
 		"TOR" => "TDOR",
@@ -109,18 +144,19 @@ gen_upgrades!(
 		"TPA" => "TPOS",
 		"TPB" => "TPUB",
 		"TRC" => "TSRC",
-		"TRD" => "TDRC",
+		"TRD" => "TRDA",
 		"TRK" => "TRCK",
 		"TS2" => "TSO2",
 		"TSA" => "TSOA",
 		"TSC" => "TSOC",
+		"TSI" => "TSIZ",
 		"TSP" => "TSOP",
 		"TSS" => "TSSE",
 		"TST" => "TSOT",
 		"TT1" => "TIT1",
 		"TT2" => "TIT2",
 		"TT3" => "TIT3",
-		"TXT" => "TOLY",
+		"TXT" => "TEXT",
 		"TXX" => "TXXX",
 		"TYE" => "TDRC",
 		"UFI" => "UFID",
@@ -153,6 +189,8 @@ gen_upgrades!(
 		// Standard frames
 		"TORY" => "TDOR",
 		"TYER" => "TDRC",
-		"IPLS" => "TIPL"
+		"IPLS" => "TIPL",
+		"EQUA" => "EQU2",
+		"RVAD" => "RVA2"
 	]
 );
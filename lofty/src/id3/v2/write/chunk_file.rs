@@ -1,6 +1,7 @@
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
 use crate::iff::chunk::Chunks;
+use crate::iff::wav::container::{write_chunks, ChunkTarget};
 use crate::util::io::{FileLike, Length, Truncate};
 
 use std::io::SeekFrom;
@@ -10,10 +11,37 @@ use byteorder::{ByteOrder, WriteBytesExt};
 const CHUNK_NAME_UPPER: [u8; 4] = [b'I', b'D', b'3', b' '];
 const CHUNK_NAME_LOWER: [u8; 4] = [b'i', b'd', b'3', b' '];
 
+/// Write an `ID3v2` tag to a WAV file
+///
+/// Unlike [`write_to_chunk_file`], this supports the RF64/BW64 and Wave64 containers.
+pub(in crate::id3::v2) fn write_to_wav_file<F>(
+	file: &mut F,
+	tag: &[u8],
+	write_options: WriteOptions,
+) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	write_chunks(
+		file,
+		[(
+			ChunkTarget::id3v2(write_options),
+			(!tag.is_empty()).then(|| tag.to_vec()),
+		)],
+	)
+}
+
+/// Write an `ID3v2` tag to a chunk-based file
+///
+/// If `wide_sizes` is set, all sizes are 64-bit, as in DSDIFF (`FRM8....DSD `).
+/// Otherwise, they are 32-bit, as in AIFF (`FORM....AIFF`).
 pub(in crate::id3::v2) fn write_to_chunk_file<F, B>(
 	file: &mut F,
 	tag: &[u8],
 	write_options: WriteOptions,
+	wide_sizes: bool,
 ) -> Result<()>
 where
 	F: FileLike,
@@ -21,22 +49,30 @@ where
 	LoftyError: From<<F as Length>::Error>,
 	B: ByteOrder,
 {
-	// RIFF....WAVE
-	file.seek(SeekFrom::Current(12))?;
+	let (form_header_size, chunk_header_size) = if wide_sizes { (16, 12) } else { (12, 8) };
+
+	file.seek(SeekFrom::Current(form_header_size))?;
 
-	let file_len = file.len()?.saturating_sub(12);
+	let file_len = file.len()?.saturating_sub(form_header_size as u64);
 
 	let mut id3v2_chunk = (None, None);
 
-	let mut chunks = Chunks::<B>::new(file_len);
+	let mut chunks = if wide_sizes {
+		Chunks::<B>::new_wide(file_len)
+	} else {
+		Chunks::<B>::new(file_len)
+	};
 
 	while chunks.next(file).is_ok() {
 		if chunks.fourcc == CHUNK_NAME_UPPER || chunks.fourcc == CHUNK_NAME_LOWER {
-			id3v2_chunk = (Some(file.stream_position()? - 8), Some(chunks.size));
+			id3v2_chunk = (
+				Some(file.stream_position()? - chunk_header_size),
+				Some(chunks.size),
+			);
 			break;
 		}
 
-		file.seek(SeekFrom::Current(i64::from(chunks.size)))?;
+		file.seek(SeekFrom::Current(chunks.size as i64))?;
 
 		chunks.correct_position(file)?;
 	}
@@ -53,7 +89,7 @@ where
 		file.read_to_end(&mut file_bytes)?;
 
 		file_bytes.splice(
-			chunk_start as usize..(chunk_start + u64::from(chunk_size) + 8) as usize,
+			chunk_start as usize..(chunk_start + chunk_size + chunk_header_size) as usize,
 			[],
 		);
 
@@ -71,7 +107,11 @@ where
 			file.write_all(&CHUNK_NAME_LOWER)?;
 		}
 
-		file.write_u32::<B>(tag.len() as u32)?;
+		if wide_sizes {
+			file.write_u64::<B>(tag.len() as u64)?;
+		} else {
+			file.write_u32::<B>(tag.len() as u32)?;
+		}
 		file.write_all(tag)?;
 
 		// It is required an odd length chunk be padded with a 0
@@ -79,11 +119,16 @@ where
 		if tag.len() % 2 != 0 {
 			file.write_u8(0)?;
 		}
+	}
 
-		let total_size = file.stream_position()? - 8;
+	// The form size excludes its ID and size fields
+	let total_size = file.len()? - (form_header_size as u64 - 4);
 
-		file.seek(SeekFrom::Start(4))?;
+	file.seek(SeekFrom::Start(4))?;
 
+	if wide_sizes {
+		file.write_u64::<B>(total_size)?;
+	} else {
 		file.write_u32::<B>(total_size as u32)?;
 	}
 
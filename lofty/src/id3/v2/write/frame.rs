@@ -1,7 +1,10 @@
 use crate::error::{Id3v2Error, Id3v2ErrorKind, Result};
+use crate::id3::v2::cipher::EncryptionMethods;
 use crate::id3::v2::frame::{FrameFlags, FrameRef};
 use crate::id3::v2::util::synchsafe::SynchsafeInteger;
-use crate::id3::v2::{Frame, FrameId, KeyValueFrame, TextInformationFrame};
+use crate::id3::v2::util::upgrade::downgrade_v2;
+use crate::id3::v2::{Frame, FrameId, Id3v2Version, KeyValueFrame, TextInformationFrame};
+use crate::macros::err;
 use crate::tag::items::Timestamp;
 
 use std::io::Write;
@@ -12,23 +15,21 @@ use byteorder::{BigEndian, WriteBytesExt};
 pub(in crate::id3::v2) fn create_items<W>(
 	writer: &mut W,
 	frames: &mut dyn Iterator<Item = FrameRef<'_>>,
+	ciphers: &EncryptionMethods,
+	unsynchronise: bool,
 ) -> Result<()>
 where
 	W: Write,
 {
-	let is_id3v23 = false;
+	let version = Id3v2Version::V4;
 
 	for frame in frames {
 		verify_frame(&frame)?;
-		let value = frame.as_bytes(is_id3v23)?;
-
-		write_frame(
-			writer,
-			frame.id().as_str(),
-			frame.flags(),
-			&value,
-			is_id3v23,
-		)?;
+		let mut flags = frame.flags();
+		flags.unsynchronisation |= unsynchronise;
+		let value = encrypt(&frame, &mut flags, frame.as_bytes(version)?, ciphers)?;
+
+		write_frame(writer, frame.id().as_str(), flags, &value, version)?;
 	}
 
 	Ok(())
@@ -37,26 +38,79 @@ where
 pub(in crate::id3::v2) fn create_items_v3<W>(
 	writer: &mut W,
 	frames: &mut dyn Iterator<Item = FrameRef<'_>>,
+	ciphers: &EncryptionMethods,
+) -> Result<()>
+where
+	W: Write,
+{
+	create_items_legacy(writer, frames, Id3v2Version::V3, ciphers)
+}
+
+pub(in crate::id3::v2) fn create_items_v2<W>(
+	writer: &mut W,
+	frames: &mut dyn Iterator<Item = FrameRef<'_>>,
+	ciphers: &EncryptionMethods,
+) -> Result<()>
+where
+	W: Write,
+{
+	create_items_legacy(writer, frames, Id3v2Version::V2, ciphers)
+}
+
+// ID3v2.2 frames are the same as their ID3v2.3 counterparts, only with 3 character IDs. They go
+// through the same conversions, and get their IDs downgraded when the frame header is written.
+fn create_items_legacy<W>(
+	writer: &mut W,
+	frames: &mut dyn Iterator<Item = FrameRef<'_>>,
+	version: Id3v2Version,
+	ciphers: &EncryptionMethods,
 ) -> Result<()>
 where
 	W: Write,
 {
 	// These are all frames from ID3v2.4
 	const FRAMES_TO_DISCARD: &[&str] = &[
-		"ASPI", "EQU2", "RVA2", "SEEK", "SIGN", "TDEN", "TDRL", "TDTG", "TMOO", "TPRO", "TSOA",
-		"TSOP", "TSOT", "TSST",
+		"ASPI", "SEEK", "SIGN", "TDEN", "TDRL", "TDTG", "TMOO", "TPRO", "TSOA", "TSOP", "TSOT",
+		"TSST",
 	];
 
 	const IPLS_ID: &str = "IPLS";
+	const RVAD_ID: &str = "RVAD";
+	const EQUA_ID: &str = "EQUA";
 
-	let is_id3v23 = true;
+	let version_name = if version == Id3v2Version::V2 {
+		"ID3v2.2"
+	} else {
+		"ID3v2.3"
+	};
 
 	let mut ipls = None;
+	let mut rvad_written = false;
+	let mut equa_written = false;
 	for mut frame in frames {
 		let id = frame.id_str();
 
-		if FRAMES_TO_DISCARD.contains(&id) {
-			log::warn!("Discarding frame: {}, not supported in ID3v2.3", id);
+		let supported = match version {
+			// TMCL has no ID3v2.2 ID of its own, it gets merged into IPL
+			Id3v2Version::V2 => id == "TMCL" || downgrade_v2(id).is_some(),
+			_ => !FRAMES_TO_DISCARD.contains(&id),
+		};
+
+		if !supported {
+			log::warn!(
+				"Discarding frame: {}, not supported in {}",
+				id,
+				version_name
+			);
+			continue;
+		}
+
+		// ID3v2.2 has no frame flags
+		if version == Id3v2Version::V2 && frame.flags().encryption.is_some() {
+			log::warn!(
+				"Discarding frame: {}, encryption is not supported in ID3v2.2",
+				id
+			);
 			continue;
 		}
 
@@ -70,7 +124,11 @@ where
 			"TDOR" | "TDRC" => {
 				let mut value = frame.0.clone();
 				let Frame::Timestamp(ref mut f) = value.to_mut() else {
-					log::warn!("Discarding frame: {}, not supported in ID3v2.3", id);
+					log::warn!(
+						"Discarding frame: {}, not supported in {}",
+						id,
+						version_name
+					);
 					continue;
 				};
 
@@ -121,15 +179,10 @@ where
 
 					for mut frame in new_frames {
 						frame.set_flags(f.header.flags);
-						let value = frame.as_bytes(is_id3v23)?;
-
-						write_frame(
-							writer,
-							frame.id().as_str(),
-							frame.flags(),
-							&value,
-							is_id3v23,
-						)?;
+						let mut flags = frame.flags();
+						let value = encrypt(&frame, &mut flags, frame.as_bytes(version)?, ciphers)?;
+
+						write_frame(writer, frame.id().as_str(), flags, &value, version)?;
 					}
 
 					continue;
@@ -140,7 +193,11 @@ where
 			"TCON" => {
 				let mut value = frame.0.clone();
 				let Frame::Text(ref mut f) = value.to_mut() else {
-					log::warn!("Discarding frame: {}, not supported in ID3v2.3", id);
+					log::warn!(
+						"Discarding frame: {}, not supported in {}",
+						id,
+						version_name
+					);
 					continue;
 				};
 
@@ -173,62 +230,109 @@ where
 					..
 				}) = value.to_mut()
 				else {
-					log::warn!("Discarding frame: {}, not supported in ID3v2.3", id);
+					log::warn!(
+						"Discarding frame: {}, not supported in {}",
+						id,
+						version_name
+					);
 					continue;
 				};
 
-				let ipls_frame;
-				match ipls {
-					Some(ref mut frame) => {
-						ipls_frame = frame;
-					},
-					None => {
-						ipls = Some(TextInformationFrame::new(
-							FrameId::Valid("IPLS".into()),
-							encoding.to_id3v23(),
-							String::new(),
-						));
-						ipls_frame = ipls.as_mut().unwrap();
-					},
-				}
+				// Each string is written with its own BOM, like any other key-value frame
+				let ipls_frame = ipls.get_or_insert_with(|| {
+					KeyValueFrame::new(
+						FrameId::Valid(IPLS_ID.into()),
+						encoding.to_id3v23(),
+						Vec::new(),
+					)
+				});
 
-				for (key, value) in key_value_pairs.drain(..) {
-					if !ipls_frame.value.is_empty() {
-						ipls_frame.value.push('\0');
-					}
+				ipls_frame.key_value_pairs.append(key_value_pairs);
+
+				continue;
+			},
+			// RVA2 (Relative volume adjustment) and EQU2 (Equalisation) are converted to their
+			// ID3v2.3 counterparts, RVAD and EQUA, which can only appear once in a tag.
+			//
+			// The ID3v2.2 RVA and EQU frames share the same format.
+			"RVA2" | "EQU2" => {
+				let (written, new_id) = if id == "RVA2" {
+					(&mut rvad_written, RVAD_ID)
+				} else {
+					(&mut equa_written, EQUA_ID)
+				};
 
-					ipls_frame.value.push_str(&format!("{}\0{}", key, value));
+				if *written {
+					log::warn!(
+						"Discarding frame: {}, only one {} frame is allowed in {}",
+						id,
+						new_id,
+						version_name
+					);
+					continue;
 				}
 
+				let value = match &*frame {
+					Frame::RelativeVolumeAdjustment(f) => f.as_rvad_bytes(),
+					Frame::Equalisation(f) => Some(f.as_equa_bytes()),
+					_ => None,
+				};
+
+				let Some(value) = value else {
+					log::warn!(
+						"Discarding frame: {}, not supported in {}",
+						id,
+						version_name
+					);
+					continue;
+				};
+
+				let mut flags = frame.flags();
+				let value = encrypt(&frame, &mut flags, value, ciphers)?;
+
+				write_frame(writer, new_id, flags, &value, version)?;
+				*written = true;
 				continue;
 			},
 			_ => {},
 		}
 
-		let value = frame.as_bytes(is_id3v23)?;
+		let mut flags = frame.flags();
+		let value = encrypt(&frame, &mut flags, frame.as_bytes(version)?, ciphers)?;
 
-		write_frame(
-			writer,
-			frame.id().as_str(),
-			frame.flags(),
-			&value,
-			is_id3v23,
-		)?;
+		write_frame(writer, frame.id().as_str(), flags, &value, version)?;
 	}
 
 	if let Some(ipls) = ipls {
-		let frame = Frame::Text(ipls);
-		let value = frame.as_bytes(is_id3v23)?;
-		write_frame(writer, IPLS_ID, frame.flags(), &value, is_id3v23)?;
+		let frame = Frame::KeyValue(ipls);
+		let value = frame.as_bytes(version)?;
+		write_frame(writer, IPLS_ID, frame.flags(), &value, version)?;
 	}
 
 	Ok(())
 }
 
+// Binary frames with the `encryption` flag set hold content that is already encrypted, either
+// from being read without a cipher, or by the caller, so they are written as-is.
+fn encrypt(
+	frame: &Frame<'_>,
+	flags: &mut FrameFlags,
+	value: Vec<u8>,
+	ciphers: &EncryptionMethods,
+) -> Result<Vec<u8>> {
+	if let Frame::Binary(_) = frame {
+		return Ok(value);
+	}
+
+	ciphers.encrypt(flags, value)
+}
+
 fn verify_frame(frame: &FrameRef<'_>) -> Result<()> {
 	match (frame.id().as_str(), &**frame) {
 		("APIC", Frame::Picture { .. })
 		| ("USLT", Frame::UnsynchronizedText(_))
+		| ("SYLT", Frame::SynchronizedText(_))
+		| ("GEOB", Frame::EncapsulatedObject(_))
 		| ("COMM", Frame::Comment(_))
 		| ("TXXX", Frame::UserText(_))
 		| ("WXXX", Frame::UserUrl(_))
@@ -239,7 +343,23 @@ fn verify_frame(frame: &FrameRef<'_>) -> Result<()> {
 		| ("WFED" | "GRP1" | "MVNM" | "MVIN", Frame::Text { .. })
 		| ("TDEN" | "TDOR" | "TDRC" | "TDRL" | "TDTG", Frame::Timestamp(_))
 		| ("RVA2", Frame::RelativeVolumeAdjustment(_))
-		| ("PRIV", Frame::Private(_)) => Ok(()),
+		| ("EQU2", Frame::Equalisation(_))
+		| ("OWNE", Frame::Ownership(_))
+		| ("ETCO", Frame::EventTimingCodes(_))
+		| ("PRIV", Frame::Private(_))
+		| ("CHAP", Frame::Chapter(_))
+		| ("CTOC", Frame::TableOfContents(_))
+		| ("COMR", Frame::Commercial(_))
+		| ("AENC", Frame::AudioEncryption(_))
+		| ("LINK", Frame::LinkedInformation(_))
+		| ("SIGN", Frame::Signature(_))
+		| ("ENCR", Frame::EncryptionMethodRegistration(_))
+		| ("GRID", Frame::GroupIdentificationRegistration(_))
+		| ("SEEK", Frame::Seek(_))
+		| ("ASPI", Frame::AudioSeekPointIndex(_))
+		| ("RBUF", Frame::RecommendedBufferSize(_))
+		| ("MLLT", Frame::MpegLocationLookupTable(_))
+		| ("POSS", Frame::PositionSynchronisation(_)) => Ok(()),
 		(id, Frame::Text { .. }) if id.starts_with('T') => Ok(()),
 		(id, Frame::Url(_)) if id.starts_with('W') => Ok(()),
 		(id, frame_value) => Err(Id3v2Error::new(Id3v2ErrorKind::BadFrame(
@@ -253,74 +373,114 @@ fn verify_frame(frame: &FrameRef<'_>) -> Result<()> {
 fn write_frame<W>(
 	writer: &mut W,
 	name: &str,
-	flags: FrameFlags,
+	mut flags: FrameFlags,
 	value: &[u8],
-	is_id3v23: bool,
+	version: Id3v2Version,
 ) -> Result<()>
 where
 	W: Write,
 {
-	if flags.encryption.is_some() {
-		write_encrypted(writer, name, value, flags, is_id3v23)?;
+	let mut content = if flags.encryption.is_some() {
+		encrypted_additional_data(flags, version)?
+	} else {
+		// Content is never compressed when written, only encrypted content can be
+		flags.compression = false;
+		additional_data(flags, value, version)?
+	};
+
+	// Unsynchronisation only exists at the frame level in ID3v2.4, and applies to the additional
+	// data as well as the value
+	if flags.unsynchronisation && version == Id3v2Version::V4 {
+		content.extend_from_slice(value);
+		let content = super::unsynchronise(&content);
+
+		write_frame_header(writer, name, content.len() as u32, flags, version)?;
+		writer.write_all(&content)?;
+
 		return Ok(());
 	}
 
-	let len = value.len() as u32;
-	let is_grouping_identity = flags.grouping_identity.is_some();
-
 	write_frame_header(
 		writer,
 		name,
-		if is_grouping_identity { len + 1 } else { len },
+		(content.len() + value.len()) as u32,
 		flags,
-		is_id3v23,
+		version,
 	)?;
 
-	if is_grouping_identity {
-		// Guaranteed to be `Some` at this point.
-		writer.write_u8(flags.grouping_identity.unwrap())?;
-	}
-
+	writer.write_all(&content)?;
 	writer.write_all(value)?;
 
 	Ok(())
 }
 
-fn write_encrypted<W>(
-	writer: &mut W,
-	name: &str,
-	value: &[u8],
-	flags: FrameFlags,
-	is_id3v23: bool,
-) -> Result<()>
-where
-	W: Write,
-{
+// The additional data follows the frame header in the same order as the flags:
+//
+// * ID3v2.3: decompressed size, encryption method symbol, group identifier
+// * ID3v2.4: group identifier, encryption method symbol, data length indicator
+fn additional_data(flags: FrameFlags, value: &[u8], version: Id3v2Version) -> Result<Vec<u8>> {
+	let mut data = Vec::new();
+
+	// ID3v2.2 has no frame flags
+	if version == Id3v2Version::V2 {
+		return Ok(data);
+	}
+
+	if let Some(group) = flags.grouping_identity {
+		data.push(group);
+	}
+
+	// The value is written as-is, so its length is all the data length indicator can be
+	if flags.data_length_indicator.is_some() && version == Id3v2Version::V4 {
+		data.extend((value.len() as u32).synch()?.to_be_bytes());
+	}
+
+	Ok(data)
+}
+
+fn encrypted_additional_data(flags: FrameFlags, version: Id3v2Version) -> Result<Vec<u8>> {
 	// Guaranteed to be `Some` at this point.
 	let method_symbol = flags.encryption.unwrap();
 
-	if method_symbol > 0x80 {
+	if method_symbol < 0x80 {
 		return Err(
 			Id3v2Error::new(Id3v2ErrorKind::InvalidEncryptionMethodSymbol(method_symbol)).into(),
 		);
 	}
 
-	if let Some(mut len) = flags.data_length_indicator {
-		if len > 0 {
-			write_frame_header(writer, name, (value.len() + 1) as u32, flags, is_id3v23)?;
-			if !is_id3v23 {
-				len = len.synch()?;
-			}
+	// ID3v2.3 only has a data length indicator (the "decompressed size") for compressed frames
+	let data_length_indicator = match version {
+		Id3v2Version::V4 => match flags.data_length_indicator {
+			Some(len) if len > 0 => Some(len.synch()?),
+			_ => return Err(Id3v2Error::new(Id3v2ErrorKind::MissingDataLengthIndicator).into()),
+		},
+		_ if flags.compression => match flags.data_length_indicator {
+			Some(len) if len > 0 => Some(len),
+			_ => return Err(Id3v2Error::new(Id3v2ErrorKind::MissingDataLengthIndicator).into()),
+		},
+		_ => None,
+	};
 
-			writer.write_u32::<BigEndian>(len)?;
-			writer.write_u8(method_symbol)?;
-			writer.write_all(value)?;
+	let mut data = Vec::with_capacity(6);
+	if version == Id3v2Version::V3 {
+		if let Some(len) = data_length_indicator {
+			data.extend(len.to_be_bytes());
+		}
+	} else if let Some(group) = flags.grouping_identity {
+		data.push(group);
+	}
 
-			return Ok(());
+	data.push(method_symbol);
+
+	if version == Id3v2Version::V3 {
+		if let Some(group) = flags.grouping_identity {
+			data.push(group);
 		}
+	} else if let Some(len) = data_length_indicator {
+		data.extend(len.to_be_bytes());
 	}
 
-	Err(Id3v2Error::new(Id3v2ErrorKind::MissingDataLengthIndicator).into())
+	Ok(data)
 }
 
 fn write_frame_header<W>(
@@ -328,19 +488,35 @@ fn write_frame_header<W>(
 	name: &str,
 	mut len: u32,
 	flags: FrameFlags,
-	is_id3v23: bool,
+	version: Id3v2Version,
 ) -> Result<()>
 where
 	W: Write,
 {
-	let flags = if is_id3v23 {
-		flags.as_id3v23_bytes()
-	} else {
-		flags.as_id3v24_bytes()
+	let flags = match version {
+		// ID3v2.2 frame headers are just a 3 character ID and a 24-bit size
+		Id3v2Version::V2 => {
+			let Some(name) = downgrade_v2(name) else {
+				return Err(
+					Id3v2Error::new(Id3v2ErrorKind::BadFrameId(name.as_bytes().to_vec())).into(),
+				);
+			};
+
+			if len > 0xFF_FFFF {
+				err!(TooMuchData);
+			}
+
+			writer.write_all(name.as_bytes())?;
+			writer.write_u24::<BigEndian>(len)?;
+
+			return Ok(());
+		},
+		Id3v2Version::V3 => flags.as_id3v23_bytes(),
+		Id3v2Version::V4 => flags.as_id3v24_bytes(),
 	};
 
 	writer.write_all(name.as_bytes())?;
-	if !is_id3v23 {
+	if version == Id3v2Version::V4 {
 		len = len.synch()?;
 	}
 
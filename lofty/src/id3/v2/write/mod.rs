@@ -1,7 +1,8 @@
 mod chunk_file;
-mod frame;
+pub(super) mod frame;
 
-use super::Id3v2TagFlags;
+use super::cipher::EncryptionMethods;
+use super::{Id3v2TagFlags, Id3v2Version};
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
 use crate::file::FileType;
@@ -18,7 +19,7 @@ use std::io::{Cursor, Read, Seek, SeekFrom, Write};
 use std::ops::Not;
 use std::sync::OnceLock;
 
-use byteorder::{BigEndian, LittleEndian, WriteBytesExt};
+use byteorder::{BigEndian, WriteBytesExt};
 
 // In the very rare chance someone wants to write a CRC in their extended header
 fn crc_32_table() -> &'static [u32; 256] {
@@ -71,18 +72,34 @@ where
 		err!(UnsupportedTag);
 	}
 
-	let id3v2 = create_tag(tag, write_options)?;
+	let id3v2 = create_tag(tag, write_options.clone())?;
 
 	match file_type {
 		// Formats such as WAV and AIFF store the ID3v2 tag in an 'ID3 ' chunk rather than at the beginning of the file
 		FileType::Wav => {
 			tag.flags.footer = false;
-			return chunk_file::write_to_chunk_file::<F, LittleEndian>(file, &id3v2, write_options);
+			return chunk_file::write_to_wav_file(file, &id3v2, write_options);
 		},
 		FileType::Aiff => {
 			tag.flags.footer = false;
-			return chunk_file::write_to_chunk_file::<F, BigEndian>(file, &id3v2, write_options);
+			return chunk_file::write_to_chunk_file::<F, BigEndian>(
+				file,
+				&id3v2,
+				write_options,
+				false,
+			);
 		},
+		FileType::Dff => {
+			tag.flags.footer = false;
+			return chunk_file::write_to_chunk_file::<F, BigEndian>(
+				file,
+				&id3v2,
+				write_options,
+				true,
+			);
+		},
+		// DSF stores the tag at the end of the file, pointed to by the `DSD ` chunk
+		FileType::Dsf => return crate::dsd::dsf::write::write_id3v2_to_dsf(file, &id3v2),
 		_ => {},
 	}
 
@@ -106,71 +123,127 @@ pub(super) fn create_tag<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 	tag: &mut Id3v2TagRef<'a, I>,
 	write_options: WriteOptions,
 ) -> Result<Vec<u8>> {
-	let frames = &mut tag.frames;
-	let mut peek = frames.peekable();
+	let frames = tag.frames.by_ref().collect::<Vec<_>>();
 
 	// We are stripping the tag
-	if peek.peek().is_none() {
+	if frames.is_empty() {
 		return Ok(Vec::new());
 	}
 
-	let is_id3v23 = write_options.use_id3v23;
-	if is_id3v23 {
+	let ciphers = EncryptionMethods::new(
+		frames.iter().map(|frame| &**frame),
+		write_options.id3v2_frame_ciphers.as_ref(),
+	);
+
+	let version = if write_options.preserve_id3v2_version {
+		log::debug!("Using the original version: {:?}", tag.original_version);
+		tag.original_version
+	} else if write_options.use_id3v22 {
+		log::debug!("Using ID3v2.2");
+		Id3v2Version::V2
+	} else if write_options.use_id3v23 {
 		log::debug!("Using ID3v2.3");
-	}
+		Id3v2Version::V3
+	} else {
+		Id3v2Version::V4
+	};
 
-	let has_footer = tag.flags.footer;
-	let needs_crc = tag.flags.crc;
-	let has_restrictions = tag.flags.restrictions.is_some();
+	let mut flags = tag.flags;
+	flags.unsynchronisation |= write_options.id3v2_unsynchronisation;
+	flags.footer |= write_options.id3v2_footer;
+	flags.crc |= write_options.id3v2_crc;
+
+	match version {
+		// ID3v2.2 has no extended header or footer
+		Id3v2Version::V2 => {
+			flags.footer = false;
+			flags.crc = false;
+			flags.restrictions = None;
+		},
+		// ID3v2.3 has no footer or restrictions
+		Id3v2Version::V3 => {
+			flags.footer = false;
+			flags.restrictions = None;
+		},
+		Id3v2Version::V4 => {},
+	}
 
-	let (mut id3v2, extended_header_len) = create_tag_header(tag.flags, is_id3v23)?;
+	let mut id3v2 = create_tag_header(flags, version)?;
 	let header_len = id3v2.get_ref().len();
 
 	// Write the items
-	if is_id3v23 {
-		frame::create_items_v3(&mut id3v2, &mut peek)?;
-	} else {
-		frame::create_items(&mut id3v2, &mut peek)?;
+	match version {
+		Id3v2Version::V2 => {
+			frame::create_items_v2(&mut id3v2, &mut frames.into_iter(), &ciphers)?;
+		},
+		Id3v2Version::V3 => {
+			frame::create_items_v3(&mut id3v2, &mut frames.into_iter(), &ciphers)?;
+		},
+		Id3v2Version::V4 => frame::create_items(
+			&mut id3v2,
+			&mut frames.into_iter(),
+			&ciphers,
+			flags.unsynchronisation,
+		)?,
 	}
 
-	let mut len = id3v2.get_ref().len() - header_len;
-
 	// https://mutagen-specs.readthedocs.io/en/latest/id3/id3v2.4.0-structure.html#padding:
 	//
 	// "[A tag] MUST NOT have any padding when a tag footer is added to the tag"
-	let padding_len = write_options.preferred_padding.unwrap_or(0) as usize;
-	if !has_footer {
-		len += padding_len;
-	}
-
-	// Go back to the start and write the final size
-	id3v2.seek(SeekFrom::Start(6))?;
-	id3v2.write_u32::<BigEndian>((extended_header_len + len as u32).synch()?)?;
+	let padding_len = if flags.footer {
+		0
+	} else {
+		write_options.preferred_padding.unwrap_or(0) as usize
+	};
 
-	if needs_crc {
-		// The CRC is calculated on all the data between the header and footer
-		#[allow(unused_mut)]
-		// Past the CRC
-		let mut content_start_idx = 22;
+	// ID3v2.4 includes the padding in the CRC, so it has to be written first
+	if version == Id3v2Version::V4 {
+		write_padding(&mut id3v2, padding_len)?;
+	}
 
-		if has_restrictions {
-			content_start_idx += 3;
-		}
+	if flags.crc {
+		// ID3v2.3: The CRC is calculated on all of the frames, excluding the padding
+		// ID3v2.4: The CRC is calculated on all of the frames and the padding
+		let crc = crc_32(&id3v2.get_ref()[header_len..]);
 
 		// Skip 16 bytes
 		//
 		// Normal ID3v2 header (10)
-		// Extended header (6)
+		// ID3v2.3: Extended header size (4), flags (2)
+		// ID3v2.4: Extended header size (4), number of flag bytes (1), flags (1)
 		id3v2.seek(SeekFrom::Start(16))?;
 
-		let tag_contents = &id3v2.get_ref()[content_start_idx..];
-		let encoded_crc = calculate_crc(tag_contents);
+		if version == Id3v2Version::V3 {
+			id3v2.write_u32::<BigEndian>(padding_len as u32)?;
+			id3v2.write_u32::<BigEndian>(crc)?;
+		} else {
+			id3v2.write_u8(5)?;
+			id3v2.write_all(&encode_crc(crc))?;
+		}
+	}
+
+	// ID3v2.4 unsynchronises each frame individually, which is handled when writing the items
+	if flags.unsynchronisation && version != Id3v2Version::V4 {
+		log::trace!("Unsynchronising the entire tag");
 
-		id3v2.write_u8(5)?;
-		id3v2.write_all(&encoded_crc)?;
+		let mut tag_bytes = id3v2.into_inner();
+		let unsynchronised = unsynchronise(&tag_bytes[10..]);
+		tag_bytes.truncate(10);
+		tag_bytes.extend(unsynchronised);
+
+		id3v2 = Cursor::new(tag_bytes);
 	}
 
-	if has_footer {
+	if version != Id3v2Version::V4 {
+		write_padding(&mut id3v2, padding_len)?;
+	}
+
+	// Go back to the start and write the final size, which excludes the header and footer
+	let len = id3v2.get_ref().len() - 10;
+	id3v2.seek(SeekFrom::Start(6))?;
+	id3v2.write_u32::<BigEndian>((len as u32).synch()?)?;
+
+	if flags.footer {
 		log::trace!("Footer requested, not padding tag");
 
 		id3v2.seek(SeekFrom::Start(3))?;
@@ -182,101 +255,152 @@ pub(super) fn create_tag<'a, I: Iterator<Item = FrameRef<'a>> + 'a>(
 		// The footer is the same as the header, but with the identifier reversed
 		id3v2.write_all(b"3DI")?;
 		id3v2.write_all(&header_without_identifier)?;
-
-		return Ok(id3v2.into_inner());
 	}
 
-	if padding_len == 0 {
-		log::trace!("No padding requested, writing tag as-is");
-		return Ok(id3v2.into_inner());
-	}
+	Ok(id3v2.into_inner())
+}
 
-	log::trace!("Padding tag with {} bytes", padding_len);
+fn write_padding(id3v2: &mut Cursor<Vec<u8>>, padding_len: usize) -> Result<()> {
+	if padding_len > 0 {
+		log::trace!("Padding tag with {} bytes", padding_len);
 
-	id3v2.seek(SeekFrom::End(0))?;
-	id3v2.write_all(&try_vec![0; padding_len])?;
+		id3v2.seek(SeekFrom::End(0))?;
+		id3v2.write_all(&try_vec![0; padding_len])?;
+	}
 
-	Ok(id3v2.into_inner())
+	Ok(())
 }
 
-fn create_tag_header(flags: Id3v2TagFlags, is_id3v23: bool) -> Result<(Cursor<Vec<u8>>, u32)> {
+fn create_tag_header(flags: Id3v2TagFlags, version: Id3v2Version) -> Result<Cursor<Vec<u8>>> {
 	let mut header = Cursor::new(Vec::new());
 
 	header.write_all(b"ID3")?;
 
-	if is_id3v23 {
+	match version {
+		// Version 2, rev 0
+		Id3v2Version::V2 => header.write_all(&[2, 0])?,
 		// Version 3, rev 0
-		header.write_all(&[3, 0])?;
-	} else {
+		Id3v2Version::V3 => header.write_all(&[3, 0])?,
 		// Version 4, rev 0
-		header.write_all(&[4, 0])?;
+		Id3v2Version::V4 => header.write_all(&[4, 0])?,
 	}
 
 	let extended_header = flags.crc || flags.restrictions.is_some();
 
-	let tag_flags = if is_id3v23 {
-		flags.as_id3v23_byte()
-	} else {
-		flags.as_id3v24_byte()
+	let mut tag_flags = match version {
+		// The only ID3v2.2 flags are unsynchronisation and compression, the latter of which is
+		// never used
+		Id3v2Version::V2 => {
+			if flags.unsynchronisation {
+				0x80
+			} else {
+				0
+			}
+		},
+		Id3v2Version::V3 => flags.as_id3v23_byte(),
+		Id3v2Version::V4 => flags.as_id3v24_byte(),
 	};
 
+	if extended_header {
+		tag_flags |= 0x40;
+	}
+
 	header.write_u8(tag_flags)?;
 	header.write_u32::<BigEndian>(0)?;
 
-	let mut extended_header_size = 0;
-	if extended_header {
-		// Structure of extended header:
+	if !extended_header {
+		return Ok(header);
+	}
+
+	if version == Id3v2Version::V3 {
+		// Structure of the ID3v2.3 extended header:
 		//
-		// Size (4)
-		// Number of flag bytes (1) (As of ID3v2.4, this will *always* be 1)
-		// Flags (1)
-		// Followed by any extra data (crc or restrictions)
+		// Size (4) (Excluding itself, this will *always* be 10, since the CRC is the only flag)
+		// Flags (2)
+		// Padding size (4)
+		// CRC (4)
+		//
+		// The padding size and CRC are written once the frames are
+		header.write_u32::<BigEndian>(10)?;
+		header.write_u16::<BigEndian>(0x8000)?;
+		header.write_all(&[0; 8])?;
 
-		// Start with a zeroed header
-		header.write_all(&[0; 6])?;
+		return Ok(header);
+	}
 
-		extended_header_size = 6_u32;
-		let mut ext_flags = 0_u8;
+	// Structure of the ID3v2.4 extended header:
+	//
+	// Size (4)
+	// Number of flag bytes (1) (As of ID3v2.4, this will *always* be 1)
+	// Flags (1)
+	// Followed by any extra data (crc or restrictions)
 
-		if flags.crc {
-			ext_flags |= 0x20;
-			extended_header_size += 6;
+	// Start with a zeroed header
+	header.write_all(&[0; 6])?;
 
-			header.write_all(&[0; 6])?;
-		}
+	let mut extended_header_size = 6_u32;
+	let mut ext_flags = 0_u8;
 
-		if let Some(restrictions) = flags.restrictions {
-			ext_flags |= 0x10;
-			extended_header_size += 2;
+	if flags.crc {
+		ext_flags |= 0x20;
+		extended_header_size += 6;
 
-			header.write_u8(1)?;
-			header.write_u8(restrictions.as_bytes())?;
-		}
+		header.write_all(&[0; 6])?;
+	}
 
-		header.seek(SeekFrom::Start(10))?;
+	if let Some(restrictions) = flags.restrictions {
+		ext_flags |= 0x10;
+		extended_header_size += 2;
 
-		// Seek back and write the actual values
-		header.write_u32::<BigEndian>(extended_header_size.synch()?)?;
 		header.write_u8(1)?;
-		header.write_u8(ext_flags)?;
+		header.write_u8(restrictions.as_bytes())?;
+	}
+
+	header.seek(SeekFrom::Start(10))?;
+
+	// Seek back and write the actual values
+	header.write_u32::<BigEndian>(extended_header_size.synch()?)?;
+	header.write_u8(1)?;
+	header.write_u8(ext_flags)?;
 
-		header.seek(SeekFrom::End(0))?;
+	header.seek(SeekFrom::End(0))?;
+
+	Ok(header)
+}
+
+// Inserts a null byte after every 0xFF that is followed by a byte that could be mistaken for
+// part of a frame sync (>= 0xE0), or by another null byte.
+//
+// See `FrameFlags::unsynchronisation`.
+fn unsynchronise(content: &[u8]) -> Vec<u8> {
+	let mut unsynchronised = Vec::with_capacity(content.len());
+
+	let mut bytes = content.iter().copied().peekable();
+	while let Some(byte) = bytes.next() {
+		unsynchronised.push(byte);
+
+		// A trailing 0xFF gets one too, as it could otherwise be followed by anything
+		if byte == 0xFF && bytes.peek().is_none_or(|&next| next >= 0xE0 || next == 0) {
+			unsynchronised.push(0);
+		}
 	}
 
-	Ok((header, extended_header_size))
+	unsynchronised
 }
 
 // https://github.com/rstemmer/id3edit/blob/0246f3dc1a7a80a64461eeeb7b9ee88379003eb1/encoding/crc.c#L6:6
-fn calculate_crc(content: &[u8]) -> [u8; 5] {
-	let crc: u32 = content
+fn crc_32(content: &[u8]) -> u32 {
+	content
 		.iter()
 		.fold(!0, |crc, octet| {
 			(crc >> 8) ^ crc_32_table()[(((crc & 0xFF) ^ u32::from(*octet)) & 0xFF) as usize]
 		})
-		.not();
+		.not()
+}
 
-	// The CRC-32 is stored as an 35 bit synchsafe integer, leaving the upper
-	// four bits always zeroed.
+// ID3v2.4 stores the CRC-32 as a 35 bit synchsafe integer, leaving the upper four bits always
+// zeroed.
+fn encode_crc(crc: u32) -> [u8; 5] {
 	let mut encoded_crc = [0; 5];
 	let mut b;
 
@@ -311,7 +435,7 @@ mod tests {
 		tag.dump_to(&mut writer, WriteOptions::default()).unwrap();
 
 		let crc_content = &writer[16..22];
-		assert_eq!(crc_content, &[5, 0x06, 0x35, 0x69, 0x7D, 0x14]);
+		assert_eq!(crc_content, &[5, 0x09, 0x07, 0x78, 0x0E, 0x00]);
 
 		// Get rid of the size byte
 		let crc_content = &crc_content[1..];
@@ -324,6 +448,44 @@ mod tests {
 			unsynch_crc |= u32::from(b) << ((4 - i) * 7);
 		}
 
-		assert_eq!(unsynch_crc, 0x66BA_7E94);
+		assert_eq!(unsynch_crc, 0x90FE_0700);
+
+		// ID3v2.4 includes the padding in the CRC
+		assert_eq!(unsynch_crc, super::crc_32(&writer[22..]));
+	}
+
+	#[test_log::test]
+	fn id3v23_write_crc32() {
+		let mut tag = Id3v2Tag::default();
+		tag.set_artist(String::from("Foo artist"));
+
+		let mut writer = Vec::new();
+		tag.dump_to(
+			&mut writer,
+			WriteOptions::default()
+				.use_id3v23(true)
+				.preferred_padding(100)
+				.id3v2_crc(true),
+		)
+		.unwrap();
+
+		// Extended header flag
+		assert_eq!(writer[5], 0x40);
+
+		// Size (10), CRC flag, padding size (100), CRC
+		assert_eq!(&writer[10..16], &[0, 0, 0, 10, 0x80, 0]);
+		assert_eq!(&writer[16..20], &100_u32.to_be_bytes());
+
+		// The CRC only covers the frames
+		let crc = super::crc_32(&writer[24..writer.len() - 100]);
+		assert_eq!(&writer[20..24], &crc.to_be_bytes());
+	}
+
+	#[test_log::test]
+	fn unsynchronise() {
+		assert_eq!(
+			super::unsynchronise(&[0xFF, 0xE0, 0xFF, 0x00, 0xFF, 0x1A, 0xFF]),
+			[0xFF, 0x00, 0xE0, 0xFF, 0x00, 0x00, 0xFF, 0x1A, 0xFF, 0x00]
+		);
 	}
 }
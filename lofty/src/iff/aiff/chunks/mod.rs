@@ -0,0 +1,217 @@
+pub(super) mod read;
+pub(super) mod write;
+
+/// A marker from the `MARK` chunk
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Marker {
+	/// A unique identifier for the marker, which must be greater than `0`
+	pub id: u16,
+	/// The position of the marker in sample frames
+	pub position: u32,
+	/// The name of the marker
+	pub name: String,
+}
+
+impl Marker {
+	/// Create a new `Marker`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::aiff::Marker;
+	///
+	/// let marker = Marker::new(1, 48000, String::from("Loop start"));
+	/// assert_eq!(marker.position, 48000);
+	/// ```
+	pub fn new(id: u16, position: u32, name: String) -> Self {
+		Self { id, position, name }
+	}
+}
+
+/// The instrument information from an `INST` chunk
+///
+/// The loops reference [`Marker`]s by their IDs.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InstrumentChunk {
+	/// The MIDI note (0-127) at which the audio plays back at its original pitch
+	pub base_note: u8,
+	/// The pitch shift to apply, in cents (-50 to 50)
+	pub detune: i8,
+	/// The lowest MIDI note to use the audio for
+	pub low_note: u8,
+	/// The highest MIDI note to use the audio for
+	pub high_note: u8,
+	/// The lowest MIDI velocity to use the audio for
+	pub low_velocity: u8,
+	/// The highest MIDI velocity to use the audio for
+	pub high_velocity: u8,
+	/// The gain to apply, in decibels
+	pub gain: i16,
+	/// The loop to play while the note is held
+	pub sustain_loop: InstrumentLoop,
+	/// The loop to play after the note is released
+	pub release_loop: InstrumentLoop,
+}
+
+impl InstrumentChunk {
+	/// Create a new `InstrumentChunk`, covering all notes and velocities, with no loops
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::aiff::{InstrumentChunk, LoopPlayMode};
+	///
+	/// let inst = InstrumentChunk::new(60);
+	/// assert_eq!(inst.high_note, 127);
+	/// assert_eq!(inst.sustain_loop.play_mode, LoopPlayMode::NoLooping);
+	/// ```
+	pub fn new(base_note: u8) -> Self {
+		Self {
+			base_note,
+			detune: 0,
+			low_note: 0,
+			high_note: 127,
+			low_velocity: 1,
+			high_velocity: 127,
+			gain: 0,
+			sustain_loop: InstrumentLoop::default(),
+			release_loop: InstrumentLoop::default(),
+		}
+	}
+}
+
+/// A loop in an [`InstrumentChunk`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InstrumentLoop {
+	/// The playback direction of the loop
+	pub play_mode: LoopPlayMode,
+	/// The ID of the [`Marker`] at the start of the loop
+	pub begin_marker: u16,
+	/// The ID of the [`Marker`] at the end of the loop
+	pub end_marker: u16,
+}
+
+impl Default for InstrumentLoop {
+	fn default() -> Self {
+		Self {
+			play_mode: LoopPlayMode::NoLooping,
+			begin_marker: 0,
+			end_marker: 0,
+		}
+	}
+}
+
+/// The playback direction of an [`InstrumentLoop`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LoopPlayMode {
+	/// The loop is not played
+	NoLooping,
+	/// Play forward
+	Forward,
+	/// Alternate between forward and backward
+	ForwardBackward,
+	/// An unknown play mode
+	Other(u16),
+}
+
+impl From<u16> for LoopPlayMode {
+	fn from(value: u16) -> Self {
+		match value {
+			0 => Self::NoLooping,
+			1 => Self::Forward,
+			2 => Self::ForwardBackward,
+			_ => Self::Other(value),
+		}
+	}
+}
+
+impl From<LoopPlayMode> for u16 {
+	fn from(value: LoopPlayMode) -> Self {
+		match value {
+			LoopPlayMode::NoLooping => 0,
+			LoopPlayMode::Forward => 1,
+			LoopPlayMode::ForwardBackward => 2,
+			LoopPlayMode::Other(value) => value,
+		}
+	}
+}
+
+/// An application specific `APPL` chunk
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ApplicationChunk {
+	/// The signature of the application, such as `stoc` or `pdos`
+	pub signature: [u8; 4],
+	/// The application specific data
+	pub data: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ApplicationChunk, InstrumentChunk, InstrumentLoop, LoopPlayMode, Marker};
+	use crate::config::ParsingMode;
+
+	#[test_log::test]
+	fn mark_re_read() {
+		let markers = vec![
+			Marker::new(1, 0, String::from("Start")),
+			Marker::new(2, 1024, String::new()),
+			Marker::new(3, 48000, String::from("Loop end")),
+		];
+
+		let content = super::write::create_mark(&markers);
+		let parsed = super::read::parse_mark(&content, ParsingMode::Strict).unwrap();
+
+		assert_eq!(parsed, markers);
+	}
+
+	#[test_log::test]
+	fn inst_re_read() {
+		let inst = InstrumentChunk {
+			detune: -12,
+			gain: -6,
+			sustain_loop: InstrumentLoop {
+				play_mode: LoopPlayMode::Forward,
+				begin_marker: 1,
+				end_marker: 2,
+			},
+			..InstrumentChunk::new(57)
+		};
+
+		let content = super::write::create_inst(inst);
+		let parsed = super::read::parse_inst(&content, ParsingMode::Strict)
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, inst);
+	}
+
+	#[test_log::test]
+	fn appl_re_read() {
+		let appl = ApplicationChunk {
+			signature: *b"stoc",
+			data: vec![1, 2, 3],
+		};
+
+		let content = super::write::create_appl(&appl);
+		let parsed = super::read::parse_appl(&content, ParsingMode::Strict)
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, appl);
+	}
+
+	#[test_log::test]
+	fn mark_too_few_markers() {
+		let markers = vec![Marker::new(1, 0, String::from("Start"))];
+
+		let mut content = super::write::create_mark(&markers);
+		// Claim there are 2 markers
+		content[1] = 2;
+
+		assert!(super::read::parse_mark(&content, ParsingMode::Strict).is_err());
+		assert_eq!(
+			super::read::parse_mark(&content, ParsingMode::BestAttempt).unwrap(),
+			markers
+		);
+	}
+}
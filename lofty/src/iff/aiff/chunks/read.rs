@@ -0,0 +1,111 @@
+use super::{ApplicationChunk, InstrumentChunk, InstrumentLoop, Marker};
+use crate::config::ParsingMode;
+use crate::error::Result;
+use crate::macros::{decode_err, parse_mode_choice};
+use crate::util::text::{latin1_decode, utf8_decode_str};
+
+use byteorder::{BigEndian, ByteOrder};
+
+/// The size of the `INST` chunk
+pub(super) const INST_SIZE: usize = 20;
+
+pub(in crate::iff::aiff) fn parse_mark(
+	content: &[u8],
+	parse_mode: ParsingMode,
+) -> Result<Vec<Marker>> {
+	if content.len() < 2 {
+		decode_err!(@BAIL Aiff, "Marker (`MARK`) chunk is too small");
+	}
+
+	let count = BigEndian::read_u16(&content[..2]);
+
+	let mut markers = Vec::with_capacity(usize::from(count));
+	let mut remaining = &content[2..];
+	for _ in 0..count {
+		// The ID, position, and name length
+		if remaining.len() < 7 {
+			parse_mode_choice!(
+				parse_mode,
+				STRICT: decode_err!(@BAIL Aiff, "Marker (`MARK`) chunk has fewer markers than expected"),
+			);
+			break;
+		}
+
+		let id = BigEndian::read_u16(&remaining[..2]);
+		let position = BigEndian::read_u32(&remaining[2..6]);
+
+		// The name is a pascal string, padded to an even length
+		let name_len = usize::from(remaining[6]);
+		let pstring_len = (1 + name_len).next_multiple_of(2);
+		if remaining.len() < 6 + pstring_len {
+			parse_mode_choice!(
+				parse_mode,
+				STRICT: decode_err!(@BAIL Aiff, "Marker (`MARK`) chunk has an invalid marker name"),
+			);
+			break;
+		}
+
+		let name_bytes = &remaining[7..7 + name_len];
+		let name = match utf8_decode_str(name_bytes) {
+			Ok(name) => name.to_owned(),
+			Err(_) => latin1_decode(name_bytes),
+		};
+
+		markers.push(Marker { id, position, name });
+		remaining = &remaining[6 + pstring_len..];
+	}
+
+	Ok(markers)
+}
+
+pub(in crate::iff::aiff) fn parse_inst(
+	content: &[u8],
+	parse_mode: ParsingMode,
+) -> Result<Option<InstrumentChunk>> {
+	if content.len() < INST_SIZE {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Aiff, "Instrument (`INST`) chunk is too small"),
+			DEFAULT: return Ok(None)
+		);
+	}
+
+	let instrument_loop = |loop_content: &[u8]| InstrumentLoop {
+		play_mode: BigEndian::read_u16(&loop_content[..2]).into(),
+		begin_marker: BigEndian::read_u16(&loop_content[2..4]),
+		end_marker: BigEndian::read_u16(&loop_content[4..6]),
+	};
+
+	Ok(Some(InstrumentChunk {
+		base_note: content[0],
+		detune: content[1] as i8,
+		low_note: content[2],
+		high_note: content[3],
+		low_velocity: content[4],
+		high_velocity: content[5],
+		gain: BigEndian::read_i16(&content[6..8]),
+		sustain_loop: instrument_loop(&content[8..14]),
+		release_loop: instrument_loop(&content[14..20]),
+	}))
+}
+
+pub(in crate::iff::aiff) fn parse_appl(
+	content: &[u8],
+	parse_mode: ParsingMode,
+) -> Result<Option<ApplicationChunk>> {
+	if content.len() < 4 {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Aiff, "Application (`APPL`) chunk is too small"),
+			DEFAULT: return Ok(None)
+		);
+	}
+
+	let mut signature = [0; 4];
+	signature.copy_from_slice(&content[..4]);
+
+	Ok(Some(ApplicationChunk {
+		signature,
+		data: content[4..].to_vec(),
+	}))
+}
@@ -0,0 +1,184 @@
+use super::read::INST_SIZE;
+use super::{ApplicationChunk, InstrumentChunk, InstrumentLoop, Marker};
+use crate::error::{LoftyError, Result};
+use crate::iff::aiff::read::verify_aiff;
+use crate::iff::chunk::Chunks;
+use crate::macros::err;
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::ops::Range;
+
+use byteorder::BigEndian;
+
+/// Write the `MARK`, `INST`, and `APPL` chunks
+///
+/// The chunks replace the first existing chunk of the same ID in place, with any others being removed.
+/// If no chunk exists, they will be appended to the end of the file.
+pub(in crate::iff::aiff) fn write_chunks<F>(
+	file: &mut F,
+	markers: &[Marker],
+	instrument: Option<InstrumentChunk>,
+	application_chunks: &[ApplicationChunk],
+) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	let mut mark = Vec::new();
+	if !markers.is_empty() {
+		mark = create_chunk(*b"MARK", &create_mark(markers));
+	}
+
+	let mut inst = Vec::new();
+	if let Some(instrument) = instrument {
+		inst = create_chunk(*b"INST", &create_inst(instrument));
+	}
+
+	let mut appl = Vec::new();
+	for application_chunk in application_chunks {
+		appl.extend(create_chunk(*b"APPL", &create_appl(application_chunk)));
+	}
+
+	replace_chunks(file, *b"MARK", &mark)?;
+	replace_chunks(file, *b"INST", &inst)?;
+	replace_chunks(file, *b"APPL", &appl)
+}
+
+/// Replace all chunks with the ID `fourcc` with `new_chunks`, which may contain multiple chunks
+fn replace_chunks<F>(file: &mut F, fourcc: [u8; 4], new_chunks: &[u8]) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	file.rewind()?;
+	verify_aiff(file)?;
+
+	let file_len = file.len()?.saturating_sub(12);
+
+	let mut existing_chunks: Vec<Range<usize>> = Vec::new();
+	let mut chunks = Chunks::<BigEndian>::new(file_len);
+	while chunks.next(file).is_ok() {
+		if chunks.fourcc == fourcc {
+			let start = (file.stream_position()? - 8) as usize;
+			let end = start + 8 + (chunks.size + chunks.padding()) as usize;
+
+			existing_chunks.push(start..end);
+		}
+
+		chunks.skip(file)?;
+	}
+
+	if existing_chunks.is_empty() && new_chunks.is_empty() {
+		return Ok(());
+	}
+
+	file.rewind()?;
+
+	let mut file_bytes = Vec::new();
+	file.read_to_end(&mut file_bytes)?;
+
+	let insert_pos = existing_chunks
+		.first()
+		.map_or(file_bytes.len(), |range| range.start);
+
+	for range in existing_chunks.into_iter().rev() {
+		file_bytes.drain(range);
+	}
+
+	let _ = file_bytes.splice(insert_pos..insert_pos, new_chunks.iter().copied());
+
+	let Ok(total_size) = u32::try_from(file_bytes.len() - 8) else {
+		err!(TooMuchData);
+	};
+	file_bytes[4..8].copy_from_slice(&total_size.to_be_bytes());
+
+	file.rewind()?;
+	file.truncate(0)?;
+	file.write_all(&file_bytes)?;
+
+	Ok(())
+}
+
+fn create_chunk(fourcc: [u8; 4], content: &[u8]) -> Vec<u8> {
+	let mut chunk = Vec::with_capacity(content.len() + 9);
+	chunk.extend(fourcc);
+	chunk.extend((content.len() as u32).to_be_bytes());
+	chunk.extend(content);
+
+	// It is required an odd length chunk be padded with a 0
+	// The 0 isn't included in the chunk size, however
+	if content.len() % 2 != 0 {
+		chunk.push(0);
+	}
+
+	chunk
+}
+
+/// Create the content of a `MARK` chunk
+pub(super) fn create_mark(markers: &[Marker]) -> Vec<u8> {
+	let mut content = Vec::new();
+	content.extend((markers.len() as u16).to_be_bytes());
+
+	for marker in markers {
+		content.extend(marker.id.to_be_bytes());
+		content.extend(marker.position.to_be_bytes());
+
+		// The name is a pascal string, so it can be at most 255 bytes
+		let mut name = marker.name.as_bytes();
+		if name.len() > 255 {
+			log::warn!("Marker name is too long, truncating to 255 bytes");
+
+			let mut end = 255;
+			while !marker.name.is_char_boundary(end) {
+				end -= 1;
+			}
+
+			name = &name[..end];
+		}
+
+		content.push(name.len() as u8);
+		content.extend(name);
+
+		// The pascal string (including the count byte) is padded to an even length
+		if name.len() % 2 == 0 {
+			content.push(0);
+		}
+	}
+
+	content
+}
+
+/// Create the content of an `INST` chunk
+pub(super) fn create_inst(inst: InstrumentChunk) -> Vec<u8> {
+	fn push_loop(content: &mut Vec<u8>, instrument_loop: InstrumentLoop) {
+		content.extend(u16::from(instrument_loop.play_mode).to_be_bytes());
+		content.extend(instrument_loop.begin_marker.to_be_bytes());
+		content.extend(instrument_loop.end_marker.to_be_bytes());
+	}
+
+	let mut content = Vec::with_capacity(INST_SIZE);
+	content.extend([
+		inst.base_note,
+		inst.detune as u8,
+		inst.low_note,
+		inst.high_note,
+		inst.low_velocity,
+		inst.high_velocity,
+	]);
+	content.extend(inst.gain.to_be_bytes());
+	push_loop(&mut content, inst.sustain_loop);
+	push_loop(&mut content, inst.release_loop);
+
+	content
+}
+
+/// Create the content of an `APPL` chunk
+pub(super) fn create_appl(appl: &ApplicationChunk) -> Vec<u8> {
+	let mut content = Vec::with_capacity(4 + appl.data.len());
+	content.extend(appl.signature);
+	content.extend(&appl.data);
+
+	content
+}
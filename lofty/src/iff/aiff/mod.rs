@@ -1,21 +1,35 @@
 //! AIFF specific items
 
+mod chunks;
 mod properties;
 mod read;
 pub(crate) mod tag;
 
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
 use crate::id3::v2::tag::Id3v2Tag;
+use crate::tag::TagExt;
+use crate::util::io::{FileLike, Length, Truncate};
 
 use lofty_attr::LoftyFile;
 
 // Exports
 
+pub use chunks::{ApplicationChunk, InstrumentChunk, InstrumentLoop, LoopPlayMode, Marker};
 pub use properties::{AiffCompressionType, AiffProperties};
 pub use tag::{AiffTextChunks, Comment};
 
 /// An AIFF file
+///
+/// ## Notes
+///
+/// * Markers, the instrument, and application chunks (`MARK`, `INST`, and `APPL`) are stored in the
+///   `AiffFile` itself, see [`AiffFile::markers`], [`AiffFile::instrument`], and
+///   [`AiffFile::application_chunks`]. They are only written when saving the `AiffFile` with
+///   [`AudioFile::save_to`](crate::file::AudioFile::save_to).
 #[derive(LoftyFile)]
 #[lofty(read_fn = "read::read_from")]
+#[lofty(write_fn = "Self::write_to")]
 #[lofty(internal_write_module_do_not_use_anywhere_else)]
 pub struct AiffFile {
 	/// Any text chunks included in the file
@@ -26,4 +40,119 @@ pub struct AiffFile {
 	pub(crate) id3v2_tag: Option<Id3v2Tag>,
 	/// The file's audio properties
 	pub(crate) properties: AiffProperties,
+	/// The markers from the `MARK` chunk
+	pub(crate) markers: Vec<Marker>,
+	/// The `INST` chunk
+	pub(crate) instrument: Option<InstrumentChunk>,
+	/// The `APPL` chunks
+	pub(crate) application_chunks: Vec<ApplicationChunk>,
+}
+
+impl AiffFile {
+	/// Returns the markers, in the order they appear in the `MARK` chunk
+	pub fn markers(&self) -> &[Marker] {
+		&self.markers
+	}
+
+	/// Returns a mutable reference to the markers
+	pub fn markers_mut(&mut self) -> &mut Vec<Marker> {
+		&mut self.markers
+	}
+
+	/// Inserts a [`Marker`]
+	///
+	/// If a marker with the same ID exists, it will be replaced and returned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::iff::aiff::{AiffFile, Marker};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/full_test.aiff";
+	/// let mut aiff_file = AiffFile::read_from(&mut std::fs::File::open(path)?, ParseOptions::new())?;
+	///
+	/// let marker = Marker::new(1, 48000, String::from("Loop start"));
+	///
+	/// assert!(aiff_file.insert_marker(marker).is_none());
+	/// assert_eq!(aiff_file.markers().len(), 1);
+	/// # Ok(()) }
+	/// ```
+	pub fn insert_marker(&mut self, marker: Marker) -> Option<Marker> {
+		match self.markers.iter_mut().find(|m| m.id == marker.id) {
+			Some(existing) => Some(std::mem::replace(existing, marker)),
+			None => {
+				self.markers.push(marker);
+				None
+			},
+		}
+	}
+
+	/// Removes the [`Marker`] with the ID `id`
+	pub fn remove_marker(&mut self, id: u16) -> Option<Marker> {
+		let pos = self.markers.iter().position(|m| m.id == id)?;
+		Some(self.markers.remove(pos))
+	}
+
+	/// Removes all markers, returning them
+	pub fn remove_markers(&mut self) -> Vec<Marker> {
+		std::mem::take(&mut self.markers)
+	}
+
+	/// Returns a reference to the `INST` chunk, if it exists
+	pub fn instrument(&self) -> Option<&InstrumentChunk> {
+		self.instrument.as_ref()
+	}
+
+	/// Returns a mutable reference to the `INST` chunk, if it exists
+	pub fn instrument_mut(&mut self) -> Option<&mut InstrumentChunk> {
+		self.instrument.as_mut()
+	}
+
+	/// Sets the `INST` chunk, returning the old one
+	pub fn set_instrument(&mut self, instrument: InstrumentChunk) -> Option<InstrumentChunk> {
+		self.instrument.replace(instrument)
+	}
+
+	/// Removes the `INST` chunk, returning it
+	pub fn remove_instrument(&mut self) -> Option<InstrumentChunk> {
+		self.instrument.take()
+	}
+
+	/// Returns the `APPL` chunks, in the order they appear in the file
+	pub fn application_chunks(&self) -> &[ApplicationChunk] {
+		&self.application_chunks
+	}
+
+	/// Returns a mutable reference to the `APPL` chunks
+	pub fn application_chunks_mut(&mut self) -> &mut Vec<ApplicationChunk> {
+		&mut self.application_chunks
+	}
+
+	// We need a special write fn to write the markers alongside the tags
+	fn write_to<F>(&self, file: &mut F, write_options: WriteOptions) -> Result<()>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		if let Some(ref text_chunks) = self.text_chunks_tag {
+			file.rewind()?;
+			text_chunks.save_to(file, write_options.clone())?;
+		}
+
+		if let Some(ref id3v2) = self.id3v2_tag {
+			file.rewind()?;
+			id3v2.save_to(file, write_options)?;
+		}
+
+		chunks::write::write_chunks(
+			file,
+			&self.markers,
+			self.instrument,
+			&self.application_chunks,
+		)
+	}
 }
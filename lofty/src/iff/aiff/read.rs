@@ -1,3 +1,4 @@
+use super::chunks::read::{parse_appl, parse_inst, parse_mark};
 use super::properties::AiffProperties;
 use super::tag::{AiffTextChunks, Comment};
 use super::AiffFile;
@@ -65,12 +66,16 @@ where
 
 	let mut id3v2_tag: Option<Id3v2Tag> = None;
 
+	let mut markers = None;
+	let mut instrument = None;
+	let mut application_chunks = Vec::new();
+
 	let mut chunks = Chunks::<BigEndian>::new(file_len);
 
 	while chunks.next(data).is_ok() {
 		match &chunks.fourcc {
 			b"ID3 " | b"id3 " if parse_options.read_tags => {
-				let tag = chunks.id3_chunk(data, parse_options)?;
+				let tag = chunks.id3_chunk(data, parse_options.clone())?;
 				if let Some(existing_tag) = id3v2_tag.as_mut() {
 					log::warn!("Duplicate ID3v2 tag found, appending frames to previous tag");
 
@@ -92,7 +97,7 @@ where
 				chunks.correct_position(data)?;
 			},
 			b"SSND" if parse_options.read_properties => {
-				stream_len = chunks.size;
+				stream_len = chunks.size as u32;
 				chunks.skip(data)?;
 			},
 			b"ANNO" if parse_options.read_tags => {
@@ -123,6 +128,36 @@ where
 
 				chunks.correct_position(data)?;
 			},
+			b"MARK" => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				if markers.is_some() {
+					log::warn!("Duplicate `MARK` chunk found, discarding");
+					continue;
+				}
+
+				markers = Some(parse_mark(&content, parse_options.parsing_mode)?);
+			},
+			b"INST" => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				if instrument.is_some() {
+					log::warn!("Duplicate `INST` chunk found, discarding");
+					continue;
+				}
+
+				instrument = parse_inst(&content, parse_options.parsing_mode)?;
+			},
+			b"APPL" => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				if let Some(application_chunk) = parse_appl(&content, parse_options.parsing_mode)? {
+					application_chunks.push(application_chunk);
+				}
+			},
 			b"NAME" if text_chunks.name.is_none() && parse_options.read_tags => {
 				text_chunks.name = Some(chunks.read_pstring(data, None)?);
 			},
@@ -178,5 +213,8 @@ where
 			_ => Some(text_chunks),
 		},
 		id3v2_tag,
+		markers: markers.unwrap_or_default(),
+		instrument,
+		application_chunks,
 	})
 }
@@ -9,35 +9,149 @@ use std::marker::PhantomData;
 
 use byteorder::{ByteOrder, ReadBytesExt};
 
+/// The suffix shared by most Wave64 chunk GUIDs, with the first 4 bytes being the chunk's FOURCC
+pub(crate) const WAVE64_GUID_SUFFIX: [u8; 12] = [
+	0xF3, 0xAC, 0xD3, 0x11, 0x8C, 0xD1, 0x00, 0xC0, 0x4F, 0x8E, 0xDB, 0x8A,
+];
+
+/// The suffix of the Wave64 `list` chunk GUID, which differs from the rest
+pub(crate) const WAVE64_LIST_GUID_SUFFIX: [u8; 12] = [
+	0x2F, 0x91, 0xCF, 0x11, 0xA5, 0xD6, 0x28, 0xDB, 0x04, 0xC1, 0x00, 0x00,
+];
+
+/// Size of a Wave64 chunk header (GUID + 64-bit size)
+pub(crate) const WAVE64_CHUNK_HEADER_SIZE: u64 = 24;
+
+/// Get the Wave64 chunk GUID for a FOURCC
+///
+/// `LIST` chunks are stored as `list`, with their own GUID suffix.
+pub(crate) fn wave64_guid(fourcc: [u8; 4]) -> [u8; 16] {
+	let mut guid = [0; 16];
+
+	if &fourcc == b"LIST" {
+		guid[..4].copy_from_slice(b"list");
+		guid[4..].copy_from_slice(&WAVE64_LIST_GUID_SUFFIX);
+	} else {
+		guid[..4].copy_from_slice(&fourcc);
+		guid[4..].copy_from_slice(&WAVE64_GUID_SUFFIX);
+	}
+
+	guid
+}
+
+/// The inverse of [`wave64_guid`], returning `None` for GUIDs that don't map to a FOURCC
+fn wave64_fourcc(guid: [u8; 16]) -> Option<[u8; 4]> {
+	let (fourcc, suffix) = guid.split_at(4);
+
+	if suffix == WAVE64_LIST_GUID_SUFFIX && fourcc == b"list" {
+		return Some(*b"LIST");
+	}
+
+	if suffix == WAVE64_GUID_SUFFIX {
+		return fourcc.try_into().ok();
+	}
+
+	None
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ChunkHeaderFormat {
+	/// A FOURCC followed by a 32-bit size (RIFF, AIFF)
+	Standard,
+	/// A FOURCC followed by a 64-bit size (DSDIFF)
+	Wide,
+	/// A GUID followed by a 64-bit size that includes the header, aligned to 8 bytes (Wave64)
+	Wave64,
+}
+
 pub(crate) struct Chunks<B>
 where
 	B: ByteOrder,
 {
 	pub fourcc: [u8; 4],
-	pub size: u32,
+	pub size: u64,
 	remaining_size: u64,
+	header_format: ChunkHeaderFormat,
+	/// Sizes for chunks with a 32-bit size of `0xFFFFFFFF`, as specified by an RF64 `ds64` chunk
+	size_overrides: Vec<([u8; 4], u64)>,
 	_phantom: PhantomData<B>,
 }
 
 impl<B: ByteOrder> Chunks<B> {
 	#[must_use]
 	pub const fn new(file_size: u64) -> Self {
+		Self::with_header_format(file_size, ChunkHeaderFormat::Standard)
+	}
+
+	/// Create a reader for chunks with 64-bit sizes, such as those found in DSDIFF
+	#[must_use]
+	pub const fn new_wide(file_size: u64) -> Self {
+		Self::with_header_format(file_size, ChunkHeaderFormat::Wide)
+	}
+
+	/// Create a reader for Wave64 chunks
+	///
+	/// Chunks with GUIDs that don't map to a FOURCC will have a FOURCC of `[0; 4]`.
+	#[must_use]
+	pub const fn new_wave64(file_size: u64) -> Self {
+		Self::with_header_format(file_size, ChunkHeaderFormat::Wave64)
+	}
+
+	const fn with_header_format(file_size: u64, header_format: ChunkHeaderFormat) -> Self {
 		Self {
 			fourcc: [0; 4],
 			size: 0,
 			remaining_size: file_size,
+			header_format,
+			size_overrides: Vec::new(),
 			_phantom: PhantomData,
 		}
 	}
 
+	/// Set the sizes to use for chunks with a 32-bit size of `0xFFFFFFFF`, from an RF64 `ds64` chunk
+	pub fn set_size_overrides(&mut self, size_overrides: Vec<([u8; 4], u64)>) {
+		self.size_overrides = size_overrides;
+	}
+
 	pub fn next<R>(&mut self, data: &mut R) -> Result<()>
 	where
 		R: Read,
 	{
-		data.read_exact(&mut self.fourcc)?;
-		self.size = data.read_u32::<B>()?;
+		let header_size;
+		match self.header_format {
+			ChunkHeaderFormat::Standard => {
+				data.read_exact(&mut self.fourcc)?;
+				self.size = u64::from(data.read_u32::<B>()?);
+				header_size = 8;
 
-		self.remaining_size = self.remaining_size.saturating_sub(8);
+				if self.size == u64::from(u32::MAX) {
+					if let Some((_, size)) =
+						self.size_overrides.iter().find(|(f, _)| *f == self.fourcc)
+					{
+						self.size = *size;
+					}
+				}
+			},
+			ChunkHeaderFormat::Wide => {
+				data.read_exact(&mut self.fourcc)?;
+				self.size = data.read_u64::<B>()?;
+				header_size = 12;
+			},
+			ChunkHeaderFormat::Wave64 => {
+				let mut guid = [0; 16];
+				data.read_exact(&mut guid)?;
+				self.fourcc = wave64_fourcc(guid).unwrap_or_default();
+
+				let Some(size) = data.read_u64::<B>()?.checked_sub(WAVE64_CHUNK_HEADER_SIZE) else {
+					err!(SizeMismatch);
+				};
+
+				self.size = size;
+				header_size = WAVE64_CHUNK_HEADER_SIZE;
+			},
+		}
+
+		self.remaining_size = self.remaining_size.saturating_sub(header_size);
 
 		Ok(())
 	}
@@ -73,7 +187,7 @@ impl<B: ByteOrder> Chunks<B> {
 	where
 		R: Read,
 	{
-		self.read(data, u64::from(self.size))
+		self.read(data, self.size)
 	}
 
 	fn read<R>(&mut self, data: &mut R, size: u64) -> Result<Vec<u8>>
@@ -119,10 +233,10 @@ impl<B: ByteOrder> Chunks<B> {
 	where
 		R: Read + Seek,
 	{
-		data.seek(SeekFrom::Current(i64::from(self.size)))?;
+		data.seek(SeekFrom::Current(self.size as i64))?;
 		self.correct_position(data)?;
 
-		self.remaining_size = self.remaining_size.saturating_sub(u64::from(self.size));
+		self.remaining_size = self.remaining_size.saturating_sub(self.size);
 
 		Ok(())
 	}
@@ -131,14 +245,23 @@ impl<B: ByteOrder> Chunks<B> {
 	where
 		R: Read + Seek,
 	{
-		// Chunks are expected to start on even boundaries, and are padded
-		// with a 0 if necessary. This is NOT the null terminator of the value,
+		// Chunks are expected to start on even boundaries (8 byte boundaries for Wave64),
+		// and are padded with 0s if necessary. This is NOT the null terminator of the value,
 		// and it is NOT included in the chunk's size
-		if self.size % 2 != 0 {
-			data.seek(SeekFrom::Current(1))?;
-			self.remaining_size = self.remaining_size.saturating_sub(1);
+		let padding = self.padding();
+		if padding > 0 {
+			data.seek(SeekFrom::Current(padding as i64))?;
+			self.remaining_size = self.remaining_size.saturating_sub(padding);
 		}
 
 		Ok(())
 	}
+
+	/// The number of padding bytes following the current chunk
+	pub fn padding(&self) -> u64 {
+		match self.header_format {
+			ChunkHeaderFormat::Wave64 => (8 - self.size % 8) % 8,
+			_ => self.size % 2,
+		}
+	}
 }
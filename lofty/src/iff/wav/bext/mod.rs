@@ -0,0 +1,486 @@
+pub(super) mod read;
+pub(super) mod write;
+
+use crate::config::WriteOptions;
+use crate::error::LoftyError;
+use crate::tag::items::Timestamp;
+use crate::tag::{Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType};
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::borrow::Cow;
+use std::io::Write;
+
+use lofty_attr::tag;
+
+const DESCRIPTION_KEY: &str = "Description";
+const ORIGINATOR_KEY: &str = "Originator";
+const ORIGINATOR_REFERENCE_KEY: &str = "OriginatorReference";
+const ORIGINATION_DATE_KEY: &str = "OriginationDate";
+const TIME_REFERENCE_KEY: &str = "TimeReference";
+const VERSION_KEY: &str = "Version";
+const UMID_KEY: &str = "UMID";
+const LOUDNESS_VALUE_KEY: &str = "LoudnessValue";
+const LOUDNESS_RANGE_KEY: &str = "LoudnessRange";
+const MAX_TRUE_PEAK_LEVEL_KEY: &str = "MaxTruePeakLevel";
+const MAX_MOMENTARY_LOUDNESS_KEY: &str = "MaxMomentaryLoudness";
+const MAX_SHORT_TERM_LOUDNESS_KEY: &str = "MaxShortTermLoudness";
+const CODING_HISTORY_KEY: &str = "CodingHistory";
+
+/// ## Item storage
+///
+/// The `bext` chunk is made up of fixed-size ASCII fields, followed by a free-form coding history.
+/// When writing, any field exceeding its size in the chunk will be truncated:
+///
+/// * `description`: 256 bytes
+/// * `originator`, `originator_reference`: 32 bytes
+/// * `origination_date`: 10 bytes (`yyyy-mm-dd`)
+/// * `origination_time`: 8 bytes (`hh:mm:ss`)
+///
+/// The loudness values are stored in hundredths of their respective units (LUFS, LU, dBTP), so
+/// for example, a value of `-2270` represents -22.7 LUFS.
+///
+/// ## Conversions
+///
+/// ### To `Tag`
+///
+/// * `description` -> [`ItemKey::Comment`]
+/// * `originator` -> [`ItemKey::Producer`]
+/// * `origination_date` and `origination_time` -> [`ItemKey::RecordingDate`]
+/// * `coding_history` -> [`ItemKey::EncoderSettings`]
+///
+/// All other fields are stored with [`ItemKey::Unknown`], with the `umid` being stored as an
+/// [`ItemValue::Binary`], and the rest as text.
+///
+/// ### From `Tag`
+///
+/// All of the [`ItemKey`]s referenced in the conversion to [`Tag`] will be checked.
+///
+/// The [`ItemKey::RecordingDate`] must be a valid [`Timestamp`] to be split into the
+/// `origination_date` and `origination_time`.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[tag(
+	description = "A Broadcast Wave Format `bext` chunk",
+	supported_formats(Wav)
+)]
+pub struct BextChunk {
+	/// A free description of the sound sequence
+	pub description: String,
+	/// The name of the originator/producer of the audio
+	pub originator: String,
+	/// An unambiguous reference allocated by the originating organization
+	pub originator_reference: String,
+	/// The date of creation of the audio, in the format `yyyy-mm-dd`
+	pub origination_date: String,
+	/// The time of creation of the audio, in the format `hh:mm:ss`
+	pub origination_time: String,
+	/// The number of samples since midnight of the first sample
+	pub time_reference: u64,
+	/// The version of the chunk
+	///
+	/// This will be raised when writing, if the `umid` (version 1) or loudness values (version 2)
+	/// are set.
+	pub version: u16,
+	/// A SMPTE 330M UMID
+	pub umid: Option<[u8; 64]>,
+	/// The integrated loudness, in hundredths of a LUFS
+	pub loudness_value: Option<i16>,
+	/// The loudness range, in hundredths of a LU
+	pub loudness_range: Option<i16>,
+	/// The maximum true peak level, in hundredths of a dBTP
+	pub max_true_peak_level: Option<i16>,
+	/// The highest value of the momentary loudness, in hundredths of a LUFS
+	pub max_momentary_loudness: Option<i16>,
+	/// The highest value of the short-term loudness, in hundredths of a LUFS
+	pub max_short_term_loudness: Option<i16>,
+	/// The coding history, made up of CR/LF terminated lines
+	pub coding_history: String,
+}
+
+impl BextChunk {
+	/// Create a new empty `BextChunk`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::BextChunk;
+	/// use lofty::tag::TagExt;
+	///
+	/// let bext = BextChunk::new();
+	/// assert!(bext.is_empty());
+	/// ```
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The origination date and time as a [`Timestamp`]
+	///
+	/// This will return `None` if the date is not set or invalid.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::BextChunk;
+	///
+	/// let mut bext = BextChunk::new();
+	/// bext.origination_date = String::from("2024-06-01");
+	/// bext.origination_time = String::from("13:45:00");
+	///
+	/// let timestamp = bext.origination_timestamp().unwrap();
+	/// assert_eq!(timestamp.to_string(), "2024-06-01T13:45:00");
+	/// ```
+	pub fn origination_timestamp(&self) -> Option<Timestamp> {
+		if self.origination_date.is_empty() {
+			return None;
+		}
+
+		// The spec allows for any of `-_:. ` to be used as separators
+		let normalize = |s: &str, sep| {
+			s.chars()
+				.map(|c| if "-_:. ".contains(c) { sep } else { c })
+				.collect::<String>()
+		};
+
+		let mut timestamp = normalize(&self.origination_date, '-');
+		if !self.origination_time.is_empty() {
+			timestamp.push('T');
+			timestamp.push_str(&normalize(&self.origination_time, ':'));
+		}
+
+		timestamp.parse().ok()
+	}
+
+	/// Set the origination date and time from a [`Timestamp`]
+	///
+	/// The time is only set if the timestamp contains an hour.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::BextChunk;
+	/// use lofty::tag::items::Timestamp;
+	///
+	/// let mut bext = BextChunk::new();
+	/// bext.set_origination_timestamp("2024-06-01T13:45".parse::<Timestamp>()?);
+	///
+	/// assert_eq!(bext.origination_date, "2024-06-01");
+	/// assert_eq!(bext.origination_time, "13:45:00");
+	/// # Ok::<(), lofty::error::LoftyError>(())
+	/// ```
+	pub fn set_origination_timestamp(&mut self, timestamp: Timestamp) {
+		self.origination_date = format!(
+			"{:04}-{:02}-{:02}",
+			timestamp.year,
+			timestamp.month.unwrap_or(1),
+			timestamp.day.unwrap_or(1)
+		);
+
+		self.origination_time.clear();
+		if let Some(hour) = timestamp.hour {
+			self.origination_time = format!(
+				"{:02}:{:02}:{:02}",
+				hour,
+				timestamp.minute.unwrap_or(0),
+				timestamp.second.unwrap_or(0)
+			);
+		}
+	}
+}
+
+impl Accessor for BextChunk {
+	fn comment(&self) -> Option<Cow<'_, str>> {
+		(!self.description.is_empty()).then_some(Cow::Borrowed(self.description.as_str()))
+	}
+	fn set_comment(&mut self, value: String) {
+		self.description = value;
+	}
+	fn remove_comment(&mut self) {
+		self.description.clear();
+	}
+
+	fn year(&self) -> Option<u32> {
+		self.origination_timestamp()
+			.map(|timestamp| u32::from(timestamp.year))
+	}
+}
+
+impl TagExt for BextChunk {
+	type Err = LoftyError;
+	type RefKey<'a> = &'a ItemKey;
+
+	#[inline]
+	fn tag_type(&self) -> TagType {
+		TagType::Bext
+	}
+
+	fn len(&self) -> usize {
+		usize::from(!self.description.is_empty())
+			+ usize::from(!self.originator.is_empty())
+			+ usize::from(!self.originator_reference.is_empty())
+			+ usize::from(!self.origination_date.is_empty())
+			+ usize::from(!self.origination_time.is_empty())
+			+ usize::from(self.time_reference != 0)
+			+ usize::from(self.umid.is_some())
+			+ usize::from(self.loudness_value.is_some())
+			+ usize::from(self.loudness_range.is_some())
+			+ usize::from(self.max_true_peak_level.is_some())
+			+ usize::from(self.max_momentary_loudness.is_some())
+			+ usize::from(self.max_short_term_loudness.is_some())
+			+ usize::from(!self.coding_history.is_empty())
+	}
+
+	fn contains<'a>(&'a self, key: Self::RefKey<'a>) -> bool {
+		match key {
+			ItemKey::Comment => !self.description.is_empty(),
+			ItemKey::Producer => !self.originator.is_empty(),
+			ItemKey::RecordingDate => !self.origination_date.is_empty(),
+			ItemKey::EncoderSettings => !self.coding_history.is_empty(),
+			_ => false,
+		}
+	}
+
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	fn save_to<F>(
+		&self,
+		file: &mut F,
+		write_options: WriteOptions,
+	) -> std::result::Result<(), Self::Err>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		write::write_bext(file, self, write_options)
+	}
+
+	fn dump_to<W: Write>(
+		&self,
+		writer: &mut W,
+		_write_options: WriteOptions,
+	) -> std::result::Result<(), Self::Err> {
+		writer.write_all(&write::create_bext(self)?)?;
+		Ok(())
+	}
+
+	fn clear(&mut self) {
+		*self = Self::default();
+	}
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SplitTagRemainder;
+
+impl SplitTag for BextChunk {
+	type Remainder = SplitTagRemainder;
+
+	fn split_tag(self) -> (Self::Remainder, Tag) {
+		(SplitTagRemainder, self.into())
+	}
+}
+
+impl MergeTag for SplitTagRemainder {
+	type Merged = BextChunk;
+
+	fn merge_tag(self, tag: Tag) -> Self::Merged {
+		tag.into()
+	}
+}
+
+impl From<BextChunk> for Tag {
+	fn from(input: BextChunk) -> Self {
+		let mut tag = Self::new(TagType::Bext);
+
+		let mut push_text = |key: &str, text: String| {
+			if !text.is_empty() {
+				tag.items.push(TagItem::new(
+					ItemKey::from_key(TagType::Bext, key),
+					ItemValue::Text(text),
+				));
+			}
+		};
+
+		if let Some(timestamp) = input.origination_timestamp() {
+			push_text(ORIGINATION_DATE_KEY, timestamp.to_string());
+		} else {
+			push_text(ORIGINATION_DATE_KEY, input.origination_date);
+		}
+
+		push_text(DESCRIPTION_KEY, input.description);
+		push_text(ORIGINATOR_KEY, input.originator);
+		push_text(ORIGINATOR_REFERENCE_KEY, input.originator_reference);
+		push_text(CODING_HISTORY_KEY, input.coding_history);
+
+		if input.time_reference != 0 {
+			push_text(TIME_REFERENCE_KEY, input.time_reference.to_string());
+		}
+
+		if input.version != 0 {
+			push_text(VERSION_KEY, input.version.to_string());
+		}
+
+		for (key, value) in [
+			(LOUDNESS_VALUE_KEY, input.loudness_value),
+			(LOUDNESS_RANGE_KEY, input.loudness_range),
+			(MAX_TRUE_PEAK_LEVEL_KEY, input.max_true_peak_level),
+			(MAX_MOMENTARY_LOUDNESS_KEY, input.max_momentary_loudness),
+			(MAX_SHORT_TERM_LOUDNESS_KEY, input.max_short_term_loudness),
+		] {
+			if let Some(value) = value {
+				push_text(key, value.to_string());
+			}
+		}
+
+		if let Some(umid) = input.umid {
+			tag.items.push(TagItem::new(
+				ItemKey::Unknown(String::from(UMID_KEY)),
+				ItemValue::Binary(umid.to_vec()),
+			));
+		}
+
+		tag
+	}
+}
+
+impl From<Tag> for BextChunk {
+	fn from(mut input: Tag) -> Self {
+		let mut bext = BextChunk::default();
+
+		let mut take_text = |key: &str| {
+			input
+				.take_strings(&ItemKey::from_key(TagType::Bext, key))
+				.next()
+				.unwrap_or_default()
+		};
+
+		bext.description = take_text(DESCRIPTION_KEY);
+		bext.originator = take_text(ORIGINATOR_KEY);
+		bext.originator_reference = take_text(ORIGINATOR_REFERENCE_KEY);
+		bext.coding_history = take_text(CODING_HISTORY_KEY);
+		bext.time_reference = take_text(TIME_REFERENCE_KEY).parse().unwrap_or(0);
+		bext.version = take_text(VERSION_KEY).parse().unwrap_or(0);
+
+		bext.loudness_value = take_text(LOUDNESS_VALUE_KEY).parse().ok();
+		bext.loudness_range = take_text(LOUDNESS_RANGE_KEY).parse().ok();
+		bext.max_true_peak_level = take_text(MAX_TRUE_PEAK_LEVEL_KEY).parse().ok();
+		bext.max_momentary_loudness = take_text(MAX_MOMENTARY_LOUDNESS_KEY).parse().ok();
+		bext.max_short_term_loudness = take_text(MAX_SHORT_TERM_LOUDNESS_KEY).parse().ok();
+
+		let origination_date = take_text(ORIGINATION_DATE_KEY);
+		match origination_date.parse::<Timestamp>() {
+			Ok(timestamp) => bext.set_origination_timestamp(timestamp),
+			Err(_) => bext.origination_date = origination_date,
+		}
+
+		if let Some(ItemValue::Binary(umid)) = input
+			.take(&ItemKey::Unknown(String::from(UMID_KEY)))
+			.next()
+			.map(TagItem::into_value)
+		{
+			bext.umid = umid.try_into().ok();
+		}
+
+		bext
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::config::{ParsingMode, WriteOptions};
+	use crate::iff::wav::BextChunk;
+	use crate::prelude::*;
+	use crate::tag::{Tag, TagType};
+
+	fn test_bext() -> BextChunk {
+		BextChunk {
+			description: String::from("Foo description"),
+			originator: String::from("Bar originator"),
+			originator_reference: String::from("Baz reference"),
+			origination_date: String::from("2024-06-01"),
+			origination_time: String::from("13:45:30"),
+			time_reference: 172_800_000,
+			version: 2,
+			umid: Some([7; 64]),
+			loudness_value: Some(-2270),
+			loudness_range: Some(520),
+			max_true_peak_level: Some(-100),
+			max_momentary_loudness: Some(-1500),
+			max_short_term_loudness: Some(-1800),
+			coding_history: String::from("A=PCM,F=48000,W=16,M=stereo,T=original\r\n"),
+		}
+	}
+
+	#[test_log::test]
+	fn bext_re_read() {
+		let bext = test_bext();
+
+		let mut writer = Vec::new();
+		bext.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+		// Skip the chunk header
+		let parsed = super::read::parse_bext(&writer[8..], ParsingMode::Strict)
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, bext);
+	}
+
+	#[test_log::test]
+	fn bext_version_0() {
+		let mut bext = test_bext();
+		bext.version = 0;
+		bext.umid = None;
+		bext.loudness_value = None;
+		bext.loudness_range = None;
+		bext.max_true_peak_level = None;
+		bext.max_momentary_loudness = None;
+		bext.max_short_term_loudness = None;
+
+		let mut writer = Vec::new();
+		bext.dump_to(&mut writer, WriteOptions::default()).unwrap();
+
+		let parsed = super::read::parse_bext(&writer[8..], ParsingMode::Strict)
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, bext);
+	}
+
+	#[test_log::test]
+	fn bext_to_tag() {
+		let tag: Tag = test_bext().into();
+
+		assert_eq!(tag.comment().as_deref(), Some("Foo description"));
+		assert_eq!(tag.get_string(&ItemKey::Producer), Some("Bar originator"));
+		assert_eq!(
+			tag.get_string(&ItemKey::RecordingDate),
+			Some("2024-06-01T13:45:30")
+		);
+		assert_eq!(
+			tag.get_string(&ItemKey::EncoderSettings),
+			Some("A=PCM,F=48000,W=16,M=stereo,T=original\r\n")
+		);
+	}
+
+	#[test_log::test]
+	fn tag_to_bext() {
+		let mut tag = Tag::new(TagType::Bext);
+		tag.insert_text(ItemKey::Comment, String::from("Foo description"));
+		tag.insert_text(ItemKey::RecordingDate, String::from("2024-06-01"));
+
+		let bext: BextChunk = tag.into();
+
+		assert_eq!(bext.description, "Foo description");
+		assert_eq!(bext.origination_date, "2024-06-01");
+		assert!(bext.origination_time.is_empty());
+	}
+
+	#[test_log::test]
+	fn bext_tag_round_trip() {
+		let bext = test_bext();
+		let tag: Tag = bext.clone().into();
+
+		assert_eq!(BextChunk::from(tag), bext);
+	}
+}
@@ -0,0 +1,73 @@
+use super::BextChunk;
+use crate::config::ParsingMode;
+use crate::error::Result;
+use crate::macros::{decode_err, parse_mode_choice};
+use crate::util::text::latin1_decode;
+
+use byteorder::{ByteOrder, LittleEndian};
+
+/// The size of the fixed fields, before the coding history
+pub(super) const BEXT_FIXED_SIZE: usize = 602;
+
+/// The value of an unset loudness field
+pub(super) const LOUDNESS_UNSET: i16 = 0x7FFF;
+
+pub(in crate::iff::wav) fn parse_bext(
+	content: &[u8],
+	parse_mode: ParsingMode,
+) -> Result<Option<BextChunk>> {
+	if content.len() < BEXT_FIXED_SIZE {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Wav, "Broadcast extension (`bext`) chunk is too small"),
+			DEFAULT: return Ok(None)
+		);
+	}
+
+	let version = LittleEndian::read_u16(&content[346..348]);
+
+	let mut umid = None;
+	if version >= 1 {
+		let mut umid_bytes = [0; 64];
+		umid_bytes.copy_from_slice(&content[348..412]);
+
+		if umid_bytes.iter().any(|b| *b != 0) {
+			umid = Some(umid_bytes);
+		}
+	}
+
+	let loudness = |offset: usize| {
+		let value = LittleEndian::read_i16(&content[offset..offset + 2]);
+		(version >= 2 && value != LOUDNESS_UNSET).then_some(value)
+	};
+
+	Ok(Some(BextChunk {
+		description: read_string(&content[..256]),
+		originator: read_string(&content[256..288]),
+		originator_reference: read_string(&content[288..320]),
+		origination_date: read_string(&content[320..330]),
+		origination_time: read_string(&content[330..338]),
+		time_reference: LittleEndian::read_u64(&content[338..346]),
+		version,
+		umid,
+		loudness_value: loudness(412),
+		loudness_range: loudness(414),
+		max_true_peak_level: loudness(416),
+		max_momentary_loudness: loudness(418),
+		max_short_term_loudness: loudness(420),
+		coding_history: read_string(&content[BEXT_FIXED_SIZE..]),
+	}))
+}
+
+/// Read a null-padded string
+///
+/// The fields are specified to be ASCII, but UTF-8 and Latin-1 are accepted as well.
+fn read_string(field: &[u8]) -> String {
+	let end = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+	let field = &field[..end];
+
+	match std::str::from_utf8(field) {
+		Ok(s) => s.to_owned(),
+		Err(_) => latin1_decode(field),
+	}
+}
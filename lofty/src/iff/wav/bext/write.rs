@@ -0,0 +1,100 @@
+use super::read::{BEXT_FIXED_SIZE, LOUDNESS_UNSET};
+use super::BextChunk;
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::iff::wav::container::{write_chunks, ChunkTarget, WavContainer};
+use crate::tag::TagExt;
+use crate::util::io::{FileLike, Length, Truncate};
+
+pub(super) fn write_bext<F>(
+	file: &mut F,
+	bext: &BextChunk,
+	_write_options: WriteOptions,
+) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	write_chunks(file, [bext_chunk(bext)])
+}
+
+/// The `bext` chunk for [`write_chunks`]
+pub(in crate::iff::wav) fn bext_chunk(bext: &BextChunk) -> (ChunkTarget, Option<Vec<u8>>) {
+	if bext.is_empty() {
+		log::debug!("No fields to write, removing `bext` chunk");
+		return (ChunkTarget::Chunk(*b"bext"), None);
+	}
+
+	(
+		ChunkTarget::Chunk(*b"bext"),
+		Some(create_bext_content(bext)),
+	)
+}
+
+/// Create a `RIFF` `bext` chunk
+pub(super) fn create_bext(bext: &BextChunk) -> Result<Vec<u8>> {
+	WavContainer::Riff.create_chunk(*b"bext", &create_bext_content(bext))
+}
+
+fn create_bext_content(bext: &BextChunk) -> Vec<u8> {
+	let mut content = Vec::with_capacity(BEXT_FIXED_SIZE + bext.coding_history.len());
+
+	write_string(&mut content, &bext.description, 256);
+	write_string(&mut content, &bext.originator, 32);
+	write_string(&mut content, &bext.originator_reference, 32);
+	write_string(&mut content, &bext.origination_date, 10);
+	write_string(&mut content, &bext.origination_time, 8);
+	content.extend(bext.time_reference.to_le_bytes());
+
+	let loudness = [
+		bext.loudness_value,
+		bext.loudness_range,
+		bext.max_true_peak_level,
+		bext.max_momentary_loudness,
+		bext.max_short_term_loudness,
+	];
+
+	let mut version = bext.version;
+	if loudness.iter().any(Option::is_some) {
+		version = version.max(2);
+	} else if bext.umid.is_some() {
+		version = version.max(1);
+	}
+
+	content.extend(version.to_le_bytes());
+	content.extend(bext.umid.unwrap_or([0; 64]));
+
+	for value in loudness {
+		let value = if version >= 2 {
+			value.unwrap_or(LOUDNESS_UNSET)
+		} else {
+			0
+		};
+
+		content.extend(value.to_le_bytes());
+	}
+
+	// Reserved
+	content.resize(BEXT_FIXED_SIZE, 0);
+
+	content.extend(bext.coding_history.as_bytes());
+
+	log::debug!("Created `bext` chunk, size: {} bytes", Vec::len(&content));
+	content
+}
+
+/// Write a null-padded string, truncating it if necessary
+fn write_string(content: &mut Vec<u8>, value: &str, size: usize) {
+	let mut end = value.len().min(size);
+	while !value.is_char_boundary(end) {
+		end -= 1;
+	}
+
+	if end < value.len() {
+		log::warn!("Truncating `bext` field to {size} bytes: {value:?}");
+	}
+
+	content.extend(&value.as_bytes()[..end]);
+	content.resize(Vec::len(content) + (size - end), 0);
+}
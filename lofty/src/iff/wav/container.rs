@@ -0,0 +1,520 @@
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::iff::chunk::{wave64_guid, Chunks, WAVE64_CHUNK_HEADER_SIZE, WAVE64_GUID_SUFFIX};
+use crate::macros::{decode_err, err, try_vec};
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::ops::Range;
+
+use byteorder::{ByteOrder, LittleEndian, ReadBytesExt};
+
+/// The GUID of the Wave64 `riff` chunk
+pub(crate) const WAVE64_RIFF_GUID: [u8; 16] = [
+	b'r', b'i', b'f', b'f', 0x2E, 0x91, 0xCF, 0x11, 0xA5, 0xD6, 0x28, 0xDB, 0x04, 0xC1, 0x00, 0x00,
+];
+
+/// The size of the `RIFF....WAVE` header
+const RIFF_HEADER_SIZE: u64 = 12;
+
+/// The size of the Wave64 `riff` chunk header, including the `wave` GUID
+const WAVE64_HEADER_SIZE: u64 = 40;
+
+/// The offset of the RIFF size in the `ds64` chunk, which immediately follows the file header
+const RF64_RIFF_SIZE_OFFSET: u64 = 20;
+
+/// The minimum size of the `ds64` chunk, excluding the table
+const DS64_MIN_SIZE: u64 = 28;
+
+/// The container of a WAV file
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum WavContainer {
+	/// A standard `RIFF` file, limited to 4 GiB
+	#[default]
+	Riff,
+	/// An RF64 file (EBU Tech 3306), with 64-bit sizes stored in the `ds64` chunk
+	Rf64,
+	/// A BW64 file (ITU-R BS.2088), which is structurally identical to RF64
+	Bw64,
+	/// A Sony Wave64 file, which uses GUIDs for chunk IDs and 64-bit chunk sizes
+	Wave64,
+}
+
+impl WavContainer {
+	fn chunk_header_size(self) -> u64 {
+		match self {
+			WavContainer::Wave64 => WAVE64_CHUNK_HEADER_SIZE,
+			_ => 8,
+		}
+	}
+
+	/// Create a chunk with the appropriate header and padding for the container
+	pub(crate) fn create_chunk(self, fourcc: [u8; 4], content: &[u8]) -> Result<Vec<u8>> {
+		let mut chunk = Vec::with_capacity(content.len() + WAVE64_CHUNK_HEADER_SIZE as usize + 7);
+
+		match self {
+			WavContainer::Wave64 => {
+				chunk.extend(wave64_guid(fourcc));
+				chunk.extend((content.len() as u64 + WAVE64_CHUNK_HEADER_SIZE).to_le_bytes());
+				chunk.extend(content);
+				chunk.resize(chunk.len().next_multiple_of(8), 0);
+			},
+			_ => {
+				let Ok(size) = u32::try_from(content.len()) else {
+					err!(TooMuchData);
+				};
+
+				chunk.extend(fourcc);
+				chunk.extend(size.to_le_bytes());
+				chunk.extend(content);
+
+				// It is required an odd length chunk be padded with a 0
+				// The 0 isn't included in the chunk size, however
+				if content.len() % 2 != 0 {
+					chunk.push(0);
+				}
+			},
+		}
+
+		Ok(chunk)
+	}
+}
+
+/// The `ds64` chunk of an RF64/BW64 file
+#[derive(Debug, Clone, Default)]
+struct Ds64 {
+	data_size: u64,
+	sample_count: u64,
+	/// Sizes of any other chunks exceeding 4 GiB
+	table: Vec<([u8; 4], u64)>,
+}
+
+impl Ds64 {
+	fn parse(content: &[u8]) -> Result<Self> {
+		if (content.len() as u64) < DS64_MIN_SIZE {
+			decode_err!(@BAIL Wav, "RF64 file has an invalid \"ds64\" chunk size");
+		}
+
+		// The RIFF size is only needed for writing
+		let data_size = LittleEndian::read_u64(&content[8..16]);
+		let sample_count = LittleEndian::read_u64(&content[16..24]);
+		let table_length = LittleEndian::read_u32(&content[24..28]);
+
+		let mut table = Vec::new();
+		for entry in content[28..].chunks_exact(12).take(table_length as usize) {
+			let mut fourcc = [0; 4];
+			fourcc.copy_from_slice(&entry[..4]);
+
+			table.push((fourcc, LittleEndian::read_u64(&entry[4..])));
+		}
+
+		Ok(Self {
+			data_size,
+			sample_count,
+			table,
+		})
+	}
+}
+
+/// The header of a WAV file, including the `ds64` chunk for RF64/BW64 files
+#[derive(Debug, Clone)]
+pub(crate) struct WavHeader {
+	pub(crate) container: WavContainer,
+	/// The size of the header, including the `ds64` chunk
+	size: u64,
+	ds64: Option<Ds64>,
+}
+
+impl WavHeader {
+	/// Read the file header, leaving the reader at the first chunk (after `ds64`)
+	pub(crate) fn read<R>(data: &mut R) -> Result<Self>
+	where
+		R: Read,
+	{
+		let mut id = [0; 12];
+		data.read_exact(&mut id)?;
+
+		let container = match &id[..4] {
+			b"RIFF" => WavContainer::Riff,
+			b"RF64" => WavContainer::Rf64,
+			b"BW64" => WavContainer::Bw64,
+			b"riff" if id[4..] == WAVE64_RIFF_GUID[4..12] => WavContainer::Wave64,
+			_ => decode_err!(@BAIL Wav, "WAV file doesn't contain a RIFF chunk"),
+		};
+
+		if container == WavContainer::Wave64 {
+			// The rest of the `riff` GUID and the 64-bit file size, followed by the `wave` GUID
+			let mut rest = [0; 28];
+			data.read_exact(&mut rest)?;
+
+			if rest[..4] != WAVE64_RIFF_GUID[12..]
+				|| &rest[12..16] != b"wave"
+				|| rest[16..] != WAVE64_GUID_SUFFIX
+			{
+				decode_err!(@BAIL Wav, "Found Wave64 file, format is not WAVE");
+			}
+
+			log::debug!("File verified to be WAV (Wave64)");
+			return Ok(Self {
+				container,
+				size: WAVE64_HEADER_SIZE,
+				ds64: None,
+			});
+		}
+
+		if &id[8..] != b"WAVE" {
+			decode_err!(@BAIL Wav, "Found RIFF file, format is not WAVE");
+		}
+
+		let mut size = RIFF_HEADER_SIZE;
+		let mut ds64 = None;
+		if container != WavContainer::Riff {
+			// The `ds64` chunk is required to be the first chunk
+			let mut chunks = Chunks::<LittleEndian>::new(u64::MAX);
+			if chunks.next(data).is_err() || &chunks.fourcc != b"ds64" {
+				decode_err!(@BAIL Wav, "RF64 file doesn't contain a \"ds64\" chunk");
+			}
+
+			if chunks.size < DS64_MIN_SIZE {
+				decode_err!(@BAIL Wav, "RF64 file has an invalid \"ds64\" chunk size");
+			}
+
+			let mut content = Vec::new();
+			data.by_ref().take(chunks.size).read_to_end(&mut content)?;
+			if chunks.padding() > 0 {
+				data.read_u8()?;
+			}
+
+			size += 8 + chunks.size + chunks.padding();
+			ds64 = Some(Ds64::parse(&content)?);
+		}
+
+		log::debug!("File verified to be WAV ({container:?})");
+		Ok(Self {
+			container,
+			size,
+			ds64,
+		})
+	}
+
+	/// The sample count from the `ds64` chunk, to be used if the `fact` chunk's is `0xFFFFFFFF`
+	pub(crate) fn sample_count(&self) -> Option<u64> {
+		self.ds64.as_ref().map(|ds64| ds64.sample_count)
+	}
+
+	/// Create a chunk reader for the rest of the file
+	pub(crate) fn chunks(&self, file_len: u64) -> Chunks<LittleEndian> {
+		let remaining = file_len.saturating_sub(self.size);
+
+		match &self.ds64 {
+			_ if self.container == WavContainer::Wave64 => Chunks::new_wave64(remaining),
+			Some(ds64) => {
+				let mut chunks = Chunks::new(remaining);
+
+				let mut size_overrides = ds64.table.clone();
+				size_overrides.push((*b"data", ds64.data_size));
+				chunks.set_size_overrides(size_overrides);
+
+				chunks
+			},
+			None => Chunks::new(remaining),
+		}
+	}
+
+	/// The offset and new value of the file size in the header, for a file of `len` bytes
+	fn size_field(&self, len: u64) -> Result<(u64, Vec<u8>)> {
+		match self.container {
+			WavContainer::Riff => {
+				let Ok(size) = u32::try_from(len - 8) else {
+					err!(TooMuchData);
+				};
+
+				Ok((4, size.to_le_bytes().to_vec()))
+			},
+			WavContainer::Rf64 | WavContainer::Bw64 => {
+				Ok((RF64_RIFF_SIZE_OFFSET, (len - 8).to_le_bytes().to_vec()))
+			},
+			// The Wave64 size includes the entire header
+			WavContainer::Wave64 => Ok((16, len.to_le_bytes().to_vec())),
+		}
+	}
+}
+
+/// The existing chunk to be replaced by a chunk passed to [`write_chunks`]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum ChunkTarget {
+	/// A chunk with this fourcc
+	Chunk([u8; 4]),
+	/// A `LIST` chunk of this type
+	///
+	/// The content of the new chunk is expected to start with the list type.
+	List([u8; 4]),
+	/// An `ID3 ` or `id3 ` chunk, with the new chunk using this fourcc
+	Id3v2([u8; 4]),
+}
+
+impl ChunkTarget {
+	/// Target an ID3v2 chunk, using the case specified in the [`WriteOptions`]
+	pub(crate) fn id3v2(write_options: WriteOptions) -> Self {
+		if write_options.uppercase_id3v2_chunk {
+			ChunkTarget::Id3v2(*b"ID3 ")
+		} else {
+			ChunkTarget::Id3v2(*b"id3 ")
+		}
+	}
+
+	fn fourcc(self) -> [u8; 4] {
+		match self {
+			ChunkTarget::Chunk(fourcc) | ChunkTarget::Id3v2(fourcc) => fourcc,
+			ChunkTarget::List(_) => *b"LIST",
+		}
+	}
+
+	fn matches(self, fourcc: [u8; 4], list_type: Option<[u8; 4]>) -> bool {
+		match self {
+			ChunkTarget::Chunk(target) => fourcc == target,
+			ChunkTarget::List(target) => &fourcc == b"LIST" && list_type == Some(target),
+			ChunkTarget::Id3v2(_) => &fourcc == b"ID3 " || &fourcc == b"id3 ",
+		}
+	}
+}
+
+/// Write a set of chunks, replacing the first existing chunk matching each [`ChunkTarget`]
+///
+/// The order of the existing chunks is preserved, and any chunk without an existing chunk to
+/// replace will be placed at the end of the file. Any chunk without content will be removed.
+///
+/// All chunks are written in a single pass. The rest of the file, such as the audio data, is
+/// moved in place as needed, rather than being read into memory.
+pub(crate) fn write_chunks<F, I>(file: &mut F, chunks: I) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+	I: IntoIterator<Item = (ChunkTarget, Option<Vec<u8>>)>,
+{
+	file.rewind()?;
+	let header = WavHeader::read(file)?;
+	let file_len = file.len()?;
+
+	let mut edits = Vec::new();
+	for (target, content) in chunks {
+		let mut chunk_bytes = Vec::new();
+		if let Some(content) = content {
+			chunk_bytes = header.container.create_chunk(target.fourcc(), &content)?;
+		}
+
+		edits.push((target, None::<Range<u64>>, chunk_bytes));
+	}
+
+	let chunk_header_size = header.container.chunk_header_size();
+
+	let mut chunks = header.chunks(file_len);
+	while chunks.next(file).is_ok() {
+		let content_start = file.stream_position()?;
+
+		let mut list_type = None;
+		if &chunks.fourcc == b"LIST" && chunks.size >= 4 {
+			let mut ty = [0; 4];
+			file.read_exact(&mut ty)?;
+			list_type = Some(ty);
+		}
+
+		let edit = edits.iter_mut().find(|(target, existing, _)| {
+			existing.is_none() && target.matches(chunks.fourcc, list_type)
+		});
+
+		if let Some((_, existing, _)) = edit {
+			let chunk_start = content_start - chunk_header_size;
+			let chunk_end = content_start
+				.saturating_add(chunks.size)
+				.saturating_add(chunks.padding())
+				.min(file_len);
+
+			log::debug!(
+				"Found existing chunk to replace, size: {} bytes",
+				chunk_end - chunk_start
+			);
+
+			*existing = Some(chunk_start..chunk_end);
+		}
+
+		file.seek(SeekFrom::Start(content_start))?;
+		chunks.skip(file)?;
+	}
+
+	let mut splices = Vec::with_capacity(edits.len());
+	for (_, existing, chunk_bytes) in &edits {
+		match existing {
+			Some(range) => splices.push((range.clone(), chunk_bytes.as_slice())),
+			None if !chunk_bytes.is_empty() => {
+				splices.push((file_len..file_len, chunk_bytes.as_slice()));
+			},
+			None => {},
+		}
+	}
+
+	if splices.is_empty() {
+		return Ok(());
+	}
+
+	splices.sort_by_key(|(range, _)| range.start);
+
+	let new_len = splices.iter().fold(file_len, |len, (range, content)| {
+		len - (range.end - range.start) + content.len() as u64
+	});
+
+	// Verify the new size fits before touching the file
+	let (size_offset, size) = header.size_field(new_len)?;
+
+	splice_file(file, file_len, &splices)?;
+	if new_len < file_len {
+		file.truncate(new_len)?;
+	}
+
+	file.seek(SeekFrom::Start(size_offset))?;
+	file.write_all(&size)?;
+
+	Ok(())
+}
+
+/// The size of the blocks used to move data within a file
+const COPY_BLOCK_SIZE: u64 = 64 * 1024;
+
+/// Replace each range of the file with its new content, moving the data between them as needed
+///
+/// The ranges are expected to be sorted and non-overlapping. Any data before the first range is
+/// untouched, and the file is *not* truncated if it shrinks.
+fn splice_file<F>(file: &mut F, file_len: u64, splices: &[(Range<u64>, &[u8])]) -> Result<()>
+where
+	F: Read + Write + Seek,
+{
+	let Some((first, _)) = splices.first() else {
+		return Ok(());
+	};
+
+	// The data to be moved (source range, destination), and the new content (destination, content)
+	let mut moves = Vec::with_capacity(splices.len());
+	let mut writes = Vec::with_capacity(splices.len());
+
+	let mut src = first.start;
+	let mut dst = first.start;
+	for (range, content) in splices {
+		if range.start > src {
+			moves.push((src..range.start, dst));
+			dst += range.start - src;
+		}
+
+		writes.push((dst, *content));
+		dst += content.len() as u64;
+		src = range.end;
+	}
+
+	if file_len > src {
+		moves.push((src..file_len, dst));
+	}
+
+	// Data moving towards the end of the file is moved starting from the end, and data moving
+	// towards the start from the start, so nothing is overwritten before it has been moved.
+	for (range, dst) in moves.iter().rev().filter(|(range, dst)| *dst > range.start) {
+		move_data(file, range.clone(), *dst)?;
+	}
+
+	for (range, dst) in moves.iter().filter(|(range, dst)| *dst < range.start) {
+		move_data(file, range.clone(), *dst)?;
+	}
+
+	for (dst, content) in writes {
+		file.seek(SeekFrom::Start(dst))?;
+		file.write_all(content)?;
+	}
+
+	Ok(())
+}
+
+/// Move the data in `src` to `dst`, one block at a time
+fn move_data<F>(file: &mut F, src: Range<u64>, dst: u64) -> Result<()>
+where
+	F: Read + Write + Seek,
+{
+	let len = src.end - src.start;
+	let mut buf = try_vec![0; len.min(COPY_BLOCK_SIZE) as usize];
+
+	let mut moved = 0;
+	while moved < len {
+		let block_len = (len - moved).min(COPY_BLOCK_SIZE);
+		let offset = if dst > src.start {
+			len - moved - block_len
+		} else {
+			moved
+		};
+
+		let block = &mut buf[..block_len as usize];
+
+		file.seek(SeekFrom::Start(src.start + offset))?;
+		file.read_exact(block)?;
+
+		file.seek(SeekFrom::Start(dst + offset))?;
+		file.write_all(block)?;
+
+		moved += block_len;
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{splice_file, COPY_BLOCK_SIZE};
+
+	use std::io::Cursor;
+	use std::ops::Range;
+
+	fn test_splices(splices: &[(Range<u64>, &[u8])]) {
+		// Large enough to need multiple blocks when moving the data
+		let original = (0..COPY_BLOCK_SIZE * 3 + 7)
+			.map(|i| (i % 251) as u8)
+			.collect::<Vec<u8>>();
+
+		let mut expected = original.clone();
+		for (range, content) in splices.iter().rev() {
+			let _ = expected.splice(
+				range.start as usize..range.end as usize,
+				content.iter().copied(),
+			);
+		}
+
+		let mut file = Cursor::new(original.clone());
+		splice_file(&mut file, original.len() as u64, splices).unwrap();
+
+		let mut spliced = file.into_inner();
+		spliced.truncate(expected.len());
+		assert_eq!(spliced, expected);
+	}
+
+	#[test_log::test]
+	fn splice_grow() {
+		test_splices(&[(10..20, &[1; 100]), (500..500, &[2; 10])]);
+	}
+
+	#[test_log::test]
+	fn splice_shrink() {
+		test_splices(&[(10..100, &[1; 5]), (500..1000, &[])]);
+	}
+
+	#[test_log::test]
+	fn splice_grow_and_shrink() {
+		test_splices(&[
+			(10..20, &[1; 100]),
+			(1000..5000, &[2; 3]),
+			(COPY_BLOCK_SIZE * 2..COPY_BLOCK_SIZE * 2 + 1, &[3; 50]),
+		]);
+	}
+
+	#[test_log::test]
+	fn splice_append() {
+		let end = COPY_BLOCK_SIZE * 3 + 7;
+		test_splices(&[(0..0, &[1; 3]), (end..end, &[2; 10]), (end..end, &[3; 10])]);
+	}
+}
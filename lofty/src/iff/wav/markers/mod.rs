@@ -0,0 +1,139 @@
+pub(super) mod read;
+pub(super) mod write;
+
+/// A cue point (marker) in a WAV file
+///
+/// Cue points are stored in the `cue ` chunk, with their labels, notes, and regions being
+/// stored in a `LIST` chunk of type `adtl`.
+///
+/// NOTE: When writing, the cue point is assumed to refer to the `data` chunk, with its position
+///       being the same as its `sample_offset`.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct CuePoint {
+	/// A unique identifier for the cue point
+	pub id: u32,
+	/// The offset of the cue point in samples
+	pub sample_offset: u32,
+	/// A label for the cue point, from a `labl` chunk
+	pub label: Option<String>,
+	/// A comment for the cue point, from a `note` chunk
+	pub note: Option<String>,
+	/// The region starting at the cue point, from an `ltxt` chunk
+	pub region: Option<CueRegion>,
+}
+
+impl CuePoint {
+	/// Create a new `CuePoint`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::CuePoint;
+	///
+	/// let cue_point = CuePoint::new(1, 48000);
+	/// assert_eq!(cue_point.sample_offset, 48000);
+	/// assert!(cue_point.label.is_none());
+	/// ```
+	pub fn new(id: u32, sample_offset: u32) -> Self {
+		Self {
+			id,
+			sample_offset,
+			..Self::default()
+		}
+	}
+}
+
+/// A region of audio, described by an `ltxt` chunk
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CueRegion {
+	/// The length of the region in samples
+	pub sample_length: u32,
+	/// The purpose of the region, usually `rgn `
+	pub purpose: [u8; 4],
+	/// The country code of the text
+	pub country: u16,
+	/// The language code of the text
+	pub language: u16,
+	/// The dialect code of the text
+	pub dialect: u16,
+	/// The code page of the text
+	pub code_page: u16,
+	/// A description of the region
+	pub text: Option<String>,
+}
+
+impl CueRegion {
+	/// Create a new `CueRegion` with a purpose of `rgn `
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::CueRegion;
+	///
+	/// let region = CueRegion::new(96000);
+	/// assert_eq!(&region.purpose, b"rgn ");
+	/// ```
+	pub fn new(sample_length: u32) -> Self {
+		Self {
+			sample_length,
+			purpose: *b"rgn ",
+			country: 0,
+			language: 0,
+			dialect: 0,
+			code_page: 0,
+			text: None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{CuePoint, CueRegion};
+	use crate::config::ParsingMode;
+
+	use std::io::Cursor;
+
+	fn test_markers() -> Vec<CuePoint> {
+		let mut region = CueRegion::new(24000);
+		region.text = Some(String::from("Bar region"));
+
+		vec![
+			CuePoint {
+				label: Some(String::from("Foo marker")),
+				note: Some(String::from("Foo note")),
+				..CuePoint::new(1, 0)
+			},
+			CuePoint {
+				label: Some(String::from("Bar")),
+				region: Some(region),
+				..CuePoint::new(2, 48000)
+			},
+			CuePoint::new(3, 96000),
+		]
+	}
+
+	#[test_log::test]
+	fn markers_re_read() {
+		let markers = test_markers();
+
+		let cue = super::write::create_cue(&markers);
+		let adtl = super::write::create_adtl(&markers);
+
+		let mut parsed = super::read::parse_cue(&cue, ParsingMode::Strict).unwrap();
+
+		// Skip the `adtl` list type
+		let mut reader = Cursor::new(&adtl[4..]);
+		let associated_data =
+			super::read::parse_adtl(&mut reader, adtl.len() as u64 - 4, ParsingMode::Strict)
+				.unwrap();
+		super::read::merge_associated_data(&mut parsed, associated_data);
+
+		assert_eq!(parsed, markers);
+	}
+
+	#[test_log::test]
+	fn no_associated_data() {
+		let markers = vec![CuePoint::new(1, 0), CuePoint::new(2, 1024)];
+		assert!(super::write::create_adtl(&markers).is_empty());
+	}
+}
@@ -0,0 +1,160 @@
+use super::{CuePoint, CueRegion};
+use crate::config::ParsingMode;
+use crate::error::Result;
+use crate::iff::chunk::Chunks;
+use crate::macros::{decode_err, parse_mode_choice};
+use crate::util::text::latin1_decode;
+
+use std::io::{Read, Seek};
+
+use byteorder::{ByteOrder, LittleEndian, ReadBytesExt};
+
+/// The size of a single cue point in the `cue ` chunk
+pub(super) const CUE_POINT_SIZE: usize = 24;
+
+/// An item from a `LIST` `adtl` chunk, to be attached to a [`CuePoint`]
+pub(in crate::iff::wav) enum AssociatedData {
+	Label(u32, String),
+	Note(u32, String),
+	Region(u32, CueRegion),
+}
+
+pub(in crate::iff::wav) fn parse_cue(
+	content: &[u8],
+	parse_mode: ParsingMode,
+) -> Result<Vec<CuePoint>> {
+	if content.len() < 4 {
+		decode_err!(@BAIL Wav, "Cue chunk is too small");
+	}
+
+	let count = LittleEndian::read_u32(&content[..4]) as usize;
+
+	let cue_points = content[4..].chunks_exact(CUE_POINT_SIZE);
+	if cue_points.len() < count {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Wav, "Cue chunk has fewer cue points than expected"),
+		);
+	}
+
+	let mut markers = Vec::with_capacity(count.min(cue_points.len()));
+	for cue_point in cue_points.take(count) {
+		// The position, chunk ID, chunk start, and block start are only relevant for
+		// files with a `wavl` chunk
+		markers.push(CuePoint::new(
+			LittleEndian::read_u32(&cue_point[..4]),
+			LittleEndian::read_u32(&cue_point[20..]),
+		));
+	}
+
+	Ok(markers)
+}
+
+/// Parse the sub-chunks of a `LIST` `adtl` chunk, with the reader positioned after the list type
+pub(in crate::iff::wav) fn parse_adtl<R>(
+	data: &mut R,
+	size: u64,
+	parse_mode: ParsingMode,
+) -> Result<Vec<AssociatedData>>
+where
+	R: Read + Seek,
+{
+	let mut associated_data = Vec::new();
+
+	let mut chunks = Chunks::<LittleEndian>::new(size);
+	while chunks.next(data).is_ok() {
+		let fourcc = chunks.fourcc;
+		if !matches!(&fourcc, b"labl" | b"note" | b"ltxt") {
+			chunks.skip(data)?;
+			continue;
+		}
+
+		let content = chunks.content(data)?;
+		chunks.correct_position(data)?;
+
+		let min_size = if &fourcc == b"ltxt" { 20 } else { 4 };
+		if content.len() < min_size {
+			parse_mode_choice!(
+				parse_mode,
+				STRICT: decode_err!(@BAIL Wav, "Associated data list contains an invalid chunk"),
+				DEFAULT: continue
+			);
+		}
+
+		let reader = &mut &content[..];
+		let cue_id = reader.read_u32::<LittleEndian>()?;
+
+		let item = match &fourcc {
+			b"labl" => AssociatedData::Label(cue_id, read_text(reader)),
+			b"note" => AssociatedData::Note(cue_id, read_text(reader)),
+			_ => {
+				let sample_length = reader.read_u32::<LittleEndian>()?;
+
+				let mut purpose = [0; 4];
+				reader.read_exact(&mut purpose)?;
+
+				let country = reader.read_u16::<LittleEndian>()?;
+				let language = reader.read_u16::<LittleEndian>()?;
+				let dialect = reader.read_u16::<LittleEndian>()?;
+				let code_page = reader.read_u16::<LittleEndian>()?;
+
+				let text = read_text(reader);
+				AssociatedData::Region(
+					cue_id,
+					CueRegion {
+						sample_length,
+						purpose,
+						country,
+						language,
+						dialect,
+						code_page,
+						text: (!text.is_empty()).then_some(text),
+					},
+				)
+			},
+		};
+
+		associated_data.push(item);
+	}
+
+	Ok(associated_data)
+}
+
+/// Attach the associated data to their cue points, discarding any without a matching ID
+pub(in crate::iff::wav) fn merge_associated_data(
+	markers: &mut [CuePoint],
+	associated_data: Vec<AssociatedData>,
+) {
+	for item in associated_data {
+		let id = match item {
+			AssociatedData::Label(id, _)
+			| AssociatedData::Note(id, _)
+			| AssociatedData::Region(id, _) => id,
+		};
+
+		let Some(marker) = markers.iter_mut().find(|marker| marker.id == id) else {
+			log::warn!("Found associated data for a nonexistent cue point: {id}");
+			continue;
+		};
+
+		match item {
+			AssociatedData::Label(_, label) => marker.label = Some(label),
+			AssociatedData::Note(_, note) => marker.note = Some(note),
+			AssociatedData::Region(_, region) => marker.region = Some(region),
+		}
+	}
+}
+
+/// Read a null-terminated string, falling back to Latin-1 if it isn't valid UTF-8
+fn read_text(content: &[u8]) -> String {
+	let end = content
+		.iter()
+		.position(|b| *b == 0)
+		.unwrap_or(content.len());
+	let content = &content[..end];
+
+	match std::str::from_utf8(content) {
+		Ok(text) => text.to_owned(),
+		Err(_) => latin1_decode(content),
+	}
+}
@@ -0,0 +1,97 @@
+use super::read::CUE_POINT_SIZE;
+use super::CuePoint;
+use crate::iff::wav::container::{ChunkTarget, WavContainer};
+
+/// The `cue ` chunk and `adtl` list for [`write_chunks`](crate::iff::wav::container::write_chunks)
+pub(in crate::iff::wav) fn marker_chunks(
+	markers: &[CuePoint],
+) -> [(ChunkTarget, Option<Vec<u8>>); 2] {
+	let cue = (!markers.is_empty()).then(|| create_cue(markers));
+
+	let adtl = create_adtl(markers);
+	let adtl = (!adtl.is_empty()).then_some(adtl);
+
+	[
+		(ChunkTarget::Chunk(*b"cue "), cue),
+		(ChunkTarget::List(*b"adtl"), adtl),
+	]
+}
+
+/// Create the content of a `cue ` chunk
+pub(super) fn create_cue(markers: &[CuePoint]) -> Vec<u8> {
+	let mut content = Vec::with_capacity(4 + markers.len() * CUE_POINT_SIZE);
+	content.extend((markers.len() as u32).to_le_bytes());
+
+	for marker in markers {
+		content.extend(marker.id.to_le_bytes());
+		// Position
+		content.extend(marker.sample_offset.to_le_bytes());
+		content.extend(b"data");
+		// Chunk start
+		content.extend(0_u32.to_le_bytes());
+		// Block start
+		content.extend(0_u32.to_le_bytes());
+		content.extend(marker.sample_offset.to_le_bytes());
+	}
+
+	content
+}
+
+/// Create the content of a `LIST` `adtl` chunk
+///
+/// This will be empty if no cue points have a label, note, or region.
+pub(super) fn create_adtl(markers: &[CuePoint]) -> Vec<u8> {
+	fn push_text_chunk(content: &mut Vec<u8>, fourcc: [u8; 4], id: u32, text: &str) {
+		let mut chunk_content = id.to_le_bytes().to_vec();
+		chunk_content.extend(text.as_bytes());
+		chunk_content.push(0);
+
+		push_chunk(content, fourcc, &chunk_content);
+	}
+
+	fn push_chunk(content: &mut Vec<u8>, fourcc: [u8; 4], chunk_content: &[u8]) {
+		// The sub-chunks always use 32-bit sizes, they are too small to fail
+		if let Ok(chunk) = WavContainer::Riff.create_chunk(fourcc, chunk_content) {
+			content.extend(chunk);
+		}
+	}
+
+	let mut content = Vec::new();
+
+	for marker in markers {
+		if let Some(label) = &marker.label {
+			push_text_chunk(&mut content, *b"labl", marker.id, label);
+		}
+
+		if let Some(note) = &marker.note {
+			push_text_chunk(&mut content, *b"note", marker.id, note);
+		}
+
+		if let Some(region) = &marker.region {
+			let mut ltxt = Vec::with_capacity(20);
+			ltxt.extend(marker.id.to_le_bytes());
+			ltxt.extend(region.sample_length.to_le_bytes());
+			ltxt.extend(region.purpose);
+			ltxt.extend(region.country.to_le_bytes());
+			ltxt.extend(region.language.to_le_bytes());
+			ltxt.extend(region.dialect.to_le_bytes());
+			ltxt.extend(region.code_page.to_le_bytes());
+
+			if let Some(text) = &region.text {
+				ltxt.extend(text.as_bytes());
+				ltxt.push(0);
+			}
+
+			push_chunk(&mut content, *b"ltxt", &ltxt);
+		}
+	}
+
+	if content.is_empty() {
+		return content;
+	}
+
+	let mut adtl = b"adtl".to_vec();
+	adtl.extend(content);
+
+	adtl
+}
@@ -1,20 +1,51 @@
 //! WAV specific items
 
+pub(crate) mod bext;
+pub(crate) mod container;
+mod markers;
 mod properties;
 mod read;
+mod sampler;
 pub(crate) mod tag;
+mod xml;
 
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::file::{FileType, TaggedFile};
 use crate::id3::v2::tag::Id3v2Tag;
+use crate::iff::wav::container::ChunkTarget;
+use crate::tag::{ItemKey, Tag, TagExt, TagType};
+use crate::util::io::{FileLike, Length, Truncate};
 
 use lofty_attr::LoftyFile;
 
 // Exports
+pub use crate::iff::wav::bext::BextChunk;
+pub use crate::iff::wav::container::WavContainer;
+pub use crate::iff::wav::markers::{CuePoint, CueRegion};
 pub use crate::iff::wav::properties::{WavFormat, WavProperties};
+pub use crate::iff::wav::sampler::{
+	AcidChunk, InstrumentChunk, SampleLoop, SampleLoopType, SamplerChunk,
+};
+pub use crate::iff::wav::xml::{IxmlMetadata, IxmlTrack};
 pub use tag::RiffInfoList;
 
 /// A WAV file
+///
+/// ## Notes
+///
+/// * Cue points are stored in the `WavFile` itself, see [`WavFile::markers`]. They are only written
+///   when saving the `WavFile` with [`AudioFile::save_to`](crate::file::AudioFile::save_to).
+/// * The same applies to the `smpl`, `inst`, and `acid` chunks, see [`WavFile::sampler`],
+///   [`WavFile::instrument`], and [`WavFile::acid`], and the `iXML` and `axml` chunks, see
+///   [`WavFile::ixml`] and [`WavFile::axml`].
+/// * When converting to [`TaggedFile`], the tempo and key from the `acid` chunk and the common fields
+///   of the `iXML` chunk (see [`IxmlMetadata`]) will be put inside of an ID3v2 tag, even if the file
+///   did not originally contain one. Existing items are not replaced.
 #[derive(LoftyFile)]
 #[lofty(read_fn = "read::read_from")]
+#[lofty(write_fn = "Self::write_to")]
+#[lofty(no_into_taggedfile_impl)]
 #[lofty(internal_write_module_do_not_use_anywhere_else)]
 pub struct WavFile {
 	/// A RIFF INFO LIST
@@ -23,6 +54,347 @@ pub struct WavFile {
 	/// An ID3v2 tag
 	#[lofty(tag_type = "Id3v2")]
 	pub(crate) id3v2_tag: Option<Id3v2Tag>,
+	/// A Broadcast Wave Format `bext` chunk
+	#[lofty(tag_type = "Bext")]
+	pub(crate) bext_tag: Option<BextChunk>,
 	/// The file's audio properties
 	pub(crate) properties: WavProperties,
+	/// The file's container
+	pub(crate) container: WavContainer,
+	/// The cue points from the `cue ` and `LIST` `adtl` chunks
+	pub(crate) markers: Vec<CuePoint>,
+	/// The `smpl` chunk
+	pub(crate) sampler: Option<SamplerChunk>,
+	/// The `inst` chunk
+	pub(crate) instrument: Option<InstrumentChunk>,
+	/// The `acid` chunk
+	pub(crate) acid: Option<AcidChunk>,
+	/// The `iXML` chunk
+	pub(crate) ixml: Option<String>,
+	/// The `axml` chunk
+	pub(crate) axml: Option<String>,
+}
+
+impl WavFile {
+	/// The file's container
+	///
+	/// # Examples
+	///
+	/// ```rust,no_run
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::iff::wav::{WavContainer, WavFile};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let mut wav_reader = std::io::Cursor::new(&[]);
+	/// let wav_file = WavFile::read_from(&mut wav_reader, ParseOptions::new())?;
+	///
+	/// if wav_file.container() == WavContainer::Rf64 {
+	/// 	println!("File uses 64-bit sizes");
+	/// }
+	/// # Ok(()) }
+	/// ```
+	pub fn container(&self) -> WavContainer {
+		self.container
+	}
+
+	/// Returns the cue points, in the order they appear in the `cue ` chunk
+	pub fn markers(&self) -> &[CuePoint] {
+		&self.markers
+	}
+
+	/// Returns a mutable reference to the cue points
+	pub fn markers_mut(&mut self) -> &mut Vec<CuePoint> {
+		&mut self.markers
+	}
+
+	/// Inserts a [`CuePoint`]
+	///
+	/// If a cue point with the same ID exists, it will be replaced and returned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::iff::wav::{CuePoint, WavFile};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/wav_format_pcm.wav";
+	/// let mut wav_file = WavFile::read_from(&mut std::fs::File::open(path)?, ParseOptions::new())?;
+	///
+	/// let mut marker = CuePoint::new(wav_file.next_marker_id(), 48000);
+	/// marker.label = Some(String::from("Chorus"));
+	///
+	/// assert!(wav_file.insert_marker(marker).is_none());
+	/// assert_eq!(wav_file.markers().len(), 1);
+	/// # Ok(()) }
+	/// ```
+	pub fn insert_marker(&mut self, marker: CuePoint) -> Option<CuePoint> {
+		match self.markers.iter_mut().find(|m| m.id == marker.id) {
+			Some(existing) => Some(std::mem::replace(existing, marker)),
+			None => {
+				self.markers.push(marker);
+				None
+			},
+		}
+	}
+
+	/// Removes the [`CuePoint`] with the ID `id`
+	pub fn remove_marker(&mut self, id: u32) -> Option<CuePoint> {
+		let pos = self.markers.iter().position(|m| m.id == id)?;
+		Some(self.markers.remove(pos))
+	}
+
+	/// Removes all cue points, returning them
+	pub fn remove_markers(&mut self) -> Vec<CuePoint> {
+		std::mem::take(&mut self.markers)
+	}
+
+	/// Returns an unused cue point ID
+	pub fn next_marker_id(&self) -> u32 {
+		self.markers
+			.iter()
+			.map(|m| m.id)
+			.max()
+			.map_or(1, |id| id.saturating_add(1))
+	}
+
+	/// Returns a reference to the `smpl` chunk, if it exists
+	pub fn sampler(&self) -> Option<&SamplerChunk> {
+		self.sampler.as_ref()
+	}
+
+	/// Returns a mutable reference to the `smpl` chunk, if it exists
+	pub fn sampler_mut(&mut self) -> Option<&mut SamplerChunk> {
+		self.sampler.as_mut()
+	}
+
+	/// Sets the `smpl` chunk, returning the old one
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::iff::wav::{SampleLoop, SamplerChunk, WavFile};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/wav_format_pcm.wav";
+	/// let mut wav_file = WavFile::read_from(&mut std::fs::File::open(path)?, ParseOptions::new())?;
+	///
+	/// let mut sampler = SamplerChunk::new(60);
+	/// sampler.loops.push(SampleLoop::new(0, 47999));
+	///
+	/// assert!(wav_file.set_sampler(sampler).is_none());
+	/// assert_eq!(wav_file.sampler().unwrap().loops.len(), 1);
+	/// # Ok(()) }
+	/// ```
+	pub fn set_sampler(&mut self, sampler: SamplerChunk) -> Option<SamplerChunk> {
+		self.sampler.replace(sampler)
+	}
+
+	/// Removes the `smpl` chunk, returning it
+	pub fn remove_sampler(&mut self) -> Option<SamplerChunk> {
+		self.sampler.take()
+	}
+
+	/// Returns a reference to the `inst` chunk, if it exists
+	pub fn instrument(&self) -> Option<&InstrumentChunk> {
+		self.instrument.as_ref()
+	}
+
+	/// Returns a mutable reference to the `inst` chunk, if it exists
+	pub fn instrument_mut(&mut self) -> Option<&mut InstrumentChunk> {
+		self.instrument.as_mut()
+	}
+
+	/// Sets the `inst` chunk, returning the old one
+	pub fn set_instrument(&mut self, instrument: InstrumentChunk) -> Option<InstrumentChunk> {
+		self.instrument.replace(instrument)
+	}
+
+	/// Removes the `inst` chunk, returning it
+	pub fn remove_instrument(&mut self) -> Option<InstrumentChunk> {
+		self.instrument.take()
+	}
+
+	/// Returns a reference to the `acid` chunk, if it exists
+	pub fn acid(&self) -> Option<&AcidChunk> {
+		self.acid.as_ref()
+	}
+
+	/// Returns a mutable reference to the `acid` chunk, if it exists
+	pub fn acid_mut(&mut self) -> Option<&mut AcidChunk> {
+		self.acid.as_mut()
+	}
+
+	/// Sets the `acid` chunk, returning the old one
+	pub fn set_acid(&mut self, acid: AcidChunk) -> Option<AcidChunk> {
+		self.acid.replace(acid)
+	}
+
+	/// Removes the `acid` chunk, returning it
+	pub fn remove_acid(&mut self) -> Option<AcidChunk> {
+		self.acid.take()
+	}
+
+	/// Returns the XML from the `iXML` chunk, if it exists
+	pub fn ixml(&self) -> Option<&str> {
+		self.ixml.as_deref()
+	}
+
+	/// Returns the common fields of the `iXML` chunk, if it exists
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::config::ParseOptions;
+	/// use lofty::file::AudioFile;
+	/// use lofty::iff::wav::WavFile;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// # let path = "tests/files/assets/minimal/wav_format_pcm.wav";
+	/// let mut wav_file = WavFile::read_from(&mut std::fs::File::open(path)?, ParseOptions::new())?;
+	///
+	/// wav_file.set_ixml(String::from(
+	/// 	"<BWFXML><SCENE>12A</SCENE><TAKE>3</TAKE></BWFXML>",
+	/// ));
+	///
+	/// let metadata = wav_file.ixml_metadata().unwrap();
+	/// assert_eq!(metadata.scene.as_deref(), Some("12A"));
+	/// # Ok(()) }
+	/// ```
+	pub fn ixml_metadata(&self) -> Option<IxmlMetadata> {
+		self.ixml.as_deref().map(IxmlMetadata::parse)
+	}
+
+	/// Sets the XML of the `iXML` chunk, returning the old one
+	pub fn set_ixml(&mut self, ixml: String) -> Option<String> {
+		self.ixml.replace(ixml)
+	}
+
+	/// Removes the `iXML` chunk, returning its XML
+	pub fn remove_ixml(&mut self) -> Option<String> {
+		self.ixml.take()
+	}
+
+	/// Returns the XML from the `axml` chunk, if it exists
+	pub fn axml(&self) -> Option<&str> {
+		self.axml.as_deref()
+	}
+
+	/// Sets the XML of the `axml` chunk, returning the old one
+	pub fn set_axml(&mut self, axml: String) -> Option<String> {
+		self.axml.replace(axml)
+	}
+
+	/// Removes the `axml` chunk, returning its XML
+	pub fn remove_axml(&mut self) -> Option<String> {
+		self.axml.take()
+	}
+
+	// We need a special write fn to write the cue points alongside the tags
+	fn write_to<F>(&self, file: &mut F, write_options: WriteOptions) -> Result<()>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		// Every chunk is written in a single pass, to avoid moving the audio data more than once
+		let mut chunks = Vec::new();
+
+		if let Some(ref riff_info) = self.riff_info_tag {
+			chunks.push(tag::write::riff_info_chunk(
+				&mut riff_info
+					.items
+					.iter()
+					.map(|(k, v)| (k.as_str(), v.as_str())),
+			));
+		}
+
+		if let Some(ref id3v2) = self.id3v2_tag {
+			let mut id3v2_bytes = Vec::new();
+			id3v2.dump_to(&mut id3v2_bytes, write_options.clone())?;
+
+			chunks.push((
+				ChunkTarget::id3v2(write_options),
+				(!id3v2_bytes.is_empty()).then_some(id3v2_bytes),
+			));
+		}
+
+		if let Some(ref bext) = self.bext_tag {
+			chunks.push(bext::write::bext_chunk(bext));
+		}
+
+		chunks.extend(markers::write::marker_chunks(&self.markers));
+		chunks.extend(sampler::write::sampler_chunks(
+			self.sampler.as_ref(),
+			self.instrument.as_ref(),
+			self.acid.as_ref(),
+		));
+
+		chunks.push((
+			ChunkTarget::Chunk(*b"iXML"),
+			self.ixml.as_ref().map(|xml| xml.as_bytes().to_vec()),
+		));
+		chunks.push((
+			ChunkTarget::Chunk(*b"axml"),
+			self.axml.as_ref().map(|xml| xml.as_bytes().to_vec()),
+		));
+
+		container::write_chunks(file, chunks)
+	}
+}
+
+impl From<WavFile> for TaggedFile {
+	fn from(value: WavFile) -> Self {
+		TaggedFile {
+			ty: FileType::Wav,
+			properties: value.properties.into(),
+			tags: {
+				let mut tags = Vec::with_capacity(3);
+
+				if let Some(riff_info) = value.riff_info_tag {
+					tags.push(riff_info.into());
+				}
+
+				// Expose the tempo, key, and iXML fields through an ID3v2 tag, creating one if necessary
+				let mut items = Vec::new();
+				if let Some(acid) = value.acid {
+					if acid.tempo.is_finite() && acid.tempo > 0.0 {
+						items.push((ItemKey::IntegerBpm, acid.tempo.round().to_string()));
+					}
+
+					if let Some(key) = acid.key() {
+						items.push((ItemKey::InitialKey, String::from(key)));
+					}
+				}
+
+				if let Some(ixml) = value.ixml {
+					items.extend(IxmlMetadata::parse(&ixml).tag_items());
+				}
+
+				let mut id3v2 = value.id3v2_tag.map(Tag::from);
+				if !items.is_empty() {
+					let id3v2 = id3v2.get_or_insert_with(|| Tag::new(TagType::Id3v2));
+					for (key, value) in items {
+						if id3v2.get(&key).is_none() {
+							id3v2.insert_text(key, value);
+						}
+					}
+				}
+
+				if let Some(id3v2) = id3v2 {
+					tags.push(id3v2);
+				}
+
+				if let Some(bext) = value.bext_tag {
+					tags.push(bext.into());
+				}
+
+				tags
+			},
+		}
+	}
 }
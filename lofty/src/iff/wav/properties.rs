@@ -170,8 +170,8 @@ where
 
 pub(super) fn read_properties(
 	fmt: &mut &[u8],
-	mut total_samples: u32,
-	stream_len: u32,
+	mut total_samples: u64,
+	stream_len: u64,
 	file_length: u64,
 ) -> Result<WavProperties> {
 	if fmt.len() < 16 {
@@ -220,7 +220,7 @@ pub(super) fn read_properties(
 	}
 
 	if bits_per_sample > 0 && (total_samples == 0 || pcm) {
-		total_samples = stream_len / (u32::from(channels) * u32::from(bits_per_sample / 8));
+		total_samples = stream_len / (u64::from(channels) * u64::from(bits_per_sample / 8));
 	}
 
 	let mut duration = Duration::ZERO;
@@ -233,22 +233,25 @@ pub(super) fn read_properties(
 	if sample_rate > 0 && total_samples > 0 {
 		log::debug!("Calculating duration and bitrate from total samples");
 
-		let length = (u64::from(total_samples) * 1000).div_round(u64::from(sample_rate));
+		// RF64 sample counts can be large enough to overflow when converting to milliseconds
+		let length = (u128::from(total_samples) * 1000).div_round(u128::from(sample_rate));
+		let length = u64::try_from(length).unwrap_or(u64::MAX);
 		duration = Duration::from_millis(length);
 		if length > 0 {
-			overall_bitrate = (file_length * 8).div_round(length) as u32;
+			overall_bitrate = bitrate(file_length, length);
 			if audio_bitrate == 0 {
 				log::warn!("Estimating audio bitrate from stream length");
-				audio_bitrate = (u64::from(stream_len) * 8).div_round(length) as u32;
+				audio_bitrate = bitrate(stream_len, length);
 			}
 		}
 	} else if stream_len > 0 && bytes_per_second > 0 {
 		log::debug!("Calculating duration and bitrate from stream length/byte rate");
 
-		let length = (u64::from(stream_len) * 1000).div_round(u64::from(bytes_per_second));
+		let length = (u128::from(stream_len) * 1000).div_round(u128::from(bytes_per_second));
+		let length = u64::try_from(length).unwrap_or(u64::MAX);
 		duration = Duration::from_millis(length);
 		if length > 0 {
-			overall_bitrate = (file_length * 8).div_round(length) as u32;
+			overall_bitrate = bitrate(file_length, length);
 		}
 	} else {
 		log::warn!("Unable to calculate duration and bitrate");
@@ -269,3 +272,8 @@ pub(super) fn read_properties(
 		channel_mask,
 	})
 }
+
+/// The bitrate (kbps) of `len` bytes over `length` milliseconds
+fn bitrate(len: u64, length: u64) -> u32 {
+	(u128::from(len) * 8).div_round(u128::from(length)) as u32
+}
@@ -1,3 +1,4 @@
+use super::container::WavHeader;
 use super::properties::WavProperties;
 use super::tag::RiffInfoList;
 use super::WavFile;
@@ -11,44 +12,33 @@ use std::io::{Read, Seek, SeekFrom};
 
 use byteorder::{LittleEndian, ReadBytesExt};
 
-pub(super) fn verify_wav<T>(data: &mut T) -> Result<()>
-where
-	T: Read + Seek,
-{
-	let mut id = [0; 12];
-	data.read_exact(&mut id)?;
-
-	if &id[..4] != b"RIFF" {
-		decode_err!(@BAIL Wav, "WAV file doesn't contain a RIFF chunk");
-	}
-
-	if &id[8..] != b"WAVE" {
-		decode_err!(@BAIL Wav, "Found RIFF file, format is not WAVE");
-	}
-
-	log::debug!("File verified to be WAV");
-	Ok(())
-}
-
 pub(super) fn read_from<R>(data: &mut R, parse_options: ParseOptions) -> Result<WavFile>
 where
 	R: Read + Seek,
 {
-	verify_wav(data)?;
+	let header = WavHeader::read(data)?;
 
 	let current_pos = data.stream_position()?;
 	let file_len = data.seek(SeekFrom::End(0))?;
 
 	data.seek(SeekFrom::Start(current_pos))?;
 
-	let mut stream_len = 0_u32;
-	let mut total_samples = 0_u32;
+	let mut stream_len = 0_u64;
+	let mut total_samples = 0_u64;
 	let mut fmt = Vec::new();
 
 	let mut riff_info = RiffInfoList::default();
 	let mut id3v2_tag: Option<Id3v2Tag> = None;
+	let mut bext = None;
+	let mut markers = Vec::new();
+	let mut associated_data = Vec::new();
+	let mut sampler = None;
+	let mut instrument = None;
+	let mut acid = None;
+	let mut ixml = None;
+	let mut axml = None;
 
-	let mut chunks = Chunks::<LittleEndian>::new(file_len);
+	let mut chunks = header.chunks(file_len);
 
 	while chunks.next(data).is_ok() {
 		match &chunks.fourcc {
@@ -61,10 +51,19 @@ where
 			},
 			b"fact" if parse_options.read_properties => {
 				if total_samples == 0 {
-					total_samples = data.read_u32::<LittleEndian>()?;
+					total_samples = u64::from(data.read_u32::<LittleEndian>()?);
+
+					// RF64 files store the actual sample count in the `ds64` chunk
+					if total_samples == u64::from(u32::MAX) {
+						total_samples = header.sample_count().unwrap_or(total_samples);
+					}
+
+					data.seek(SeekFrom::Current(chunks.size.saturating_sub(4) as i64))?;
 				} else {
-					data.seek(SeekFrom::Current(4))?;
+					data.seek(SeekFrom::Current(chunks.size as i64))?;
 				}
+
+				chunks.correct_position(data)?;
 			},
 			b"data" if parse_options.read_properties => {
 				if stream_len == 0 {
@@ -88,12 +87,37 @@ where
 					b"INFO" if parse_options.read_tags => {
 						// TODO: We already get the current position above, just keep it up to date and use it here
 						//       to avoid the seeks.
-						let end = data.stream_position()? + u64::from(size);
+						let end = data.stream_position()? + size;
 						if end > file_len {
 							err!(SizeMismatch);
 						}
 
-						super::tag::read::parse_riff_info(data, &mut chunks, end, &mut riff_info)?;
+						let mut info_chunks = Chunks::<LittleEndian>::new(size);
+						super::tag::read::parse_riff_info(
+							data,
+							&mut info_chunks,
+							end,
+							&mut riff_info,
+						)?;
+
+						// Wave64 chunks are padded to 8 byte boundaries
+						data.seek(SeekFrom::Start(end))?;
+						chunks.correct_position(data)?;
+					},
+					b"adtl" => {
+						let end = data.stream_position()? + size;
+						if end > file_len {
+							err!(SizeMismatch);
+						}
+
+						associated_data.extend(super::markers::read::parse_adtl(
+							data,
+							size,
+							parse_options.parsing_mode,
+						)?);
+
+						data.seek(SeekFrom::Start(end))?;
+						chunks.correct_position(data)?;
 					},
 					_ => {
 						data.seek(SeekFrom::Current(-4))?;
@@ -101,8 +125,81 @@ where
 					},
 				}
 			},
+			b"cue " => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				if !markers.is_empty() {
+					log::warn!("Duplicate `cue ` chunk found, discarding");
+					continue;
+				}
+
+				markers = super::markers::read::parse_cue(&content, parse_options.parsing_mode)?;
+			},
+			b"smpl" => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				if sampler.is_some() {
+					log::warn!("Duplicate `smpl` chunk found, discarding");
+					continue;
+				}
+
+				sampler = super::sampler::read::parse_smpl(&content, parse_options.parsing_mode)?;
+			},
+			b"inst" => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				if instrument.is_some() {
+					log::warn!("Duplicate `inst` chunk found, discarding");
+					continue;
+				}
+
+				instrument =
+					super::sampler::read::parse_inst(&content, parse_options.parsing_mode)?;
+			},
+			b"acid" => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				if acid.is_some() {
+					log::warn!("Duplicate `acid` chunk found, discarding");
+					continue;
+				}
+
+				acid = super::sampler::read::parse_acid(&content, parse_options.parsing_mode)?;
+			},
+			b"iXML" | b"axml" => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				let xml = if &chunks.fourcc == b"iXML" {
+					&mut ixml
+				} else {
+					&mut axml
+				};
+
+				if xml.is_some() {
+					log::warn!("Duplicate XML chunk found, discarding");
+					continue;
+				}
+
+				*xml = super::xml::read::parse_xml_chunk(content, parse_options.parsing_mode)?;
+			},
+			b"bext" if parse_options.read_tags => {
+				let content = chunks.content(data)?;
+				chunks.correct_position(data)?;
+
+				if bext.is_some() {
+					log::warn!("Duplicate `bext` chunk found, discarding");
+					continue;
+				}
+
+				bext = super::bext::read::parse_bext(&content, parse_options.parsing_mode)?;
+			},
 			b"ID3 " | b"id3 " if parse_options.read_tags => {
-				let tag = chunks.id3_chunk(data, parse_options)?;
+				let tag = chunks.id3_chunk(data, parse_options.clone())?;
 				if let Some(existing_tag) = id3v2_tag.as_mut() {
 					log::warn!("Duplicate ID3v2 tag found, appending frames to previous tag");
 
@@ -119,6 +216,8 @@ where
 		}
 	}
 
+	super::markers::read::merge_associated_data(&mut markers, associated_data);
+
 	let properties = if parse_options.read_properties {
 		let file_length = data.stream_position()?;
 
@@ -131,5 +230,13 @@ where
 		properties,
 		riff_info_tag: (!riff_info.items.is_empty()).then_some(riff_info),
 		id3v2_tag,
+		bext_tag: bext,
+		container: header.container,
+		markers,
+		sampler,
+		instrument,
+		acid,
+		ixml,
+		axml,
 	})
 }
@@ -0,0 +1,345 @@
+pub(super) mod read;
+pub(super) mod write;
+
+/// The names of the pitch classes, starting at C
+const NOTE_NAMES: [&str; 12] = [
+	"C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// The sampler information from an `smpl` chunk
+///
+/// This describes how the audio should be played back by a sampler, including its loops.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct SamplerChunk {
+	/// The MIDI Manufacturers Association manufacturer code, or `0`
+	pub manufacturer: u32,
+	/// The product code of the intended sampler, or `0`
+	pub product: u32,
+	/// The duration of a single sample in nanoseconds
+	pub sample_period: u32,
+	/// The MIDI note (0-127) at which the audio plays back at its original pitch
+	pub midi_unity_note: u32,
+	/// The fraction of a semitone above `midi_unity_note`, where `0x80000000` is half a semitone
+	pub midi_pitch_fraction: u32,
+	/// The SMPTE format (0, 24, 25, 29, or 30 frames per second)
+	pub smpte_format: u32,
+	/// The SMPTE time offset of the first sample, as `hh:mm:ss:ff` packed into bytes
+	pub smpte_offset: u32,
+	/// The sample loops
+	pub loops: Vec<SampleLoop>,
+	/// Any manufacturer specific data following the loops
+	pub sampler_data: Vec<u8>,
+}
+
+impl SamplerChunk {
+	/// Create a new `SamplerChunk`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::SamplerChunk;
+	///
+	/// // Middle C
+	/// let smpl = SamplerChunk::new(60);
+	/// assert_eq!(smpl.midi_unity_note, 60);
+	/// assert!(smpl.loops.is_empty());
+	/// ```
+	pub fn new(midi_unity_note: u32) -> Self {
+		Self {
+			midi_unity_note,
+			..Self::default()
+		}
+	}
+}
+
+/// A loop in a [`SamplerChunk`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SampleLoop {
+	/// The ID of an associated [`CuePoint`](super::CuePoint), or `0`
+	pub cue_point_id: u32,
+	/// The playback direction of the loop
+	pub loop_type: SampleLoopType,
+	/// The offset of the first sample of the loop
+	pub start: u32,
+	/// The offset of the last sample of the loop
+	pub end: u32,
+	/// The fraction of a sample at which to loop, where `0x80000000` is half a sample
+	pub fraction: u32,
+	/// The number of times to play the loop, where `0` is infinite
+	pub play_count: u32,
+}
+
+impl SampleLoop {
+	/// Create a new infinite, forward `SampleLoop`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::{SampleLoop, SampleLoopType};
+	///
+	/// let sample_loop = SampleLoop::new(0, 47999);
+	/// assert_eq!(sample_loop.loop_type, SampleLoopType::Forward);
+	/// assert_eq!(sample_loop.play_count, 0);
+	/// ```
+	pub fn new(start: u32, end: u32) -> Self {
+		Self {
+			cue_point_id: 0,
+			loop_type: SampleLoopType::Forward,
+			start,
+			end,
+			fraction: 0,
+			play_count: 0,
+		}
+	}
+}
+
+/// The playback direction of a [`SampleLoop`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SampleLoopType {
+	/// Play forward
+	Forward,
+	/// Alternate between forward and backward
+	Alternating,
+	/// Play backward
+	Backward,
+	/// A manufacturer specific loop type
+	Other(u32),
+}
+
+impl From<u32> for SampleLoopType {
+	fn from(value: u32) -> Self {
+		match value {
+			0 => Self::Forward,
+			1 => Self::Alternating,
+			2 => Self::Backward,
+			_ => Self::Other(value),
+		}
+	}
+}
+
+impl From<SampleLoopType> for u32 {
+	fn from(value: SampleLoopType) -> Self {
+		match value {
+			SampleLoopType::Forward => 0,
+			SampleLoopType::Alternating => 1,
+			SampleLoopType::Backward => 2,
+			SampleLoopType::Other(value) => value,
+		}
+	}
+}
+
+/// The instrument information from an `inst` chunk
+///
+/// This describes the note and velocity range the audio should be mapped to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct InstrumentChunk {
+	/// The MIDI note (0-127) at which the audio plays back at its original pitch
+	pub unshifted_note: u8,
+	/// The pitch shift to apply, in cents (-50 to 50)
+	pub fine_tune: i8,
+	/// The gain to apply, in decibels (-64 to 64)
+	pub gain: i8,
+	/// The lowest MIDI note to use the audio for
+	pub low_note: u8,
+	/// The highest MIDI note to use the audio for
+	pub high_note: u8,
+	/// The lowest MIDI velocity to use the audio for
+	pub low_velocity: u8,
+	/// The highest MIDI velocity to use the audio for
+	pub high_velocity: u8,
+}
+
+impl InstrumentChunk {
+	/// Create a new `InstrumentChunk`, covering all notes and velocities
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::InstrumentChunk;
+	///
+	/// let inst = InstrumentChunk::new(60);
+	/// assert_eq!(inst.low_note, 0);
+	/// assert_eq!(inst.high_note, 127);
+	/// ```
+	pub fn new(unshifted_note: u8) -> Self {
+		Self {
+			unshifted_note,
+			fine_tune: 0,
+			gain: 0,
+			low_note: 0,
+			high_note: 127,
+			low_velocity: 1,
+			high_velocity: 127,
+		}
+	}
+}
+
+/// The loop information from an `acid` chunk, written by ACID and other loop-based software
+///
+/// ## Conversions
+///
+/// When converting a [`WavFile`](super::WavFile) to a [`TaggedFile`](crate::file::TaggedFile),
+/// the tempo and root note will be available as [`ItemKey::IntegerBpm`](crate::tag::ItemKey::IntegerBpm)
+/// and [`ItemKey::InitialKey`](crate::tag::ItemKey::InitialKey) respectively.
+#[derive(Copy, Clone, Debug, PartialEq, Default)]
+pub struct AcidChunk {
+	/// The flags of the chunk, see the `FLAG_*` constants
+	pub flags: u32,
+	/// The root note, where `0x30` is C, `0x31` is C#, etc.
+	///
+	/// This is only meaningful if [`AcidChunk::FLAG_ROOT_NOTE`] is set.
+	pub root_note: u16,
+	/// An unknown value, preserved when writing
+	pub unknown1: u16,
+	/// An unknown value, preserved when writing
+	pub unknown2: f32,
+	/// The number of beats in the audio
+	pub beats: u32,
+	/// The denominator of the time signature
+	pub meter_denominator: u16,
+	/// The numerator of the time signature
+	pub meter_numerator: u16,
+	/// The tempo in beats per minute
+	pub tempo: f32,
+}
+
+impl AcidChunk {
+	/// The audio is a one-shot, rather than a loop
+	pub const FLAG_ONE_SHOT: u32 = 0x01;
+	/// The [`root_note`](AcidChunk::root_note) is set
+	pub const FLAG_ROOT_NOTE: u32 = 0x02;
+	/// The audio should be stretched to match the project tempo
+	pub const FLAG_STRETCH: u32 = 0x04;
+	/// The audio should be streamed from the disk
+	pub const FLAG_DISK_BASED: u32 = 0x08;
+
+	/// Create a new `AcidChunk` with a tempo and a 4/4 time signature
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::AcidChunk;
+	///
+	/// let acid = AcidChunk::new(120.0, 8);
+	/// assert_eq!(acid.meter_numerator, 4);
+	/// assert!(acid.key().is_none());
+	/// ```
+	pub fn new(tempo: f32, beats: u32) -> Self {
+		Self {
+			flags: Self::FLAG_STRETCH,
+			root_note: 0x30,
+			beats,
+			meter_denominator: 4,
+			meter_numerator: 4,
+			tempo,
+			..Self::default()
+		}
+	}
+
+	/// Whether the audio is a one-shot, rather than a loop
+	pub fn is_one_shot(&self) -> bool {
+		self.flags & Self::FLAG_ONE_SHOT != 0
+	}
+
+	/// The name of the root note, if it is set
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::AcidChunk;
+	///
+	/// let mut acid = AcidChunk::new(120.0, 8);
+	/// acid.set_root_note(0x33);
+	///
+	/// assert_eq!(acid.key(), Some("D#"));
+	/// ```
+	pub fn key(&self) -> Option<&'static str> {
+		if self.flags & Self::FLAG_ROOT_NOTE == 0 {
+			return None;
+		}
+
+		Some(NOTE_NAMES[usize::from(self.root_note % 12)])
+	}
+
+	/// Set the root note, where `0x30` is C, `0x31` is C#, etc.
+	///
+	/// This will also set [`AcidChunk::FLAG_ROOT_NOTE`].
+	pub fn set_root_note(&mut self, root_note: u16) {
+		self.root_note = root_note;
+		self.flags |= Self::FLAG_ROOT_NOTE;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{AcidChunk, InstrumentChunk, SampleLoop, SampleLoopType, SamplerChunk};
+	use crate::config::ParsingMode;
+
+	#[test_log::test]
+	fn smpl_re_read() {
+		let mut smpl = SamplerChunk::new(60);
+		smpl.sample_period = 20833;
+		smpl.loops.push(SampleLoop::new(0, 47999));
+		smpl.loops.push(SampleLoop {
+			cue_point_id: 2,
+			loop_type: SampleLoopType::Other(32),
+			play_count: 4,
+			..SampleLoop::new(48000, 95999)
+		});
+		smpl.sampler_data = vec![1, 2, 3, 4];
+
+		let content = super::write::create_smpl(&smpl);
+		let parsed = super::read::parse_smpl(&content, ParsingMode::Strict)
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, smpl);
+	}
+
+	#[test_log::test]
+	fn inst_re_read() {
+		let inst = InstrumentChunk {
+			fine_tune: -12,
+			gain: -6,
+			..InstrumentChunk::new(57)
+		};
+
+		let content = super::write::create_inst(inst);
+		let parsed = super::read::parse_inst(&content, ParsingMode::Strict)
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, inst);
+	}
+
+	#[test_log::test]
+	fn acid_re_read() {
+		let mut acid = AcidChunk::new(93.5, 16);
+		acid.set_root_note(0x3B);
+
+		let content = super::write::create_acid(&acid);
+		let parsed = super::read::parse_acid(&content, ParsingMode::Strict)
+			.unwrap()
+			.unwrap();
+
+		assert_eq!(parsed, acid);
+		assert_eq!(parsed.key(), Some("B"));
+	}
+
+	#[test_log::test]
+	fn smpl_too_few_loops() {
+		let mut smpl = SamplerChunk::new(60);
+		smpl.loops.push(SampleLoop::new(0, 1024));
+
+		let mut content = super::write::create_smpl(&smpl);
+		// Claim there are 2 loops
+		content[28] = 2;
+
+		assert!(super::read::parse_smpl(&content, ParsingMode::Strict).is_err());
+
+		let parsed = super::read::parse_smpl(&content, ParsingMode::BestAttempt)
+			.unwrap()
+			.unwrap();
+		assert_eq!(parsed.loops.len(), 1);
+	}
+}
@@ -0,0 +1,124 @@
+use super::{AcidChunk, InstrumentChunk, SampleLoop, SamplerChunk};
+use crate::config::ParsingMode;
+use crate::error::Result;
+use crate::macros::{decode_err, parse_mode_choice};
+
+use byteorder::{ByteOrder, LittleEndian};
+
+/// The size of the fixed fields of the `smpl` chunk, before the loops
+pub(super) const SMPL_FIXED_SIZE: usize = 36;
+
+/// The size of a single loop in the `smpl` chunk
+pub(super) const SAMPLE_LOOP_SIZE: usize = 24;
+
+/// The size of the `inst` chunk
+pub(super) const INST_SIZE: usize = 7;
+
+/// The size of the `acid` chunk
+pub(super) const ACID_SIZE: usize = 24;
+
+pub(in crate::iff::wav) fn parse_smpl(
+	content: &[u8],
+	parse_mode: ParsingMode,
+) -> Result<Option<SamplerChunk>> {
+	if content.len() < SMPL_FIXED_SIZE {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Wav, "Sampler (`smpl`) chunk is too small"),
+			DEFAULT: return Ok(None)
+		);
+	}
+
+	let field = |index: usize| LittleEndian::read_u32(&content[index * 4..index * 4 + 4]);
+
+	let loop_count = field(7) as usize;
+	let sampler_data_size = field(8) as usize;
+
+	let loops_content = &content[SMPL_FIXED_SIZE..];
+	let loop_entries = loops_content.chunks_exact(SAMPLE_LOOP_SIZE);
+	if loop_entries.len() < loop_count {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Wav, "Sampler (`smpl`) chunk has fewer loops than expected"),
+		);
+	}
+
+	let mut loops = Vec::with_capacity(loop_count.min(loop_entries.len()));
+	for entry in loop_entries.take(loop_count) {
+		let entry_field = |index: usize| LittleEndian::read_u32(&entry[index * 4..index * 4 + 4]);
+
+		loops.push(SampleLoop {
+			cue_point_id: entry_field(0),
+			loop_type: entry_field(1).into(),
+			start: entry_field(2),
+			end: entry_field(3),
+			fraction: entry_field(4),
+			play_count: entry_field(5),
+		});
+	}
+
+	let sampler_data_start = loops.len() * SAMPLE_LOOP_SIZE;
+	let sampler_data = loops_content
+		.get(sampler_data_start..)
+		.map(|data| data[..sampler_data_size.min(data.len())].to_vec())
+		.unwrap_or_default();
+
+	Ok(Some(SamplerChunk {
+		manufacturer: field(0),
+		product: field(1),
+		sample_period: field(2),
+		midi_unity_note: field(3),
+		midi_pitch_fraction: field(4),
+		smpte_format: field(5),
+		smpte_offset: field(6),
+		loops,
+		sampler_data,
+	}))
+}
+
+pub(in crate::iff::wav) fn parse_inst(
+	content: &[u8],
+	parse_mode: ParsingMode,
+) -> Result<Option<InstrumentChunk>> {
+	if content.len() < INST_SIZE {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Wav, "Instrument (`inst`) chunk is too small"),
+			DEFAULT: return Ok(None)
+		);
+	}
+
+	Ok(Some(InstrumentChunk {
+		unshifted_note: content[0],
+		fine_tune: content[1] as i8,
+		gain: content[2] as i8,
+		low_note: content[3],
+		high_note: content[4],
+		low_velocity: content[5],
+		high_velocity: content[6],
+	}))
+}
+
+pub(in crate::iff::wav) fn parse_acid(
+	content: &[u8],
+	parse_mode: ParsingMode,
+) -> Result<Option<AcidChunk>> {
+	if content.len() < ACID_SIZE {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Wav, "ACID (`acid`) chunk is too small"),
+			DEFAULT: return Ok(None)
+		);
+	}
+
+	Ok(Some(AcidChunk {
+		flags: LittleEndian::read_u32(&content[..4]),
+		root_note: LittleEndian::read_u16(&content[4..6]),
+		unknown1: LittleEndian::read_u16(&content[6..8]),
+		unknown2: LittleEndian::read_f32(&content[8..12]),
+		beats: LittleEndian::read_u32(&content[12..16]),
+		meter_denominator: LittleEndian::read_u16(&content[16..18]),
+		meter_numerator: LittleEndian::read_u16(&content[18..20]),
+		tempo: LittleEndian::read_f32(&content[20..24]),
+	}))
+}
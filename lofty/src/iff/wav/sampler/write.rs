@@ -0,0 +1,78 @@
+use super::read::{ACID_SIZE, INST_SIZE, SAMPLE_LOOP_SIZE, SMPL_FIXED_SIZE};
+use super::{AcidChunk, InstrumentChunk, SamplerChunk};
+use crate::iff::wav::container::ChunkTarget;
+
+/// The `smpl`, `inst`, and `acid` chunks for [`write_chunks`](crate::iff::wav::container::write_chunks)
+///
+/// Any chunk that is `None` will be removed.
+pub(in crate::iff::wav) fn sampler_chunks(
+	smpl: Option<&SamplerChunk>,
+	inst: Option<&InstrumentChunk>,
+	acid: Option<&AcidChunk>,
+) -> [(ChunkTarget, Option<Vec<u8>>); 3] {
+	[
+		(ChunkTarget::Chunk(*b"smpl"), smpl.map(create_smpl)),
+		(ChunkTarget::Chunk(*b"inst"), inst.copied().map(create_inst)),
+		(ChunkTarget::Chunk(*b"acid"), acid.map(create_acid)),
+	]
+}
+
+/// Create the content of an `smpl` chunk
+pub(super) fn create_smpl(smpl: &SamplerChunk) -> Vec<u8> {
+	let mut content = Vec::with_capacity(
+		SMPL_FIXED_SIZE + smpl.loops.len() * SAMPLE_LOOP_SIZE + smpl.sampler_data.len(),
+	);
+
+	content.extend(smpl.manufacturer.to_le_bytes());
+	content.extend(smpl.product.to_le_bytes());
+	content.extend(smpl.sample_period.to_le_bytes());
+	content.extend(smpl.midi_unity_note.to_le_bytes());
+	content.extend(smpl.midi_pitch_fraction.to_le_bytes());
+	content.extend(smpl.smpte_format.to_le_bytes());
+	content.extend(smpl.smpte_offset.to_le_bytes());
+	content.extend((smpl.loops.len() as u32).to_le_bytes());
+	content.extend((smpl.sampler_data.len() as u32).to_le_bytes());
+
+	for sample_loop in &smpl.loops {
+		content.extend(sample_loop.cue_point_id.to_le_bytes());
+		content.extend(u32::from(sample_loop.loop_type).to_le_bytes());
+		content.extend(sample_loop.start.to_le_bytes());
+		content.extend(sample_loop.end.to_le_bytes());
+		content.extend(sample_loop.fraction.to_le_bytes());
+		content.extend(sample_loop.play_count.to_le_bytes());
+	}
+
+	content.extend(&smpl.sampler_data);
+	content
+}
+
+/// Create the content of an `inst` chunk
+pub(super) fn create_inst(inst: InstrumentChunk) -> Vec<u8> {
+	let content: [u8; INST_SIZE] = [
+		inst.unshifted_note,
+		inst.fine_tune as u8,
+		inst.gain as u8,
+		inst.low_note,
+		inst.high_note,
+		inst.low_velocity,
+		inst.high_velocity,
+	];
+
+	content.to_vec()
+}
+
+/// Create the content of an `acid` chunk
+pub(super) fn create_acid(acid: &AcidChunk) -> Vec<u8> {
+	let mut content = Vec::with_capacity(ACID_SIZE);
+
+	content.extend(acid.flags.to_le_bytes());
+	content.extend(acid.root_note.to_le_bytes());
+	content.extend(acid.unknown1.to_le_bytes());
+	content.extend(acid.unknown2.to_le_bytes());
+	content.extend(acid.beats.to_le_bytes());
+	content.extend(acid.meter_denominator.to_le_bytes());
+	content.extend(acid.meter_numerator.to_le_bytes());
+	content.extend(acid.tempo.to_le_bytes());
+
+	content
+}
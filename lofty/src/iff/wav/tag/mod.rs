@@ -1,5 +1,5 @@
 pub(super) mod read;
-mod write;
+pub(super) mod write;
 
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
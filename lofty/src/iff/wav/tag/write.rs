@@ -1,15 +1,9 @@
 use super::RIFFInfoListRef;
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
-use crate::iff::chunk::Chunks;
-use crate::iff::wav::read::verify_wav;
-use crate::macros::err;
+use crate::iff::wav::container::{write_chunks, ChunkTarget, WavContainer};
 use crate::util::io::{FileLike, Length, Truncate};
 
-use std::io::{Read, Seek, SeekFrom};
-
-use byteorder::{LittleEndian, WriteBytesExt};
-
 pub(in crate::iff::wav) fn write_riff_info<'a, F, I>(
 	file: &mut F,
 	tag: &mut RIFFInfoListRef<'a, I>,
@@ -21,89 +15,49 @@ where
 	LoftyError: From<<F as Length>::Error>,
 	I: Iterator<Item = (&'a str, &'a str)>,
 {
-	verify_wav(file)?;
-	let file_len = file.len()?.saturating_sub(12);
-
-	let mut riff_info_bytes = Vec::new();
-	create_riff_info(&mut tag.items, &mut riff_info_bytes)?;
-
-	let Some(info_list_size) = find_info_list(file, file_len)? else {
-		// Simply append the info list to the end of the file and update the file size
-		file.seek(SeekFrom::End(0))?;
-
-		file.write_all(&riff_info_bytes)?;
-
-		let len = (file.stream_position()? - 8) as u32;
-
-		file.seek(SeekFrom::Start(4))?;
-		file.write_u32::<LittleEndian>(len)?;
-
-		return Ok(());
-	};
-
-	// Replace the existing tag
-
-	let info_list_start = file.seek(SeekFrom::Current(-12))? as usize;
-	let info_list_end = info_list_start + 8 + info_list_size as usize;
-
-	file.rewind()?;
-
-	let mut file_bytes = Vec::new();
-	file.read_to_end(&mut file_bytes)?;
-
-	let _ = file_bytes.splice(info_list_start..info_list_end, riff_info_bytes);
-
-	let total_size = (file_bytes.len() - 8) as u32;
-	let _ = file_bytes.splice(4..8, total_size.to_le_bytes());
-
-	file.rewind()?;
-	file.truncate(0)?;
-	file.write_all(&file_bytes)?;
-
-	Ok(())
+	write_chunks(file, [riff_info_chunk(&mut tag.items)])
 }
 
-fn find_info_list<R>(data: &mut R, file_size: u64) -> Result<Option<u32>>
-where
-	R: Read + Seek,
-{
-	let mut info = None;
-
-	let mut chunks = Chunks::<LittleEndian>::new(file_size);
-
-	while chunks.next(data).is_ok() {
-		if &chunks.fourcc == b"LIST" {
-			let mut list_type = [0; 4];
-			data.read_exact(&mut list_type)?;
-
-			if &list_type == b"INFO" {
-				log::debug!("Found existing RIFF INFO list, size: {} bytes", chunks.size);
-
-				info = Some(chunks.size);
-				break;
-			}
-
-			data.seek(SeekFrom::Current(-8))?;
-		}
+/// The RIFF INFO list for [`write_chunks`]
+pub(in crate::iff::wav) fn riff_info_chunk(
+	items: &mut dyn Iterator<Item = (&str, &str)>,
+) -> (ChunkTarget, Option<Vec<u8>>) {
+	let mut riff_info_content = Vec::new();
+	create_riff_info_content(items, &mut riff_info_content);
 
-		chunks.skip(data)?;
+	if riff_info_content.is_empty() {
+		log::debug!("No items to write, removing RIFF INFO list");
+		return (ChunkTarget::List(*b"INFO"), None);
 	}
 
-	Ok(info)
+	(ChunkTarget::List(*b"INFO"), Some(riff_info_content))
 }
 
 pub(super) fn create_riff_info(
 	items: &mut dyn Iterator<Item = (&str, &str)>,
 	bytes: &mut Vec<u8>,
 ) -> Result<()> {
-	let mut items = items.peekable();
+	let mut content = Vec::new();
+	create_riff_info_content(items, &mut content);
 
-	if items.peek().is_none() {
+	if content.is_empty() {
 		log::debug!("No items to write, removing RIFF INFO list");
 		return Ok(());
 	}
 
-	bytes.extend(b"LIST");
+	bytes.extend(WavContainer::Riff.create_chunk(*b"LIST", &content)?);
+
+	log::debug!("Created RIFF INFO list, size: {} bytes", content.len());
+	Ok(())
+}
+
+fn create_riff_info_content(items: &mut dyn Iterator<Item = (&str, &str)>, bytes: &mut Vec<u8>) {
+	let mut items = items.peekable();
+
+	if items.peek().is_none() {
+		return;
+	}
+
 	bytes.extend(b"INFO");
 
 	for (k, v) in items {
@@ -123,20 +77,4 @@ pub(super) fn create_riff_info(
 		bytes.extend(val_b);
 		bytes.extend(terminator);
 	}
-
-	let packet_size = Vec::len(bytes) - 4;
-
-	if packet_size > u32::MAX as usize {
-		err!(TooMuchData);
-	}
-
-	log::debug!("Created RIFF INFO list, size: {} bytes", packet_size);
-	let size = (packet_size as u32).to_le_bytes();
-
-	#[allow(clippy::needless_range_loop)]
-	for i in 0..4 {
-		bytes.insert(i + 4, size[i]);
-	}
-
-	Ok(())
 }
@@ -0,0 +1,253 @@
+pub(super) mod read;
+
+use crate::tag::ItemKey;
+
+use std::borrow::Cow;
+
+/// A view of the common fields of an `iXML` chunk
+///
+/// This is not a complete iXML parser, only the fields listed below are extracted. The raw XML is
+/// available with [`WavFile::ixml`](super::WavFile::ixml).
+///
+/// ## Conversions
+///
+/// When converting a [`WavFile`](super::WavFile) to a [`TaggedFile`](crate::file::TaggedFile),
+/// the fields will be mapped as follows:
+///
+/// | iXML         | [`ItemKey`]                                                                   |
+/// |--------------|-------------------------------------------------------------------------------|
+/// | `PROJECT`    | [`ItemKey::AlbumTitle`]                                                       |
+/// | `SCENE`      | [`ItemKey::ContentGroup`]                                                     |
+/// | `TAKE`       | [`ItemKey::TrackTitle`]                                                       |
+/// | `TAPE`       | [`ItemKey::SetSubtitle`]                                                      |
+/// | `NOTE`       | [`ItemKey::Comment`]                                                          |
+/// | `TRACK_LIST` | [`ItemKey::TrackSubtitle`] (`index: name` for each track, separated by `; `) |
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct IxmlMetadata {
+	/// The name of the project
+	pub project: Option<String>,
+	/// The scene name
+	pub scene: Option<String>,
+	/// The take name
+	pub take: Option<String>,
+	/// The name of the tape (or roll, card, etc.)
+	pub tape: Option<String>,
+	/// A note about the take
+	pub note: Option<String>,
+	/// The tracks from `TRACK_LIST`
+	pub tracks: Vec<IxmlTrack>,
+}
+
+/// A track from the `TRACK_LIST` of an `iXML` chunk
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct IxmlTrack {
+	/// The channel index, starting at 1
+	pub channel_index: Option<u32>,
+	/// The index of the track in the interleaved audio, starting at 1
+	pub interleave_index: Option<u32>,
+	/// The name of the track, such as "Boom"
+	pub name: Option<String>,
+	/// The function of the track, such as "M-MID_SIDE"
+	pub function: Option<String>,
+}
+
+impl IxmlMetadata {
+	/// Extract the common fields from an iXML document
+	///
+	/// Missing fields are left empty, this never fails.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::iff::wav::IxmlMetadata;
+	///
+	/// let ixml = "<BWFXML><SCENE>12A</SCENE><TAKE>3</TAKE></BWFXML>";
+	/// let metadata = IxmlMetadata::parse(ixml);
+	///
+	/// assert_eq!(metadata.scene.as_deref(), Some("12A"));
+	/// assert_eq!(metadata.take.as_deref(), Some("3"));
+	/// assert!(metadata.tracks.is_empty());
+	/// ```
+	pub fn parse(ixml: &str) -> Self {
+		let mut tracks = Vec::new();
+		if let Some(track_list) = element(ixml, "TRACK_LIST") {
+			for track in elements(track_list, "TRACK") {
+				tracks.push(IxmlTrack {
+					channel_index: text(track, "CHANNEL_INDEX").and_then(|i| i.parse().ok()),
+					interleave_index: text(track, "INTERLEAVE_INDEX").and_then(|i| i.parse().ok()),
+					name: text(track, "NAME"),
+					function: text(track, "FUNCTION"),
+				});
+			}
+		}
+
+		Self {
+			project: text(ixml, "PROJECT"),
+			scene: text(ixml, "SCENE"),
+			take: text(ixml, "TAKE"),
+			tape: text(ixml, "TAPE"),
+			note: text(ixml, "NOTE"),
+			tracks,
+		}
+	}
+
+	pub(crate) fn tag_items(&self) -> Vec<(ItemKey, String)> {
+		let mut items = Vec::new();
+
+		let fields = [
+			(ItemKey::AlbumTitle, &self.project),
+			(ItemKey::ContentGroup, &self.scene),
+			(ItemKey::TrackTitle, &self.take),
+			(ItemKey::SetSubtitle, &self.tape),
+			(ItemKey::Comment, &self.note),
+		];
+
+		for (key, value) in fields {
+			if let Some(value) = value {
+				items.push((key, value.clone()));
+			}
+		}
+
+		let track_names = self
+			.tracks
+			.iter()
+			.filter_map(|track| {
+				let name = track.name.as_deref()?;
+				let index = track.channel_index.or(track.interleave_index)?;
+				Some(format!("{index}: {name}"))
+			})
+			.collect::<Vec<_>>();
+
+		if !track_names.is_empty() {
+			items.push((ItemKey::TrackSubtitle, track_names.join("; ")));
+		}
+
+		items
+	}
+}
+
+/// Get the content of the first `<name>` element
+fn element<'a>(xml: &'a str, name: &str) -> Option<&'a str> {
+	elements(xml, name).next()
+}
+
+/// Get the content of all `<name>` elements
+fn elements<'a>(mut xml: &'a str, name: &str) -> impl Iterator<Item = &'a str> {
+	let open = format!("<{name}>");
+	let close = format!("</{name}>");
+
+	std::iter::from_fn(move || {
+		let start = xml.find(&open)? + open.len();
+		let len = xml[start..].find(&close)?;
+
+		let content = &xml[start..start + len];
+		xml = &xml[start + len + close.len()..];
+
+		Some(content)
+	})
+}
+
+/// Get the unescaped text of the first `<name>` element, if it isn't empty
+fn text(xml: &str, name: &str) -> Option<String> {
+	let content = element(xml, name)?.trim();
+	if content.is_empty() {
+		return None;
+	}
+
+	Some(unescape(content).into_owned())
+}
+
+fn unescape(text: &str) -> Cow<'_, str> {
+	if !text.contains('&') {
+		return Cow::Borrowed(text);
+	}
+
+	Cow::Owned(
+		text.replace("&lt;", "<")
+			.replace("&gt;", ">")
+			.replace("&quot;", "\"")
+			.replace("&apos;", "'")
+			.replace("&amp;", "&"),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{IxmlMetadata, IxmlTrack};
+	use crate::tag::ItemKey;
+
+	const IXML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<BWFXML>
+	<IXML_VERSION>2.10</IXML_VERSION>
+	<PROJECT>Foo &amp; Bar</PROJECT>
+	<SCENE>12A</SCENE>
+	<TAKE>3</TAKE>
+	<TAKE_TYPE>DEFAULT</TAKE_TYPE>
+	<TAPE>DAY01</TAPE>
+	<NOTE></NOTE>
+	<TRACK_LIST>
+		<TRACK_COUNT>2</TRACK_COUNT>
+		<TRACK>
+			<CHANNEL_INDEX>1</CHANNEL_INDEX>
+			<INTERLEAVE_INDEX>1</INTERLEAVE_INDEX>
+			<NAME>Boom</NAME>
+		</TRACK>
+		<TRACK>
+			<CHANNEL_INDEX>2</CHANNEL_INDEX>
+			<INTERLEAVE_INDEX>2</INTERLEAVE_INDEX>
+			<NAME>Lav 1</NAME>
+			<FUNCTION>LEFT</FUNCTION>
+		</TRACK>
+	</TRACK_LIST>
+</BWFXML>"#;
+
+	#[test_log::test]
+	fn parse_ixml() {
+		let metadata = IxmlMetadata::parse(IXML);
+
+		let expected = IxmlMetadata {
+			project: Some(String::from("Foo & Bar")),
+			scene: Some(String::from("12A")),
+			take: Some(String::from("3")),
+			tape: Some(String::from("DAY01")),
+			note: None,
+			tracks: vec![
+				IxmlTrack {
+					channel_index: Some(1),
+					interleave_index: Some(1),
+					name: Some(String::from("Boom")),
+					function: None,
+				},
+				IxmlTrack {
+					channel_index: Some(2),
+					interleave_index: Some(2),
+					name: Some(String::from("Lav 1")),
+					function: Some(String::from("LEFT")),
+				},
+			],
+		};
+
+		assert_eq!(metadata, expected);
+	}
+
+	#[test_log::test]
+	fn ixml_tag_items() {
+		let items = IxmlMetadata::parse(IXML).tag_items();
+
+		assert_eq!(
+			items,
+			vec![
+				(ItemKey::AlbumTitle, String::from("Foo & Bar")),
+				(ItemKey::ContentGroup, String::from("12A")),
+				(ItemKey::TrackTitle, String::from("3")),
+				(ItemKey::SetSubtitle, String::from("DAY01")),
+				(ItemKey::TrackSubtitle, String::from("1: Boom; 2: Lav 1")),
+			]
+		);
+	}
+
+	#[test_log::test]
+	fn parse_not_ixml() {
+		assert_eq!(IxmlMetadata::parse("<foo>"), IxmlMetadata::default());
+	}
+}
@@ -0,0 +1,26 @@
+use crate::config::ParsingMode;
+use crate::error::Result;
+use crate::macros::{decode_err, parse_mode_choice};
+use crate::util::text::utf8_decode;
+
+/// Parse the content of an `iXML` or `axml` chunk
+///
+/// Some writers reserve space for later edits by padding the XML with nulls, those are removed.
+pub(in crate::iff::wav) fn parse_xml_chunk(
+	content: Vec<u8>,
+	parse_mode: ParsingMode,
+) -> Result<Option<String>> {
+	let Ok(xml) = utf8_decode(content) else {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Wav, "XML chunk contains invalid UTF-8"),
+			DEFAULT: return Ok(None)
+		);
+	};
+
+	if xml.is_empty() {
+		return Ok(None);
+	}
+
+	Ok(Some(xml))
+}
@@ -109,6 +109,7 @@ pub(crate) mod _this_is_internal {}
 pub mod config;
 pub mod error;
 pub mod file;
+pub mod lyrics;
 pub(crate) mod macros;
 pub mod picture;
 pub mod probe;
@@ -119,13 +120,18 @@ mod util;
 
 pub mod aac;
 pub mod ape;
+pub mod asf;
+pub mod caf;
+pub mod dsd;
 pub mod flac;
 pub mod id3;
 pub mod iff;
+pub mod matroska;
 pub mod mp4;
 pub mod mpeg;
 pub mod musepack;
 pub mod ogg;
+pub mod tta;
 pub mod wavpack;
 
 pub use crate::probe::{read_from, read_from_path};
@@ -0,0 +1,539 @@
+use crate::config::ParsingMode;
+use crate::error::{LoftyError, Result};
+use crate::macros::err;
+use crate::tag::items::{SyncedLyrics, SyncedLyricsLine, SyncedLyricsWord};
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The ID tag storing [`Lrc::offset`]
+const OFFSET_TAG: &str = "offset";
+
+/// The largest timestamp (in milliseconds) that can be parsed, the largest that a `SYLT` frame can store
+const MAX_TIMESTAMP_MILLIS: u64 = u32::MAX as u64;
+
+/// [LRC] lyrics
+///
+/// LRC is a plain text format for synchronized lyrics, with each line prefixed by its timestamp.
+/// The lines can be preceded by ID tags, such as the artist, or an offset for all timestamps.
+/// Enhanced LRC additionally allows for the timing of individual words:
+///
+/// ```text
+/// [ar:Foo artist]
+/// [offset:+250]
+/// [00:12.00]First line
+/// [00:17.20]<00:17.20>Second <00:17.80>line
+/// ```
+///
+/// [LRC]: https://en.wikipedia.org/wiki/LRC_(file_format)
+///
+/// # Examples
+///
+/// ```rust
+/// use lofty::lyrics::Lrc;
+/// use std::time::Duration;
+///
+/// # fn main() -> lofty::error::Result<()> {
+/// let lrc: Lrc = "[ar:Foo artist]\n[00:12.00]First line\n[00:17.20]<00:17.20>Second <00:17.80>line"
+/// 	.parse()?;
+///
+/// assert_eq!(lrc.id_tag("ar"), Some("Foo artist"));
+/// assert_eq!(lrc.lines[1].text, "Second line");
+/// assert_eq!(lrc.lines[1].words[1].timestamp, Duration::from_millis(17_800));
+///
+/// // And back to text
+/// assert!(lrc
+/// 	.to_string()
+/// 	.ends_with("[00:17.20]<00:17.20>Second <00:17.80>line\n"));
+/// # Ok(()) }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Lrc {
+	/// ID tags (`[key:value]`), such as `("ar", "Foo artist")`
+	///
+	/// This doesn't include the `offset` tag, see [`Lrc::offset`].
+	pub id_tags: Vec<(String, String)>,
+	/// An adjustment, in milliseconds, for every timestamp
+	///
+	/// A positive offset makes the lyrics appear sooner.
+	pub offset: i64,
+	/// The lines, in order of their timestamps
+	///
+	/// NOTE: The timestamps are stored as written, without the offset applied. See [`Lrc::to_synced_lyrics`].
+	pub lines: Vec<SyncedLyricsLine>,
+}
+
+impl Lrc {
+	/// Parse LRC lyrics
+	///
+	/// Lines with multiple timestamps (`[00:12.00][00:30.00]Chorus`) will be repeated for each
+	/// timestamp. Empty lines are ignored.
+	///
+	/// # Errors
+	///
+	/// * A line isn't an ID tag or a timed line (only in [`ParsingMode::Strict`])
+	/// * A timestamp or offset is invalid, including timestamps greater than `u32::MAX` milliseconds
+	///   (only in [`ParsingMode::Strict`])
+	pub fn parse(input: &str, parse_mode: ParsingMode) -> Result<Self> {
+		let mut lrc = Self::default();
+
+		let input = input.strip_prefix('\u{FEFF}').unwrap_or(input);
+		for line in input.lines() {
+			let line = line.trim();
+			if line.is_empty() {
+				continue;
+			}
+
+			if let Err(e) = lrc.parse_line(line) {
+				if parse_mode == ParsingMode::Strict {
+					return Err(e);
+				}
+
+				log::warn!("Skipping invalid LRC line {line:?}: {e}");
+			}
+		}
+
+		// Lines with multiple timestamps are split up, so they may be out of order
+		lrc.lines.sort_by_key(|line| line.timestamp);
+
+		Ok(lrc)
+	}
+
+	fn parse_line(&mut self, line: &str) -> Result<()> {
+		let mut timestamps = Vec::new();
+
+		let mut rest = line;
+		while let Some(tag) = rest.strip_prefix('[') {
+			let Some((content, remaining)) = tag.split_once(']') else {
+				err!(BadLrc("Found an unterminated tag"));
+			};
+
+			match parse_timestamp(content) {
+				Some(timestamp) => timestamps.push(timestamp),
+				// ID tags take up the entire line
+				None if timestamps.is_empty() && remaining.trim().is_empty() => {
+					return self.parse_id_tag(content);
+				},
+				None => err!(BadLrc("Found an invalid timestamp")),
+			}
+
+			rest = remaining;
+		}
+
+		let Some(&first_timestamp) = timestamps.first() else {
+			err!(BadLrc("Line doesn't start with a tag"));
+		};
+
+		let parsed = parse_line_text(first_timestamp, rest.trim_start());
+		for timestamp in timestamps {
+			let mut line = parsed.clone();
+			line.timestamp = timestamp;
+
+			// Word timestamps are relative to the line they're in
+			for word in &mut line.words {
+				word.timestamp =
+					timestamp.saturating_add(word.timestamp.saturating_sub(first_timestamp));
+			}
+
+			self.lines.push(line);
+		}
+
+		Ok(())
+	}
+
+	fn parse_id_tag(&mut self, content: &str) -> Result<()> {
+		let Some((key, value)) = content.split_once(':') else {
+			err!(BadLrc("Found an ID tag without a value"));
+		};
+
+		let key = key.trim();
+		if key.is_empty() || key.starts_with(|c: char| c.is_ascii_digit()) {
+			err!(BadLrc("Found an invalid ID tag"));
+		}
+
+		let value = value.trim();
+		if key.eq_ignore_ascii_case(OFFSET_TAG) {
+			let Ok(offset) = value.parse() else {
+				err!(BadLrc("Found an invalid offset"));
+			};
+
+			self.offset = offset;
+			return Ok(());
+		}
+
+		self.id_tags.push((key.to_string(), value.to_string()));
+		Ok(())
+	}
+
+	/// Get the value of an ID tag
+	///
+	/// NOTE: The key is case-insensitive.
+	pub fn id_tag(&self, key: &str) -> Option<&str> {
+		self.id_tags
+			.iter()
+			.find(|(k, _)| k.eq_ignore_ascii_case(key))
+			.map(|(_, value)| value.as_str())
+	}
+
+	/// Create LRC lyrics from [`SyncedLyrics`]
+	///
+	/// NOTE: The language and description of the lyrics can't be stored in LRC, and are discarded.
+	pub fn from_synced_lyrics(lyrics: &SyncedLyrics) -> Self {
+		Self {
+			id_tags: Vec::new(),
+			offset: 0,
+			lines: lyrics.lines.clone(),
+		}
+	}
+
+	/// Convert the lyrics to [`SyncedLyrics`]
+	///
+	/// The [`offset`](Lrc::offset) will be applied to all timestamps. The ID tags are discarded.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::lyrics::Lrc;
+	/// use std::time::Duration;
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let lrc: Lrc = "[offset:+500]\n[00:12.00]Foo".parse()?;
+	///
+	/// let lyrics = lrc.to_synced_lyrics();
+	/// assert_eq!(lyrics.lines[0].timestamp, Duration::from_millis(11_500));
+	/// # Ok(()) }
+	/// ```
+	pub fn to_synced_lyrics(&self) -> SyncedLyrics {
+		let offset = Duration::from_millis(self.offset.unsigned_abs());
+		let apply_offset = |timestamp: Duration| {
+			if self.offset >= 0 {
+				timestamp.saturating_sub(offset)
+			} else {
+				timestamp.saturating_add(offset)
+			}
+		};
+
+		let mut lines = self.lines.clone();
+		for line in &mut lines {
+			line.timestamp = apply_offset(line.timestamp);
+
+			for word in &mut line.words {
+				word.timestamp = apply_offset(word.timestamp);
+			}
+		}
+
+		SyncedLyrics::new(lines)
+	}
+
+	/// Parse LRC lyrics stored in a plain lyrics item, such as Vorbis Comments' `LYRICS`
+	///
+	/// This will return `None` if the text isn't strictly LRC, as plain lyrics may contain
+	/// annotations such as `[Chorus]`.
+	pub(crate) fn from_lyrics_text(text: &str) -> Option<Self> {
+		Self::parse(text, ParsingMode::Strict)
+			.ok()
+			.filter(|lrc| !lrc.lines.is_empty())
+	}
+
+	/// Create the text to store in a plain lyrics item, see [`Lrc::from_lyrics_text`]
+	pub(crate) fn into_lyrics_text(self) -> String {
+		let mut text = self.to_string();
+		text.truncate(text.trim_end().len());
+		text
+	}
+}
+
+impl FromStr for Lrc {
+	type Err = LoftyError;
+
+	fn from_str(s: &str) -> Result<Self> {
+		Lrc::parse(s, ParsingMode::BestAttempt)
+	}
+}
+
+impl Display for Lrc {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		for (key, value) in &self.id_tags {
+			writeln!(f, "[{key}:{value}]")?;
+		}
+
+		if self.offset != 0 {
+			writeln!(f, "[{OFFSET_TAG}:{:+}]", self.offset)?;
+		}
+
+		for line in &self.lines {
+			write!(f, "[{}]", LrcTimestamp(line.timestamp))?;
+
+			if line.words.is_empty() {
+				write_text(f, &line.text)?;
+			}
+
+			for word in &line.words {
+				write!(f, "<{}>", LrcTimestamp(word.timestamp))?;
+				write_text(f, &word.text)?;
+			}
+
+			writeln!(f)?;
+		}
+
+		Ok(())
+	}
+}
+
+/// Parse an `mm:ss.xx` timestamp
+///
+/// The fraction is optional, and can be up to 3 digits. Timestamps greater than
+/// [`MAX_TIMESTAMP_MILLIS`] are rejected.
+fn parse_timestamp(timestamp: &str) -> Option<Duration> {
+	fn parse_number(number: &str) -> Option<u64> {
+		if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) {
+			return None;
+		}
+
+		number.parse().ok()
+	}
+
+	let (minutes, seconds) = timestamp.split_once(':')?;
+	let (seconds, fraction) = seconds.split_once('.').unwrap_or((seconds, ""));
+
+	let minutes =
+		parse_number(minutes).filter(|&minutes| minutes <= MAX_TIMESTAMP_MILLIS / 60_000)?;
+	let seconds = parse_number(seconds)?;
+
+	let mut millis = 0;
+	if !fraction.is_empty() {
+		if fraction.len() > 3 {
+			return None;
+		}
+
+		millis = parse_number(fraction)? * 10_u64.pow(3 - fraction.len() as u32);
+	}
+
+	let millis = (minutes * 60_000)
+		.checked_add(seconds.checked_mul(1000)?)?
+		.checked_add(millis)?;
+	if millis > MAX_TIMESTAMP_MILLIS {
+		return None;
+	}
+
+	Some(Duration::from_millis(millis))
+}
+
+/// Parse the text following the timestamps of a line, which may contain `<mm:ss.xx>` word timestamps
+fn parse_line_text(timestamp: Duration, text: &str) -> SyncedLyricsLine {
+	let mut words = Vec::new();
+	let mut word_start = timestamp;
+	let mut word_text = String::new();
+	let mut has_word_timing = false;
+
+	let mut rest = text;
+	while let Some(start) = rest.find('<') {
+		word_text.push_str(&rest[..start]);
+
+		let marker = &rest[start + 1..];
+		let Some((word_timestamp, remaining)) = marker
+			.split_once('>')
+			.and_then(|(content, remaining)| Some((parse_timestamp(content)?, remaining)))
+		else {
+			// Not a word timestamp, keep the '<' as-is
+			word_text.push('<');
+			rest = marker;
+			continue;
+		};
+
+		if !word_text.is_empty() {
+			words.push(SyncedLyricsWord::new(
+				word_start,
+				std::mem::take(&mut word_text),
+			));
+		}
+
+		has_word_timing = true;
+		word_start = word_timestamp;
+		rest = remaining;
+	}
+
+	word_text.push_str(rest);
+
+	if !has_word_timing {
+		return SyncedLyricsLine::new(timestamp, word_text);
+	}
+
+	if !word_text.is_empty() {
+		words.push(SyncedLyricsWord::new(word_start, word_text));
+	}
+
+	let mut line = SyncedLyricsLine::from_words(words);
+	line.timestamp = timestamp;
+	line
+}
+
+/// LRC has no way to represent line breaks within a line, so they're replaced with spaces
+fn write_text(f: &mut Formatter<'_>, text: &str) -> std::fmt::Result {
+	let text = text.trim_matches(['\r', '\n']);
+	for (idx, part) in text.lines().enumerate() {
+		if idx > 0 {
+			f.write_str(" ")?;
+		}
+
+		f.write_str(part)?;
+	}
+
+	Ok(())
+}
+
+/// A timestamp formatted as `mm:ss.xx`, or `mm:ss.xxx` if it isn't representable in hundredths
+struct LrcTimestamp(Duration);
+
+impl Display for LrcTimestamp {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let total_millis = self.0.as_millis();
+
+		let minutes = total_millis / 60_000;
+		let seconds = total_millis / 1000 % 60;
+		let millis = total_millis % 1000;
+
+		if millis % 10 == 0 {
+			write!(f, "{minutes:02}:{seconds:02}.{:02}", millis / 10)
+		} else {
+			write!(f, "{minutes:02}:{seconds:02}.{millis:03}")
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::config::ParsingMode;
+	use crate::lyrics::Lrc;
+	use crate::tag::items::{SyncedLyricsLine, SyncedLyricsWord};
+
+	use std::time::Duration;
+
+	const LRC: &str = "\
+[ar:Foo artist]
+[ti:Bar title]
+[offset:-250]
+[00:01.50]First line
+[00:05.00][01:10.00]<00:05.00>Repeated <00:05.75>line
+[00:08.125]Last line
+";
+
+	#[test_log::test]
+	fn lrc_parse() {
+		let lrc = Lrc::parse(LRC, ParsingMode::Strict).unwrap();
+
+		assert_eq!(
+			lrc.id_tags,
+			vec![
+				(String::from("ar"), String::from("Foo artist")),
+				(String::from("ti"), String::from("Bar title"))
+			]
+		);
+		assert_eq!(lrc.offset, -250);
+
+		assert_eq!(lrc.lines.len(), 4);
+		assert_eq!(
+			lrc.lines[0],
+			SyncedLyricsLine::new(Duration::from_millis(1500), String::from("First line"))
+		);
+		assert_eq!(lrc.lines[2].timestamp, Duration::from_millis(8125));
+
+		// The repeated line is moved to the end, with its word timestamps adjusted
+		assert_eq!(
+			lrc.lines[3],
+			SyncedLyricsLine::from_words(vec![
+				SyncedLyricsWord::new(Duration::from_secs(70), String::from("Repeated ")),
+				SyncedLyricsWord::new(Duration::from_millis(70_750), String::from("line")),
+			])
+		);
+	}
+
+	#[test_log::test]
+	fn lrc_re_emit() {
+		let lrc = Lrc::parse(LRC, ParsingMode::Strict).unwrap();
+
+		let emitted = lrc.to_string();
+		assert_eq!(
+			emitted,
+			"\
+[ar:Foo artist]
+[ti:Bar title]
+[offset:-250]
+[00:01.50]First line
+[00:05.00]<00:05.00>Repeated <00:05.75>line
+[00:08.125]Last line
+[01:10.00]<01:10.00>Repeated <01:10.75>line
+"
+		);
+
+		assert_eq!(Lrc::parse(&emitted, ParsingMode::Strict).unwrap(), lrc);
+	}
+
+	#[test_log::test]
+	fn lrc_text_before_word_timing() {
+		let lrc: Lrc = "[00:01.00]Foo <00:01.50>bar <baz>".parse().unwrap();
+
+		assert_eq!(lrc.lines[0].text, "Foo bar <baz>");
+		assert_eq!(
+			lrc.lines[0].words,
+			vec![
+				SyncedLyricsWord::new(Duration::from_secs(1), String::from("Foo ")),
+				SyncedLyricsWord::new(Duration::from_millis(1500), String::from("bar <baz>")),
+			]
+		);
+	}
+
+	#[test_log::test]
+	fn lrc_offset() {
+		let mut lrc: Lrc = "[00:00.10]Foo\n[00:02.00]<00:02.00>Bar <00:02.50>baz"
+			.parse()
+			.unwrap();
+
+		lrc.offset = 500;
+		let lyrics = lrc.to_synced_lyrics();
+		assert_eq!(lyrics.lines[0].timestamp, Duration::ZERO);
+		assert_eq!(lyrics.lines[1].timestamp, Duration::from_millis(1500));
+		assert_eq!(lyrics.lines[1].words[1].timestamp, Duration::from_secs(2));
+
+		lrc.offset = -500;
+		let lyrics = lrc.to_synced_lyrics();
+		assert_eq!(lyrics.lines[0].timestamp, Duration::from_millis(600));
+		assert_eq!(lyrics.lines[1].words[1].timestamp, Duration::from_secs(3));
+	}
+
+	#[test_log::test]
+	fn lrc_huge_timestamps() {
+		let lrc: Lrc =
+			"[00:00.00][307445734561825860:15.999]<00:00.50>x\n[00:99999999999999999.00]y"
+				.parse()
+				.unwrap();
+		assert!(lrc.lines.is_empty());
+
+		// The largest timestamp, with a word timestamp past it
+		let lrc: Lrc = "[00:00.00][71582:47.295]<00:00.50>x".parse().unwrap();
+		assert_eq!(lrc.lines.len(), 2);
+		assert_eq!(
+			lrc.lines[1].words[0].timestamp,
+			Duration::from_millis(u64::from(u32::MAX) + 500)
+		);
+	}
+
+	#[test_log::test]
+	fn lrc_invalid_lines() {
+		let input = "[Chorus]\n[00:01.00]Foo\nBar\n[00:xx.00]Baz";
+
+		assert!(Lrc::parse(input, ParsingMode::Strict).is_err());
+		assert!(Lrc::from_lyrics_text(input).is_none());
+
+		let lrc = Lrc::parse(input, ParsingMode::BestAttempt).unwrap();
+		assert!(lrc.id_tags.is_empty());
+		assert_eq!(
+			lrc.lines,
+			vec![SyncedLyricsLine::new(
+				Duration::from_secs(1),
+				String::from("Foo")
+			)]
+		);
+	}
+}
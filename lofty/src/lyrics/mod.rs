@@ -0,0 +1,8 @@
+//! Synchronized lyrics formats
+//!
+//! Embedded synchronized lyrics are represented by [`SyncedLyrics`](crate::tag::items::SyncedLyrics).
+//! This module handles the formats used to exchange them outside of tags.
+
+mod lrc;
+
+pub use lrc::Lrc;
@@ -0,0 +1,372 @@
+//! Low-level EBML reading and writing
+//!
+//! See <https://www.rfc-editor.org/rfc/rfc8794> for the EBML specification, and
+//! <https://www.matroska.org/technical/elements.html> for the Matroska element IDs.
+
+use crate::error::{ErrorKind, LoftyError, Result};
+use crate::macros::{decode_err, err};
+use crate::util::text::utf8_decode_str;
+
+use std::io::Read;
+
+use byteorder::ReadBytesExt;
+
+// Element IDs, including their length markers
+
+// EBML header
+pub(crate) const EBML_HEADER: u32 = 0x1A45_DFA3;
+pub(crate) const DOC_TYPE: u32 = 0x4282;
+pub(crate) const DOC_TYPE_VERSION: u32 = 0x4287;
+
+// Global elements
+pub(crate) const VOID: u32 = 0xEC;
+
+// Segment, and its direct children
+pub(crate) const SEGMENT: u32 = 0x1853_8067;
+pub(crate) const SEEK_HEAD: u32 = 0x114D_9B74;
+pub(crate) const INFO: u32 = 0x1549_A966;
+pub(crate) const TRACKS: u32 = 0x1654_AE6B;
+pub(crate) const CLUSTER: u32 = 0x1F43_B675;
+pub(crate) const CUES: u32 = 0x1C53_BB6B;
+pub(crate) const ATTACHMENTS: u32 = 0x1941_A469;
+pub(crate) const CHAPTERS: u32 = 0x1043_A770;
+pub(crate) const TAGS: u32 = 0x1254_C367;
+
+// SeekHead
+pub(crate) const SEEK: u32 = 0x4DBB;
+pub(crate) const SEEK_ID: u32 = 0x53AB;
+pub(crate) const SEEK_POSITION: u32 = 0x53AC;
+
+// Info
+pub(crate) const TIMESTAMP_SCALE: u32 = 0x2A_D7B1;
+pub(crate) const DURATION: u32 = 0x4489;
+pub(crate) const MUXING_APP: u32 = 0x4D80;
+pub(crate) const WRITING_APP: u32 = 0x5741;
+
+// Tracks
+pub(crate) const TRACK_ENTRY: u32 = 0xAE;
+pub(crate) const TRACK_NUMBER: u32 = 0xD7;
+pub(crate) const TRACK_UID: u32 = 0x73C5;
+pub(crate) const TRACK_TYPE: u32 = 0x83;
+pub(crate) const FLAG_ENABLED: u32 = 0xB9;
+pub(crate) const FLAG_DEFAULT: u32 = 0x88;
+pub(crate) const NAME: u32 = 0x536E;
+pub(crate) const LANGUAGE: u32 = 0x22_B59C;
+pub(crate) const CODEC_ID: u32 = 0x86;
+pub(crate) const CODEC_PRIVATE: u32 = 0x63A2;
+pub(crate) const AUDIO: u32 = 0xE1;
+pub(crate) const SAMPLING_FREQUENCY: u32 = 0xB5;
+pub(crate) const OUTPUT_SAMPLING_FREQUENCY: u32 = 0x78B5;
+pub(crate) const CHANNELS: u32 = 0x9F;
+pub(crate) const BIT_DEPTH: u32 = 0x6264;
+
+// Attachments
+pub(crate) const ATTACHED_FILE: u32 = 0x61A7;
+pub(crate) const FILE_DESCRIPTION: u32 = 0x467E;
+pub(crate) const FILE_NAME: u32 = 0x466E;
+pub(crate) const FILE_MEDIA_TYPE: u32 = 0x4660;
+pub(crate) const FILE_DATA: u32 = 0x465C;
+pub(crate) const FILE_UID: u32 = 0x46AE;
+
+// Tags
+pub(crate) const TAG: u32 = 0x7373;
+pub(crate) const TARGETS: u32 = 0x63C0;
+pub(crate) const TARGET_TYPE_VALUE: u32 = 0x68CA;
+pub(crate) const TARGET_TYPE: u32 = 0x63CA;
+pub(crate) const TAG_TRACK_UID: u32 = 0x63C5;
+pub(crate) const TAG_EDITION_UID: u32 = 0x63C9;
+pub(crate) const TAG_CHAPTER_UID: u32 = 0x63C4;
+pub(crate) const TAG_ATTACHMENT_UID: u32 = 0x63C6;
+pub(crate) const SIMPLE_TAG: u32 = 0x67C8;
+pub(crate) const TAG_NAME: u32 = 0x45A3;
+pub(crate) const TAG_LANGUAGE: u32 = 0x447A;
+pub(crate) const TAG_LANGUAGE_BCP47: u32 = 0x447B;
+pub(crate) const TAG_DEFAULT: u32 = 0x4484;
+pub(crate) const TAG_STRING: u32 = 0x4487;
+pub(crate) const TAG_BINARY: u32 = 0x4485;
+
+/// The direct children of a Segment
+///
+/// These are used to find the end of elements with an unknown size.
+pub(crate) const TOP_LEVEL_IDS: [u32; 8] = [
+	SEEK_HEAD,
+	INFO,
+	TRACKS,
+	CLUSTER,
+	CUES,
+	ATTACHMENTS,
+	CHAPTERS,
+	TAGS,
+];
+
+/// The largest element size that can be stored, all bits set is reserved for "unknown"
+const MAX_SIZE: u64 = (1 << 56) - 2;
+
+/// The header of an EBML element
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ElementHeader {
+	/// The element ID, including its length marker
+	pub(crate) id: u32,
+	/// The size of the element's data, or `None` if it is unknown
+	pub(crate) size: Option<u64>,
+	/// The size of the ID and size fields
+	pub(crate) len: u64,
+	/// The size of the size field
+	pub(crate) size_len: u8,
+}
+
+impl ElementHeader {
+	pub(crate) fn read<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read,
+	{
+		let (id, id_len) = read_id(reader)?;
+		let (size, size_len) = read_size(reader)?;
+
+		Ok(Self {
+			id,
+			size,
+			len: u64::from(id_len + size_len),
+			size_len,
+		})
+	}
+}
+
+fn read_id<R>(reader: &mut R) -> Result<(u32, u8)>
+where
+	R: Read,
+{
+	let first = reader.read_u8()?;
+
+	let len = first.leading_zeros() as u8 + 1;
+	if len > 4 {
+		decode_err!(@BAIL Matroska, "Encountered an invalid element ID");
+	}
+
+	let mut id = u32::from(first);
+	for _ in 1..len {
+		id = (id << 8) | u32::from(reader.read_u8()?);
+	}
+
+	Ok((id, len))
+}
+
+fn read_size<R>(reader: &mut R) -> Result<(Option<u64>, u8)>
+where
+	R: Read,
+{
+	let first = reader.read_u8()?;
+
+	let len = first.leading_zeros() as u8 + 1;
+	if len > 8 {
+		decode_err!(@BAIL Matroska, "Encountered an invalid element size");
+	}
+
+	// The marker takes up the entire first byte for 8 byte sizes
+	let mask = 0xFF_u8.checked_shr(u32::from(len)).unwrap_or(0);
+	let mut size = u64::from(first & mask);
+	let mut unknown = first & mask == mask;
+	for _ in 1..len {
+		let byte = reader.read_u8()?;
+		unknown &= byte == 0xFF;
+		size = (size << 8) | u64::from(byte);
+	}
+
+	Ok(((!unknown).then_some(size), len))
+}
+
+/// Iterates over the children of a master element that has already been read into memory
+pub(crate) struct Children<'a> {
+	content: &'a [u8],
+}
+
+impl<'a> Children<'a> {
+	pub(crate) fn new(content: &'a [u8]) -> Self {
+		Self { content }
+	}
+}
+
+impl<'a> Iterator for Children<'a> {
+	type Item = Result<(u32, &'a [u8])>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.content.is_empty() {
+			return None;
+		}
+
+		let header = match ElementHeader::read(&mut self.content) {
+			Ok(header) => header,
+			Err(e) => {
+				self.content = &[];
+				return Some(Err(e));
+			},
+		};
+
+		// An unknown size extends to the end of the parent
+		let size = header.size.unwrap_or(self.content.len() as u64);
+		if size > self.content.len() as u64 {
+			self.content = &[];
+			return Some(Err(LoftyError::new(ErrorKind::SizeMismatch)));
+		}
+
+		let (content, remaining) = self.content.split_at(size as usize);
+		self.content = remaining;
+
+		Some(Ok((header.id, content)))
+	}
+}
+
+pub(crate) fn read_uint(data: &[u8]) -> Result<u64> {
+	if data.len() > 8 {
+		decode_err!(@BAIL Matroska, "Unsigned integer element is too large");
+	}
+
+	Ok(data.iter().fold(0, |acc, b| (acc << 8) | u64::from(*b)))
+}
+
+pub(crate) fn read_float(data: &[u8]) -> Result<f64> {
+	match data.len() {
+		0 => Ok(0.0),
+		4 => Ok(f64::from(f32::from_be_bytes(data.try_into().unwrap()))),
+		8 => Ok(f64::from_be_bytes(data.try_into().unwrap())),
+		_ => decode_err!(@BAIL Matroska, "Float element has an invalid size"),
+	}
+}
+
+/// Reads a UTF-8 or ASCII string, which may be padded with null bytes
+pub(crate) fn read_string(data: &[u8]) -> Result<String> {
+	let end = data.iter().position(|b| *b == 0).unwrap_or(data.len());
+	utf8_decode_str(&data[..end]).map(str::to_owned)
+}
+
+/// The number of bytes needed to store `size` as a variable size integer
+pub(crate) fn size_len(size: u64) -> u8 {
+	let mut len = 1;
+	while len < 8 && size >= (1 << (7 * u64::from(len))) - 1 {
+		len += 1;
+	}
+
+	len
+}
+
+pub(crate) fn write_id(writer: &mut Vec<u8>, id: u32) {
+	let bytes = id.to_be_bytes();
+	let start = bytes.iter().position(|b| *b != 0).unwrap_or(3);
+	writer.extend(&bytes[start..]);
+}
+
+pub(crate) fn write_size(writer: &mut Vec<u8>, size: u64, len: u8) {
+	let marked = size | (1 << (7 * u64::from(len)));
+	writer.extend(&marked.to_be_bytes()[8 - len as usize..]);
+}
+
+/// Writes an element, using the smallest possible size field
+pub(crate) fn write_element(writer: &mut Vec<u8>, id: u32, content: &[u8]) -> Result<()> {
+	let size = content.len() as u64;
+	if size > MAX_SIZE {
+		err!(TooMuchData);
+	}
+
+	write_id(writer, id);
+	write_size(writer, size, size_len(size));
+	writer.extend(content);
+
+	Ok(())
+}
+
+pub(crate) fn write_uint_element(writer: &mut Vec<u8>, id: u32, value: u64) -> Result<()> {
+	let bytes = value.to_be_bytes();
+	let start = bytes.iter().position(|b| *b != 0).unwrap_or(7);
+	write_element(writer, id, &bytes[start..])
+}
+
+pub(crate) fn write_string_element(writer: &mut Vec<u8>, id: u32, value: &str) -> Result<()> {
+	write_element(writer, id, value.as_bytes())
+}
+
+/// Creates an element that will take up exactly `total_len` bytes
+///
+/// The size field is widened as needed, so that any leftover space can always be filled with a
+/// `Void` element (which needs at least 2 bytes).
+///
+/// Returns the element, followed by any necessary padding, or `None` if it is too large.
+pub(crate) fn fit_element(id: u32, content: &[u8], total_len: u64) -> Option<Vec<u8>> {
+	let mut id_bytes = Vec::new();
+	write_id(&mut id_bytes, id);
+
+	let size = content.len() as u64;
+	for len in size_len(size)..=8 {
+		let element_len = id_bytes.len() as u64 + u64::from(len) + size;
+		if element_len > total_len {
+			return None;
+		}
+
+		let padding = total_len - element_len;
+		if padding == 1 {
+			continue;
+		}
+
+		let mut element = id_bytes.clone();
+		write_size(&mut element, size, len);
+		element.extend(content);
+
+		if padding > 0 {
+			element.extend(void_element(padding));
+		}
+
+		return Some(element);
+	}
+
+	None
+}
+
+/// Creates a `Void` element that takes up exactly `total_len` bytes
+///
+/// `total_len` must be at least 2.
+pub(crate) fn void_element(total_len: u64) -> Vec<u8> {
+	debug_assert!(total_len >= 2);
+
+	let mut len = 1;
+	while len < 8 && total_len - 1 - u64::from(len) >= (1 << (7 * u64::from(len))) - 1 {
+		len += 1;
+	}
+
+	let size = total_len - 1 - u64::from(len);
+
+	let mut element = Vec::with_capacity(total_len as usize);
+	write_id(&mut element, VOID);
+	write_size(&mut element, size, len);
+	element.resize(total_len as usize, 0);
+
+	element
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{fit_element, void_element, ElementHeader, VOID};
+
+	#[test_log::test]
+	fn void_sizes() {
+		for len in [2, 3, 127, 128, 129, 130, 16_384, 16_385, 100_000] {
+			let void = void_element(len);
+			assert_eq!(void.len() as u64, len);
+
+			let header = ElementHeader::read(&mut &void[..]).unwrap();
+			assert_eq!(header.id, VOID);
+			assert_eq!(header.len + header.size.unwrap(), len);
+		}
+	}
+
+	#[test_log::test]
+	fn fit_with_single_byte_leftover() {
+		// 2 (ID) + 1 (size) + 3 (content) = 6, leaving a single byte that can't be a `Void`
+		let element = fit_element(0x4487, b"Foo", 7).unwrap();
+		assert_eq!(element.len(), 7);
+
+		let header = ElementHeader::read(&mut &element[..]).unwrap();
+		assert_eq!(header.size, Some(3));
+		assert_eq!(header.len, 4);
+
+		assert!(fit_element(0x4487, b"Foo", 5).is_none());
+	}
+}
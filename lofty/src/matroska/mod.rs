@@ -0,0 +1,36 @@
+//! Matroska specific items
+//!
+//! ## File notes
+//!
+//! This covers both Matroska (`.mka`, `.mkv`) and WebM (`.webm`) files, which share the same
+//! EBML based structure. Only audio tracks are exposed in [`MatroskaProperties`].
+//!
+//! Metadata is stored in the `Tags` element, and pictures are stored as attachments. Writing a
+//! [`MatroskaTag`] will rewrite both elements, reusing any `Void` padding where possible to avoid
+//! moving the rest of the file.
+
+mod element;
+mod properties;
+mod read;
+mod segment;
+pub(crate) mod tag;
+pub(crate) mod write;
+
+use lofty_attr::LoftyFile;
+
+// Exports
+pub use properties::{AudioTrack, MatroskaProperties};
+pub use tag::{
+	AttachedPicture, MatroskaTag, SimpleTag, SimpleTagValue, TagGroup, Target, TargetType,
+};
+
+/// A Matroska file
+#[derive(LoftyFile, Default)]
+#[lofty(read_fn = "read::read_from")]
+pub struct MatroskaFile {
+	/// A Matroska tag
+	#[lofty(tag_type = "Matroska")]
+	pub(crate) matroska_tag: Option<MatroskaTag>,
+	/// The file's audio properties
+	pub(crate) properties: MatroskaProperties,
+}
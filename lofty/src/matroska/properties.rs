@@ -0,0 +1,332 @@
+use super::element::{
+	read_float, read_string, read_uint, Children, AUDIO, BIT_DEPTH, CHANNELS, CODEC_ID,
+	CODEC_PRIVATE, DURATION, FLAG_DEFAULT, FLAG_ENABLED, LANGUAGE, MUXING_APP, NAME,
+	OUTPUT_SAMPLING_FREQUENCY, SAMPLING_FREQUENCY, TIMESTAMP_SCALE, TRACK_ENTRY, TRACK_NUMBER,
+	TRACK_TYPE, TRACK_UID, WRITING_APP,
+};
+use crate::error::Result;
+use crate::properties::FileProperties;
+
+use std::time::Duration;
+
+/// The `TrackType` of audio tracks
+const AUDIO_TRACK_TYPE: u64 = 2;
+
+/// The default `TimestampScale`, 1 millisecond in nanoseconds
+const DEFAULT_TIMESTAMP_SCALE: u64 = 1_000_000;
+
+/// An audio track in a Matroska file
+#[derive(Debug, Clone, PartialEq, Default)]
+#[non_exhaustive]
+pub struct AudioTrack {
+	pub(crate) number: u64,
+	pub(crate) uid: u64,
+	pub(crate) enabled: bool,
+	pub(crate) default: bool,
+	pub(crate) name: Option<String>,
+	pub(crate) language: String,
+	pub(crate) codec_id: String,
+	pub(crate) codec_private: Option<Vec<u8>>,
+	pub(crate) sample_rate: u32,
+	pub(crate) output_sample_rate: Option<u32>,
+	pub(crate) channels: u8,
+	pub(crate) bit_depth: Option<u8>,
+}
+
+impl AudioTrack {
+	/// The track number, as used in blocks
+	pub fn number(&self) -> u64 {
+		self.number
+	}
+
+	/// The track's unique ID, as used in [`Target`](crate::matroska::Target)s
+	pub fn uid(&self) -> u64 {
+		self.uid
+	}
+
+	/// Whether the track is usable
+	pub fn is_enabled(&self) -> bool {
+		self.enabled
+	}
+
+	/// Whether the track should be selected by default
+	pub fn is_default(&self) -> bool {
+		self.default
+	}
+
+	/// A human-readable track name
+	pub fn name(&self) -> Option<&str> {
+		self.name.as_deref()
+	}
+
+	/// The track's language, as an ISO 639-2 code
+	pub fn language(&self) -> &str {
+		&self.language
+	}
+
+	/// The codec ID, such as `A_OPUS` or `A_FLAC`
+	///
+	/// See <https://www.matroska.org/technical/codec_specs.html>
+	pub fn codec_id(&self) -> &str {
+		&self.codec_id
+	}
+
+	/// Codec-specific data, such as the `OpusHead` packet or FLAC metadata blocks
+	pub fn codec_private(&self) -> Option<&[u8]> {
+		self.codec_private.as_deref()
+	}
+
+	/// Sample rate (Hz)
+	///
+	/// NOTE: For codecs using SBR, such as HE-AAC, this is *half* of the output sample rate.
+	///       See [`AudioTrack::output_sample_rate`].
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// The output sample rate (Hz), if it differs from [`AudioTrack::sample_rate`]
+	pub fn output_sample_rate(&self) -> Option<u32> {
+		self.output_sample_rate
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// Bits per sample
+	pub fn bit_depth(&self) -> Option<u8> {
+		self.bit_depth
+	}
+}
+
+/// A Matroska file's audio properties
+///
+/// A Matroska file can contain multiple audio tracks. The sample rate, channel count, and bit depth
+/// come from the first default audio track (or the first audio track, if none are marked as default).
+/// See [`MatroskaProperties::audio_tracks`] for the rest.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[non_exhaustive]
+pub struct MatroskaProperties {
+	pub(crate) doc_type: String,
+	pub(crate) doc_type_version: u64,
+	pub(crate) muxing_app: String,
+	pub(crate) writing_app: String,
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) audio_tracks: Vec<AudioTrack>,
+}
+
+impl From<MatroskaProperties> for FileProperties {
+	fn from(input: MatroskaProperties) -> Self {
+		let track = input.primary_track();
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: track.map(|t| t.output_sample_rate.unwrap_or(t.sample_rate)),
+			bit_depth: track.and_then(|t| t.bit_depth),
+			channels: track.map(|t| t.channels),
+			channel_mask: None,
+		}
+	}
+}
+
+impl MatroskaProperties {
+	fn primary_track(&self) -> Option<&AudioTrack> {
+		self.audio_tracks
+			.iter()
+			.find(|track| track.default && track.enabled)
+			.or_else(|| self.audio_tracks.first())
+	}
+
+	/// The document type, either `matroska` or `webm`
+	pub fn doc_type(&self) -> &str {
+		&self.doc_type
+	}
+
+	/// The version of the document type
+	pub fn doc_type_version(&self) -> u64 {
+		self.doc_type_version
+	}
+
+	/// The library used to mux the file
+	pub fn muxing_app(&self) -> &str {
+		&self.muxing_app
+	}
+
+	/// The application used to write the file
+	pub fn writing_app(&self) -> &str {
+		&self.writing_app
+	}
+
+	/// Duration of the audio
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	///
+	/// This is estimated from the size of all clusters, and will be 0 if the file contains any
+	/// non-audio tracks.
+	pub fn audio_bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.primary_track()
+			.map_or(0, |t| t.output_sample_rate.unwrap_or(t.sample_rate))
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.primary_track().map_or(0, |t| t.channels)
+	}
+
+	/// Bits per sample
+	pub fn bit_depth(&self) -> Option<u8> {
+		self.primary_track().and_then(|t| t.bit_depth)
+	}
+
+	/// The codec ID of the audio track, such as `A_OPUS` or `A_FLAC`
+	pub fn codec_id(&self) -> Option<&str> {
+		self.primary_track().map(|t| t.codec_id.as_str())
+	}
+
+	/// All audio tracks in the file
+	pub fn audio_tracks(&self) -> &[AudioTrack] {
+		&self.audio_tracks
+	}
+}
+
+/// The properties read from the `Info` element
+#[derive(Default)]
+pub(super) struct SegmentInfo {
+	pub(super) timestamp_scale: Option<u64>,
+	pub(super) duration: Option<f64>,
+	pub(super) muxing_app: String,
+	pub(super) writing_app: String,
+}
+
+pub(super) fn read_info(content: &[u8]) -> Result<SegmentInfo> {
+	let mut info = SegmentInfo::default();
+
+	for child in Children::new(content) {
+		let (id, data) = child?;
+		match id {
+			TIMESTAMP_SCALE => info.timestamp_scale = Some(read_uint(data)?),
+			DURATION => info.duration = Some(read_float(data)?),
+			MUXING_APP => info.muxing_app = read_string(data)?,
+			WRITING_APP => info.writing_app = read_string(data)?,
+			_ => {},
+		}
+	}
+
+	Ok(info)
+}
+
+/// Reads all audio tracks from the `Tracks` element, returning whether any non-audio tracks were found
+pub(super) fn read_tracks(content: &[u8], audio_tracks: &mut Vec<AudioTrack>) -> Result<bool> {
+	let mut has_other_tracks = false;
+
+	for child in Children::new(content) {
+		let (id, data) = child?;
+		if id != TRACK_ENTRY {
+			continue;
+		}
+
+		match read_track_entry(data)? {
+			Some(track) => audio_tracks.push(track),
+			None => has_other_tracks = true,
+		}
+	}
+
+	Ok(has_other_tracks)
+}
+
+fn read_track_entry(content: &[u8]) -> Result<Option<AudioTrack>> {
+	let mut track_type = None;
+	let mut track = AudioTrack {
+		enabled: true,
+		default: true,
+		language: String::from("eng"),
+		sample_rate: 8000,
+		channels: 1,
+		..AudioTrack::default()
+	};
+
+	for child in Children::new(content) {
+		let (id, data) = child?;
+		match id {
+			TRACK_NUMBER => track.number = read_uint(data)?,
+			TRACK_UID => track.uid = read_uint(data)?,
+			TRACK_TYPE => track_type = Some(read_uint(data)?),
+			FLAG_ENABLED => track.enabled = read_uint(data)? != 0,
+			FLAG_DEFAULT => track.default = read_uint(data)? != 0,
+			NAME => track.name = Some(read_string(data)?),
+			LANGUAGE => track.language = read_string(data)?,
+			CODEC_ID => track.codec_id = read_string(data)?,
+			CODEC_PRIVATE => track.codec_private = Some(data.to_vec()),
+			AUDIO => read_audio_settings(data, &mut track)?,
+			_ => {},
+		}
+	}
+
+	if track_type != Some(AUDIO_TRACK_TYPE) {
+		return Ok(None);
+	}
+
+	Ok(Some(track))
+}
+
+fn read_audio_settings(content: &[u8], track: &mut AudioTrack) -> Result<()> {
+	for child in Children::new(content) {
+		let (id, data) = child?;
+		match id {
+			SAMPLING_FREQUENCY => track.sample_rate = read_float(data)?.round() as u32,
+			OUTPUT_SAMPLING_FREQUENCY => {
+				track.output_sample_rate = Some(read_float(data)?.round() as u32)
+			},
+			CHANNELS => track.channels = read_uint(data)? as u8,
+			BIT_DEPTH => track.bit_depth = Some(read_uint(data)? as u8),
+			_ => {},
+		}
+	}
+
+	Ok(())
+}
+
+pub(super) fn finalize(
+	properties: &mut MatroskaProperties,
+	info: SegmentInfo,
+	file_length: u64,
+	audio_length: Option<u64>,
+) {
+	properties.muxing_app = info.muxing_app;
+	properties.writing_app = info.writing_app;
+
+	let timestamp_scale = info.timestamp_scale.unwrap_or(DEFAULT_TIMESTAMP_SCALE);
+	let Some(duration) = info.duration.filter(|d| d.is_finite() && *d > 0.0) else {
+		log::warn!("Segment has no duration, unable to calculate bitrates");
+		return;
+	};
+
+	properties.duration = Duration::from_nanos((duration * timestamp_scale as f64) as u64);
+
+	let length = properties.duration.as_millis() as u64;
+	if length == 0 {
+		return;
+	}
+
+	properties.overall_bitrate = (file_length * 8 / length) as u32;
+	if let Some(audio_length) = audio_length {
+		properties.audio_bitrate = (audio_length * 8 / length) as u32;
+	}
+}
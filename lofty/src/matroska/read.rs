@@ -0,0 +1,93 @@
+use super::element::{
+	read_string, read_uint, Children, ATTACHMENTS, CLUSTER, DOC_TYPE, DOC_TYPE_VERSION, INFO, TAGS,
+	TRACKS,
+};
+use super::properties::{finalize, read_info, read_tracks, MatroskaProperties, SegmentInfo};
+use super::segment::{ElementPosition, Layout};
+use super::tag::read::{read_attachments, read_tags};
+use super::tag::MatroskaTag;
+use super::MatroskaFile;
+use crate::config::ParseOptions;
+use crate::error::Result;
+use crate::macros::{decode_err, try_vec};
+use crate::tag::TagExt;
+
+use std::io::{Read, Seek, SeekFrom};
+
+pub(super) fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<MatroskaFile>
+where
+	R: Read + Seek,
+{
+	let file_length = reader.seek(SeekFrom::End(0))?;
+
+	let layout = Layout::read(reader, file_length)?;
+
+	let mut properties = MatroskaProperties::default();
+
+	let ebml_header = read_element(reader, &layout.ebml_header)?;
+	for child in Children::new(&ebml_header) {
+		let (id, data) = child?;
+		match id {
+			DOC_TYPE => properties.doc_type = read_string(data)?,
+			DOC_TYPE_VERSION => properties.doc_type_version = read_uint(data)?,
+			_ => {},
+		}
+	}
+
+	if properties.doc_type != "matroska" && properties.doc_type != "webm" {
+		decode_err!(@BAIL Matroska, "File has an unsupported DocType");
+	}
+
+	log::debug!(
+		"File verified to be Matroska, DocType: {}",
+		properties.doc_type
+	);
+
+	let mut tag = MatroskaTag::default();
+	let mut info = SegmentInfo::default();
+	let mut has_other_tracks = false;
+	let mut audio_length = 0;
+
+	for element in &layout.elements {
+		match element.id {
+			INFO if parse_options.read_properties => {
+				info = read_info(&read_element(reader, element)?)?;
+			},
+			TRACKS if parse_options.read_properties => {
+				let content = read_element(reader, element)?;
+				has_other_tracks |= read_tracks(&content, &mut properties.audio_tracks)?;
+			},
+			CLUSTER => audio_length += element.end() - element.start,
+			TAGS if parse_options.read_tags => {
+				read_tags(&read_element(reader, element)?, &mut tag)?;
+			},
+			ATTACHMENTS if parse_options.read_tags && parse_options.read_cover_art => {
+				read_attachments(&read_element(reader, element)?, &mut tag)?;
+			},
+			_ => {},
+		}
+	}
+
+	if parse_options.read_properties {
+		// The clusters can only be attributed to the audio if there's nothing else in them
+		let audio_length = (!has_other_tracks).then_some(audio_length);
+		finalize(&mut properties, info, file_length, audio_length);
+	}
+
+	Ok(MatroskaFile {
+		matroska_tag: (!tag.is_empty()).then_some(tag),
+		properties,
+	})
+}
+
+fn read_element<R>(reader: &mut R, element: &ElementPosition) -> Result<Vec<u8>>
+where
+	R: Read + Seek,
+{
+	reader.seek(SeekFrom::Start(element.data_start()))?;
+
+	let mut content = try_vec![0; element.size as usize];
+	reader.read_exact(&mut content)?;
+
+	Ok(content)
+}
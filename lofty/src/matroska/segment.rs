@@ -0,0 +1,174 @@
+use super::element::{ElementHeader, EBML_HEADER, SEGMENT, TOP_LEVEL_IDS};
+use crate::error::Result;
+use crate::macros::decode_err;
+
+use std::io::{Read, Seek, SeekFrom};
+
+/// The position of an element in the file
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ElementPosition {
+	pub(crate) id: u32,
+	/// The offset of the element header
+	pub(crate) start: u64,
+	/// The size of the element header
+	pub(crate) header_len: u64,
+	/// The size of the element's data
+	///
+	/// For elements with an unknown size, this is determined by reading their children.
+	pub(crate) size: u64,
+}
+
+impl ElementPosition {
+	pub(crate) fn data_start(&self) -> u64 {
+		self.start + self.header_len
+	}
+
+	pub(crate) fn end(&self) -> u64 {
+		self.data_start() + self.size
+	}
+}
+
+/// The position of the Segment element in the file
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct SegmentPosition {
+	/// The offset of the Segment's size field
+	pub(crate) size_start: u64,
+	/// The size of the Segment's size field
+	pub(crate) size_len: u8,
+	/// Whether the Segment's size is unknown, meaning it extends to the end of the file
+	pub(crate) unknown_size: bool,
+	/// The offset of the Segment's data, all `SeekPosition`s are relative to this
+	pub(crate) data_start: u64,
+	pub(crate) end: u64,
+}
+
+/// The layout of a Matroska file
+///
+/// This only includes the EBML header, the first Segment, and the Segment's direct children.
+#[derive(Debug)]
+pub(crate) struct Layout {
+	pub(crate) ebml_header: ElementPosition,
+	pub(crate) segment: SegmentPosition,
+	pub(crate) elements: Vec<ElementPosition>,
+}
+
+impl Layout {
+	pub(crate) fn read<R>(reader: &mut R, file_length: u64) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		reader.seek(SeekFrom::Start(0))?;
+
+		let header = ElementHeader::read(reader)?;
+		if header.id != EBML_HEADER {
+			decode_err!(@BAIL Matroska, "File missing EBML header");
+		}
+
+		let Some(size) = header.size else {
+			decode_err!(@BAIL Matroska, "EBML header has an unknown size");
+		};
+
+		let ebml_header = ElementPosition {
+			id: EBML_HEADER,
+			start: 0,
+			header_len: header.len,
+			size,
+		};
+
+		// Skip anything between the EBML header and the Segment, such as `Void` elements
+		let mut pos = ebml_header.end();
+		let segment = loop {
+			if pos >= file_length {
+				decode_err!(@BAIL Matroska, "File missing Segment");
+			}
+
+			reader.seek(SeekFrom::Start(pos))?;
+			let header = ElementHeader::read(reader)?;
+			if header.id == SEGMENT {
+				let data_start = pos + header.len;
+				break SegmentPosition {
+					size_start: data_start - u64::from(header.size_len),
+					size_len: header.size_len,
+					unknown_size: header.size.is_none(),
+					data_start,
+					end: header
+						.size
+						.map_or(file_length, |size| data_start + size)
+						.min(file_length),
+				};
+			}
+
+			let Some(size) = header.size else {
+				decode_err!(@BAIL Matroska, "Encountered a top-level element with an unknown size");
+			};
+
+			pos += header.len + size;
+		};
+
+		let mut elements = Vec::new();
+		let mut pos = segment.data_start;
+		while pos < segment.end {
+			reader.seek(SeekFrom::Start(pos))?;
+
+			let header = match ElementHeader::read(reader) {
+				Ok(header) => header,
+				Err(e) => {
+					// Trailing garbage is common enough, and shouldn't prevent reading the file
+					log::warn!("Failed to read element header at {pos}: {e}");
+					break;
+				},
+			};
+
+			let size = match header.size {
+				Some(size) => size.min(segment.end.saturating_sub(pos + header.len)),
+				None => find_unknown_size_end(reader, pos + header.len, segment.end)?,
+			};
+
+			let element = ElementPosition {
+				id: header.id,
+				start: pos,
+				header_len: header.len,
+				size,
+			};
+
+			pos = element.end();
+			elements.push(element);
+		}
+
+		Ok(Self {
+			ebml_header,
+			segment,
+			elements,
+		})
+	}
+
+	pub(crate) fn find(&self, id: u32) -> impl Iterator<Item = &ElementPosition> + '_ {
+		self.elements.iter().filter(move |element| element.id == id)
+	}
+}
+
+/// Finds the size of an element with an unknown size, such as a live-streamed `Cluster`
+///
+/// The element ends once a direct child of the Segment is found.
+fn find_unknown_size_end<R>(reader: &mut R, data_start: u64, segment_end: u64) -> Result<u64>
+where
+	R: Read + Seek,
+{
+	let mut pos = data_start;
+	while pos < segment_end {
+		reader.seek(SeekFrom::Start(pos))?;
+
+		let header = ElementHeader::read(reader)?;
+		if TOP_LEVEL_IDS.contains(&header.id) {
+			break;
+		}
+
+		let Some(size) = header.size else {
+			decode_err!(@BAIL Matroska, "Encountered nested elements with unknown sizes");
+		};
+
+		pos = (pos + header.len + size).min(segment_end);
+	}
+
+	Ok(pos - data_start)
+}
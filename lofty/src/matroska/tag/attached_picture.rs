@@ -0,0 +1,106 @@
+use crate::picture::{MimeType, Picture, PictureType};
+
+/// A picture stored in the `Attachments` element
+///
+/// Only attachments with an image media type are considered pictures. Any other attachments,
+/// such as fonts, are left untouched when writing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttachedPicture {
+	pub(crate) file_name: String,
+	pub(crate) uid: u64,
+	pub(crate) picture: Picture,
+}
+
+impl AttachedPicture {
+	/// Create a new `AttachedPicture`
+	///
+	/// The file name and unique ID will be generated when writing.
+	pub fn new(picture: Picture) -> Self {
+		Self {
+			file_name: String::new(),
+			uid: 0,
+			picture,
+		}
+	}
+
+	/// The attachment's file name
+	///
+	/// This will be empty for pictures that have yet to be written.
+	pub fn file_name(&self) -> &str {
+		&self.file_name
+	}
+
+	/// The attachment's unique ID
+	///
+	/// This will be 0 for pictures that have yet to be written.
+	pub fn uid(&self) -> u64 {
+		self.uid
+	}
+
+	/// The picture
+	pub fn picture(&self) -> &Picture {
+		&self.picture
+	}
+
+	/// Consume the `AttachedPicture`, returning the picture
+	pub fn into_picture(self) -> Picture {
+		self.picture
+	}
+
+	/// Determine the picture type from the file name
+	///
+	/// See <https://www.matroska.org/technical/attachments.html#cover-art>
+	pub(crate) fn picture_type_from_name(file_name: &str) -> PictureType {
+		let stem = file_name
+			.rsplit_once('.')
+			.map_or(file_name, |(stem, _)| stem)
+			.to_ascii_lowercase();
+
+		match stem.as_str() {
+			"cover" | "cover_land" => PictureType::CoverFront,
+			"small_cover" | "small_cover_land" => PictureType::Icon,
+			_ => PictureType::Other,
+		}
+	}
+
+	/// Generate a file name that follows the cover art conventions, if possible
+	///
+	/// Names already in `taken` will be avoided.
+	pub(crate) fn generate_file_name(&self, index: usize, taken: &[String]) -> String {
+		let extension = self.extension();
+
+		let stem = match self.picture.pic_type {
+			PictureType::CoverFront => "cover",
+			PictureType::Icon => "small_cover",
+			_ => "picture",
+		};
+
+		let mut file_name = format!("{stem}.{extension}");
+		let mut n = index;
+		while taken.contains(&file_name) {
+			file_name = format!("picture{n}.{extension}");
+			n += 1;
+		}
+
+		file_name
+	}
+
+	/// The media type to store the picture with
+	pub(crate) fn media_type(&self) -> &str {
+		match self.picture.mime_str() {
+			"" => "application/octet-stream",
+			mime_type => mime_type,
+		}
+	}
+
+	fn extension(&self) -> &'static str {
+		match self.picture.mime_type() {
+			Some(MimeType::Png) => "png",
+			Some(MimeType::Jpeg) => "jpg",
+			Some(MimeType::Tiff) => "tiff",
+			Some(MimeType::Bmp) => "bmp",
+			Some(MimeType::Gif) => "gif",
+			_ => "bin",
+		}
+	}
+}
@@ -0,0 +1,737 @@
+mod attached_picture;
+pub(crate) mod read;
+mod simple_tag;
+mod target;
+pub(crate) mod write;
+
+use crate::config::WriteOptions;
+use crate::error::LoftyError;
+use crate::picture::Picture;
+use crate::tag::{
+	try_parse_year, Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType,
+};
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::borrow::Cow;
+use std::io::Write;
+
+use lofty_attr::tag;
+
+pub use attached_picture::AttachedPicture;
+pub use simple_tag::{SimpleTag, SimpleTagValue};
+pub use target::{Target, TargetType};
+
+/// A group of [`SimpleTag`]s that share a [`Target`]
+///
+/// This is a `Tag` element in the file.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TagGroup {
+	/// What the tags apply to
+	pub target: Target,
+	/// The tags
+	pub simple_tags: Vec<SimpleTag>,
+}
+
+impl TagGroup {
+	/// Create a new empty `TagGroup`
+	pub fn new(target: Target) -> Self {
+		Self {
+			target,
+			simple_tags: Vec::new(),
+		}
+	}
+}
+
+/// Maps the tag names that depend on the target level
+///
+/// Anything not listed here is mapped through the [`TagType::Matroska`] [`ItemKey`] mappings,
+/// which assume [`TargetType::Track`].
+const LEVELED_KEYS: [(TargetType, &str, ItemKey); 8] = [
+	(TargetType::Album, "TITLE", ItemKey::AlbumTitle),
+	(TargetType::Album, "ARTIST", ItemKey::AlbumArtist),
+	(TargetType::Album, "TOTAL_PARTS", ItemKey::TrackTotal),
+	(
+		TargetType::Album,
+		"REPLAYGAIN_GAIN",
+		ItemKey::ReplayGainAlbumGain,
+	),
+	(
+		TargetType::Album,
+		"REPLAYGAIN_PEAK",
+		ItemKey::ReplayGainAlbumPeak,
+	),
+	(TargetType::Part, "TITLE", ItemKey::SetSubtitle),
+	(TargetType::Part, "PART_NUMBER", ItemKey::DiscNumber),
+	(TargetType::Part, "TOTAL_PARTS", ItemKey::DiscTotal),
+];
+
+/// Names that only have a meaning at [`TargetType::Track`] in the [`ItemKey`] mappings
+const TRACK_ONLY_NAMES: [&str; 6] = [
+	"TITLE",
+	"ARTIST",
+	"PART_NUMBER",
+	"TOTAL_PARTS",
+	"REPLAYGAIN_GAIN",
+	"REPLAYGAIN_PEAK",
+];
+
+fn leveled_item_key(level: TargetType, name: &str) -> Option<ItemKey> {
+	match level {
+		TargetType::Track => Some(ItemKey::from_key(TagType::Matroska, name)),
+		TargetType::Album | TargetType::Part => {
+			if let Some((_, _, key)) = LEVELED_KEYS
+				.iter()
+				.find(|(l, n, _)| *l == level && *n == name)
+			{
+				return Some(key.clone());
+			}
+
+			if TRACK_ONLY_NAMES.contains(&name) {
+				return None;
+			}
+
+			// Anything unknown would end up at the track level when merged
+			match ItemKey::from_key(TagType::Matroska, name) {
+				ItemKey::Unknown(_) => None,
+				key => Some(key),
+			}
+		},
+		_ => None,
+	}
+}
+
+/// Whether the key can be stored, either through the [`TagType::Matroska`] mappings or at another level
+pub(crate) fn supports_key(key: &ItemKey) -> bool {
+	LEVELED_KEYS.iter().any(|(_, _, k)| k == key) || key.map_key(TagType::Matroska, false).is_some()
+}
+
+fn leveled_name(key: &ItemKey) -> Option<(TargetType, &str)> {
+	if let Some((level, name, _)) = LEVELED_KEYS.iter().find(|(_, _, k)| k == key) {
+		return Some((*level, name));
+	}
+
+	key.map_key(TagType::Matroska, true)
+		.filter(|name| !name.is_empty())
+		.map(|name| (TargetType::Track, name))
+}
+
+macro_rules! impl_accessor {
+	($($name:ident => $level:ident, $key:literal;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<Cow<'_, str>> {
+					self.get(TargetType::$level, $key)
+						.and_then(SimpleTag::text)
+						.map(Cow::Borrowed)
+				}
+
+				fn [<set_ $name>](&mut self, value: String) {
+					self.insert(TargetType::$level, SimpleTag::text_item($key, value))
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					self.remove(TargetType::$level, $key);
+				}
+			)+
+		}
+	}
+}
+
+macro_rules! impl_number_accessor {
+	($($name:ident => $level:ident, $key:literal;)+) => {
+		paste::paste! {
+			$(
+				fn $name(&self) -> Option<u32> {
+					self.get(TargetType::$level, $key)
+						.and_then(SimpleTag::text)
+						.and_then(|text| text.trim().parse().ok())
+				}
+
+				fn [<set_ $name>](&mut self, value: u32) {
+					self.insert(TargetType::$level, SimpleTag::text_item($key, value.to_string()))
+				}
+
+				fn [<remove_ $name>](&mut self) {
+					self.remove(TargetType::$level, $key);
+				}
+			)+
+		}
+	}
+}
+
+/// ## Item storage
+///
+/// Matroska stores its metadata as [`SimpleTag`]s, which are grouped by a [`Target`] describing
+/// what they apply to. For example, `TITLE` can either be the title of a track or an album,
+/// depending on its [`TargetType`]. A [`Target`] with no `TargetTypeValue` is treated as
+/// [`TargetType::Track`].
+///
+/// Pictures are stored as attachments, see [`AttachedPicture`].
+///
+/// ## Conversions
+///
+/// ### To `Tag`
+///
+/// Only [`SimpleTag`]s that apply to the file as a whole (or its tracks), are in the default language,
+/// have a string value, and have no nested tags will be converted. The [`ItemKey`] is determined by
+/// the target level:
+///
+/// * [`TargetType::Track`]: The [`TagType::Matroska`] mappings, with unknown names kept as [`ItemKey::Unknown`]
+/// * [`TargetType::Album`]: `TITLE`, `ARTIST`, `TOTAL_PARTS`, and `REPLAYGAIN_*` map to their
+///   album equivalents ([`ItemKey::AlbumTitle`], [`ItemKey::AlbumArtist`], [`ItemKey::TrackTotal`], ...).
+///   Other names with a mapping, such as `GENRE`, are also converted.
+/// * [`TargetType::Part`]: `TITLE`, `PART_NUMBER`, and `TOTAL_PARTS` map to [`ItemKey::SetSubtitle`],
+///   [`ItemKey::DiscNumber`], and [`ItemKey::DiscTotal`]. Other names with a mapping are also converted.
+///
+/// Every other level is left untouched. All attached pictures will be converted.
+///
+/// All remaining items will be retained when using [`SplitTag`] and [`MergeTag`].
+///
+/// ### From `Tag`
+///
+/// Every item is stored as a [`SimpleTagValue::String`] in the level it was mapped from above,
+/// defaulting to [`TargetType::Track`].
+#[derive(Default, Debug, PartialEq, Eq, Clone)]
+#[tag(description = "A `Matroska` tag", supported_formats(Matroska))]
+pub struct MatroskaTag {
+	pub(crate) groups: Vec<TagGroup>,
+	pub(crate) pictures: Vec<AttachedPicture>,
+}
+
+impl MatroskaTag {
+	/// Create a new empty `MatroskaTag`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::matroska::MatroskaTag;
+	/// use lofty::tag::TagExt;
+	///
+	/// let matroska_tag = MatroskaTag::new();
+	/// assert!(matroska_tag.is_empty());
+	/// ```
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns all [`TagGroup`]s
+	pub fn groups(&self) -> &[TagGroup] {
+		&self.groups
+	}
+
+	/// Returns a mutable reference to all [`TagGroup`]s
+	pub fn groups_mut(&mut self) -> &mut Vec<TagGroup> {
+		&mut self.groups
+	}
+
+	/// Get the first [`SimpleTag`] with the given name that applies to the entire file at the given level
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::matroska::{MatroskaTag, TargetType};
+	/// use lofty::tag::Accessor;
+	///
+	/// let mut matroska_tag = MatroskaTag::new();
+	/// matroska_tag.set_album(String::from("Foo album"));
+	///
+	/// // The album title is the `TITLE` of the album level
+	/// let album = matroska_tag.get(TargetType::Album, "TITLE");
+	/// assert!(album.is_some());
+	///
+	/// // Which is separate from the track title
+	/// assert!(matroska_tag.get(TargetType::Track, "TITLE").is_none());
+	/// ```
+	pub fn get(&self, level: TargetType, name: &str) -> Option<&SimpleTag> {
+		self.groups
+			.iter()
+			.filter(|group| Self::is_file_wide_group(group, level))
+			.flat_map(|group| &group.simple_tags)
+			.find(|simple_tag| simple_tag.default && simple_tag.name == name)
+	}
+
+	/// Insert a [`SimpleTag`] that applies to the entire file at the given level
+	///
+	/// This will replace any default language [`SimpleTag`]s with the same name at that level.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::matroska::{MatroskaTag, SimpleTag, SimpleTagValue, TargetType};
+	///
+	/// let mut matroska_tag = MatroskaTag::new();
+	/// matroska_tag.insert(
+	/// 	TargetType::Album,
+	/// 	SimpleTag::new("LABEL", SimpleTagValue::String(String::from("Foo label"))),
+	/// );
+	///
+	/// assert!(matroska_tag.get(TargetType::Album, "LABEL").is_some());
+	/// ```
+	pub fn insert(&mut self, level: TargetType, simple_tag: SimpleTag) {
+		if simple_tag.default {
+			for group in &mut self.groups {
+				if Self::is_file_wide_group(group, level) {
+					group
+						.simple_tags
+						.retain(|t| !(t.default && t.name == simple_tag.name));
+				}
+			}
+		}
+
+		self.push(level, simple_tag);
+	}
+
+	/// Append a [`SimpleTag`] that applies to the entire file at the given level
+	///
+	/// Unlike [`MatroskaTag::insert`], this will keep any existing tags with the same name.
+	pub fn push(&mut self, level: TargetType, simple_tag: SimpleTag) {
+		let index = match self
+			.groups
+			.iter()
+			.position(|group| Self::is_file_wide_group(group, level))
+		{
+			Some(index) => index,
+			None => {
+				self.groups.push(TagGroup::new(Target::new(level)));
+				self.groups.len() - 1
+			},
+		};
+
+		self.groups[index].simple_tags.push(simple_tag);
+	}
+
+	/// Remove all [`SimpleTag`]s with the given name that apply to the entire file at the given level
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::matroska::{MatroskaTag, TargetType};
+	/// use lofty::tag::Accessor;
+	///
+	/// let mut matroska_tag = MatroskaTag::new();
+	/// matroska_tag.set_title(String::from("Foo title"));
+	/// assert!(matroska_tag.get(TargetType::Track, "TITLE").is_some());
+	///
+	/// matroska_tag.remove(TargetType::Track, "TITLE");
+	/// assert!(matroska_tag.get(TargetType::Track, "TITLE").is_none());
+	/// ```
+	pub fn remove(&mut self, level: TargetType, name: &str) {
+		for group in &mut self.groups {
+			if Self::is_file_wide_group(group, level) {
+				group.simple_tags.retain(|t| t.name != name);
+			}
+		}
+	}
+
+	/// Returns all attached pictures
+	pub fn attached_pictures(&self) -> &[AttachedPicture] {
+		&self.pictures
+	}
+
+	/// Returns all pictures
+	pub fn pictures(&self) -> impl Iterator<Item = &Picture> + '_ {
+		self.pictures.iter().map(AttachedPicture::picture)
+	}
+
+	/// Attach a picture
+	///
+	/// The attachment's file name will follow the Matroska cover art conventions where possible,
+	/// for example a [`PictureType::CoverFront`](crate::picture::PictureType::CoverFront) JPEG
+	/// will be named `cover.jpg`.
+	pub fn push_picture(&mut self, picture: Picture) {
+		self.pictures.push(AttachedPicture::new(picture));
+	}
+
+	/// Remove all attached pictures
+	pub fn remove_pictures(&mut self) {
+		self.pictures.clear();
+	}
+
+	fn is_file_wide_group(group: &TagGroup, level: TargetType) -> bool {
+		group.target.level() == level && group.target.is_file_wide()
+	}
+
+	fn year_from(&self, name: &str) -> Option<u32> {
+		self.get(TargetType::Track, name)
+			.and_then(SimpleTag::text)
+			.and_then(try_parse_year)
+	}
+}
+
+impl Accessor for MatroskaTag {
+	impl_accessor!(
+		artist  => Track, "ARTIST";
+		title   => Track, "TITLE";
+		album   => Album, "TITLE";
+		genre   => Track, "GENRE";
+		comment => Track, "COMMENT";
+	);
+
+	impl_number_accessor!(
+		track       => Track, "PART_NUMBER";
+		track_total => Album, "TOTAL_PARTS";
+		disk        => Part, "PART_NUMBER";
+		disk_total  => Part, "TOTAL_PARTS";
+	);
+
+	fn year(&self) -> Option<u32> {
+		self.year_from("DATE_RECORDED")
+			.or_else(|| self.year_from("DATE_RELEASED"))
+	}
+
+	fn set_year(&mut self, value: u32) {
+		self.insert(
+			TargetType::Track,
+			SimpleTag::text_item("DATE_RECORDED", value.to_string()),
+		);
+	}
+
+	fn remove_year(&mut self) {
+		self.remove(TargetType::Track, "DATE_RECORDED");
+		self.remove(TargetType::Track, "DATE_RELEASED");
+	}
+}
+
+impl TagExt for MatroskaTag {
+	type Err = LoftyError;
+	type RefKey<'a> = &'a str;
+
+	#[inline]
+	fn tag_type(&self) -> TagType {
+		TagType::Matroska
+	}
+
+	fn len(&self) -> usize {
+		self.groups
+			.iter()
+			.map(|group| group.simple_tags.len())
+			.sum::<usize>()
+			+ self.pictures.len()
+	}
+
+	fn contains<'a>(&'a self, key: Self::RefKey<'a>) -> bool {
+		self.groups
+			.iter()
+			.any(|group| group.simple_tags.iter().any(|t| t.name == key))
+	}
+
+	fn is_empty(&self) -> bool {
+		self.groups.iter().all(|group| group.simple_tags.is_empty()) && self.pictures.is_empty()
+	}
+
+	/// Write a `Matroska` tag to a file
+	///
+	/// This will rewrite the `Tags` and `Attachments` elements. If the new elements don't fit in place
+	/// of the old ones (including any trailing `Void` elements), the old ones will be replaced with
+	/// `Void` elements, and the new ones appended to the end of the Segment.
+	///
+	/// # Errors
+	///
+	/// * Attempting to write the tag to a format that does not support it
+	fn save_to<F>(
+		&self,
+		file: &mut F,
+		write_options: WriteOptions,
+	) -> std::result::Result<(), Self::Err>
+	where
+		F: FileLike,
+		LoftyError: From<<F as Truncate>::Error>,
+		LoftyError: From<<F as Length>::Error>,
+	{
+		write::write_to(file, self, write_options)
+	}
+
+	/// Dumps the tag to a writer
+	///
+	/// This will write a `Tags` element, followed by an `Attachments` element containing the pictures.
+	///
+	/// # Errors
+	///
+	/// * [`std::io::Error`]
+	fn dump_to<W: Write>(
+		&self,
+		writer: &mut W,
+		write_options: WriteOptions,
+	) -> std::result::Result<(), Self::Err> {
+		write::dump_to(self, writer, write_options)
+	}
+
+	fn clear(&mut self) {
+		self.groups.clear();
+		self.pictures.clear();
+	}
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SplitTagRemainder(MatroskaTag);
+
+impl From<SplitTagRemainder> for MatroskaTag {
+	fn from(from: SplitTagRemainder) -> Self {
+		from.0
+	}
+}
+
+impl SplitTag for MatroskaTag {
+	type Remainder = SplitTagRemainder;
+
+	fn split_tag(mut self) -> (Self::Remainder, Tag) {
+		let mut tag = Tag::new(TagType::Matroska);
+
+		for group in &mut self.groups {
+			if !group.target.is_file_wide() {
+				continue;
+			}
+
+			let level = group.target.level();
+			for simple_tag in std::mem::take(&mut group.simple_tags) {
+				let key = match (&simple_tag.value, simple_tag.default) {
+					(Some(SimpleTagValue::String(_)), true) if simple_tag.children.is_empty() => {
+						leveled_item_key(level, &simple_tag.name)
+					},
+					_ => None,
+				};
+
+				let (Some(key), Some(SimpleTagValue::String(value))) = (key, &simple_tag.value)
+				else {
+					group.simple_tags.push(simple_tag);
+					continue;
+				};
+
+				tag.items
+					.push(TagItem::new(key, ItemValue::Text(value.clone())));
+			}
+		}
+
+		for attached_picture in std::mem::take(&mut self.pictures) {
+			tag.push_picture(attached_picture.into_picture());
+		}
+
+		(SplitTagRemainder(self), tag)
+	}
+}
+
+impl MergeTag for SplitTagRemainder {
+	type Merged = MatroskaTag;
+
+	fn merge_tag(self, tag: Tag) -> Self::Merged {
+		let Self(mut merged) = self;
+
+		for item in tag.items {
+			let Some((level, name)) = leveled_name(item.key()) else {
+				continue;
+			};
+
+			let name = name.to_string();
+			let value = match item.item_value {
+				ItemValue::Text(text) | ItemValue::Locator(text) => SimpleTagValue::String(text),
+				ItemValue::Binary(data) => SimpleTagValue::Binary(data),
+			};
+
+			merged.push(level, SimpleTag::new(name, value));
+		}
+
+		for picture in tag.pictures {
+			merged.push_picture(picture);
+		}
+
+		merged
+	}
+}
+
+impl From<MatroskaTag> for Tag {
+	fn from(input: MatroskaTag) -> Self {
+		input.split_tag().1
+	}
+}
+
+impl From<Tag> for MatroskaTag {
+	fn from(input: Tag) -> Self {
+		SplitTagRemainder::default().merge_tag(input)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::config::WriteOptions;
+	use crate::matroska::{MatroskaTag, SimpleTag, SimpleTagValue, TagGroup, Target, TargetType};
+	use crate::picture::{MimeType, Picture, PictureType};
+	use crate::prelude::*;
+	use crate::tag::{Tag, TagType};
+
+	fn read_tag() -> MatroskaTag {
+		let bytes = crate::tag::utils::test_utils::read_path("tests/tags/assets/test.matroska");
+		super::read::read_dumped(&bytes).unwrap()
+	}
+
+	#[test_log::test]
+	fn parse_matroska_tags() {
+		let tag = read_tag();
+
+		assert_eq!(tag.groups().len(), 2);
+
+		let album = &tag.groups()[0];
+		assert_eq!(album.target.target_type, Some(TargetType::Album));
+		assert_eq!(
+			album.simple_tags,
+			vec![SimpleTag::new(
+				"TITLE",
+				SimpleTagValue::String(String::from("Baz album"))
+			)]
+		);
+
+		let track = &tag.groups()[1];
+		assert_eq!(track.target.target_type, Some(TargetType::Track));
+		assert_eq!(track.simple_tags.len(), 6);
+		assert_eq!(tag.title().as_deref(), Some("Foo title"));
+		assert_eq!(tag.artist().as_deref(), Some("Bar artist"));
+		assert_eq!(tag.comment().as_deref(), Some("Qux comment"));
+		assert_eq!(tag.genre().as_deref(), Some("Classical"));
+		assert_eq!(tag.track(), Some(1));
+
+		let artist = tag.get(TargetType::Track, "ARTIST").unwrap();
+		assert_eq!(
+			artist.children,
+			vec![SimpleTag::new(
+				"SORT_WITH",
+				SimpleTagValue::String(String::from("Artist, Bar"))
+			)]
+		);
+	}
+
+	#[test_log::test]
+	fn matroska_re_read() {
+		let parsed_tag = read_tag();
+
+		let mut writer = Vec::new();
+		parsed_tag
+			.dump_to(&mut writer, WriteOptions::default())
+			.unwrap();
+
+		let temp_parsed_tag = super::read::read_dumped(&writer).unwrap();
+
+		assert_eq!(parsed_tag, temp_parsed_tag);
+	}
+
+	#[test_log::test]
+	fn matroska_to_tag() {
+		let tag: Tag = read_tag().into();
+
+		// The artist has a nested tag, and will stay in the remainder
+		assert!(tag.get(&ItemKey::TrackArtist).is_none());
+
+		assert_eq!(tag.get_string(&ItemKey::TrackTitle), Some("Foo title"));
+		assert_eq!(tag.get_string(&ItemKey::AlbumTitle), Some("Baz album"));
+		assert_eq!(tag.get_string(&ItemKey::Comment), Some("Qux comment"));
+		assert_eq!(tag.get_string(&ItemKey::TrackNumber), Some("1"));
+		assert_eq!(tag.get_string(&ItemKey::Genre), Some("Classical"));
+	}
+
+	#[test_log::test]
+	fn tag_to_matroska() {
+		let tag = crate::tag::utils::test_utils::create_tag(TagType::Matroska);
+
+		let matroska_tag: MatroskaTag = tag.into();
+
+		assert_eq!(matroska_tag.title().as_deref(), Some("Foo title"));
+		assert_eq!(matroska_tag.artist().as_deref(), Some("Bar artist"));
+		assert_eq!(matroska_tag.album().as_deref(), Some("Baz album"));
+		assert_eq!(matroska_tag.comment().as_deref(), Some("Qux comment"));
+		assert_eq!(matroska_tag.track(), Some(1));
+		assert_eq!(matroska_tag.genre().as_deref(), Some("Classical"));
+
+		// The album title should be in its own group
+		assert_eq!(
+			matroska_tag
+				.get(TargetType::Album, "TITLE")
+				.and_then(SimpleTag::text),
+			Some("Baz album")
+		);
+	}
+
+	#[test_log::test]
+	fn split_merge_keeps_other_targets() {
+		let mut matroska_tag = MatroskaTag::new();
+		matroska_tag.set_title(String::from("Foo title"));
+
+		let mut chapter = TagGroup::new(Target {
+			chapter_uids: vec![1234],
+			..Target::new(TargetType::Track)
+		});
+		chapter.simple_tags.push(SimpleTag::new(
+			"TITLE",
+			SimpleTagValue::String(String::from("Chapter title")),
+		));
+		matroska_tag.groups_mut().push(chapter.clone());
+
+		let mut translated = SimpleTag::new(
+			"TITLE",
+			SimpleTagValue::String(String::from("Titre de la piste")),
+		);
+		translated.language = String::from("fre");
+		translated.default = false;
+		matroska_tag.push(TargetType::Track, translated.clone());
+
+		let (remainder, mut tag) = matroska_tag.split_tag();
+		assert_eq!(tag.len(), 1);
+		tag.insert_text(ItemKey::TrackTitle, String::from("Bar title"));
+
+		let merged = remainder.merge_tag(tag);
+		assert_eq!(merged.title().as_deref(), Some("Bar title"));
+		assert!(merged.groups().contains(&chapter));
+		assert!(merged
+			.groups()
+			.iter()
+			.any(|group| group.simple_tags.contains(&translated)));
+	}
+
+	#[test_log::test]
+	fn disc_numbers() {
+		let mut tag = Tag::new(TagType::Matroska);
+		tag.set_disk(2);
+		tag.set_disk_total(3);
+		tag.set_track_total(10);
+
+		let matroska_tag: MatroskaTag = tag.into();
+		assert_eq!(matroska_tag.disk(), Some(2));
+		assert_eq!(matroska_tag.disk_total(), Some(3));
+		assert_eq!(matroska_tag.track_total(), Some(10));
+		assert_eq!(
+			matroska_tag
+				.get(TargetType::Part, "PART_NUMBER")
+				.and_then(SimpleTag::text),
+			Some("2")
+		);
+
+		let tag: Tag = matroska_tag.into();
+		assert_eq!(tag.disk(), Some(2));
+		assert_eq!(tag.disk_total(), Some(3));
+		assert_eq!(tag.track_total(), Some(10));
+	}
+
+	#[test_log::test]
+	fn pictures() {
+		let picture = Picture::new_unchecked(
+			PictureType::CoverFront,
+			Some(MimeType::Jpeg),
+			Some(String::from("Foo description")),
+			vec![0xFF, 0xD8, 0xFF, 0xE0],
+		);
+
+		let mut matroska_tag = MatroskaTag::new();
+		matroska_tag.push_picture(picture.clone());
+
+		let mut writer = Vec::new();
+		matroska_tag
+			.dump_to(&mut writer, WriteOptions::default())
+			.unwrap();
+
+		let read_tag = super::read::read_dumped(&writer).unwrap();
+		let attached = &read_tag.attached_pictures()[0];
+		assert_eq!(attached.file_name(), "cover.jpg");
+		assert_ne!(attached.uid(), 0);
+		assert_eq!(attached.picture(), &picture);
+
+		let tag: Tag = read_tag.into();
+		assert_eq!(tag.pictures(), std::slice::from_ref(&picture));
+	}
+}
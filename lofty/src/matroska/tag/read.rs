@@ -0,0 +1,202 @@
+use super::{
+	AttachedPicture, MatroskaTag, SimpleTag, SimpleTagValue, TagGroup, Target, TargetType,
+};
+use crate::error::Result;
+use crate::matroska::element::{
+	read_string, read_uint, Children, ATTACHED_FILE, FILE_DATA, FILE_DESCRIPTION, FILE_MEDIA_TYPE,
+	FILE_NAME, FILE_UID, SIMPLE_TAG, TAG, TAG_ATTACHMENT_UID, TAG_BINARY, TAG_CHAPTER_UID,
+	TAG_DEFAULT, TAG_EDITION_UID, TAG_LANGUAGE, TAG_LANGUAGE_BCP47, TAG_NAME, TAG_STRING,
+	TAG_TRACK_UID, TARGETS, TARGET_TYPE, TARGET_TYPE_VALUE,
+};
+use crate::picture::{MimeType, Picture, PictureType};
+
+use std::borrow::Cow;
+
+/// Reads the content of a `Tags` element
+pub(crate) fn read_tags(content: &[u8], tag: &mut MatroskaTag) -> Result<()> {
+	for child in Children::new(content) {
+		let (id, data) = child?;
+		if id == TAG {
+			tag.groups.push(read_tag(data)?);
+		}
+	}
+
+	Ok(())
+}
+
+fn read_tag(content: &[u8]) -> Result<TagGroup> {
+	let mut group = TagGroup::default();
+
+	for child in Children::new(content) {
+		let (id, data) = child?;
+		match id {
+			TARGETS => group.target = read_targets(data)?,
+			SIMPLE_TAG => {
+				if let Some(simple_tag) = read_simple_tag(data)? {
+					group.simple_tags.push(simple_tag);
+				}
+			},
+			_ => {},
+		}
+	}
+
+	Ok(group)
+}
+
+fn read_targets(content: &[u8]) -> Result<Target> {
+	let mut target = Target::default();
+
+	for child in Children::new(content) {
+		let (id, data) = child?;
+		match id {
+			TARGET_TYPE_VALUE => {
+				let value = read_uint(data)?;
+				target.target_type = TargetType::from_value(value);
+				if target.target_type.is_none() {
+					log::warn!("Encountered an unknown TargetTypeValue ({value}), discarding");
+				}
+			},
+			TARGET_TYPE => target.name = Some(read_string(data)?),
+			TAG_TRACK_UID => push_uid(&mut target.track_uids, data)?,
+			TAG_EDITION_UID => push_uid(&mut target.edition_uids, data)?,
+			TAG_CHAPTER_UID => push_uid(&mut target.chapter_uids, data)?,
+			TAG_ATTACHMENT_UID => push_uid(&mut target.attachment_uids, data)?,
+			_ => {},
+		}
+	}
+
+	Ok(target)
+}
+
+fn push_uid(uids: &mut Vec<u64>, data: &[u8]) -> Result<()> {
+	// A UID of 0 means the tag applies to *all* items of that kind
+	let uid = read_uint(data)?;
+	if uid != 0 {
+		uids.push(uid);
+	}
+
+	Ok(())
+}
+
+fn read_simple_tag(content: &[u8]) -> Result<Option<SimpleTag>> {
+	let mut simple_tag = SimpleTag::default();
+
+	for child in Children::new(content) {
+		let (id, data) = child?;
+		match id {
+			TAG_NAME => simple_tag.name = read_string(data)?,
+			TAG_LANGUAGE => simple_tag.language = read_string(data)?,
+			TAG_LANGUAGE_BCP47 => simple_tag.language_bcp47 = Some(read_string(data)?),
+			TAG_DEFAULT => simple_tag.default = read_uint(data)? != 0,
+			TAG_STRING => simple_tag.value = Some(SimpleTagValue::String(read_string(data)?)),
+			TAG_BINARY => simple_tag.value = Some(SimpleTagValue::Binary(data.to_vec())),
+			SIMPLE_TAG => {
+				if let Some(nested) = read_simple_tag(data)? {
+					simple_tag.children.push(nested);
+				}
+			},
+			_ => {},
+		}
+	}
+
+	if simple_tag.name.is_empty() {
+		log::warn!("Encountered a SimpleTag with no name, discarding");
+		return Ok(None);
+	}
+
+	Ok(Some(simple_tag))
+}
+
+/// Reads all pictures from the content of an `Attachments` element
+pub(crate) fn read_attachments(content: &[u8], tag: &mut MatroskaTag) -> Result<()> {
+	for child in Children::new(content) {
+		let (id, data) = child?;
+		if id != ATTACHED_FILE {
+			continue;
+		}
+
+		if let Some(picture) = read_attached_file(data)? {
+			tag.pictures.push(picture);
+		}
+	}
+
+	Ok(())
+}
+
+fn read_attached_file(content: &[u8]) -> Result<Option<AttachedPicture>> {
+	let mut description = None;
+	let mut file_name = String::new();
+	let mut media_type = String::new();
+	let mut file_data = None;
+	let mut uid = 0;
+
+	for child in Children::new(content) {
+		let (id, data) = child?;
+		match id {
+			FILE_DESCRIPTION => description = Some(read_string(data)?),
+			FILE_NAME => file_name = read_string(data)?,
+			FILE_MEDIA_TYPE => media_type = read_string(data)?,
+			FILE_DATA => file_data = Some(data),
+			FILE_UID => uid = read_uint(data)?,
+			_ => {},
+		}
+	}
+
+	if !is_picture(&file_name, &media_type) {
+		return Ok(None);
+	}
+
+	let Some(file_data) = file_data else {
+		log::warn!("Encountered an attached picture with no data, discarding");
+		return Ok(None);
+	};
+
+	let picture = Picture {
+		pic_type: AttachedPicture::picture_type_from_name(&file_name),
+		mime_type: is_picture_media_type(&media_type).then(|| MimeType::from_str(&media_type)),
+		description: description.map(Cow::Owned),
+		data: Cow::Owned(file_data.to_vec()),
+	};
+
+	Ok(Some(AttachedPicture {
+		file_name,
+		uid,
+		picture,
+	}))
+}
+
+/// Whether an attachment should be treated as a picture
+///
+/// Pictures without a known media type are written as `application/octet-stream`, so they can
+/// only be identified by their cover art names.
+pub(crate) fn is_picture(file_name: &str, media_type: &str) -> bool {
+	is_picture_media_type(media_type)
+		|| AttachedPicture::picture_type_from_name(file_name) != PictureType::Other
+}
+
+fn is_picture_media_type(media_type: &str) -> bool {
+	media_type
+		.get(..6)
+		.is_some_and(|prefix| prefix.eq_ignore_ascii_case("image/"))
+}
+
+/// Reads a standalone `Tags` element, as written by [`MatroskaTag::dump_to`](crate::tag::TagExt::dump_to)
+#[cfg(test)]
+pub(crate) fn read_dumped(mut content: &[u8]) -> Result<MatroskaTag> {
+	use crate::matroska::element::{ElementHeader, ATTACHMENTS, TAGS};
+
+	let mut tag = MatroskaTag::default();
+	while !content.is_empty() {
+		let header = ElementHeader::read(&mut content)?;
+		let (data, remaining) = content.split_at(header.size.unwrap() as usize);
+		content = remaining;
+
+		match header.id {
+			TAGS => read_tags(data, &mut tag)?,
+			ATTACHMENTS => read_attachments(data, &mut tag)?,
+			_ => {},
+		}
+	}
+
+	Ok(tag)
+}
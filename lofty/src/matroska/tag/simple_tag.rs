@@ -0,0 +1,78 @@
+/// The value of a [`SimpleTag`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SimpleTagValue {
+	/// A UTF-8 string (`TagString`)
+	String(String),
+	/// Binary data (`TagBinary`)
+	Binary(Vec<u8>),
+}
+
+/// A single tag in a [`TagGroup`](crate::matroska::TagGroup)
+///
+/// Tag names are case-sensitive, and should be uppercase.
+/// See <https://www.matroska.org/technical/tagging.html> for the official names.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SimpleTag {
+	/// The name of the tag, such as `TITLE`
+	pub name: String,
+	/// The value of the tag
+	///
+	/// This can be missing when the tag is only used as a parent for [`SimpleTag::children`].
+	pub value: Option<SimpleTagValue>,
+	/// The language of the tag, as an ISO 639-2 code (`und` if unknown)
+	pub language: String,
+	/// The language of the tag, as a BCP 47 tag
+	///
+	/// When present, this takes precedence over [`SimpleTag::language`].
+	pub language_bcp47: Option<String>,
+	/// Whether this is the default/original language to use for the tag
+	pub default: bool,
+	/// Nested tags, giving additional information about this tag (such as `SORT_WITH`)
+	pub children: Vec<SimpleTag>,
+}
+
+impl SimpleTag {
+	/// Create a new `SimpleTag`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::matroska::{SimpleTag, SimpleTagValue};
+	///
+	/// let tag = SimpleTag::new("TITLE", SimpleTagValue::String(String::from("Foo title")));
+	/// assert_eq!(tag.language, "und");
+	/// assert!(tag.default);
+	/// ```
+	pub fn new(name: impl Into<String>, value: SimpleTagValue) -> Self {
+		Self {
+			name: name.into(),
+			value: Some(value),
+			..Self::default()
+		}
+	}
+
+	/// Returns the value as a string, if it is of type [`SimpleTagValue::String`]
+	pub fn text(&self) -> Option<&str> {
+		match &self.value {
+			Some(SimpleTagValue::String(text)) => Some(text),
+			_ => None,
+		}
+	}
+
+	pub(crate) fn text_item(name: &str, value: String) -> Self {
+		Self::new(name, SimpleTagValue::String(value))
+	}
+}
+
+impl Default for SimpleTag {
+	fn default() -> Self {
+		Self {
+			name: String::new(),
+			value: None,
+			language: String::from("und"),
+			language_bcp47: None,
+			default: true,
+			children: Vec::new(),
+		}
+	}
+}
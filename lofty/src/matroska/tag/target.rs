@@ -0,0 +1,108 @@
+/// The logical level of a [`Target`]
+///
+/// See <https://www.matroska.org/technical/tagging.html#target-types>
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum TargetType {
+	/// The lowest level, such as a shot in a video
+	Shot = 10,
+	/// A subdivision of a track, such as a movement
+	Subtrack = 20,
+	/// A track, song, or chapter
+	Track = 30,
+	/// A session or part, such as a disc in a multi-disc album
+	Part = 40,
+	/// An album, concert, or movie
+	Album = 50,
+	/// An edition, issue, or volume
+	Edition = 60,
+	/// The highest level, such as a collection of albums
+	Collection = 70,
+}
+
+impl TargetType {
+	/// Get a `TargetType` from its `TargetTypeValue`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::matroska::TargetType;
+	///
+	/// assert_eq!(TargetType::from_value(50), Some(TargetType::Album));
+	/// assert_eq!(TargetType::from_value(55), None);
+	/// ```
+	pub fn from_value(value: u64) -> Option<Self> {
+		match value {
+			10 => Some(Self::Shot),
+			20 => Some(Self::Subtrack),
+			30 => Some(Self::Track),
+			40 => Some(Self::Part),
+			50 => Some(Self::Album),
+			60 => Some(Self::Edition),
+			70 => Some(Self::Collection),
+			_ => None,
+		}
+	}
+
+	/// Get the `TargetTypeValue`
+	pub fn value(self) -> u64 {
+		self as u64
+	}
+}
+
+/// What a [`TagGroup`](crate::matroska::TagGroup) applies to
+///
+/// A target with no UIDs applies to the entire file.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Target {
+	/// The logical level of the target
+	///
+	/// While the specification defaults to [`TargetType::Album`] when this is missing, many muxers
+	/// omit it for file-wide tags. A missing `TargetTypeValue` is treated as [`TargetType::Track`].
+	pub target_type: Option<TargetType>,
+	/// An informational name for the level, such as `ALBUM` or `TRACK`
+	pub name: Option<String>,
+	/// The tracks that the tags apply to
+	pub track_uids: Vec<u64>,
+	/// The editions that the tags apply to
+	pub edition_uids: Vec<u64>,
+	/// The chapters that the tags apply to
+	pub chapter_uids: Vec<u64>,
+	/// The attachments that the tags apply to
+	pub attachment_uids: Vec<u64>,
+}
+
+impl Target {
+	/// Create a new `Target` for a level, applying to the entire file
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::matroska::{Target, TargetType};
+	///
+	/// let target = Target::new(TargetType::Album);
+	/// assert_eq!(target.level(), TargetType::Album);
+	/// assert!(target.track_uids.is_empty());
+	/// ```
+	pub fn new(target_type: TargetType) -> Self {
+		Self {
+			target_type: Some(target_type),
+			..Self::default()
+		}
+	}
+
+	/// The effective level of the target
+	///
+	/// See [`Target::target_type`].
+	pub fn level(&self) -> TargetType {
+		self.target_type.unwrap_or(TargetType::Track)
+	}
+
+	/// Whether the tags apply to the file (or its tracks) as a whole, rather than specific editions,
+	/// chapters, or attachments
+	pub(crate) fn is_file_wide(&self) -> bool {
+		self.edition_uids.is_empty()
+			&& self.chapter_uids.is_empty()
+			&& self.attachment_uids.is_empty()
+	}
+}
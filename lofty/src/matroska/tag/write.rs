@@ -0,0 +1,422 @@
+use super::read::is_picture;
+use super::{MatroskaTag, SimpleTag, SimpleTagValue, TagGroup};
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::matroska::element::{
+	fit_element, read_string, read_uint, void_element, write_element, write_id, write_size,
+	write_string_element, write_uint_element, Children, ATTACHED_FILE, ATTACHMENTS, FILE_DATA,
+	FILE_DESCRIPTION, FILE_MEDIA_TYPE, FILE_NAME, FILE_UID, SEEK, SEEK_HEAD, SEEK_ID,
+	SEEK_POSITION, SIMPLE_TAG, TAG, TAGS, TAG_ATTACHMENT_UID, TAG_BINARY, TAG_CHAPTER_UID,
+	TAG_DEFAULT, TAG_EDITION_UID, TAG_LANGUAGE, TAG_LANGUAGE_BCP47, TAG_NAME, TAG_STRING,
+	TAG_TRACK_UID, TARGETS, TARGET_TYPE, TARGET_TYPE_VALUE, VOID,
+};
+use crate::matroska::segment::{ElementPosition, Layout};
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::io::{Cursor, Write};
+
+pub(crate) fn write_to<F>(
+	file: &mut F,
+	tag: &MatroskaTag,
+	_write_options: WriteOptions,
+) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	file.rewind()?;
+
+	let mut data = Vec::new();
+	file.read_to_end(&mut data)?;
+
+	let layout = read_layout(&data)?;
+
+	let tags = create_tags(tag)?;
+	let attachments = create_attachments(&data, &layout, tag)?;
+
+	replace_element(&mut data, TAGS, tags.as_deref())?;
+	replace_element(&mut data, ATTACHMENTS, attachments.as_deref())?;
+	update_seek_heads(&mut data)?;
+
+	file.rewind()?;
+	file.truncate(0)?;
+	file.write_all(&data)?;
+
+	Ok(())
+}
+
+pub(crate) fn dump_to<W: Write>(
+	tag: &MatroskaTag,
+	writer: &mut W,
+	_write_options: WriteOptions,
+) -> Result<()> {
+	let mut data = Vec::new();
+	write_element(&mut data, TAGS, &create_tags(tag)?.unwrap_or_default())?;
+
+	if !tag.pictures.is_empty() {
+		let mut attachments = Vec::new();
+		write_pictures(&mut attachments, tag, Vec::new(), Vec::new())?;
+		write_element(&mut data, ATTACHMENTS, &attachments)?;
+	}
+
+	writer.write_all(&data)?;
+	Ok(())
+}
+
+fn read_layout(data: &[u8]) -> Result<Layout> {
+	Layout::read(&mut Cursor::new(data), data.len() as u64)
+}
+
+fn element_data<'a>(data: &'a [u8], element: &ElementPosition) -> &'a [u8] {
+	&data[element.data_start() as usize..element.end() as usize]
+}
+
+/// Creates the content of the `Tags` element, or `None` if there's nothing to write
+fn create_tags(tag: &MatroskaTag) -> Result<Option<Vec<u8>>> {
+	let mut content = Vec::new();
+	for group in &tag.groups {
+		if group.simple_tags.is_empty() {
+			continue;
+		}
+
+		let mut tag_content = Vec::new();
+		write_targets(&mut tag_content, group)?;
+
+		for simple_tag in &group.simple_tags {
+			write_simple_tag(&mut tag_content, simple_tag)?;
+		}
+
+		write_element(&mut content, TAG, &tag_content)?;
+	}
+
+	if content.is_empty() {
+		return Ok(None);
+	}
+
+	Ok(Some(content))
+}
+
+fn write_targets(writer: &mut Vec<u8>, group: &TagGroup) -> Result<()> {
+	let target = &group.target;
+
+	let mut content = Vec::new();
+	if let Some(target_type) = target.target_type {
+		write_uint_element(&mut content, TARGET_TYPE_VALUE, target_type.value())?;
+	}
+
+	if let Some(name) = &target.name {
+		write_string_element(&mut content, TARGET_TYPE, name)?;
+	}
+
+	for (id, uids) in [
+		(TAG_TRACK_UID, &target.track_uids),
+		(TAG_EDITION_UID, &target.edition_uids),
+		(TAG_CHAPTER_UID, &target.chapter_uids),
+		(TAG_ATTACHMENT_UID, &target.attachment_uids),
+	] {
+		for uid in uids {
+			write_uint_element(&mut content, id, *uid)?;
+		}
+	}
+
+	// `Targets` is mandatory, even when empty
+	write_element(writer, TARGETS, &content)
+}
+
+fn write_simple_tag(writer: &mut Vec<u8>, simple_tag: &SimpleTag) -> Result<()> {
+	let mut content = Vec::new();
+	write_string_element(&mut content, TAG_NAME, &simple_tag.name)?;
+	write_string_element(&mut content, TAG_LANGUAGE, &simple_tag.language)?;
+
+	if let Some(language_bcp47) = &simple_tag.language_bcp47 {
+		write_string_element(&mut content, TAG_LANGUAGE_BCP47, language_bcp47)?;
+	}
+
+	write_uint_element(&mut content, TAG_DEFAULT, u64::from(simple_tag.default))?;
+
+	match &simple_tag.value {
+		Some(SimpleTagValue::String(value)) => {
+			write_string_element(&mut content, TAG_STRING, value)?
+		},
+		Some(SimpleTagValue::Binary(value)) => write_element(&mut content, TAG_BINARY, value)?,
+		None => {},
+	}
+
+	for child in &simple_tag.children {
+		write_simple_tag(&mut content, child)?;
+	}
+
+	write_element(writer, SIMPLE_TAG, &content)
+}
+
+/// Creates the content of the `Attachments` element, or `None` if there's nothing to write
+///
+/// Any existing attachments that aren't pictures are kept as-is.
+fn create_attachments(data: &[u8], layout: &Layout, tag: &MatroskaTag) -> Result<Option<Vec<u8>>> {
+	let mut content = Vec::new();
+	let mut taken_names = Vec::new();
+	let mut taken_uids = Vec::new();
+
+	for attachments in layout.find(ATTACHMENTS) {
+		for child in Children::new(element_data(data, attachments)) {
+			let (id, attached_file) = child?;
+			if id != ATTACHED_FILE {
+				continue;
+			}
+
+			let mut file_name = String::new();
+			let mut media_type = String::new();
+			let mut uid = 0;
+			for child in Children::new(attached_file) {
+				let (id, data) = child?;
+				match id {
+					FILE_NAME => file_name = read_string(data)?,
+					FILE_MEDIA_TYPE => media_type = read_string(data)?,
+					FILE_UID => uid = read_uint(data)?,
+					_ => {},
+				}
+			}
+
+			if is_picture(&file_name, &media_type) {
+				continue;
+			}
+
+			write_element(&mut content, ATTACHED_FILE, attached_file)?;
+			taken_names.push(file_name);
+			taken_uids.push(uid);
+		}
+	}
+
+	write_pictures(&mut content, tag, taken_names, taken_uids)?;
+
+	if content.is_empty() {
+		return Ok(None);
+	}
+
+	Ok(Some(content))
+}
+
+fn write_pictures(
+	writer: &mut Vec<u8>,
+	tag: &MatroskaTag,
+	mut taken_names: Vec<String>,
+	mut taken_uids: Vec<u64>,
+) -> Result<()> {
+	taken_names.extend(
+		tag.pictures
+			.iter()
+			.map(|attached_picture| attached_picture.file_name.clone()),
+	);
+	taken_uids.extend(
+		tag.pictures
+			.iter()
+			.map(|attached_picture| attached_picture.uid),
+	);
+
+	let mut next_uid = taken_uids.iter().copied().max().unwrap_or(0);
+
+	for (index, attached_picture) in tag.pictures.iter().enumerate() {
+		let file_name = if attached_picture.file_name.is_empty() {
+			let file_name = attached_picture.generate_file_name(index, &taken_names);
+			taken_names.push(file_name.clone());
+			file_name
+		} else {
+			attached_picture.file_name.clone()
+		};
+
+		let uid = if attached_picture.uid == 0 {
+			next_uid += 1;
+			next_uid
+		} else {
+			attached_picture.uid
+		};
+
+		let picture = &attached_picture.picture;
+
+		let mut content = Vec::new();
+		if let Some(description) = picture.description() {
+			write_string_element(&mut content, FILE_DESCRIPTION, description)?;
+		}
+
+		write_string_element(&mut content, FILE_NAME, &file_name)?;
+		write_string_element(&mut content, FILE_MEDIA_TYPE, attached_picture.media_type())?;
+		write_element(&mut content, FILE_DATA, picture.data())?;
+		write_uint_element(&mut content, FILE_UID, uid)?;
+
+		write_element(writer, ATTACHED_FILE, &content)?;
+	}
+
+	Ok(())
+}
+
+/// Replaces every occurrence of an element in the Segment
+///
+/// All existing occurrences are replaced with `Void` elements. The new element is then placed in the
+/// first run of `Void` elements large enough to hold it, or appended to the end of the Segment.
+fn replace_element(data: &mut Vec<u8>, id: u32, content: Option<&[u8]>) -> Result<()> {
+	let layout = read_layout(data)?;
+	for element in layout.find(id) {
+		let (start, end) = (element.start as usize, element.end() as usize);
+		data.splice(start..end, void_element((end - start) as u64));
+	}
+
+	let Some(content) = content else {
+		return Ok(());
+	};
+
+	let layout = read_layout(data)?;
+
+	let mut runs = Vec::new();
+	for element in &layout.elements {
+		match runs.last_mut() {
+			Some((_, end)) if element.id == VOID && *end == element.start => *end = element.end(),
+			_ if element.id == VOID => runs.push((element.start, element.end())),
+			_ => {},
+		}
+	}
+
+	for (start, end) in runs {
+		if let Some(element) = fit_element(id, content, end - start) {
+			data.splice(start as usize..end as usize, element);
+			return Ok(());
+		}
+	}
+
+	log::debug!("Appending element {id:#X} to the end of the Segment");
+
+	let mut element = Vec::new();
+	write_element(&mut element, id, content)?;
+
+	let segment = layout.segment;
+	let element_len = element.len() as u64;
+	data.splice(segment.end as usize..segment.end as usize, element);
+
+	if segment.unknown_size {
+		return Ok(());
+	}
+
+	// The size field has to stay the same length, as the `SeekHead` and `Cues` positions would
+	// otherwise shift. If the new size doesn't fit, the Segment is marked as having an unknown size.
+	let size_len = segment.size_len;
+	let new_size = segment.end - segment.data_start + element_len;
+	let max_size = (1_u64 << (7 * u32::from(size_len))) - 1;
+
+	let mut size_field = Vec::new();
+	write_size(&mut size_field, new_size.min(max_size), size_len);
+
+	let size_start = segment.size_start as usize;
+	data[size_start..size_start + usize::from(size_len)].copy_from_slice(&size_field);
+
+	Ok(())
+}
+
+/// Updates the `Seek` entries for the `Tags` and `Attachments` elements
+///
+/// Entries for elements that no longer exist are removed, and a missing entry is added to
+/// the first `SeekHead`.
+fn update_seek_heads(data: &mut [u8]) -> Result<()> {
+	let layout = read_layout(data)?;
+
+	let positions = [TAGS, ATTACHMENTS].map(|id| {
+		(
+			id,
+			layout
+				.find(id)
+				.next()
+				.map(|element| element.start - layout.segment.data_start),
+		)
+	});
+
+	let seek_heads = layout.find(SEEK_HEAD).collect::<Vec<_>>();
+	let mut indexed = [false; 2];
+
+	let mut new_seek_heads = Vec::with_capacity(seek_heads.len());
+	for seek_head in &seek_heads {
+		let mut entries = Vec::new();
+		let mut changed = false;
+
+		for child in Children::new(element_data(data, seek_head)) {
+			let (id, seek) = child?;
+			if id != SEEK {
+				continue;
+			}
+
+			let mut seek_id = 0;
+			let mut seek_position = 0;
+			for child in Children::new(seek) {
+				let (id, data) = child?;
+				match id {
+					SEEK_ID => seek_id = read_uint(data)?,
+					SEEK_POSITION => seek_position = read_uint(data)?,
+					_ => {},
+				}
+			}
+
+			match positions
+				.iter()
+				.position(|(id, _)| u64::from(*id) == seek_id)
+			{
+				Some(index) => match positions[index].1 {
+					Some(position) => {
+						indexed[index] = true;
+						changed |= position != seek_position;
+						entries.push((positions[index].0, position));
+					},
+					None => changed = true,
+				},
+				None => entries.push((seek_id as u32, seek_position)),
+			}
+		}
+
+		new_seek_heads.push((entries, changed));
+	}
+
+	if let Some((entries, changed)) = new_seek_heads.first_mut() {
+		for (index, (id, position)) in positions.iter().enumerate() {
+			if let (false, Some(position)) = (indexed[index], position) {
+				entries.push((*id, *position));
+				*changed = true;
+			}
+		}
+	}
+
+	for (seek_head, (entries, changed)) in seek_heads.iter().zip(new_seek_heads) {
+		if !changed {
+			continue;
+		}
+
+		let mut content = Vec::new();
+		for (id, position) in entries {
+			let mut seek_id = Vec::new();
+			write_id(&mut seek_id, id);
+
+			let mut seek = Vec::new();
+			write_element(&mut seek, SEEK_ID, &seek_id)?;
+			write_uint_element(&mut seek, SEEK_POSITION, position)?;
+
+			write_element(&mut content, SEEK, &seek)?;
+		}
+
+		// The SeekHead can take up any `Void` elements that follow it
+		let mut end = seek_head.end();
+		for element in layout
+			.elements
+			.iter()
+			.skip_while(|e| e.start < seek_head.end())
+		{
+			if element.id != VOID || element.start != end {
+				break;
+			}
+
+			end = element.end();
+		}
+
+		match fit_element(SEEK_HEAD, &content, end - seek_head.start) {
+			Some(element) => data[seek_head.start as usize..end as usize].copy_from_slice(&element),
+			None => log::warn!("Unable to fit the updated SeekHead, leaving it as-is"),
+		}
+	}
+
+	Ok(())
+}
@@ -0,0 +1,22 @@
+use super::tag::write;
+use super::MatroskaTag;
+use crate::config::WriteOptions;
+use crate::error::{LoftyError, Result};
+use crate::macros::err;
+use crate::tag::{Tag, TagType};
+use crate::util::io::{FileLike, Length, Truncate};
+
+pub(crate) fn write_to<F>(file: &mut F, tag: &Tag, write_options: WriteOptions) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	match tag.tag_type() {
+		TagType::Matroska => {
+			let matroska_tag: MatroskaTag = tag.clone().into();
+			write::write_to(file, &matroska_tag, write_options)
+		},
+		_ => err!(UnsupportedTag),
+	}
+}
@@ -106,7 +106,7 @@ impl TryFrom<ItemKey> for AtomIdent<'static> {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct AtomInfo {
 	pub(crate) start: u64,
 	pub(crate) len: u64,
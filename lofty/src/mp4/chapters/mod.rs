@@ -0,0 +1,143 @@
+//! Chapter support for MP4 files
+//!
+//! Chapters are read from the Nero `chpl` atom (`moov.udta.chpl`), falling back to a QuickTime
+//! chapter track (a text track referenced by `trak.tref.chap`). Only the `chpl` atom is written.
+
+pub(super) mod read;
+pub(super) mod write;
+
+use crate::error::Result;
+use crate::tag::items::Chapter;
+
+use std::time::Duration;
+
+use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
+
+/// The `chpl` atom stores times in 100 nanosecond units
+const CHPL_TIMESCALE: u64 = 10_000_000;
+
+/// Both the chapter count and title lengths are stored in a single byte
+const CHPL_MAX_LEN: usize = u8::MAX as usize;
+
+/// Parse the content of a `chpl` atom
+pub(super) fn parse_chpl(mut content: &[u8]) -> Result<Vec<Chapter>> {
+	let version = content.read_u8()?;
+	let _flags = content.read_u24::<BigEndian>()?;
+
+	if version > 0 {
+		let _reserved = content.read_u32::<BigEndian>()?;
+	}
+
+	let count = content.read_u8()?;
+
+	let mut chapters = Vec::with_capacity(usize::from(count));
+	for _ in 0..count {
+		let start = content.read_u64::<BigEndian>()?;
+		let title_len = usize::from(content.read_u8()?);
+
+		let Some(title) = content.get(..title_len) else {
+			log::warn!("Chapter title exceeds the `chpl` atom, stopping");
+			break;
+		};
+
+		content = &content[title_len..];
+
+		let start = Duration::from_nanos(start.saturating_mul(1_000_000_000 / CHPL_TIMESCALE));
+		let title = String::from_utf8_lossy(title);
+		chapters.push(Chapter::new(
+			start,
+			(!title.is_empty()).then(|| title.into_owned()),
+		));
+	}
+
+	Ok(chapters)
+}
+
+/// Create a `chpl` atom, including its header
+///
+/// NOTE: The atom can only hold 255 chapters, any extras will be discarded.
+pub(super) fn create_chpl(chapters: &[Chapter]) -> Result<Vec<u8>> {
+	if chapters.len() > CHPL_MAX_LEN {
+		log::warn!(
+			"Too many chapters for the `chpl` atom, discarding {}",
+			chapters.len() - CHPL_MAX_LEN
+		);
+	}
+
+	let chapters = &chapters[..chapters.len().min(CHPL_MAX_LEN)];
+
+	let mut chpl = Vec::new();
+
+	// Size, filled in later
+	chpl.write_u32::<BigEndian>(0)?;
+	chpl.extend(b"chpl");
+	// Version 1, no flags
+	chpl.write_u32::<BigEndian>(0x0100_0000)?;
+	// Reserved
+	chpl.write_u32::<BigEndian>(0)?;
+	chpl.write_u8(chapters.len() as u8)?;
+
+	for chapter in chapters {
+		let start = chapter.start.as_nanos() / u128::from(1_000_000_000 / CHPL_TIMESCALE);
+		chpl.write_u64::<BigEndian>(u64::try_from(start).unwrap_or(u64::MAX))?;
+
+		let mut title = chapter.title.as_deref().unwrap_or_default();
+		if title.len() > CHPL_MAX_LEN {
+			log::warn!("Chapter title is too long for the `chpl` atom, truncating");
+
+			let mut end = CHPL_MAX_LEN;
+			while !title.is_char_boundary(end) {
+				end -= 1;
+			}
+
+			title = &title[..end];
+		}
+
+		chpl.write_u8(title.len() as u8)?;
+		chpl.extend(title.as_bytes());
+	}
+
+	let size = chpl.len() as u32;
+	chpl[..4].copy_from_slice(&size.to_be_bytes());
+
+	Ok(chpl)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{create_chpl, parse_chpl};
+	use crate::tag::items::Chapter;
+
+	use std::time::Duration;
+
+	#[test_log::test]
+	fn chpl_re_read() {
+		let chapters = vec![
+			Chapter::new(Duration::ZERO, Some(String::from("Intro"))),
+			Chapter::new(Duration::from_millis(61_500), Some(String::from("Part 1"))),
+			Chapter::new(Duration::from_secs(150), None),
+		];
+
+		let chpl = create_chpl(&chapters).unwrap();
+		assert_eq!(&chpl[4..8], b"chpl");
+		assert_eq!(
+			chpl.len() as u32,
+			u32::from_be_bytes(chpl[..4].try_into().unwrap())
+		);
+
+		let parsed = parse_chpl(&chpl[8..]).unwrap();
+		assert_eq!(parsed, chapters);
+	}
+
+	#[test_log::test]
+	fn chpl_truncate_title() {
+		// 127 * 2 byte characters, and an extra 2 bytes to go over the limit
+		let title = "é".repeat(128);
+		let chapters = vec![Chapter::new(Duration::ZERO, Some(title))];
+
+		let chpl = create_chpl(&chapters).unwrap();
+		let parsed = parse_chpl(&chpl[8..]).unwrap();
+
+		assert_eq!(parsed[0].title.as_deref(), Some("é".repeat(127).as_str()));
+	}
+}
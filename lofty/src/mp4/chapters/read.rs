@@ -0,0 +1,281 @@
+use crate::config::ParsingMode;
+use crate::error::Result;
+use crate::macros::err;
+use crate::mp4::atom_info::{AtomIdent, AtomInfo};
+use crate::mp4::read::{atom_tree, AtomReader};
+use crate::tag::items::Chapter;
+use crate::util::text::{decode_text, TextDecodeOptions, TextEncoding};
+
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+/// The sample tables needed to locate the samples of a chapter track
+#[derive(Default)]
+struct SampleTables {
+	/// (sample count, sample duration)
+	time_to_sample: Vec<(u32, u32)>,
+	/// The size of every sample, or a single size for all samples
+	sample_sizes: Vec<u32>,
+	/// (first chunk, samples per chunk)
+	sample_to_chunk: Vec<(u32, u32)>,
+	chunk_offsets: Vec<u64>,
+}
+
+/// Read the chapters from a QuickTime chapter track
+///
+/// Each sample of the track is a chapter title, with the sample's duration being that of the chapter.
+///
+/// NOTE: The reader is expected to have no bounds set, see [`AtomReader::reset_bounds`]
+pub(in crate::mp4) fn read_chapter_track<R>(
+	reader: &mut AtomReader<R>,
+	mdia: &AtomInfo,
+	parse_mode: ParsingMode,
+) -> Result<Vec<Chapter>>
+where
+	R: Read + Seek,
+{
+	let mut timescale = None;
+	let mut tables = SampleTables::default();
+
+	for atom in children(reader, mdia, parse_mode)? {
+		match atom.ident {
+			AtomIdent::Fourcc(ref fourcc) if fourcc == b"mdhd" => {
+				seek_to_content(reader, &atom)?;
+				timescale = Some(read_timescale(reader)?);
+			},
+			AtomIdent::Fourcc(ref fourcc) if fourcc == b"minf" => {
+				let Some(stbl) = children(reader, &atom, parse_mode)?
+					.into_iter()
+					.find(|child| child.ident == AtomIdent::Fourcc(*b"stbl"))
+				else {
+					continue;
+				};
+
+				tables = read_sample_tables(reader, &stbl, parse_mode)?;
+			},
+			_ => {},
+		}
+	}
+
+	let Some(timescale) = timescale.filter(|timescale| *timescale > 0) else {
+		err!(BadAtom("Chapter track has no valid timescale"));
+	};
+
+	let offsets = sample_offsets(&tables);
+
+	let mut chapters = Vec::new();
+	let mut start = 0u64;
+	let durations = tables
+		.time_to_sample
+		.iter()
+		.flat_map(|&(count, duration)| std::iter::repeat(duration).take(count as usize));
+
+	for ((offset, size), duration) in offsets.zip(durations) {
+		let end = start + u64::from(duration);
+
+		let mut chapter = Chapter::new(to_duration(start, timescale), None);
+		chapter.end = Some(to_duration(end, timescale));
+		chapter.title = read_title(reader, offset, size)?;
+
+		chapters.push(chapter);
+		start = end;
+	}
+
+	Ok(chapters)
+}
+
+/// Iterate over the (offset, size) of every sample
+fn sample_offsets(tables: &SampleTables) -> impl Iterator<Item = (u64, u32)> + '_ {
+	let sample_size = |idx: usize| match tables.sample_sizes.as_slice() {
+		[size] => *size,
+		sizes => sizes.get(idx).copied().unwrap_or(0),
+	};
+
+	let mut sample_idx = 0;
+	tables
+		.chunk_offsets
+		.iter()
+		.enumerate()
+		.flat_map(move |(chunk_idx, &chunk_offset)| {
+			// Chunks are 1-based
+			let chunk_number = chunk_idx as u32 + 1;
+			let samples_per_chunk = tables
+				.sample_to_chunk
+				.iter()
+				.take_while(|(first_chunk, _)| *first_chunk <= chunk_number)
+				.last()
+				.map_or(0, |(_, samples_per_chunk)| *samples_per_chunk);
+
+			let mut offset = chunk_offset;
+			let mut samples = Vec::new();
+			for _ in 0..samples_per_chunk {
+				let size = sample_size(sample_idx);
+				samples.push((offset, size));
+
+				offset += u64::from(size);
+				sample_idx += 1;
+			}
+
+			samples
+		})
+}
+
+fn read_title<R>(reader: &mut AtomReader<R>, offset: u64, size: u32) -> Result<Option<String>>
+where
+	R: Read + Seek,
+{
+	// Samples start with a 16-bit length
+	if size < 2 {
+		return Ok(None);
+	}
+
+	reader.seek(SeekFrom::Start(offset))?;
+
+	let len = u32::from(reader.read_u16()?).min(size - 2);
+	let mut text = Vec::new();
+	reader.take(u64::from(len)).read_to_end(&mut text)?;
+
+	let encoding = match text.get(..2) {
+		Some([0xFE, 0xFF] | [0xFF, 0xFE]) => TextEncoding::UTF16,
+		_ => TextEncoding::UTF8,
+	};
+
+	let title = decode_text(&mut &text[..], TextDecodeOptions::new().encoding(encoding))?;
+	Ok(title.text_or_none())
+}
+
+fn read_sample_tables<R>(
+	reader: &mut AtomReader<R>,
+	stbl: &AtomInfo,
+	parse_mode: ParsingMode,
+) -> Result<SampleTables>
+where
+	R: Read + Seek,
+{
+	let mut tables = SampleTables::default();
+
+	for atom in children(reader, stbl, parse_mode)? {
+		let AtomIdent::Fourcc(fourcc) = atom.ident else {
+			continue;
+		};
+
+		seek_to_content(reader, &atom)?;
+		let content_len = atom.len - atom.header_size();
+
+		match &fourcc {
+			b"stts" => {
+				let count = read_entry_count(reader, content_len, 8)?;
+				for _ in 0..count {
+					tables
+						.time_to_sample
+						.push((reader.read_u32()?, reader.read_u32()?));
+				}
+			},
+			b"stsz" => {
+				let _version_and_flags = reader.read_u32()?;
+				let sample_size = reader.read_u32()?;
+				let count = reader.read_u32()?;
+
+				if sample_size != 0 {
+					tables.sample_sizes.push(sample_size);
+					continue;
+				}
+
+				if u64::from(count) * 4 > content_len.saturating_sub(12) {
+					err!(SizeMismatch);
+				}
+
+				for _ in 0..count {
+					tables.sample_sizes.push(reader.read_u32()?);
+				}
+			},
+			b"stsc" => {
+				let count = read_entry_count(reader, content_len, 12)?;
+				for _ in 0..count {
+					let first_chunk = reader.read_u32()?;
+					let samples_per_chunk = reader.read_u32()?;
+					let _sample_description_id = reader.read_u32()?;
+
+					tables
+						.sample_to_chunk
+						.push((first_chunk, samples_per_chunk));
+				}
+			},
+			b"stco" => {
+				let count = read_entry_count(reader, content_len, 4)?;
+				for _ in 0..count {
+					tables.chunk_offsets.push(u64::from(reader.read_u32()?));
+				}
+			},
+			b"co64" => {
+				let count = read_entry_count(reader, content_len, 8)?;
+				for _ in 0..count {
+					tables.chunk_offsets.push(reader.read_u64()?);
+				}
+			},
+			_ => {},
+		}
+	}
+
+	Ok(tables)
+}
+
+/// Read the version, flags, and entry count of a table, verifying that the entries fit in the atom
+fn read_entry_count<R>(reader: &mut AtomReader<R>, content_len: u64, entry_size: u64) -> Result<u32>
+where
+	R: Read + Seek,
+{
+	let _version_and_flags = reader.read_u32()?;
+	let count = reader.read_u32()?;
+
+	if u64::from(count) * entry_size > content_len.saturating_sub(8) {
+		err!(SizeMismatch);
+	}
+
+	Ok(count)
+}
+
+fn read_timescale<R>(reader: &mut AtomReader<R>) -> Result<u32>
+where
+	R: Read + Seek,
+{
+	let version = reader.read_u8()?;
+	let _flags = reader.read_uint(3)?;
+
+	// Skip the creation and modification times
+	if version == 1 {
+		reader.seek(SeekFrom::Current(16))?;
+	} else {
+		reader.seek(SeekFrom::Current(8))?;
+	}
+
+	Ok(reader.read_u32()?)
+}
+
+/// List the children of a container atom
+fn children<R>(
+	reader: &mut AtomReader<R>,
+	parent: &AtomInfo,
+	parse_mode: ParsingMode,
+) -> Result<Vec<AtomInfo>>
+where
+	R: Read + Seek,
+{
+	seek_to_content(reader, parent)?;
+
+	let (_, children) = atom_tree(reader, parent.len - parent.header_size(), b"", parse_mode)?;
+	Ok(children)
+}
+
+fn seek_to_content<R>(reader: &mut AtomReader<R>, atom: &AtomInfo) -> Result<()>
+where
+	R: Read + Seek,
+{
+	reader.seek(SeekFrom::Start(atom.start + atom.header_size()))?;
+	Ok(())
+}
+
+fn to_duration(units: u64, timescale: u32) -> Duration {
+	let nanos = u128::from(units) * 1_000_000_000 / u128::from(timescale);
+	Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+}
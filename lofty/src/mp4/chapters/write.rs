@@ -0,0 +1,153 @@
+use super::create_chpl;
+use crate::config::ParseOptions;
+use crate::error::{FileEncodingError, LoftyError, Result};
+use crate::file::FileType;
+use crate::mp4::atom_info::{AtomIdent, ATOM_HEADER_LEN};
+use crate::mp4::ilst::write::update_offsets;
+use crate::mp4::write::{AtomWriter, ContextualAtom};
+use crate::tag::items::Chapter;
+use crate::util::io::{FileLike, Length, Truncate};
+
+use std::io::{Read, Seek, SeekFrom};
+
+/// Replace the `moov.udta.chpl` atom
+///
+/// If `chapters` is empty, the existing atom will be removed. Nothing will be written if the file
+/// already has the same chapters, be it in a `chpl` atom or a QuickTime chapter track.
+pub(in crate::mp4) fn write_chapters<F>(file: &mut F, chapters: &[Chapter]) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+{
+	log::debug!("Attempting to write chapters to file");
+
+	let mut atom_writer = AtomWriter::new_from_file(file, ParseOptions::DEFAULT_PARSING_MODE)?;
+
+	let Some(moov) = atom_writer.find_contextual_atom(*b"moov") else {
+		return Err(FileEncodingError::new(
+			FileType::Mp4,
+			"Could not find \"moov\" atom in target file",
+		)
+		.into());
+	};
+
+	let udta = find_child(moov, *b"udta");
+	let existing_chpl = udta.and_then(|udta| find_child(udta, *b"chpl"));
+
+	let new_chpl = if chapters.is_empty() {
+		Vec::new()
+	} else {
+		create_chpl(chapters)?
+	};
+
+	if let Some(existing_chpl) = existing_chpl {
+		let mut existing_chpl_bytes = Vec::new();
+
+		let mut write_handle = atom_writer.start_write();
+		write_handle.seek(SeekFrom::Start(existing_chpl.info.start))?;
+		Read::by_ref(&mut write_handle)
+			.take(existing_chpl.info.len)
+			.read_to_end(&mut existing_chpl_bytes)?;
+
+		if existing_chpl_bytes == new_chpl {
+			log::debug!("Chapters are unchanged, nothing to do");
+			return Ok(());
+		}
+	} else {
+		if chapters.is_empty() {
+			return Ok(());
+		}
+
+		// A QuickTime chapter track may already hold the same chapters
+		let mut write_handle = atom_writer.start_write();
+		write_handle.rewind()?;
+
+		let existing_chapters = crate::mp4::read::read_from(
+			&mut write_handle,
+			ParseOptions::new()
+				.read_properties(false)
+				.read_cover_art(false),
+		)?
+		.ilst_tag
+		.map(|ilst| ilst.chapters)
+		.unwrap_or_default();
+
+		if existing_chapters == chapters {
+			log::debug!("Chapters are unchanged, nothing to do");
+			return Ok(());
+		}
+	}
+
+	let moov_start = moov.info.start;
+	let moov_end = moov.info.start + moov.info.len;
+
+	let range;
+	let replacement;
+	let mut udta_size = None;
+	match (udta, existing_chpl) {
+		(Some(udta), Some(existing_chpl)) => {
+			log::trace!("Replacing existing `chpl` atom");
+
+			range = existing_chpl.info.start..existing_chpl.info.start + existing_chpl.info.len;
+			replacement = new_chpl;
+			udta_size = Some((&udta.info, udta.info.len - (range.end - range.start)));
+		},
+		(Some(udta), None) => {
+			log::trace!("Adding `chpl` atom to existing `udta` atom");
+
+			let udta_end = udta.info.start + udta.info.len;
+
+			range = udta_end..udta_end;
+			replacement = new_chpl;
+			udta_size = Some((&udta.info, udta.info.len));
+		},
+		(None, _) => {
+			log::trace!("No `udta` atom found, creating one");
+
+			let mut udta = Vec::with_capacity(ATOM_HEADER_LEN as usize + new_chpl.len());
+			udta.extend(((ATOM_HEADER_LEN as usize + new_chpl.len()) as u32).to_be_bytes());
+			udta.extend(b"udta");
+			udta.extend(new_chpl);
+
+			range = moov_end..moov_end;
+			replacement = udta;
+		},
+	}
+
+	let difference = replacement.len() as i64 - (range.end - range.start) as i64;
+
+	if difference != 0 {
+		update_offsets(&atom_writer, moov, difference, range.start)?;
+	}
+
+	let mut write_handle = atom_writer.start_write();
+
+	if let Some((udta, size_without_chpl)) = udta_size {
+		write_handle.seek(SeekFrom::Start(udta.start))?;
+		write_handle.write_atom_size(
+			udta.start,
+			size_without_chpl + replacement.len() as u64,
+			udta.extended,
+		)?;
+	}
+
+	write_handle.seek(SeekFrom::Start(moov_start))?;
+	write_handle.write_atom_size(
+		moov_start,
+		(moov.info.len as i64 + difference) as u64,
+		moov.info.extended,
+	)?;
+
+	write_handle.splice(range.start as usize..range.end as usize, replacement);
+	drop(write_handle);
+
+	atom_writer.save_to(file)
+}
+
+fn find_child(parent: &ContextualAtom, fourcc: [u8; 4]) -> Option<&ContextualAtom> {
+	parent
+		.children
+		.iter()
+		.find(|child| child.info.ident == AtomIdent::Fourcc(fourcc))
+}
@@ -9,9 +9,11 @@ pub(crate) mod write;
 use super::AtomIdent;
 use crate::config::{global_options, WriteOptions};
 use crate::error::LoftyError;
+use crate::lyrics::Lrc;
 use crate::mp4::ilst::atom::AtomDataStorage;
 use crate::picture::{Picture, PictureType, TOMBSTONE_PICTURE};
 use crate::tag::companion_tag::CompanionTag;
+use crate::tag::items::Chapter;
 use crate::tag::{
 	try_parse_year, Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType,
 };
@@ -34,6 +36,7 @@ const GENRE: AtomIdent<'_> = AtomIdent::Fourcc(*b"\xa9gen");
 const COMMENT: AtomIdent<'_> = AtomIdent::Fourcc(*b"\xa9cmt");
 const ADVISORY_RATING: AtomIdent<'_> = AtomIdent::Fourcc(*b"rtng");
 const COVR: AtomIdent<'_> = AtomIdent::Fourcc(*b"covr");
+const LYRICS: AtomIdent<'_> = AtomIdent::Fourcc(*b"\xa9lyr");
 
 macro_rules! impl_accessor {
 	($($name:ident => $const:ident;)+) => {
@@ -104,6 +107,12 @@ macro_rules! impl_flag_accessors {
 ///
 /// Do note, all pictures will be [`PictureType::Other`](crate::PictureType::Other)
 ///
+/// ## Chapters
+///
+/// Chapters are not stored in the `ilst` atom itself, but in the Nero `chpl` atom (`moov.udta.chpl`).
+/// When reading, a QuickTime chapter track will be used if there is no `chpl` atom. Only the
+/// `chpl` atom is written, and it is replaced (or removed) whenever the `Ilst` is saved.
+///
 /// ### From `Tag`
 ///
 /// When converting from [`Tag`], only items with a value of [`ItemValue::Text`](crate::ItemValue::Text), as
@@ -114,6 +123,7 @@ macro_rules! impl_flag_accessors {
 #[tag(description = "An MP4 ilst atom", supported_formats(Mp4))]
 pub struct Ilst {
 	pub(crate) atoms: Vec<Atom<'static>>,
+	pub(crate) chapters: Vec<Chapter>,
 }
 
 impl Ilst {
@@ -445,6 +455,41 @@ impl Ilst {
 		})
 	}
 
+	/// Returns the chapters
+	///
+	/// See [`Ilst`'s chapter notes](Ilst#chapters).
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::mp4::Ilst;
+	/// use lofty::tag::items::Chapter;
+	/// use std::time::Duration;
+	///
+	/// let mut ilst = Ilst::new();
+	/// ilst.set_chapters(vec![Chapter::new(
+	/// 	Duration::ZERO,
+	/// 	Some(String::from("Intro")),
+	/// )]);
+	///
+	/// assert_eq!(ilst.chapters().len(), 1);
+	/// ```
+	pub fn chapters(&self) -> &[Chapter] {
+		&self.chapters
+	}
+
+	/// Replaces the chapters
+	///
+	/// NOTE: The `chpl` atom can only hold 255 chapters, and 255 byte titles.
+	pub fn set_chapters(&mut self, chapters: Vec<Chapter>) {
+		self.chapters = chapters;
+	}
+
+	/// Removes all chapters, returning them
+	pub fn remove_chapters(&mut self) -> Vec<Chapter> {
+		std::mem::take(&mut self.chapters)
+	}
+
 	// Extracts a u16 from an integer pair
 	fn extract_number(&self, fourcc: [u8; 4], expected_size: usize) -> Option<u16> {
 		if let Some(atom) = self.get(&AtomIdent::Fourcc(fourcc)) {
@@ -605,7 +650,7 @@ impl TagExt for Ilst {
 	}
 
 	fn len(&self) -> usize {
-		self.atoms.len()
+		self.atoms.len() + self.chapters.len()
 	}
 
 	fn contains<'a>(&'a self, key: Self::RefKey<'a>) -> bool {
@@ -613,7 +658,7 @@ impl TagExt for Ilst {
 	}
 
 	fn is_empty(&self) -> bool {
-		self.atoms.is_empty()
+		self.atoms.is_empty() && self.chapters.is_empty()
 	}
 
 	fn save_to<F>(
@@ -639,6 +684,7 @@ impl TagExt for Ilst {
 
 	fn clear(&mut self) {
 		self.atoms.clear();
+		self.chapters.clear();
 	}
 }
 
@@ -665,6 +711,8 @@ impl SplitTag for Ilst {
 	fn split_tag(mut self) -> (Self::Remainder, Tag) {
 		let mut tag = Tag::new(TagType::Mp4Ilst);
 
+		tag.chapters = std::mem::take(&mut self.chapters);
+
 		self.atoms.retain_mut(|atom| {
 			let Atom { ident, data } = atom;
 			let value = match data.first_mut() {
@@ -734,6 +782,14 @@ impl SplitTag for Ilst {
 				},
 			);
 
+			// Synchronized lyrics are stored as LRC
+			if let (ItemKey::Lyrics, ItemValue::Text(text)) = (&key, &value) {
+				if let Some(lrc) = Lrc::from_lyrics_text(text) {
+					tag.synced_lyrics.push(lrc.to_synced_lyrics());
+					return false; // Atom consumed
+				}
+			}
+
 			tag.items.push(TagItem::new(key, value));
 			false // Atom consumed
 		});
@@ -846,9 +902,34 @@ impl MergeTag for SplitTagRemainder {
 			})
 		}
 
+		// Synchronized lyrics are stored as LRC, which takes the place of any plain lyrics
+		if let Some(lyrics) = tag.synced_lyrics.first() {
+			if tag.synced_lyrics.len() > 1 {
+				log::warn!(
+					"MP4 can only store one set of synchronized lyrics, discarding the rest"
+				);
+			}
+
+			if merged.get(&LYRICS).is_some() {
+				log::warn!(
+					"Tag has both plain and synchronized lyrics, discarding the plain lyrics"
+				);
+				let _ = merged.remove(&LYRICS);
+			}
+
+			merged.atoms.push(Atom {
+				ident: LYRICS,
+				data: AtomDataStorage::Single(AtomData::UTF8(
+					Lrc::from_synced_lyrics(lyrics).into_lyrics_text(),
+				)),
+			});
+		}
+
 		create_int_pair(&mut merged, *b"trkn", tracks);
 		create_int_pair(&mut merged, *b"disk", discs);
 
+		merged.chapters = tag.chapters;
+
 		merged
 	}
 }
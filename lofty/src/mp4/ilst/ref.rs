@@ -5,6 +5,7 @@
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
 use crate::mp4::{Atom, AtomData, AtomIdent, Ilst};
+use crate::tag::items::Chapter;
 use crate::util::io::{FileLike, Length, Truncate};
 
 use std::io::Write;
@@ -13,12 +14,14 @@ impl Ilst {
 	pub(crate) fn as_ref(&self) -> IlstRef<'_, impl IntoIterator<Item = &AtomData>> {
 		IlstRef {
 			atoms: Box::new(self.atoms.iter().map(Atom::as_ref)),
+			chapters: &self.chapters,
 		}
 	}
 }
 
 pub(crate) struct IlstRef<'a, I> {
 	pub(super) atoms: Box<dyn Iterator<Item = AtomRef<'a, I>> + 'a>,
+	pub(super) chapters: &'a [Chapter],
 }
 
 impl<'a, I: 'a> IlstRef<'a, I>
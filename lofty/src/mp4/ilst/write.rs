@@ -5,6 +5,7 @@ use crate::error::{FileEncodingError, LoftyError, Result};
 use crate::file::FileType;
 use crate::macros::{decode_err, err, try_vec};
 use crate::mp4::atom_info::{AtomIdent, AtomInfo, ATOM_HEADER_LEN, FOURCC_LEN};
+use crate::mp4::chapters::write::write_chapters;
 use crate::mp4::ilst::r#ref::AtomRef;
 use crate::mp4::read::{atom_tree, find_child_atom, meta_is_full, verify_mp4, AtomReader};
 use crate::mp4::write::{AtomWriter, AtomWriterCompanion, ContextualAtom};
@@ -21,12 +22,30 @@ use byteorder::{BigEndian, ReadBytesExt, WriteBytesExt};
 const FULL_ATOM_SIZE: u64 = ATOM_HEADER_LEN + 4;
 const HDLR_SIZE: u64 = ATOM_HEADER_LEN + 25;
 
-// TODO: We are forcing the use of ParseOptions::DEFAULT_PARSING_MODE. This is not good. It should be caller-specified.
 pub(crate) fn write_to<'a, F, I>(
 	file: &mut F,
 	tag: &mut IlstRef<'a, I>,
 	write_options: WriteOptions,
 ) -> Result<()>
+where
+	F: FileLike,
+	LoftyError: From<<F as Truncate>::Error>,
+	LoftyError: From<<F as Length>::Error>,
+	I: IntoIterator<Item = &'a AtomData> + 'a,
+{
+	write_ilst(file, tag, write_options)?;
+
+	// The chapters are stored outside of the `ilst` atom, in `moov.udta.chpl`
+	file.rewind()?;
+	write_chapters(file, tag.chapters)
+}
+
+// TODO: We are forcing the use of ParseOptions::DEFAULT_PARSING_MODE. This is not good. It should be caller-specified.
+fn write_ilst<'a, F, I>(
+	file: &mut F,
+	tag: &mut IlstRef<'a, I>,
+	write_options: WriteOptions,
+) -> Result<()>
 where
 	F: FileLike,
 	LoftyError: From<<F as Truncate>::Error>,
@@ -427,7 +446,7 @@ where
 	Ok(())
 }
 
-fn update_offsets(
+pub(in crate::mp4) fn update_offsets(
 	writer: &AtomWriter,
 	moov: &ContextualAtom,
 	difference: i64,
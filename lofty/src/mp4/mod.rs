@@ -4,6 +4,7 @@
 //!
 //! The only supported tag format is [`Ilst`].
 mod atom_info;
+mod chapters;
 pub(crate) mod ilst;
 mod moov;
 mod properties;
@@ -21,7 +22,10 @@ pub mod constants {
 	pub use super::ilst::constants::*;
 }
 
-pub use crate::mp4::properties::{AudioObjectType, Mp4Codec, Mp4Properties};
+pub use crate::mp4::properties::{
+	AlacSpecificConfig, AudioObjectType, Mp4Codec, Mp4Properties, OpusChannelMapping,
+	OpusSpecificConfig,
+};
 pub use atom_info::AtomIdent;
 pub use ilst::advisory_rating::AdvisoryRating;
 pub use ilst::atom::{Atom, AtomData};
@@ -1,18 +1,32 @@
 use super::atom_info::{AtomIdent, AtomInfo};
+use super::chapters::parse_chpl;
 use super::ilst::read::parse_ilst;
 use super::ilst::Ilst;
-use super::read::{find_child_atom, meta_is_full, skip_atom, AtomReader};
-use crate::config::ParseOptions;
+use super::read::{meta_is_full, skip_atom, AtomReader};
+use crate::config::{ParseOptions, ParsingMode};
 use crate::error::Result;
-use crate::macros::decode_err;
+use crate::macros::{decode_err, try_vec};
+use crate::tag::items::Chapter;
 
-use std::io::{Read, Seek};
+use std::io::{Read, Seek, SeekFrom};
 
 pub(crate) struct Moov {
 	// Represents the trak.mdia atom
 	pub(crate) traks: Vec<AtomInfo>,
 	// Represents a parsed moov.udta.meta.ilst
 	pub(crate) ilst: Option<Ilst>,
+	// Represents a parsed moov.udta.chpl
+	pub(crate) chapters: Option<Vec<Chapter>>,
+	// Represents the trak.mdia atom of a QuickTime chapter track
+	pub(crate) chapter_trak: Option<AtomInfo>,
+}
+
+/// The information needed from a `trak` atom
+struct Trak {
+	id: u32,
+	// The track IDs from trak.tref.chap
+	chapter_track_ids: Vec<u32>,
+	mdia: Option<AtomInfo>,
 }
 
 impl Moov {
@@ -40,21 +54,30 @@ impl Moov {
 	{
 		let mut traks = Vec::new();
 		let mut ilst = None;
+		let mut chapters = None;
+		let mut all_traks = Vec::new();
 
 		while let Ok(Some(atom)) = reader.next() {
 			if let AtomIdent::Fourcc(fourcc) = atom.ident {
 				match &fourcc {
-					b"trak" if parse_options.read_properties => {
-						// All we need from here is trak.mdia
-						if let Some(mdia) =
-							find_child_atom(reader, atom.len, *b"mdia", parse_options.parsing_mode)?
-						{
-							skip_atom(reader, mdia.extended, mdia.len)?;
-							traks.push(mdia);
+					b"trak" if parse_options.read_properties || parse_options.read_tags => {
+						let trak = parse_trak(reader, &atom, parse_options.clone())?;
+
+						// All we need for the properties is trak.mdia
+						if let (true, Some(mdia)) = (parse_options.read_properties, &trak.mdia) {
+							traks.push(mdia.clone());
 						}
+
+						all_traks.push(trak);
 					},
 					b"udta" if parse_options.read_tags => {
-						let ilst_parsed = ilst_from_udta(reader, parse_options, atom.len - 8)?;
+						let (ilst_parsed, chapters_parsed) =
+							parse_udta(reader, &atom, parse_options.clone())?;
+
+						if chapters.is_none() {
+							chapters = chapters_parsed;
+						}
+
 						if let Some(ilst_parsed) = ilst_parsed {
 							let Some(mut existing_ilst) = ilst else {
 								ilst = Some(ilst_parsed);
@@ -78,50 +101,152 @@ impl Moov {
 			skip_atom(reader, atom.extended, atom.len)?
 		}
 
-		Ok(Self { traks, ilst })
+		let mut chapter_trak = None;
+		if parse_options.read_tags {
+			// The first track referenced as a chapter track by another track
+			chapter_trak = all_traks
+				.iter()
+				.flat_map(|trak| &trak.chapter_track_ids)
+				.find_map(|id| all_traks.iter().find(|trak| trak.id == *id))
+				.and_then(|trak| trak.mdia.clone());
+		}
+
+		Ok(Self {
+			traks,
+			ilst,
+			chapters,
+			chapter_trak,
+		})
 	}
 }
 
-fn ilst_from_udta<R>(
+fn parse_trak<R>(
 	reader: &mut AtomReader<R>,
+	trak: &AtomInfo,
 	parse_options: ParseOptions,
-	len: u64,
-) -> Result<Option<Ilst>>
+) -> Result<Trak>
 where
 	R: Read + Seek,
 {
-	let mut read = 8;
-	let mut found_meta = false;
-	let mut meta_atom_size = 0;
+	let mut ret = Trak {
+		id: 0,
+		chapter_track_ids: Vec::new(),
+		mdia: None,
+	};
 
-	while read < len {
+	let mut read = trak.header_size();
+	while read < trak.len {
 		let Some(atom) = reader.next()? else {
 			break;
 		};
 
-		if atom.ident == AtomIdent::Fourcc(*b"meta") {
-			found_meta = true;
-			meta_atom_size = atom.len;
-			break;
+		read += atom.len;
+
+		match atom.ident {
+			AtomIdent::Fourcc(ref fourcc) if fourcc == b"tkhd" && atom.len >= 24 => {
+				let version = reader.read_u8()?;
+				let _flags = reader.read_uint(3)?;
+
+				// Skip the creation and modification times
+				let times_len = if version == 1 { 16 } else { 8 };
+				reader.seek(SeekFrom::Current(times_len))?;
+
+				ret.id = reader.read_u32()?;
+
+				let read_len = atom.header_size() + 4 + times_len as u64 + 4;
+				reader.seek(SeekFrom::Current(atom.len.saturating_sub(read_len) as i64))?;
+			},
+			AtomIdent::Fourcc(ref fourcc) if fourcc == b"tref" && parse_options.read_tags => {
+				let mut tref_read = atom.header_size();
+				while tref_read < atom.len {
+					let Some(reference) = reader.next()? else {
+						break;
+					};
+
+					tref_read += reference.len;
+
+					if reference.ident != AtomIdent::Fourcc(*b"chap") {
+						skip_atom(reader, reference.extended, reference.len)?;
+						continue;
+					}
+
+					let ids_len = reference.len - reference.header_size();
+					for _ in 0..ids_len / 4 {
+						ret.chapter_track_ids.push(reader.read_u32()?);
+					}
+
+					reader.seek(SeekFrom::Current((ids_len % 4) as i64))?;
+				}
+			},
+			AtomIdent::Fourcc(ref fourcc) if fourcc == b"mdia" => {
+				skip_atom(reader, atom.extended, atom.len)?;
+				ret.mdia = Some(atom);
+			},
+			_ => skip_atom(reader, atom.extended, atom.len)?,
 		}
+	}
+
+	Ok(ret)
+}
+
+fn parse_udta<R>(
+	reader: &mut AtomReader<R>,
+	udta: &AtomInfo,
+	parse_options: ParseOptions,
+) -> Result<(Option<Ilst>, Option<Vec<Chapter>>)>
+where
+	R: Read + Seek,
+{
+	let mut read = udta.header_size();
+	let mut ilst = None;
+	let mut chapters = None;
+
+	while read < udta.len {
+		let Some(atom) = reader.next()? else {
+			break;
+		};
 
 		read += atom.len;
-		skip_atom(reader, atom.extended, atom.len)?;
-	}
 
-	if !found_meta {
-		return Ok(None);
+		match atom.ident {
+			AtomIdent::Fourcc(ref fourcc) if fourcc == b"meta" && ilst.is_none() => {
+				let content_start = reader.stream_position()?;
+
+				ilst = ilst_from_meta(reader, parse_options.clone(), atom.len)?;
+
+				// Skip anything following the `ilst` atom
+				let meta_end = content_start + atom.len - atom.header_size();
+				let pos = reader.stream_position()?;
+				reader.seek(SeekFrom::Current(meta_end.saturating_sub(pos) as i64))?;
+			},
+			AtomIdent::Fourcc(ref fourcc) if fourcc == b"chpl" => {
+				let mut content = try_vec![0; (atom.len - atom.header_size()) as usize];
+				reader.read_exact(&mut content)?;
+
+				match parse_chpl(&content) {
+					Ok(parsed) => chapters = Some(parsed),
+					Err(e) if parse_options.parsing_mode == ParsingMode::Strict => return Err(e),
+					Err(e) => log::warn!("Failed to parse `chpl` atom: {e}"),
+				}
+			},
+			_ => skip_atom(reader, atom.extended, atom.len)?,
+		}
 	}
 
+	Ok((ilst, chapters))
+}
+
+fn ilst_from_meta<R>(
+	reader: &mut AtomReader<R>,
+	parse_options: ParseOptions,
+	meta_atom_size: u64,
+) -> Result<Option<Ilst>>
+where
+	R: Read + Seek,
+{
 	// It's possible for the `meta` atom to be non-full,
 	// so we have to check for that case
-	let full_meta_atom = meta_is_full(reader)?;
-
-	if full_meta_atom {
-		read = 12;
-	} else {
-		read = 8;
-	}
+	let mut read = if meta_is_full(reader)? { 12 } else { 8 };
 
 	let mut found_ilst = false;
 	let mut ilst_atom_size = 0;
@@ -23,6 +23,9 @@ pub enum Mp4Codec {
 	ALAC,
 	MP3,
 	FLAC,
+	Opus,
+	AC3,
+	EAC3,
 }
 
 #[allow(missing_docs)]
@@ -131,6 +134,134 @@ impl TryFrom<u8> for AudioObjectType {
 	}
 }
 
+/// ALAC-specific information, from the `alac` atom
+///
+/// See [here](https://github.com/macosforge/alac/blob/master/ALACMagicCookieDescription.txt) for
+/// more information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AlacSpecificConfig {
+	/// The number of samples per frame
+	pub frame_length: u32,
+	/// The compatible version, this should always be 0
+	pub compatible_version: u8,
+	/// Bits per sample
+	pub bit_depth: u8,
+	/// Rice history multiplier, used by the decoder
+	pub pb: u8,
+	/// Rice initial history, used by the decoder
+	pub mb: u8,
+	/// Rice parameter limit, used by the decoder
+	pub kb: u8,
+	/// Channel count
+	pub channels: u8,
+	/// Unused
+	pub max_run: u16,
+	/// The maximum size of a single frame, 0 if unknown
+	pub max_frame_bytes: u32,
+	/// The average bitrate (bps), 0 if unknown
+	pub average_bitrate: u32,
+	/// Sample rate (Hz)
+	pub sample_rate: u32,
+}
+
+impl AlacSpecificConfig {
+	pub(crate) fn read<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read,
+	{
+		Ok(Self {
+			frame_length: reader.read_u32::<BigEndian>()?,
+			compatible_version: reader.read_u8()?,
+			bit_depth: reader.read_u8()?,
+			pb: reader.read_u8()?,
+			mb: reader.read_u8()?,
+			kb: reader.read_u8()?,
+			channels: reader.read_u8()?,
+			max_run: reader.read_u16::<BigEndian>()?,
+			max_frame_bytes: reader.read_u32::<BigEndian>()?,
+			average_bitrate: reader.read_u32::<BigEndian>()?,
+			sample_rate: reader.read_u32::<BigEndian>()?,
+		})
+	}
+}
+
+/// Opus-specific information, from the `dOps` atom
+///
+/// See [here](https://opus-codec.org/docs/opus_in_isobmff.html#4.3.2) for more information.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OpusSpecificConfig {
+	/// The `dOps` version, this should always be 0
+	pub version: u8,
+	/// The number of output channels
+	pub output_channel_count: u8,
+	/// The number of samples (at 48 kHz) to discard from the decoder output when starting playback
+	pub pre_skip: u16,
+	/// The sample rate of the original input, before encoding
+	///
+	/// This is only informational, as Opus is always decoded at 48 kHz.
+	pub input_sample_rate: u32,
+	/// The gain to apply to the decoder output, in Q7.8 dB
+	pub output_gain: i16,
+	/// The channel mapping family
+	pub channel_mapping_family: u8,
+	/// The channel mapping table, only present if [`Self::channel_mapping_family`] is non-zero
+	pub channel_mapping: Option<OpusChannelMapping>,
+}
+
+/// An Opus channel mapping table
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OpusChannelMapping {
+	/// The number of Opus streams
+	pub stream_count: u8,
+	/// The number of streams that are coupled (stereo)
+	pub coupled_count: u8,
+	/// The mapping of each output channel to a decoded channel
+	pub channel_mapping: Vec<u8>,
+}
+
+impl OpusSpecificConfig {
+	fn read<R>(reader: &mut R) -> Result<Self>
+	where
+		R: Read,
+	{
+		let version = reader.read_u8()?;
+		if version != 0 {
+			decode_err!(@BAIL Mp4, "Unsupported `dOps` version");
+		}
+
+		let output_channel_count = reader.read_u8()?;
+		let pre_skip = reader.read_u16::<BigEndian>()?;
+		let input_sample_rate = reader.read_u32::<BigEndian>()?;
+		let output_gain = reader.read_i16::<BigEndian>()?;
+		let channel_mapping_family = reader.read_u8()?;
+
+		let mut channel_mapping = None;
+		if channel_mapping_family != 0 {
+			let stream_count = reader.read_u8()?;
+			let coupled_count = reader.read_u8()?;
+
+			let mut mapping = try_vec![0; output_channel_count as usize];
+			reader.read_exact(&mut mapping)?;
+
+			channel_mapping = Some(OpusChannelMapping {
+				stream_count,
+				coupled_count,
+				channel_mapping: mapping,
+			});
+		}
+
+		Ok(Self {
+			version,
+			output_channel_count,
+			pre_skip,
+			input_sample_rate,
+			output_gain,
+			channel_mapping_family,
+			channel_mapping,
+		})
+	}
+}
+
 /// An MP4 file's audio properties
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 #[non_exhaustive]
@@ -144,6 +275,8 @@ pub struct Mp4Properties {
 	pub(crate) bit_depth: Option<u8>,
 	pub(crate) channels: u8,
 	pub(crate) drm_protected: bool,
+	pub(crate) alac_config: Option<AlacSpecificConfig>,
+	pub(crate) opus_config: Option<OpusSpecificConfig>,
 }
 
 impl From<Mp4Properties> for FileProperties {
@@ -209,6 +342,20 @@ impl Mp4Properties {
 	pub fn is_drm_protected(&self) -> bool {
 		self.drm_protected
 	}
+
+	/// ALAC-specific information
+	///
+	/// This is only applicable to files with the [`Mp4Codec::ALAC`] codec.
+	pub fn alac_config(&self) -> Option<&AlacSpecificConfig> {
+		self.alac_config.as_ref()
+	}
+
+	/// Opus-specific information
+	///
+	/// This is only applicable to files with the [`Mp4Codec::Opus`] codec.
+	pub fn opus_config(&self) -> Option<&OpusSpecificConfig> {
+		self.opus_config.as_ref()
+	}
 }
 
 struct AudioTrak {
@@ -441,12 +588,12 @@ where
 		};
 
 		match fourcc {
-			b"mp4a" => mp4a_properties(reader, properties)?,
-			b"alac" => alac_properties(reader, properties)?,
-			b"fLaC" => flac_properties(reader, properties)?,
-			// Maybe do these?
-			// TODO: dops (opus)
-			// TODO: wave (https://developer.apple.com/library/archive/documentation/QuickTime/QTFF/QTFFChap3/qtff3.html#//apple_ref/doc/uid/TP40000939-CH205-134202)
+			b"mp4a" => mp4a_properties(reader, &atom, properties)?,
+			b"alac" => alac_properties(reader, &atom, properties)?,
+			b"fLaC" => flac_properties(reader, &atom, properties)?,
+			b"Opus" => opus_properties(reader, &atom, properties)?,
+			b"ac-3" => ac3_properties(reader, &atom, properties)?,
+			b"ec-3" => eac3_properties(reader, &atom, properties)?,
 
 			// Special case to detect encrypted files
 			b"drms" => {
@@ -555,12 +702,135 @@ where
 	Ok(properties)
 }
 
+/// The fields common to all audio sample entries
+///
+/// QuickTime files may use version 1 or 2 sample entries, which extend the original with more
+/// precise information. See [here](https://developer.apple.com/library/archive/documentation/QuickTime/QTFF/QTFFChap3/qtff3.html#//apple_ref/doc/uid/TP40000939-CH205-SW1).
+struct AudioSampleEntry {
+	channels: u8,
+	sample_size: u8,
+	sample_rate: u32,
+}
+
+impl AudioSampleEntry {
+	/// Reads the sample entry, leaving the reader at the start of its child atoms
+	fn read<R>(stsd: &mut AtomReader<R>) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		// Skipping 8 bytes
+		// Reserved (6)
+		// Data reference index (2)
+		stsd.seek(SeekFrom::Current(8))?;
+
+		let version = stsd.read_u16()?;
+
+		// Skipping 6 bytes
+		// Revision level (2)
+		// Vendor (4)
+		stsd.seek(SeekFrom::Current(6))?;
+
+		let mut channels = stsd.read_u16()? as u8;
+		let mut sample_size = stsd.read_u16()? as u8;
+
+		// Skipping 4 bytes
+		// Compression ID (2)
+		// Packet size (2)
+		stsd.seek(SeekFrom::Current(4))?;
+
+		// 16.16 fixed point, we only care about the integer part
+		let mut sample_rate = stsd.read_u32()? >> 16;
+
+		match version {
+			0 => {},
+			1 => {
+				// Skipping 16 bytes
+				// Samples per packet (4)
+				// Bytes per packet (4)
+				// Bytes per frame (4)
+				// Bytes per sample (4)
+				stsd.seek(SeekFrom::Current(16))?;
+			},
+			2 => {
+				// Size of struct only (4)
+				stsd.seek(SeekFrom::Current(4))?;
+
+				sample_rate = f64::from_bits(stsd.read_u64()?) as u32;
+				channels = stsd.read_u32()? as u8;
+
+				// Always 0x7F000000 (4)
+				stsd.seek(SeekFrom::Current(4))?;
+
+				sample_size = stsd.read_u32()? as u8;
+
+				// Skipping 12 bytes
+				// Format specific flags (4)
+				// Bytes per audio packet (4)
+				// LPCM frames per audio packet (4)
+				stsd.seek(SeekFrom::Current(12))?;
+			},
+			_ => {
+				decode_err!(@BAIL Mp4, "Encountered an unknown audio sample entry version");
+			},
+		}
+
+		Ok(Self {
+			channels,
+			sample_size,
+			sample_rate,
+		})
+	}
+}
+
+/// Finds the first child of a sample entry with the given fourcc
+///
+/// QuickTime files may store the codec configuration in a `wave` atom, which is searched as well.
+/// See [here](https://developer.apple.com/library/archive/documentation/QuickTime/QTFF/QTFFChap3/qtff3.html#//apple_ref/doc/uid/TP40000939-CH205-134202).
+fn find_sample_entry_child<R>(
+	stsd: &mut AtomReader<R>,
+	sample_entry: &AtomInfo,
+	expected: [u8; 4],
+) -> Result<Option<AtomInfo>>
+where
+	R: Read + Seek,
+{
+	let end = sample_entry.start + sample_entry.len;
+
+	while stsd.stream_position()? + 8 <= end {
+		// The `wave` atom is ended with a terminator atom, which has an empty identifier
+		let mut header = [0; 8];
+		stsd.read_exact(&mut header)?;
+		if header[4..] == [0; 4] {
+			continue;
+		}
+
+		stsd.seek(SeekFrom::Current(-8))?;
+
+		let Some(atom) = stsd.next()? else {
+			break;
+		};
+
+		match atom.ident {
+			AtomIdent::Fourcc(fourcc) if fourcc == expected => return Ok(Some(atom)),
+			// The children of `wave` are searched in place
+			AtomIdent::Fourcc(ref fourcc) if fourcc == b"wave" => {},
+			_ => skip_atom(stsd, atom.extended, atom.len)?,
+		}
+	}
+
+	Ok(None)
+}
+
 // https://wiki.multimedia.cx/index.php?title=MPEG-4_Audio#Sampling_Frequencies
 pub(crate) const SAMPLE_RATES: [u32; 15] = [
 	96000, 88200, 64000, 48000, 44100, 32000, 24000, 22050, 16000, 12000, 11025, 8000, 7350, 0, 0,
 ];
 
-fn mp4a_properties<R>(stsd: &mut AtomReader<R>, properties: &mut Mp4Properties) -> Result<()>
+fn mp4a_properties<R>(
+	stsd: &mut AtomReader<R>,
+	sample_entry: &AtomInfo,
+	properties: &mut Mp4Properties,
+) -> Result<()>
 where
 	R: Read + Seek,
 {
@@ -571,34 +841,20 @@ where
 	// Set the codec to AAC, which is a good guess if we fail before reaching the `esds`
 	properties.codec = Mp4Codec::AAC;
 
-	// Skipping 16 bytes
-	// Reserved (6)
-	// Data reference index (2)
-	// Version (2)
-	// Revision level (2)
-	// Vendor (4)
-	stsd.seek(SeekFrom::Current(16))?;
+	let AudioSampleEntry {
+		channels,
+		sample_rate,
+		..
+	} = AudioSampleEntry::read(stsd)?;
 
-	properties.channels = stsd.read_u16()? as u8;
-
-	// Skipping 4 bytes
-	// Sample size (2)
-	// Compression ID (2)
-	stsd.seek(SeekFrom::Current(4))?;
-
-	properties.sample_rate = stsd.read_u32()?;
-
-	stsd.seek(SeekFrom::Current(2))?;
+	properties.channels = channels;
+	properties.sample_rate = sample_rate;
 
 	// This information is often followed by an esds (elementary stream descriptor) atom containing the bitrate
-	let Ok(Some(esds)) = stsd.next() else {
+	let Ok(Some(_esds)) = find_sample_entry_child(stsd, sample_entry, *b"esds") else {
 		return Ok(());
 	};
 
-	if esds.ident != AtomIdent::Fourcc(*b"esds") {
-		return Ok(());
-	}
-
 	// There are 4 bytes we expect to be zeroed out
 	// Version (1)
 	// Flags (3)
@@ -729,100 +985,73 @@ where
 	Ok(())
 }
 
-fn alac_properties<R>(stsd: &mut AtomReader<R>, properties: &mut Mp4Properties) -> Result<()>
+fn alac_properties<R>(
+	stsd: &mut AtomReader<R>,
+	sample_entry: &AtomInfo,
+	properties: &mut Mp4Properties,
+) -> Result<()>
 where
 	R: Read + Seek,
 {
-	// With ALAC, we can expect the length to be exactly 88 (80 here since we removed the size and identifier)
-	if stsd.seek(SeekFrom::End(0))? != 80 {
-		return Ok(());
-	}
-
-	// Unlike the "mp4a" atom, we cannot read the data that immediately follows it
-	// For ALAC, we have to skip the first "alac" atom entirely, and read the one that
-	// immediately follows it.
-	//
-	// We are skipping over 44 bytes total
-	// stsd information/alac atom header (16, see `read_properties`)
-	// First alac atom's content (28)
-	stsd.seek(SeekFrom::Start(44))?;
-
-	let Ok(Some(alac)) = stsd.next() else {
-		return Ok(());
-	};
-
-	if alac.ident != AtomIdent::Fourcc(*b"alac") {
-		return Ok(());
-	}
-
 	properties.codec = Mp4Codec::ALAC;
 
-	// Skipping 9 bytes
-	// Version (4)
-	// Samples per frame (4)
-	// Compatible version (1)
-	stsd.seek(SeekFrom::Current(9))?;
+	let AudioSampleEntry {
+		channels,
+		sample_size,
+		sample_rate,
+	} = AudioSampleEntry::read(stsd)?;
 
-	// Sample size (1)
-	let sample_size = stsd.read_u8()?;
+	properties.channels = channels;
 	properties.bit_depth = Some(sample_size);
+	properties.sample_rate = sample_rate;
 
-	// Skipping 3 bytes
-	// Rice history mult (1)
-	// Rice initial history (1)
-	// Rice parameter limit (1)
-	stsd.seek(SeekFrom::Current(3))?;
+	// The sample entry fields aren't reliable, so we need to read the magic cookie in the `alac` atom
+	// that immediately follows it.
+	let Ok(Some(_alac)) = find_sample_entry_child(stsd, sample_entry, *b"alac") else {
+		return Ok(());
+	};
 
-	properties.channels = stsd.read_u8()?;
+	// Skipping 4 bytes
+	// Version (1)
+	// Flags (3)
+	stsd.seek(SeekFrom::Current(4))?;
 
-	// Skipping 6 bytes
-	// Max run (2)
-	// Max frame size (4)
-	stsd.seek(SeekFrom::Current(6))?;
+	let config = AlacSpecificConfig::read(stsd)?;
 
-	properties.audio_bitrate = stsd.read_u32()? / 1000;
-	properties.sample_rate = stsd.read_u32()?;
+	properties.bit_depth = Some(config.bit_depth);
+	properties.channels = config.channels;
+	properties.audio_bitrate = config.average_bitrate / 1000;
+	properties.sample_rate = config.sample_rate;
+	properties.alac_config = Some(config);
 
 	Ok(())
 }
 
-fn flac_properties<R>(stsd: &mut AtomReader<R>, properties: &mut Mp4Properties) -> Result<()>
+fn flac_properties<R>(
+	stsd: &mut AtomReader<R>,
+	sample_entry: &AtomInfo,
+	properties: &mut Mp4Properties,
+) -> Result<()>
 where
 	R: Read + Seek,
 {
 	properties.codec = Mp4Codec::FLAC;
 
-	// Skipping 16 bytes
-	//
-	// Reserved (6)
-	// Data reference index (2)
-	// Version (2)
-	// Revision level (2)
-	// Vendor (4)
-	stsd.seek(SeekFrom::Current(16))?;
-
-	properties.channels = stsd.read_u16()? as u8;
-	properties.bit_depth = Some(stsd.read_u16()? as u8);
-
-	// Skipping 4 bytes
-	//
-	// Compression ID (2)
-	// Packet size (2)
-	stsd.seek(SeekFrom::Current(4))?;
-
-	properties.sample_rate = u32::from(stsd.read_u16()?);
+	let AudioSampleEntry {
+		channels,
+		sample_size,
+		sample_rate,
+	} = AudioSampleEntry::read(stsd)?;
 
-	let _reserved = stsd.read_u16()?;
+	properties.channels = channels;
+	properties.bit_depth = Some(sample_size);
+	properties.sample_rate = sample_rate;
 
 	// There should be a dfla atom, but it's not worth erroring if absent.
-	let Some(dfla) = stsd.next()? else {
+	let Some(dfla) = find_sample_entry_child(stsd, sample_entry, *b"dfLa")? else {
 		return Ok(());
 	};
 
-	if dfla.ident != AtomIdent::Fourcc(*b"dfLa") {
-		return Ok(());
-	}
-
 	// Skipping 4 bytes
 	//
 	// Version (1)
@@ -847,6 +1076,175 @@ where
 	Ok(())
 }
 
+fn opus_properties<R>(
+	stsd: &mut AtomReader<R>,
+	sample_entry: &AtomInfo,
+	properties: &mut Mp4Properties,
+) -> Result<()>
+where
+	R: Read + Seek,
+{
+	properties.codec = Mp4Codec::Opus;
+
+	let AudioSampleEntry {
+		channels,
+		sample_rate,
+		..
+	} = AudioSampleEntry::read(stsd)?;
+
+	properties.channels = channels;
+	properties.sample_rate = sample_rate;
+
+	// https://opus-codec.org/docs/opus_in_isobmff.html#4.3.2
+	let Ok(Some(_dops)) = find_sample_entry_child(stsd, sample_entry, *b"dOps") else {
+		return Ok(());
+	};
+
+	let config = OpusSpecificConfig::read(stsd)?;
+
+	properties.channels = config.output_channel_count;
+	properties.opus_config = Some(config);
+
+	// Bitrate values are calculated later...
+
+	Ok(())
+}
+
+// https://wiki.multimedia.cx/index.php?title=AC-3#Sample_Rates
+const AC3_SAMPLE_RATES: [u32; 3] = [48000, 44100, 32000];
+
+// ETSI TS 102 366, Table 4.13, indexed by `frmsizecod >> 1`
+const AC3_BITRATES: [u32; 19] = [
+	32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320, 384, 448, 512, 576, 640,
+];
+
+// ETSI TS 102 366, Table 4.3, indexed by `acmod`
+const AC3_CHANNELS: [u8; 8] = [2, 1, 2, 3, 3, 4, 4, 5];
+
+// ETSI TS 102 366, Table F.6.1, the number of channels for each bit in `chan_loc`, starting from the MSB
+const EAC3_CHAN_LOC_CHANNELS: [u8; 9] = [2, 2, 1, 1, 2, 2, 2, 1, 1];
+
+fn ac3_properties<R>(
+	stsd: &mut AtomReader<R>,
+	sample_entry: &AtomInfo,
+	properties: &mut Mp4Properties,
+) -> Result<()>
+where
+	R: Read + Seek,
+{
+	properties.codec = Mp4Codec::AC3;
+
+	let AudioSampleEntry {
+		channels,
+		sample_rate,
+		..
+	} = AudioSampleEntry::read(stsd)?;
+
+	properties.channels = channels;
+	properties.sample_rate = sample_rate;
+
+	// ETSI TS 102 366, Annex F.4
+	let Ok(Some(_dac3)) = find_sample_entry_child(stsd, sample_entry, *b"dac3") else {
+		return Ok(());
+	};
+
+	// fscod (2)
+	// bsid (5)
+	// bsmod (3)
+	// acmod (3)
+	// lfeon (1)
+	// bit_rate_code (5)
+	// reserved (5)
+	let specific_box = stsd.read_u24()?;
+
+	let fscod = (specific_box >> 22) as usize;
+	if let Some(sample_rate) = AC3_SAMPLE_RATES.get(fscod) {
+		properties.sample_rate = *sample_rate;
+	}
+
+	let acmod = ((specific_box >> 11) & 0x07) as usize;
+	let lfeon = ((specific_box >> 10) & 0x01) as u8;
+	properties.channels = AC3_CHANNELS[acmod] + lfeon;
+
+	let bit_rate_code = ((specific_box >> 5) & 0x1F) as usize;
+	if let Some(bitrate) = AC3_BITRATES.get(bit_rate_code) {
+		properties.audio_bitrate = *bitrate;
+	}
+
+	Ok(())
+}
+
+fn eac3_properties<R>(
+	stsd: &mut AtomReader<R>,
+	sample_entry: &AtomInfo,
+	properties: &mut Mp4Properties,
+) -> Result<()>
+where
+	R: Read + Seek,
+{
+	properties.codec = Mp4Codec::EAC3;
+
+	let AudioSampleEntry {
+		channels,
+		sample_rate,
+		..
+	} = AudioSampleEntry::read(stsd)?;
+
+	properties.channels = channels;
+	properties.sample_rate = sample_rate;
+
+	// ETSI TS 102 366, Annex F.6
+	let Ok(Some(_dec3)) = find_sample_entry_child(stsd, sample_entry, *b"dec3") else {
+		return Ok(());
+	};
+
+	// data_rate (13)
+	// num_ind_sub (3)
+	let data_rate = stsd.read_u16()? >> 3;
+	properties.audio_bitrate = u32::from(data_rate);
+
+	// We only care about the first independent substream
+	//
+	// fscod (2)
+	// bsid (5)
+	// reserved (1)
+	let fscod = (stsd.read_u8()? >> 6) as usize;
+	if let Some(sample_rate) = AC3_SAMPLE_RATES.get(fscod) {
+		properties.sample_rate = *sample_rate;
+	}
+
+	// asvc (1)
+	// bsmod (3)
+	// acmod (3)
+	// lfeon (1)
+	let byte = stsd.read_u8()?;
+	let acmod = ((byte >> 1) & 0x07) as usize;
+	let lfeon = byte & 0x01;
+
+	let mut channels = AC3_CHANNELS[acmod] + lfeon;
+
+	// reserved (3)
+	// num_dep_sub (4)
+	// if num_dep_sub > 0
+	//     chan_loc (9)
+	// else
+	//     reserved (1)
+	let byte = stsd.read_u8()?;
+	let num_dep_sub = (byte >> 1) & 0x0F;
+	if num_dep_sub > 0 {
+		let chan_loc = (u16::from(byte & 0x01) << 8) | u16::from(stsd.read_u8()?);
+		for (bit, count) in EAC3_CHAN_LOC_CHANNELS.iter().enumerate() {
+			if chan_loc & (0x100 >> bit) != 0 {
+				channels += count;
+			}
+		}
+	}
+
+	properties.channels = channels;
+
+	Ok(())
+}
+
 // Used to calculate the bitrate, when it isn't readily available to us
 fn mdat_length<R>(reader: &mut AtomReader<R>) -> Result<u64>
 where
@@ -1,9 +1,10 @@
 mod atom_reader;
 
 use super::atom_info::{AtomIdent, AtomInfo};
+use super::chapters::read::read_chapter_track;
 use super::moov::Moov;
 use super::properties::Mp4Properties;
-use super::Mp4File;
+use super::{Ilst, Mp4File};
 use crate::config::{ParseOptions, ParsingMode};
 use crate::error::{ErrorKind, LoftyError, Result};
 use crate::macros::{decode_err, err};
@@ -63,14 +64,37 @@ where
 	let moov_info = Moov::find(&mut reader)?;
 	reader.reset_bounds(moov_info.start + 8, moov_info.len - 8);
 
-	let moov = Moov::parse(&mut reader, parse_options)?;
+	let moov = Moov::parse(&mut reader, parse_options.clone())?;
+
+	// Remove the length restriction
+	reader.reset_bounds(0, file_length);
+
+	let mut ilst = moov.ilst;
+
+	// The `chpl` atom takes precedence over a QuickTime chapter track
+	let chapters = match (moov.chapters, moov.chapter_trak) {
+		(Some(chapters), _) => chapters,
+		(None, Some(chapter_trak)) => {
+			match read_chapter_track(&mut reader, &chapter_trak, parse_options.parsing_mode) {
+				Ok(chapters) => chapters,
+				Err(e) if parse_options.parsing_mode == ParsingMode::Strict => return Err(e),
+				Err(e) => {
+					log::warn!("Failed to read chapter track: {e}");
+					Vec::new()
+				},
+			}
+		},
+		(None, None) => Vec::new(),
+	};
+
+	if !chapters.is_empty() {
+		ilst.get_or_insert_with(Ilst::default).chapters = chapters;
+	}
 
 	Ok(Mp4File {
 		ftyp,
-		ilst_tag: moov.ilst,
+		ilst_tag: ilst,
 		properties: if parse_options.read_properties {
-			// Remove the length restriction
-			reader.reset_bounds(0, file_length);
 			super::properties::read_properties(
 				&mut reader,
 				&moov.traks,
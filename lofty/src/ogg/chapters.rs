@@ -0,0 +1,203 @@
+//! Conversions between `CHAPTERxxx` comments and [`Chapter`]s
+//!
+//! See <https://wiki.xiph.org/Chapter_Extension>
+
+use crate::tag::items::Chapter;
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+const CHAPTER_PREFIX: &str = "CHAPTER";
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum ChapterField {
+	Start,
+	Name,
+	Url,
+}
+
+/// Split a key such as `CHAPTER001NAME` into its index and field
+fn parse_key(key: &str) -> Option<(u32, ChapterField)> {
+	let prefix = key.get(..CHAPTER_PREFIX.len())?;
+	if !prefix.eq_ignore_ascii_case(CHAPTER_PREFIX) {
+		return None;
+	}
+
+	let rest = &key[CHAPTER_PREFIX.len()..];
+	let digits_end = rest
+		.find(|c: char| !c.is_ascii_digit())
+		.unwrap_or(rest.len());
+	if digits_end == 0 {
+		return None;
+	}
+
+	let index = rest[..digits_end].parse().ok()?;
+	let field = match &rest[digits_end..] {
+		"" => ChapterField::Start,
+		name if name.eq_ignore_ascii_case("NAME") => ChapterField::Name,
+		url if url.eq_ignore_ascii_case("URL") => ChapterField::Url,
+		_ => return None,
+	};
+
+	Some((index, field))
+}
+
+/// Parse a timestamp in the form `HH:MM:SS.sss`
+fn parse_timestamp(value: &str) -> Option<Duration> {
+	let (whole, fraction) = value.trim().split_once('.').unwrap_or((value.trim(), ""));
+
+	let mut seconds = 0u64;
+	let mut components = 0;
+	for component in whole.split(':') {
+		if component.is_empty() || !component.bytes().all(|b| b.is_ascii_digit()) {
+			return None;
+		}
+
+		seconds = seconds
+			.checked_mul(60)?
+			.checked_add(component.parse().ok()?)?;
+		components += 1;
+	}
+
+	if components > 3 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+		return None;
+	}
+
+	// Only nanosecond precision is needed
+	let mut nanos = 0;
+	for (idx, digit) in fraction.bytes().take(9).enumerate() {
+		nanos += u32::from(digit - b'0') * 10u32.pow(8 - idx as u32);
+	}
+
+	Some(Duration::new(seconds, nanos))
+}
+
+fn format_timestamp(timestamp: Duration) -> String {
+	let seconds = timestamp.as_secs();
+	format!(
+		"{:02}:{:02}:{:02}.{:03}",
+		seconds / 3600,
+		(seconds / 60) % 60,
+		seconds % 60,
+		timestamp.subsec_millis()
+	)
+}
+
+/// Remove all `CHAPTERxxx` comments, converting them to [`Chapter`]s
+///
+/// Any `CHAPTERxxxNAME` or `CHAPTERxxxURL` comments without a valid `CHAPTERxxx` comment will be
+/// left untouched.
+pub(super) fn take_chapters(items: &mut Vec<(String, String)>) -> Vec<Chapter> {
+	let mut chapters = BTreeMap::new();
+	for (key, value) in &*items {
+		if let Some((index, ChapterField::Start)) = parse_key(key) {
+			if let Some(start) = parse_timestamp(value) {
+				chapters.insert(index, Chapter::new(start, None));
+			}
+		}
+	}
+
+	if chapters.is_empty() {
+		return Vec::new();
+	}
+
+	items.retain_mut(|(key, value)| {
+		let Some((index, field)) = parse_key(key) else {
+			return true;
+		};
+
+		let Some(chapter) = chapters.get_mut(&index) else {
+			return true;
+		};
+
+		match field {
+			ChapterField::Start => {},
+			ChapterField::Name => chapter.title = Some(std::mem::take(value)),
+			ChapterField::Url => chapter.url = Some(std::mem::take(value)),
+		}
+
+		false
+	});
+
+	chapters.into_values().collect()
+}
+
+/// Create the `CHAPTERxxx` comments for a list of [`Chapter`]s
+pub(crate) fn chapter_items(chapters: &[Chapter]) -> Vec<(String, String)> {
+	let mut items = Vec::with_capacity(chapters.len() * 2);
+	for (index, chapter) in chapters.iter().enumerate() {
+		let key = format!("{CHAPTER_PREFIX}{index:03}");
+
+		items.push((key.clone(), format_timestamp(chapter.start)));
+
+		if let Some(title) = &chapter.title {
+			items.push((format!("{key}NAME"), title.clone()));
+		}
+
+		if let Some(url) = &chapter.url {
+			items.push((format!("{key}URL"), url.clone()));
+		}
+	}
+
+	items
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{chapter_items, parse_timestamp, take_chapters};
+	use crate::tag::items::Chapter;
+
+	use std::time::Duration;
+
+	#[test_log::test]
+	fn chapter_comments_re_read() {
+		let mut first = Chapter::new(Duration::ZERO, Some(String::from("Intro")));
+		first.url = Some(String::from("https://example.com"));
+		let second = Chapter::new(Duration::from_millis(3_723_450), None);
+
+		let mut items = chapter_items(&[first.clone(), second.clone()]);
+		assert_eq!(
+			items[0],
+			(String::from("CHAPTER000"), String::from("00:00:00.000"))
+		);
+		assert_eq!(
+			items[3],
+			(String::from("CHAPTER001"), String::from("01:02:03.450"))
+		);
+
+		items.push((String::from("TITLE"), String::from("Foo title")));
+
+		let chapters = take_chapters(&mut items);
+		assert_eq!(chapters, vec![first, second]);
+		assert_eq!(items.len(), 1);
+	}
+
+	#[test_log::test]
+	fn parse_chapter_timestamps() {
+		assert_eq!(parse_timestamp("00:01:30"), Some(Duration::from_secs(90)));
+		assert_eq!(
+			parse_timestamp("1:02:03.5"),
+			Some(Duration::from_millis(3_723_500))
+		);
+		assert_eq!(
+			parse_timestamp("00:00:00.000000001"),
+			Some(Duration::from_nanos(1))
+		);
+		assert_eq!(parse_timestamp("00:00:00:00"), None);
+		assert_eq!(parse_timestamp("00:xx:00"), None);
+	}
+
+	#[test_log::test]
+	fn orphaned_chapter_name_is_kept() {
+		let mut items = vec![
+			(String::from("chapter001"), String::from("00:00:10.000")),
+			(String::from("chapter001name"), String::from("Part 1")),
+			(String::from("CHAPTER002NAME"), String::from("Part 2")),
+		];
+
+		let chapters = take_chapters(&mut items);
+		assert_eq!(chapters.len(), 1);
+		assert_eq!(chapters[0].title.as_deref(), Some("Part 1"));
+		assert_eq!(items.len(), 1);
+	}
+}
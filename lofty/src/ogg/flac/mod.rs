@@ -0,0 +1,183 @@
+pub(super) mod properties;
+
+use super::tag::VorbisComments;
+use super::verify_signature;
+use crate::config::{ParseOptions, ParsingMode};
+use crate::error::Result;
+use crate::flac::block::{BLOCK_ID_PICTURE, BLOCK_ID_VORBIS_COMMENTS};
+use crate::flac::FlacProperties;
+use crate::macros::decode_err;
+use crate::ogg::constants::OGG_FLAC_HEAD;
+use crate::picture::Picture;
+
+use std::io::{Read, Seek, SeekFrom};
+
+use lofty_attr::LoftyFile;
+use ogg_pager::{Packets, PageHeader};
+
+/// The size of the identification packet, up to the end of the STREAMINFO block header
+pub(crate) const STREAMINFO_OFFSET: usize = 17;
+
+/// The size of the STREAMINFO block, excluding its header
+const STREAMINFO_SIZE: usize = 34;
+
+/// The size of a FLAC metadata block header
+pub(crate) const BLOCK_HEADER_SIZE: usize = 4;
+
+/// An OGG FLAC file
+///
+/// ## Notes
+///
+/// * Pictures stored in their own `PICTURE` metadata blocks are moved into the [`VorbisComments`] tag.
+///   When writing, they will be stored as `METADATA_BLOCK_PICTURE` fields instead.
+#[derive(LoftyFile)]
+#[lofty(read_fn = "Self::read_from")]
+pub struct OggFlacFile {
+	/// The vorbis comments contained in the file
+	///
+	/// NOTE: While a metadata packet is required, it isn't required to actually have any data.
+	#[lofty(tag_type = "VorbisComments")]
+	pub(crate) vorbis_comments_tag: VorbisComments,
+	/// The file's audio properties
+	pub(crate) properties: FlacProperties,
+}
+
+impl OggFlacFile {
+	fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<Self>
+	where
+		R: Read + Seek,
+	{
+		let start = reader.stream_position()?;
+		let first_page_header = PageHeader::read(reader)?;
+		reader.seek(SeekFrom::Start(start))?;
+
+		let header_packet_count = header_packet_count(reader)?;
+		let packets = Packets::read_count(reader, header_packet_count)?;
+
+		let mut vorbis_comments_tag = VorbisComments::default();
+		if parse_options.read_tags {
+			read_tag(&packets, &mut vorbis_comments_tag, parse_options.clone())?;
+		}
+
+		Ok(Self {
+			properties: if parse_options.read_properties {
+				properties::read_properties(reader, &first_page_header, &packets)?
+			} else {
+				FlacProperties::default()
+			},
+			// A metadata packet is mandatory in OGG FLAC
+			vorbis_comments_tag,
+		})
+	}
+}
+
+/// Reads the number of header packets, including the identification packet
+///
+/// The reader will be returned to its original position.
+pub(crate) fn header_packet_count<R>(reader: &mut R) -> Result<isize>
+where
+	R: Read + Seek,
+{
+	let start = reader.stream_position()?;
+
+	let packets = Packets::read_count(reader, 1)?;
+	let identification_packet = packets
+		.get(0)
+		.ok_or_else(|| decode_err!(OggFlac, "Expected identification packet"))?;
+	verify_identification_packet(identification_packet)?;
+
+	let count = u16::from_be_bytes([identification_packet[7], identification_packet[8]]);
+	if count > 0 {
+		reader.seek(SeekFrom::Start(start))?;
+		return Ok(count as isize + 1);
+	}
+
+	// A count of 0 means it's unknown, so we have to search for the last metadata block
+	log::debug!(
+		"OGG FLAC: Unknown number of header packets, searching for the last metadata block"
+	);
+
+	let mut count = 2;
+	loop {
+		reader.seek(SeekFrom::Start(start))?;
+
+		let packets = Packets::read_count(reader, count)?;
+		let Some(last_packet) = packets.iter().last() else {
+			decode_err!(@BAIL OggFlac, "Expected metadata packet");
+		};
+
+		if last_packet.first().is_some_and(|b| b & 0x80 != 0) {
+			break;
+		}
+
+		count += 1;
+	}
+
+	reader.seek(SeekFrom::Start(start))?;
+	Ok(count)
+}
+
+fn verify_identification_packet(packet: &[u8]) -> Result<()> {
+	verify_signature(packet, OGG_FLAC_HEAD)?;
+
+	// Signature (5), major version (1), minor version (1), header packet count (2), "fLaC" (4),
+	// followed by the STREAMINFO block
+	if packet.len() < STREAMINFO_OFFSET + STREAMINFO_SIZE || &packet[9..13] != b"fLaC" {
+		decode_err!(@BAIL OggFlac, "Invalid identification packet");
+	}
+
+	if packet[5] != 1 {
+		decode_err!(@BAIL OggFlac, "Unsupported mapping version");
+	}
+
+	Ok(())
+}
+
+fn read_tag(
+	packets: &Packets,
+	tag: &mut VorbisComments,
+	parse_options: ParseOptions,
+) -> Result<()> {
+	let mut packets = packets.iter().skip(1);
+
+	// The VORBIS_COMMENT block is required to be the first one after the identification packet
+	let Some(metadata_packet) = packets.next() else {
+		decode_err!(@BAIL OggFlac, "Expected metadata packet");
+	};
+
+	if metadata_packet.len() < BLOCK_HEADER_SIZE
+		|| metadata_packet[0] & 0x7F != BLOCK_ID_VORBIS_COMMENTS
+	{
+		decode_err!(@BAIL OggFlac, "Expected a VORBIS_COMMENT block");
+	}
+
+	let reader = &mut &metadata_packet[BLOCK_HEADER_SIZE..];
+	*tag = super::read::read_comments(reader, reader.len() as u64, parse_options.clone())?;
+
+	if !parse_options.read_cover_art {
+		return Ok(());
+	}
+
+	for packet in packets {
+		if packet.len() < BLOCK_HEADER_SIZE || packet[0] & 0x7F != BLOCK_ID_PICTURE {
+			continue;
+		}
+
+		match Picture::from_flac_bytes(
+			&packet[BLOCK_HEADER_SIZE..],
+			false,
+			parse_options.parsing_mode,
+		) {
+			Ok(picture) => tag.pictures.push(picture),
+			Err(e) => {
+				if parse_options.parsing_mode == ParsingMode::Strict {
+					return Err(e);
+				}
+
+				log::warn!("Unable to read FLAC picture block, discarding");
+			},
+		}
+	}
+
+	Ok(())
+}
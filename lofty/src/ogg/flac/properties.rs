@@ -0,0 +1,54 @@
+use super::STREAMINFO_OFFSET;
+use crate::error::Result;
+use crate::flac::FlacProperties;
+use crate::ogg::find_last_page;
+use crate::util::math::RoundedDivision;
+
+use std::io::{Read, Seek, SeekFrom};
+use std::time::Duration;
+
+use ogg_pager::{Packets, PageHeader};
+
+pub(in crate::ogg) fn read_properties<R>(
+	data: &mut R,
+	first_page_header: &PageHeader,
+	packets: &Packets,
+) -> Result<FlacProperties>
+where
+	R: Read + Seek,
+{
+	// Safe to unwrap, it is impossible to get this far without
+	// an identification packet.
+	let identification_packet = packets.get(0).unwrap();
+
+	let file_length = data.seek(SeekFrom::End(0))? - first_page_header.start;
+	let header_size = packets
+		.iter()
+		.map(|packet| packet.len() as u64)
+		.sum::<u64>();
+	let stream_length = file_length.saturating_sub(header_size);
+
+	let stream_info = &mut &identification_packet[STREAMINFO_OFFSET..];
+	let mut properties =
+		crate::flac::properties::read_properties(stream_info, stream_length, file_length)?;
+
+	// The total sample count is optional in STREAMINFO, so we may have to use the last page instead
+	if properties.duration.is_zero() && properties.sample_rate > 0 {
+		data.seek(SeekFrom::Start(first_page_header.start))?;
+
+		let Ok(last_page) = find_last_page(data) else {
+			log::warn!("OGG FLAC: Unable to find the last page, unable to calculate length");
+			return Ok(properties);
+		};
+
+		let total_samples = u128::from(last_page.header().abgp);
+		let length = (total_samples * 1000).div_round(u128::from(properties.sample_rate)) as u64;
+		if length > 0 {
+			properties.duration = Duration::from_millis(length);
+			properties.overall_bitrate = (file_length.saturating_mul(8) / length) as u32;
+			properties.audio_bitrate = (stream_length.saturating_mul(8) / length) as u32;
+		}
+	}
+
+	Ok(properties)
+}
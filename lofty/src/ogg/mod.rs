@@ -3,7 +3,9 @@
 //! ## File notes
 //!
 //! The only supported tag format is [`VorbisComments`]
+mod chapters;
 pub(crate) mod constants;
+pub(crate) mod flac;
 pub(crate) mod opus;
 mod picture_storage;
 pub(crate) mod read;
@@ -21,6 +23,7 @@ use ogg_pager::{Page, PageHeader};
 
 // Exports
 
+pub use flac::OggFlacFile;
 pub use opus::properties::OpusProperties;
 pub use opus::OpusFile;
 pub use picture_storage::OggPictureStorage;
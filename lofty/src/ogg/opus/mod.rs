@@ -30,7 +30,7 @@ impl OpusFile {
 		R: Read + Seek,
 	{
 		let file_information =
-			super::read::read_from(reader, OPUSHEAD, OPUSTAGS, 2, parse_options)?;
+			super::read::read_from(reader, OPUSHEAD, OPUSTAGS, 2, parse_options.clone())?;
 
 		Ok(Self {
 			properties: if parse_options.read_properties {
@@ -1,11 +1,14 @@
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
 use crate::file::FileType;
+use crate::lyrics::Lrc;
 use crate::macros::err;
+use crate::ogg::chapters::{chapter_items, take_chapters};
 use crate::ogg::picture_storage::OggPictureStorage;
 use crate::ogg::write::OGGFormat;
 use crate::picture::{Picture, PictureInformation};
 use crate::probe::Probe;
+use crate::tag::items::SyncedLyrics;
 use crate::tag::{
 	try_parse_year, Accessor, ItemKey, ItemValue, MergeTag, SplitTag, Tag, TagExt, TagItem, TagType,
 };
@@ -47,6 +50,9 @@ macro_rules! impl_accessor {
 /// In order to preserve the vendor string, a required part of the OGG formats, it will simply be inserted as
 /// [`ItemKey::EncoderSoftware`], given an item with this key does not already exist.
 ///
+/// Chapters (`CHAPTERxxx`, `CHAPTERxxxNAME`, and `CHAPTERxxxURL`) will be converted to
+/// [`Chapter`](crate::tag::items::Chapter)s.
+///
 /// ### From `Tag`
 ///
 /// If a [`TagItem`] with the key [`ItemKey::EncoderSoftware`] is available, it will be taken and
@@ -61,7 +67,7 @@ macro_rules! impl_accessor {
 #[derive(Default, PartialEq, Eq, Debug, Clone)]
 #[tag(
 	description = "Vorbis comments",
-	supported_formats(Flac, Opus, Speex, Vorbis)
+	supported_formats(Flac, OggFlac, Opus, Speex, Vorbis)
 )]
 pub struct VorbisComments {
 	/// An identifier for the encoding software
@@ -529,11 +535,20 @@ impl SplitTag for VorbisComments {
 	fn split_tag(mut self) -> (Self::Remainder, Tag) {
 		let mut tag = Tag::new(TagType::VorbisComments);
 
+		tag.chapters = take_chapters(&mut self.items);
+
 		for (k, v) in std::mem::take(&mut self.items) {
-			tag.items.push(TagItem::new(
-				ItemKey::from_key(TagType::VorbisComments, &k),
-				ItemValue::Text(v),
-			));
+			let item_key = ItemKey::from_key(TagType::VorbisComments, &k);
+
+			// Synchronized lyrics are stored as LRC
+			if item_key == ItemKey::Lyrics {
+				if let Some(lrc) = Lrc::from_lyrics_text(&v) {
+					tag.synced_lyrics.push(lrc.to_synced_lyrics());
+					continue;
+				}
+			}
+
+			tag.items.push(TagItem::new(item_key, ItemValue::Text(v)));
 		}
 
 		// We need to preserve the vendor string
@@ -613,6 +628,9 @@ impl MergeTag for SplitTagRemainder {
 			}
 		}
 
+		merged.items.extend(chapter_items(&tag.chapters));
+		merged.items.extend(synced_lyrics_items(&tag.synced_lyrics));
+
 		merged
 	}
 }
@@ -682,12 +700,16 @@ where
 	}
 }
 
-pub(crate) fn create_vorbis_comments_ref(
-	tag: &Tag,
+/// Create the parts of a [`VorbisCommentsRef`] from a [`Tag`]
+///
+/// The `extra_items` should be created with [`tag_extra_items`].
+pub(crate) fn create_vorbis_comments_ref<'a>(
+	tag: &'a Tag,
+	extra_items: &'a [(String, String)],
 ) -> (
-	&str,
-	impl Iterator<Item = (&str, &str)>,
-	impl Iterator<Item = (&Picture, PictureInformation)>,
+	&'a str,
+	impl Iterator<Item = (&'a str, &'a str)>,
+	impl Iterator<Item = (&'a Picture, PictureInformation)>,
 ) {
 	let vendor = tag.get_string(&ItemKey::EncoderSoftware).unwrap_or("");
 
@@ -699,6 +721,8 @@ pub(crate) fn create_vorbis_comments_ref(
 		_ => None,
 	});
 
+	let items = items.chain(extra_items.iter().map(|(k, v)| (k.as_str(), v.as_str())));
+
 	let pictures = tag
 		.pictures
 		.iter()
@@ -706,6 +730,23 @@ pub(crate) fn create_vorbis_comments_ref(
 	(vendor, items, pictures)
 }
 
+/// Create the comments for a [`Tag`]'s chapters and synchronized lyrics, which aren't stored as items
+pub(crate) fn tag_extra_items(tag: &Tag) -> Vec<(String, String)> {
+	let mut items = chapter_items(tag.chapters());
+	items.extend(synced_lyrics_items(tag.synced_lyrics()));
+	items
+}
+
+/// Create LRC `LYRICS` comments for synchronized lyrics
+fn synced_lyrics_items(lyrics: &[SyncedLyrics]) -> impl Iterator<Item = (String, String)> + '_ {
+	lyrics.iter().map(|lyrics| {
+		(
+			String::from("LYRICS"),
+			Lrc::from_synced_lyrics(lyrics).into_lyrics_text(),
+		)
+	})
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::config::{ParseOptions, ParsingMode, WriteOptions};
@@ -1,10 +1,11 @@
-use super::verify_signature;
+use super::{flac, verify_signature};
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
 use crate::file::FileType;
+use crate::flac::block::{BLOCK_ID_PICTURE, BLOCK_ID_VORBIS_COMMENTS};
 use crate::macros::{decode_err, err, try_vec};
 use crate::ogg::constants::{OPUSTAGS, VORBIS_COMMENT_HEAD};
-use crate::ogg::tag::{create_vorbis_comments_ref, VorbisCommentsRef};
+use crate::ogg::tag::{create_vorbis_comments_ref, tag_extra_items, VorbisCommentsRef};
 use crate::picture::{Picture, PictureInformation};
 use crate::tag::{Tag, TagType};
 use crate::util::io::{FileLike, Length, Truncate};
@@ -20,6 +21,7 @@ pub(crate) enum OGGFormat {
 	Opus,
 	Vorbis,
 	Speex,
+	Flac,
 }
 
 impl OGGFormat {
@@ -27,7 +29,7 @@ impl OGGFormat {
 		match self {
 			OGGFormat::Opus => Some(OPUSTAGS),
 			OGGFormat::Vorbis => Some(VORBIS_COMMENT_HEAD),
-			OGGFormat::Speex => None,
+			OGGFormat::Speex | OGGFormat::Flac => None,
 		}
 	}
 
@@ -36,6 +38,8 @@ impl OGGFormat {
 			FileType::Opus => (OGGFormat::Opus, 2),
 			FileType::Vorbis => (OGGFormat::Vorbis, 3),
 			FileType::Speex => (OGGFormat::Speex, 2),
+			// The actual number of header packets is stored in the identification packet
+			FileType::OggFlac => (OGGFormat::Flac, 2),
 			_ => unreachable!("You forgot to add support for FileType::{:?}!", file_type),
 		}
 	}
@@ -56,7 +60,8 @@ where
 		err!(UnsupportedTag);
 	}
 
-	let (vendor, items, pictures) = create_vorbis_comments_ref(tag);
+	let extra_items = tag_extra_items(tag);
+	let (vendor, items, pictures) = create_vorbis_comments_ref(tag, &extra_items);
 
 	let mut comments_ref = VorbisCommentsRef {
 		vendor: Cow::from(vendor),
@@ -98,7 +103,13 @@ where
 	let stream_serial = first_page_header.stream_serial;
 
 	file.seek(SeekFrom::Start(start))?;
-	let mut packets = Packets::read_count(file, header_packet_count)?;
+
+	let header_packet_count = match format {
+		OGGFormat::Flac => flac::header_packet_count(file)?,
+		_ => header_packet_count,
+	};
+
+	let packets = Packets::read_count(file, header_packet_count)?;
 
 	let mut remaining_file_content = Vec::new();
 	file.read_to_end(&mut remaining_file_content)?;
@@ -114,8 +125,22 @@ where
 
 	let comment_signature = comment_signature.unwrap_or_default();
 
+	// OGG FLAC stores the comments in a regular metadata block
+	let comment_start = match format {
+		OGGFormat::Flac => {
+			if comment_packet.len() < flac::BLOCK_HEADER_SIZE
+				|| comment_packet[0] & 0x7F != BLOCK_ID_VORBIS_COMMENTS
+			{
+				decode_err!(@BAIL OggFlac, "Expected a VORBIS_COMMENT block");
+			}
+
+			flac::BLOCK_HEADER_SIZE
+		},
+		_ => comment_signature.len(),
+	};
+
 	// Retain the file's vendor string
-	let md_reader = &mut &comment_packet[comment_signature.len()..];
+	let md_reader = &mut &comment_packet[comment_start..];
 
 	let vendor_len = md_reader.read_u32::<LittleEndian>()?;
 	let mut vendor = try_vec![0; vendor_len as usize];
@@ -136,14 +161,30 @@ where
 	let add_framing_bit = format == OGGFormat::Vorbis;
 	let new_metadata_packet = create_metadata_packet(tag, comment_signature, add_framing_bit)?;
 
+	let mut header_packets = packets.iter().map(<[u8]>::to_vec).collect::<Vec<_>>();
+
 	// Replace the old comment packet
-	packets.set(1, new_metadata_packet);
+	header_packets[1] = new_metadata_packet;
+
+	if format == OGGFormat::Flac {
+		flac_header_packets(&mut header_packets)?;
+	}
 
 	file.rewind()?;
 	file.truncate(0)?;
 
-	let pages_written =
-		packets.write_to(file, stream_serial, 0, CONTAINS_FIRST_PAGE_OF_BITSTREAM)? as u32;
+	let pages = ogg_pager::paginate(
+		header_packets.iter().map(Vec::as_slice),
+		stream_serial,
+		0,
+		CONTAINS_FIRST_PAGE_OF_BITSTREAM,
+	)?;
+
+	let pages_written = pages.len() as u32;
+	for mut page in pages {
+		page.gen_crc();
+		file.write_all(&page.as_bytes())?;
+	}
 
 	// Correct all remaining page sequence numbers
 	let mut pages_reader = Cursor::new(&remaining_file_content[..]);
@@ -160,6 +201,56 @@ where
 	Ok(())
 }
 
+/// Fixes up the OGG FLAC header packets after the comment packet is replaced
+///
+/// The new comments are wrapped in a metadata block, and any `PICTURE` blocks are dropped, since the
+/// pictures are now stored in the comments.
+fn flac_header_packets(header_packets: &mut Vec<Vec<u8>>) -> Result<()> {
+	let mut comment_block = Vec::with_capacity(header_packets[1].len() + flac::BLOCK_HEADER_SIZE);
+	comment_block.push(BLOCK_ID_VORBIS_COMMENTS);
+
+	let Ok(block_size) = u32::try_from(header_packets[1].len()) else {
+		err!(TooMuchData);
+	};
+
+	if block_size > 0xFF_FFFF {
+		err!(TooMuchData);
+	}
+
+	comment_block.extend(&block_size.to_be_bytes()[1..]);
+	comment_block.append(&mut header_packets[1]);
+	header_packets[1] = comment_block;
+
+	let mut index = 0;
+	header_packets.retain(|packet| {
+		index += 1;
+		if index <= 2 {
+			return true;
+		}
+
+		match packet.first() {
+			Some(block_type) => block_type & 0x7F != BLOCK_ID_PICTURE,
+			None => true,
+		}
+	});
+
+	// Only the last metadata block can have the "last" flag set
+	let last = header_packets.len() - 1;
+	for (index, packet) in header_packets.iter_mut().enumerate().skip(1) {
+		if index == last {
+			packet[0] |= 0x80;
+		} else {
+			packet[0] &= 0x7F;
+		}
+	}
+
+	// The header packet count doesn't include the identification packet
+	let count = (header_packets.len() - 1) as u16;
+	header_packets[0][7..9].copy_from_slice(&count.to_be_bytes());
+
+	Ok(())
+}
+
 pub(super) fn create_metadata_packet<'a, II, IP>(
 	tag: &mut VorbisCommentsRef<'a, II, IP>,
 	comment_signature: &[u8],
@@ -3,13 +3,13 @@
 use crate::config::ParsingMode;
 use crate::error::{ErrorKind, LoftyError, Result};
 use crate::macros::err;
-use crate::util::text::utf8_decode_str;
+use crate::util::text::{utf16_decode_bytes, utf16_encode, utf8_decode_str};
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
 use std::io::{Cursor, Read, Seek, SeekFrom};
 
-use byteorder::{BigEndian, ReadBytesExt as _};
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt as _};
 use data_encoding::BASE64;
 
 /// Common picture item keys for APE
@@ -791,6 +791,73 @@ impl Picture {
 		})
 	}
 
+	/// Convert a [`Picture`] to an ASF `WM/Picture` byte vec
+	///
+	/// NOTE: This is only the attribute data, the `WM/Picture` name will not be prepended.
+	pub fn as_asf_bytes(&self) -> Vec<u8> {
+		let mut data = vec![self.pic_type.as_u8()];
+
+		data.extend((self.data.len() as u32).to_le_bytes());
+		data.extend(utf16_encode(self.mime_str(), u16::to_le_bytes, false, true));
+		data.extend(utf16_encode(
+			self.description.as_deref().unwrap_or_default(),
+			u16::to_le_bytes,
+			false,
+			true,
+		));
+		data.extend(self.data.iter());
+
+		data
+	}
+
+	/// Get a [`Picture`] from an ASF `WM/Picture` attribute
+	///
+	/// NOTE: This function expects `bytes` to contain *only* the attribute data
+	///
+	/// # Errors
+	///
+	/// This function will return [`NotAPicture`](ErrorKind::NotAPicture)
+	/// if at any point it's unable to parse the data
+	pub fn from_asf_bytes(bytes: &[u8]) -> Result<Self> {
+		fn read_utf16_string(reader: &mut &[u8]) -> Result<String> {
+			let Some(end) = reader
+				.chunks_exact(2)
+				.position(|c| c == [0, 0])
+				.map(|pos| pos * 2)
+			else {
+				err!(NotAPicture);
+			};
+
+			let text = utf16_decode_bytes(&reader[..end], u16::from_le_bytes)?;
+			*reader = &reader[end + 2..];
+
+			Ok(text)
+		}
+
+		let reader = &mut &*bytes;
+
+		let Ok(pic_type) = reader.read_u8() else {
+			err!(NotAPicture);
+		};
+		let Ok(size) = reader.read_u32::<LittleEndian>() else {
+			err!(NotAPicture);
+		};
+
+		let mime_type = read_utf16_string(reader)?;
+		let description = read_utf16_string(reader)?;
+
+		let Some(data) = reader.get(..size as usize) else {
+			err!(NotAPicture);
+		};
+
+		Ok(Picture {
+			pic_type: PictureType::from_u8(pic_type),
+			mime_type: (!mime_type.is_empty()).then(|| MimeType::from_str(&mime_type)),
+			description: (!description.is_empty()).then_some(Cow::Owned(description)),
+			data: Cow::Owned(data.to_vec()),
+		})
+	}
+
 	pub(crate) fn mimetype_from_bin(bytes: &[u8]) -> Result<MimeType> {
 		match bytes[..8] {
 			[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A] => Ok(MimeType::Png),
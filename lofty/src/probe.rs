@@ -2,13 +2,18 @@
 
 use crate::aac::AacFile;
 use crate::ape::ApeFile;
+use crate::asf::AsfFile;
+use crate::caf::CafFile;
 use crate::config::{global_options, ParseOptions};
+use crate::dsd::dff::DffFile;
+use crate::dsd::dsf::DsfFile;
 use crate::error::Result;
 use crate::file::{AudioFile, FileType, FileTypeGuessResult, TaggedFile};
 use crate::flac::FlacFile;
 use crate::iff::aiff::AiffFile;
 use crate::iff::wav::WavFile;
 use crate::macros::err;
+use crate::matroska::MatroskaFile;
 use crate::mp4::Mp4File;
 use crate::mpeg::header::search_for_frame_sync;
 use crate::mpeg::MpegFile;
@@ -16,7 +21,9 @@ use crate::musepack::MpcFile;
 use crate::ogg::opus::OpusFile;
 use crate::ogg::speex::SpeexFile;
 use crate::ogg::vorbis::VorbisFile;
+use crate::ogg::OggFlacFile;
 use crate::resolve::custom_resolvers;
+use crate::tta::TtaFile;
 use crate::wavpack::WavPackFile;
 
 use std::fs::File;
@@ -306,6 +313,7 @@ impl<R: Read + Seek> Probe<R> {
 	pub fn guess_file_type(mut self) -> std::io::Result<Self> {
 		let max_junk_bytes = self
 			.options
+			.as_ref()
 			.map_or(ParseOptions::DEFAULT_MAX_JUNK_BYTES, |options| {
 				options.max_junk_bytes
 			});
@@ -373,7 +381,9 @@ impl<R: Read + Seek> Probe<R> {
 				let file_type_after_id3_block = match &ident {
 					[b'M', b'A', b'C', ..] => Ok(Some(FileType::Ape)),
 					b"fLaC" => Ok(Some(FileType::Flac)),
+					b"ADIF" => Ok(Some(FileType::Aac)),
 					b"MPCK" | [b'M', b'P', b'+', ..] => Ok(Some(FileType::Mpc)),
+					b"TTA1" => Ok(Some(FileType::Tta)),
 					// Search for a frame sync, which may be preceded by junk
 					_ => self.check_mpeg_or_aac(max_junk_bytes),
 				};
@@ -468,14 +478,21 @@ impl<R: Read + Seek> Probe<R> {
 				FileType::Aac => AacFile::read_from(reader, options)?.into(),
 				FileType::Aiff => AiffFile::read_from(reader, options)?.into(),
 				FileType::Ape => ApeFile::read_from(reader, options)?.into(),
+				FileType::Asf => AsfFile::read_from(reader, options)?.into(),
+				FileType::Caf => CafFile::read_from(reader, options)?.into(),
+				FileType::Dff => DffFile::read_from(reader, options)?.into(),
+				FileType::Dsf => DsfFile::read_from(reader, options)?.into(),
 				FileType::Flac => FlacFile::read_from(reader, options)?.into(),
+				FileType::Matroska => MatroskaFile::read_from(reader, options)?.into(),
 				FileType::Mpeg => MpegFile::read_from(reader, options)?.into(),
+				FileType::OggFlac => OggFlacFile::read_from(reader, options)?.into(),
 				FileType::Opus => OpusFile::read_from(reader, options)?.into(),
 				FileType::Vorbis => VorbisFile::read_from(reader, options)?.into(),
 				FileType::Wav => WavFile::read_from(reader, options)?.into(),
 				FileType::Mp4 => Mp4File::read_from(reader, options)?.into(),
 				FileType::Mpc => MpcFile::read_from(reader, options)?.into(),
 				FileType::Speex => SpeexFile::read_from(reader, options)?.into(),
+				FileType::Tta => TtaFile::read_from(reader, options)?.into(),
 				FileType::WavPack => WavPackFile::read_from(reader, options)?.into(),
 				FileType::Custom(c) => {
 					if !unsafe { global_options().use_custom_resolvers } {
@@ -651,14 +668,14 @@ mod tests {
 		let within_limits = create_fake_mp3(40);
 		let probe = Probe::new(std::io::Cursor::new(&within_limits))
 			.set_file_type(FileType::Mpeg)
-			.options(parse_options);
+			.options(parse_options.clone());
 		assert!(probe.read().is_ok());
 
 		// An allocation with a size of 60 bytes should fail
 		let too_big = create_fake_mp3(60);
 		let probe = Probe::new(std::io::Cursor::new(&too_big))
 			.set_file_type(FileType::Mpeg)
-			.options(parse_options);
+			.options(parse_options.clone());
 		assert!(probe.read().is_err());
 
 		// Now test the default allocation limit (16MB), which should of course be ok with 60 bytes
@@ -699,6 +716,14 @@ mod tests {
 		test_probe("tests/files/assets/minimal/full_test.aac", FileType::Aac);
 	}
 
+	#[test_log::test]
+	fn probe_aac_adif() {
+		test_probe(
+			"tests/files/assets/minimal/full_test_adif.aac",
+			FileType::Aac,
+		);
+	}
+
 	#[test_log::test]
 	fn probe_aiff() {
 		test_probe("tests/files/assets/minimal/full_test.aiff", FileType::Aiff);
@@ -709,6 +734,34 @@ mod tests {
 		test_probe("tests/files/assets/minimal/full_test.ape", FileType::Ape);
 	}
 
+	#[test_log::test]
+	fn probe_asf() {
+		test_probe("tests/files/assets/minimal/full_test.wma", FileType::Asf);
+	}
+
+	#[test_log::test]
+	fn probe_caf() {
+		test_probe("tests/files/assets/minimal/full_test.caf", FileType::Caf);
+	}
+
+	#[test_log::test]
+	fn probe_matroska() {
+		test_probe(
+			"tests/files/assets/minimal/full_test.mka",
+			FileType::Matroska,
+		);
+	}
+
+	#[test_log::test]
+	fn probe_dff() {
+		test_probe("tests/files/assets/minimal/full_test.dff", FileType::Dff);
+	}
+
+	#[test_log::test]
+	fn probe_dsf() {
+		test_probe("tests/files/assets/minimal/full_test.dsf", FileType::Dsf);
+	}
+
 	#[test_log::test]
 	fn probe_flac() {
 		test_probe("tests/files/assets/minimal/full_test.flac", FileType::Flac);
@@ -739,6 +792,19 @@ mod tests {
 		test_probe("tests/files/assets/minimal/full_test.opus", FileType::Opus);
 	}
 
+	#[test_log::test]
+	fn probe_ogg_flac() {
+		// `.oga` can be used for any audio in an OGG container, so only the content is checked
+		let path = "tests/files/assets/minimal/full_test.oga";
+		test_probe_file(path, FileType::OggFlac);
+		assert_eq!(Probe::open(path).unwrap().file_type(), None);
+	}
+
+	#[test_log::test]
+	fn probe_tta() {
+		test_probe("tests/files/assets/minimal/full_test.tta", FileType::Tta);
+	}
+
 	#[test_log::test]
 	fn probe_speex() {
 		test_probe("tests/files/assets/minimal/full_test.spx", FileType::Speex);
@@ -759,4 +825,20 @@ mod tests {
 			FileType::Wav,
 		);
 	}
+
+	#[test_log::test]
+	fn probe_wav_rf64() {
+		test_probe(
+			"tests/files/assets/minimal/wav_format_pcm_rf64.wav",
+			FileType::Wav,
+		);
+	}
+
+	#[test_log::test]
+	fn probe_wav_wave64() {
+		test_probe(
+			"tests/files/assets/minimal/wav_format_pcm.w64",
+			FileType::Wav,
+		);
+	}
 }
@@ -1,20 +1,29 @@
-use crate::aac::{AACProperties, AacFile};
+use crate::aac::{AACProperties, AacFile, AacFormat, AdifBitstreamType};
 use crate::ape::{ApeFile, ApeProperties};
+use crate::asf::{AsfCodec, AsfFile, AsfProperties};
+use crate::caf::{CafCodec, CafFile, CafProperties};
 use crate::config::ParseOptions;
+use crate::dsd::dff::{DffCompression, DffFile, DffProperties};
+use crate::dsd::dsf::{DsfFile, DsfProperties};
 use crate::file::AudioFile;
 use crate::flac::{FlacFile, FlacProperties};
 use crate::iff::aiff::{AiffFile, AiffProperties};
 use crate::iff::wav::{WavFile, WavFormat, WavProperties};
-use crate::mp4::{AudioObjectType, Mp4Codec, Mp4File, Mp4Properties};
+use crate::matroska::{AudioTrack, MatroskaFile, MatroskaProperties};
+use crate::mp4::{
+	AlacSpecificConfig, AudioObjectType, Mp4Codec, Mp4File, Mp4Properties, OpusChannelMapping,
+	OpusSpecificConfig,
+};
 use crate::mpeg::{ChannelMode, Layer, MpegFile, MpegProperties, MpegVersion};
 use crate::musepack::sv4to6::MpcSv4to6Properties;
 use crate::musepack::sv7::{Link, MpcSv7Properties, Profile};
 use crate::musepack::sv8::{EncoderInfo, MpcSv8Properties, ReplayGain, StreamHeader};
 use crate::musepack::{MpcFile, MpcProperties};
 use crate::ogg::{
-	OpusFile, OpusProperties, SpeexFile, SpeexProperties, VorbisFile, VorbisProperties,
+	OggFlacFile, OpusFile, OpusProperties, SpeexFile, SpeexProperties, VorbisFile, VorbisProperties,
 };
-use crate::properties::ChannelMask;
+use crate::properties::{ChannelMask, FileProperties};
+use crate::tta::{TtaFile, TtaFormat, TtaProperties};
 use crate::wavpack::{WavPackFile, WavPackProperties};
 
 use std::fs::File;
@@ -25,6 +34,8 @@ use std::time::Duration;
 // is an issue.
 
 const AAC_PROPERTIES: AACProperties = AACProperties {
+	format: AacFormat::Adts,
+	bitstream_type: None,
 	version: MpegVersion::V4,
 	audio_object_type: AudioObjectType::AacLowComplexity,
 	duration: Duration::from_millis(1474), /* TODO: This is ~100ms greater than FFmpeg's report, can we do better? */
@@ -37,6 +48,21 @@ const AAC_PROPERTIES: AACProperties = AACProperties {
 	original: false,
 };
 
+const AAC_ADIF_PROPERTIES: AACProperties = AACProperties {
+	format: AacFormat::Adif,
+	bitstream_type: Some(AdifBitstreamType::Variable),
+	version: MpegVersion::V4,
+	audio_object_type: AudioObjectType::AacLowComplexity,
+	duration: Duration::from_millis(1318),
+	overall_bitrate: 128,
+	audio_bitrate: 128,
+	sample_rate: 48000,
+	channels: 2,
+	channel_mask: Some(ChannelMask::stereo()),
+	copyright: false,
+	original: false,
+};
+
 const AIFF_PROPERTIES: AiffProperties = AiffProperties {
 	duration: Duration::from_millis(1428),
 	overall_bitrate: 1542,
@@ -57,6 +83,89 @@ const APE_PROPERTIES: ApeProperties = ApeProperties {
 	channels: 2,
 };
 
+const ASF_PROPERTIES: AsfProperties = AsfProperties {
+	codec: AsfCodec::Wma2,
+	format_tag: 0x0161,
+	duration: Duration::from_secs(1),
+	overall_bitrate: 131,
+	audio_bitrate: 128,
+	sample_rate: 44100,
+	bit_depth: 16,
+	channels: 2,
+	is_encrypted: false,
+};
+
+const CAF_AAC_PROPERTIES: CafProperties = CafProperties {
+	codec: CafCodec::Aac,
+	format_id: *b"aac ",
+	duration: Duration::from_millis(1428),
+	overall_bitrate: 49,
+	audio_bitrate: 47,
+	sample_rate: 48000,
+	bit_depth: None,
+	channels: 2,
+	alac_config: None,
+};
+
+const CAF_ALAC_PROPERTIES: CafProperties = CafProperties {
+	codec: CafCodec::Alac,
+	format_id: *b"alac",
+	duration: Duration::from_millis(1463),
+	overall_bitrate: 30,
+	audio_bitrate: 29,
+	sample_rate: 44100,
+	bit_depth: Some(16),
+	channels: 2,
+	alac_config: Some(AlacSpecificConfig {
+		frame_length: 4096,
+		compatible_version: 0,
+		bit_depth: 16,
+		pb: 40,
+		mb: 10,
+		kb: 14,
+		channels: 2,
+		max_run: 255,
+		max_frame_bytes: 0,
+		average_bitrate: 0,
+		sample_rate: 44100,
+	}),
+};
+
+const CAF_LPCM_PROPERTIES: CafProperties = CafProperties {
+	codec: CafCodec::Lpcm,
+	format_id: *b"lpcm",
+	duration: Duration::from_millis(500),
+	overall_bitrate: 129,
+	audio_bitrate: 128,
+	sample_rate: 8000,
+	bit_depth: Some(16),
+	channels: 1,
+	alac_config: None,
+};
+
+const DFF_PROPERTIES: DffProperties = DffProperties {
+	version: 0x0105_0000,
+	compression: DffCompression::Dsd,
+	duration: Duration::from_millis(23),
+	overall_bitrate: 5772,
+	audio_bitrate: 5644,
+	sample_rate: 2_822_400,
+	channels: 2,
+	channel_mask: Some(ChannelMask::stereo()),
+	sample_count: 65536,
+};
+
+const DSF_PROPERTIES: DsfProperties = DsfProperties {
+	duration: Duration::from_millis(23),
+	overall_bitrate: 5741,
+	audio_bitrate: 5644,
+	sample_rate: 2_822_400,
+	bits_per_sample: 1,
+	channels: 2,
+	channel_mask: Some(ChannelMask::stereo()),
+	sample_count: 65536,
+};
+
 const FLAC_PROPERTIES: FlacProperties = FlacProperties {
 	duration: Duration::from_millis(1428),
 	overall_bitrate: 321,
@@ -122,6 +231,36 @@ const MP4_AAC_PROPERTIES: Mp4Properties = Mp4Properties {
 	bit_depth: None,
 	channels: 2,
 	drm_protected: false,
+	alac_config: None,
+	opus_config: None,
+};
+
+const MP4_AC3_PROPERTIES: Mp4Properties = Mp4Properties {
+	codec: Mp4Codec::AC3,
+	extended_audio_object_type: None,
+	duration: Duration::from_millis(1449),
+	overall_bitrate: 135,
+	audio_bitrate: 124,
+	sample_rate: 48000,
+	bit_depth: None,
+	channels: 6,
+	drm_protected: false,
+	alac_config: None,
+	opus_config: None,
+};
+
+const MP4_EAC3_PROPERTIES: Mp4Properties = Mp4Properties {
+	codec: Mp4Codec::EAC3,
+	extended_audio_object_type: None,
+	duration: Duration::from_millis(1449),
+	overall_bitrate: 135,
+	audio_bitrate: 124,
+	sample_rate: 48000,
+	bit_depth: None,
+	channels: 8,
+	drm_protected: false,
+	alac_config: None,
+	opus_config: None,
 };
 
 const MP4_ALAC_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -134,6 +273,20 @@ const MP4_ALAC_PROPERTIES: Mp4Properties = Mp4Properties {
 	bit_depth: Some(16),
 	channels: 2,
 	drm_protected: false,
+	alac_config: Some(AlacSpecificConfig {
+		frame_length: 4096,
+		compatible_version: 0,
+		bit_depth: 16,
+		pb: 40,
+		mb: 10,
+		kb: 14,
+		channels: 2,
+		max_run: 0,
+		max_frame_bytes: 16388,
+		average_bitrate: 1_536_000,
+		sample_rate: 48000,
+	}),
+	opus_config: None,
 };
 
 const MP4_ALS_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -146,6 +299,8 @@ const MP4_ALS_PROPERTIES: Mp4Properties = Mp4Properties {
 	bit_depth: None,
 	channels: 2,
 	drm_protected: false,
+	alac_config: None,
+	opus_config: None,
 };
 
 const MP4_FLAC_PROPERTIES: Mp4Properties = Mp4Properties {
@@ -158,6 +313,8 @@ const MP4_FLAC_PROPERTIES: Mp4Properties = Mp4Properties {
 	bit_depth: Some(16),
 	channels: 2,
 	drm_protected: false,
+	alac_config: None,
+	opus_config: None,
 };
 
 // Properties verified with libmpcdec 1.2.2
@@ -234,6 +391,16 @@ const OPUS_PROPERTIES: OpusProperties = OpusProperties {
 	input_sample_rate: 48000,
 };
 
+const OGG_FLAC_PROPERTIES: FlacProperties = FlacProperties {
+	duration: Duration::from_millis(1428),
+	overall_bitrate: 279,
+	audio_bitrate: 279,
+	sample_rate: 48000,
+	bit_depth: 16,
+	channels: 2,
+	signature: 164_506_065_180_489_231_127_156_351_872_182_799_315,
+};
+
 const SPEEX_PROPERTIES: SpeexProperties = SpeexProperties {
 	duration: Duration::from_millis(1469),
 	version: 1,
@@ -258,6 +425,17 @@ const VORBIS_PROPERTIES: VorbisProperties = VorbisProperties {
 	bitrate_minimum: 0,
 };
 
+const TTA_PROPERTIES: TtaProperties = TtaProperties {
+	format: TtaFormat::Simple,
+	duration: Duration::from_millis(1428),
+	overall_bitrate: 49,
+	audio_bitrate: 48,
+	sample_rate: 48000,
+	bit_depth: 16,
+	channels: 2,
+	total_samples: 68546,
+};
+
 const WAV_PROPERTIES: WavProperties = WavProperties {
 	format: WavFormat::PCM,
 	duration: Duration::from_millis(1428),
@@ -269,6 +447,18 @@ const WAV_PROPERTIES: WavProperties = WavProperties {
 	channel_mask: None,
 };
 
+// The same stream as `WAV_PROPERTIES`, in an RF64/Wave64 container
+const WAV_64_BIT_PROPERTIES: WavProperties = WavProperties {
+	format: WavFormat::PCM,
+	duration: Duration::from_millis(1428),
+	overall_bitrate: 1543,
+	audio_bitrate: 1536,
+	sample_rate: 48000,
+	bit_depth: 16,
+	channels: 2,
+	channel_mask: None,
+};
+
 const WAVPACK_PROPERTIES: WavPackProperties = WavPackProperties {
 	version: 1040,
 	duration: Duration::from_millis(1428),
@@ -301,6 +491,14 @@ fn aac_properties() {
 	);
 }
 
+#[test_log::test]
+fn aac_adif_properties() {
+	assert_eq!(
+		get_properties::<AacFile>("tests/files/assets/minimal/full_test_adif.aac"),
+		AAC_ADIF_PROPERTIES
+	);
+}
+
 #[test_log::test]
 fn aiff_properties() {
 	assert_eq!(
@@ -317,6 +515,64 @@ fn ape_properties() {
 	);
 }
 
+#[test_log::test]
+fn asf_properties() {
+	assert_eq!(
+		get_properties::<AsfFile>("tests/files/assets/minimal/full_test.wma"),
+		ASF_PROPERTIES
+	)
+}
+
+#[test_log::test]
+fn caf_aac_properties() {
+	assert_eq!(
+		get_properties::<CafFile>("tests/files/assets/minimal/full_test.caf"),
+		CAF_AAC_PROPERTIES
+	)
+}
+
+#[test_log::test]
+fn caf_alac_properties() {
+	assert_eq!(
+		get_properties::<CafFile>("tests/files/assets/minimal/caf_alac.caf"),
+		CAF_ALAC_PROPERTIES
+	)
+}
+
+#[test_log::test]
+fn caf_lpcm_properties() {
+	assert_eq!(
+		get_properties::<CafFile>("tests/files/assets/minimal/caf_lpcm.caf"),
+		CAF_LPCM_PROPERTIES
+	)
+}
+
+#[test_log::test]
+fn dff_properties() {
+	assert_eq!(
+		get_properties::<DffFile>("tests/files/assets/minimal/full_test.dff"),
+		DFF_PROPERTIES
+	)
+}
+
+#[test_log::test]
+fn dsf_properties() {
+	assert_eq!(
+		get_properties::<DsfFile>("tests/files/assets/minimal/full_test.dsf"),
+		DSF_PROPERTIES
+	)
+}
+
+#[test_log::test]
+fn dsf_msb_first_bit_depth() {
+	let properties = DsfProperties {
+		bits_per_sample: 8,
+		..DSF_PROPERTIES
+	};
+
+	assert_eq!(FileProperties::from(properties).bit_depth(), Some(1));
+}
+
 #[test_log::test]
 fn flac_properties() {
 	assert_eq!(
@@ -325,6 +581,41 @@ fn flac_properties() {
 	)
 }
 
+#[test_log::test]
+fn matroska_properties() {
+	let expected = MatroskaProperties {
+		doc_type: String::from("matroska"),
+		doc_type_version: 4,
+		muxing_app: String::from("lofty"),
+		writing_app: String::from("lofty"),
+		duration: Duration::from_secs(1),
+		overall_bitrate: 130,
+		audio_bitrate: 128,
+		audio_tracks: vec![AudioTrack {
+			number: 1,
+			uid: 1,
+			enabled: true,
+			default: true,
+			name: None,
+			language: String::from("eng"),
+			codec_id: String::from("A_OPUS"),
+			codec_private: Some(vec![
+				b'O', b'p', b'u', b's', b'H', b'e', b'a', b'd', 1, 2, 0x38, 0x01, 0x80, 0xBB, 0x00,
+				0x00, 0x00, 0x00, 0x00,
+			]),
+			sample_rate: 48000,
+			output_sample_rate: None,
+			channels: 2,
+			bit_depth: None,
+		}],
+	};
+
+	assert_eq!(
+		get_properties::<MatroskaFile>("tests/files/assets/minimal/full_test.mka"),
+		expected
+	);
+}
+
 #[test_log::test]
 fn mp1_properties() {
 	assert_eq!(
@@ -381,6 +672,65 @@ fn mp4_flac_properties() {
 	)
 }
 
+#[test_log::test]
+fn mp4_aac_wave_properties() {
+	// A QuickTime version 1 sample entry, with the `esds` atom inside of a `wave` atom
+	assert_eq!(
+		get_properties::<Mp4File>("tests/files/assets/minimal/m4a_codec_aac_wave.m4a"),
+		MP4_AAC_PROPERTIES
+	)
+}
+
+#[test_log::test]
+fn mp4_ac3_properties() {
+	assert_eq!(
+		get_properties::<Mp4File>("tests/files/assets/minimal/mp4_codec_ac3.mp4"),
+		MP4_AC3_PROPERTIES
+	)
+}
+
+#[test_log::test]
+fn mp4_eac3_properties() {
+	assert_eq!(
+		get_properties::<Mp4File>("tests/files/assets/minimal/mp4_codec_eac3.mp4"),
+		MP4_EAC3_PROPERTIES
+	)
+}
+
+#[test_log::test]
+fn mp4_opus_properties() {
+	let expected = Mp4Properties {
+		codec: Mp4Codec::Opus,
+		extended_audio_object_type: None,
+		duration: Duration::from_millis(1449),
+		overall_bitrate: 135,
+		audio_bitrate: 124,
+		sample_rate: 48000,
+		bit_depth: None,
+		channels: 2,
+		drm_protected: false,
+		alac_config: None,
+		opus_config: Some(OpusSpecificConfig {
+			version: 0,
+			output_channel_count: 2,
+			pre_skip: 312,
+			input_sample_rate: 44100,
+			output_gain: -256,
+			channel_mapping_family: 1,
+			channel_mapping: Some(OpusChannelMapping {
+				stream_count: 1,
+				coupled_count: 1,
+				channel_mapping: vec![0, 1],
+			}),
+		}),
+	};
+
+	assert_eq!(
+		get_properties::<Mp4File>("tests/files/assets/minimal/mp4_codec_opus.mp4"),
+		expected
+	)
+}
+
 #[test_log::test]
 fn mpc_sv5_properties() {
 	assert_eq!(
@@ -413,6 +763,14 @@ fn opus_properties() {
 	)
 }
 
+#[test_log::test]
+fn ogg_flac_properties() {
+	assert_eq!(
+		get_properties::<OggFlacFile>("tests/files/assets/minimal/full_test.oga"),
+		OGG_FLAC_PROPERTIES
+	)
+}
+
 #[test_log::test]
 fn speex_properties() {
 	assert_eq!(
@@ -429,6 +787,14 @@ fn vorbis_properties() {
 	)
 }
 
+#[test_log::test]
+fn tta_properties() {
+	assert_eq!(
+		get_properties::<TtaFile>("tests/files/assets/minimal/full_test.tta"),
+		TTA_PROPERTIES
+	)
+}
+
 #[test_log::test]
 fn wav_properties() {
 	assert_eq!(
@@ -437,6 +803,22 @@ fn wav_properties() {
 	)
 }
 
+#[test_log::test]
+fn wav_rf64_properties() {
+	assert_eq!(
+		get_properties::<WavFile>("tests/files/assets/minimal/wav_format_pcm_rf64.wav"),
+		WAV_64_BIT_PROPERTIES
+	)
+}
+
+#[test_log::test]
+fn wav_wave64_properties() {
+	assert_eq!(
+		get_properties::<WavFile>("tests/files/assets/minimal/wav_format_pcm.w64"),
+		WAV_64_BIT_PROPERTIES
+	)
+}
+
 #[test_log::test]
 fn wavpack_properties() {
 	assert_eq!(
@@ -70,6 +70,157 @@ gen_map!(
 	"COMM" | "ANNO" => Comment
 );
 
+gen_map!(
+	ASF_MAP;
+
+	"Title"                        => TrackTitle,
+	"Author"                       => TrackArtist,
+	"Copyright"                    => CopyrightMessage,
+	"Description"                  => Comment,
+	"WM/AlbumTitle"                => AlbumTitle,
+	"WM/SetSubTitle"               => SetSubtitle,
+	"WM/ContentGroupDescription"   => ContentGroup,
+	"WM/SubTitle"                  => TrackSubtitle,
+	"WM/OriginalAlbumTitle"        => OriginalAlbumTitle,
+	"WM/OriginalArtist"            => OriginalArtist,
+	"WM/OriginalLyricist"          => OriginalLyricist,
+	"WM/AlbumSortOrder"            => AlbumTitleSortOrder,
+	"WM/AlbumArtistSortOrder"      => AlbumArtistSortOrder,
+	"WM/TitleSortOrder"            => TrackTitleSortOrder,
+	"WM/ArtistSortOrder"           => TrackArtistSortOrder,
+	"WM/ComposerSortOrder"         => ComposerSortOrder,
+	"WM/AlbumArtist"               => AlbumArtist,
+	"WM/ARTISTS"                   => TrackArtists,
+	"WM/Writer"                    => Lyricist,
+	"WM/Composer"                  => Composer,
+	"WM/Conductor"                 => Conductor,
+	"WM/Director"                  => Director,
+	"WM/Producer"                  => Producer,
+	"WM/Publisher"                 => Label,
+	"WM/ModifiedBy"                => Remixer,
+	"WM/PartOfSet"                 => DiscNumber,
+	"TotalDiscs"                   => DiscTotal,
+	"WM/TrackNumber"               => TrackNumber,
+	"TotalTracks"                  => TrackTotal,
+	"WM/Year"                      => RecordingDate,
+	"WM/OriginalReleaseYear"       => OriginalReleaseDate,
+	"WM/ISRC"                      => Isrc,
+	"WM/Barcode"                   => Barcode,
+	"WM/CatalogNo"                 => CatalogNumber,
+	"WM/Work"                      => Work,
+	"WM/IsCompilation"             => FlagCompilation,
+	"WM/Media"                     => OriginalMediaType,
+	"WM/EncodedBy"                 => EncodedBy,
+	"WM/ToolName"                  => EncoderSoftware,
+	"WM/EncodingSettings"          => EncoderSettings,
+	"REPLAYGAIN_ALBUM_GAIN"        => ReplayGainAlbumGain,
+	"REPLAYGAIN_ALBUM_PEAK"        => ReplayGainAlbumPeak,
+	"REPLAYGAIN_TRACK_GAIN"        => ReplayGainTrackGain,
+	"REPLAYGAIN_TRACK_PEAK"        => ReplayGainTrackPeak,
+	"WM/Genre"                     => Genre,
+	"WM/InitialKey"                => InitialKey,
+	"WM/Mood"                      => Mood,
+	"WM/BeatsPerMinute"            => Bpm,
+	"WM/Lyrics"                    => Lyrics,
+	"WM/Language"                  => Language,
+	"WM/Script"                    => Script,
+	"WM/AuthorURL"                 => TrackArtistUrl,
+	"WM/AudioFileURL"              => AudioFileUrl,
+	"WM/AudioSourceURL"            => AudioSourceUrl,
+	"WM/RadioStationName"          => InternetRadioStationName,
+	"WM/RadioStationOwner"         => InternetRadioStationOwner,
+	"MusicBrainz/Track Id"         => MusicBrainzRecordingId,
+	"MusicBrainz/Release Track Id" => MusicBrainzTrackId,
+	"MusicBrainz/Album Id"         => MusicBrainzReleaseId,
+	"MusicBrainz/Release Group Id" => MusicBrainzReleaseGroupId,
+	"MusicBrainz/Artist Id"        => MusicBrainzArtistId,
+	"MusicBrainz/Album Artist Id"  => MusicBrainzReleaseArtistId,
+	"MusicBrainz/Work Id"          => MusicBrainzWorkId
+);
+
+gen_map!(
+	MATROSKA_MAP;
+
+	"TITLE"            => TrackTitle,
+	"SUBTITLE"         => TrackSubtitle,
+	"ARTIST"           => TrackArtist,
+	"LEAD_PERFORMER"   => Performer,
+	"ARRANGER"         => Arranger,
+	"LYRICIST"         => Lyricist,
+	"COMPOSER"         => Composer,
+	"CONDUCTOR"        => Conductor,
+	"DIRECTOR"         => Director,
+	"PRODUCER"         => Producer,
+	"ENGINEER"         => Engineer,
+	"MIXED_BY"         => MixEngineer,
+	"REMIXED_BY"       => Remixer,
+	"PUBLISHER"        => Publisher,
+	"LABEL"            => Label,
+	"GENRE"            => Genre,
+	"MOOD"             => Mood,
+	"ORIGINAL_MEDIA_TYPE" => OriginalMediaType,
+	"COMMENT"          => Comment,
+	"DESCRIPTION"      => Description,
+	"LYRICS"           => Lyrics,
+	"BPM"              => Bpm,
+	"INITIAL_KEY"      => InitialKey,
+	"DATE_RELEASED"    => ReleaseDate,
+	"DATE_RECORDED"    => RecordingDate,
+	"DATE_ENCODED"     => EncodingTime,
+	"DATE_TAGGED"      => TaggingTime,
+	"ISRC"             => Isrc,
+	"BARCODE"          => Barcode,
+	"CATALOG_NUMBER"   => CatalogNumber,
+	"ENCODER"          => EncoderSoftware,
+	"ENCODER_SETTINGS" => EncoderSettings,
+	"ENCODED_BY"       => EncodedBy,
+	"COPYRIGHT"        => CopyrightMessage,
+	"LICENSE"          => License,
+	"PART_NUMBER"      => TrackNumber,
+	"TOTAL_PARTS"      => TrackTotal,
+	"REPLAYGAIN_GAIN"  => ReplayGainTrackGain,
+	"REPLAYGAIN_PEAK"  => ReplayGainTrackPeak,
+	// Not part of the specification, but commonly used by other taggers
+	"ALBUM"            => AlbumTitle,
+	"ALBUM_ARTIST"     => AlbumArtist
+);
+
+gen_map!(
+	BEXT_MAP;
+
+	"Description"     => Comment,
+	"Originator"      => Producer,
+	"OriginationDate" => RecordingDate,
+	"CodingHistory"   => EncoderSettings
+);
+
+gen_map!(
+	CAF_INFO_MAP;
+
+	"title"                => TrackTitle,
+	"artist"               => TrackArtist,
+	"album"                => AlbumTitle,
+	"composer"             => Composer,
+	"lyricist"             => Lyricist,
+	"genre"                => Genre,
+	"track number"         => TrackNumber,
+	"year"                 => Year,
+	"recorded date"        => RecordingDate,
+	"tempo"                => Bpm,
+	"key signature"        => InitialKey,
+	"copyright"            => CopyrightMessage,
+	"encoding application" => EncoderSoftware,
+	"comments"             => Comment
+);
+
+gen_map!(
+	DFF_TEXT_MAP;
+
+	"DITI" => TrackTitle,
+	"DIAR" => TrackArtist,
+	"COMT" => Comment
+);
+
 gen_map!(
 	APE_MAP;
 
@@ -490,8 +641,18 @@ gen_item_keys!(
 
 		[TagType::Ape, APE_MAP],
 
+		[TagType::Asf, ASF_MAP],
+
+		[TagType::Bext, BEXT_MAP],
+
+		[TagType::CafInfo, CAF_INFO_MAP],
+
+		[TagType::DffText, DFF_TEXT_MAP],
+
 		[TagType::Id3v2, ID3V2_MAP],
 
+		[TagType::Matroska, MATROSKA_MAP],
+
 		[TagType::Mp4Ilst, ILST_MAP],
 
 		[TagType::RiffInfo, RIFF_INFO_MAP],
@@ -902,6 +1063,10 @@ impl TagItem {
 			return VALID_ITEMKEYS.contains(&self.item_key);
 		}
 
+		if tag_type == TagType::Matroska {
+			return crate::matroska::tag::supports_key(&self.item_key);
+		}
+
 		self.item_key.map_key(tag_type, false).is_some()
 	}
 }
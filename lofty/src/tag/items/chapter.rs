@@ -0,0 +1,68 @@
+use crate::picture::Picture;
+
+use std::time::Duration;
+
+/// A format-independent representation of a chapter
+///
+/// Chapters are used to mark sections of long audio, such as podcasts and audiobooks.
+///
+/// ## Supported formats
+///
+/// | Format            | Storage                                                     | End time | URL | Image |
+/// |-------------------|-------------------------------------------------------------|----------|-----|-------|
+/// | ID3v2             | `CHAP` frames, ordered by a top-level `CTOC` frame          | Yes      | Yes | Yes   |
+/// | MP4 ilst          | Nero `chpl` atom, or (read only) QuickTime chapter tracks   | No[^1]   | No  | No    |
+/// | Vorbis Comments   | `CHAPTERxxx`, `CHAPTERxxxNAME`, and `CHAPTERxxxURL` fields  | No       | Yes | No    |
+///
+/// [^1]: QuickTime chapter tracks store an end time, which will be read
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Chapter {
+	/// The start of the chapter
+	pub start: Duration,
+	/// The end of the chapter
+	///
+	/// If this is `None`, formats that require an end time will use the start of the next chapter.
+	pub end: Option<Duration>,
+	/// The title of the chapter
+	pub title: Option<String>,
+	/// A URL associated with the chapter
+	pub url: Option<String>,
+	/// An image associated with the chapter
+	pub image: Option<Picture>,
+}
+
+impl Chapter {
+	/// Create a new `Chapter`
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::items::Chapter;
+	/// use std::time::Duration;
+	///
+	/// let chapter = Chapter::new(Duration::from_secs(90), Some(String::from("Introduction")));
+	/// assert_eq!(chapter.title.as_deref(), Some("Introduction"));
+	/// assert!(chapter.end.is_none());
+	/// ```
+	pub fn new(start: Duration, title: Option<String>) -> Self {
+		Self {
+			start,
+			end: None,
+			title,
+			url: None,
+			image: None,
+		}
+	}
+}
+
+/// Get the end of every chapter, falling back to the start of the next chapter, or the
+/// chapter's own start if it is the last
+pub(crate) fn chapter_ends(chapters: &[Chapter]) -> impl Iterator<Item = Duration> + '_ {
+	chapters.iter().enumerate().map(|(idx, chapter)| {
+		chapter.end.unwrap_or_else(|| {
+			chapters
+				.get(idx + 1)
+				.map_or(chapter.start, |next| next.start.max(chapter.start))
+		})
+	})
+}
@@ -1,7 +1,13 @@
 //! Various generic representations of tag items
 
+mod chapter;
 mod lang;
+mod synced_lyrics;
 mod timestamp;
 
+pub use chapter::Chapter;
 pub use lang::*;
+pub use synced_lyrics::{SyncedLyrics, SyncedLyricsLine, SyncedLyricsWord};
 pub use timestamp::Timestamp;
+
+pub(crate) use chapter::chapter_ends;
@@ -0,0 +1,130 @@
+use super::lang::{Lang, UNKNOWN_LANGUAGE};
+
+use std::time::Duration;
+
+/// A format-independent representation of time-synchronized lyrics
+///
+/// ## Supported formats
+///
+/// | Format          | Storage                                                                    |
+/// |-----------------|----------------------------------------------------------------------------|
+/// | ID3v2           | `SYLT` frames with a content type of [`Lyrics`] and millisecond timestamps |
+/// | MP4 ilst        | [LRC] text in the `©lyr` atom                                              |
+/// | Vorbis Comments | [LRC] text in `LYRICS` fields                                              |
+///
+/// `SYLT` frames using MPEG frame timestamps can't be converted without knowing the duration of
+/// an MPEG frame, and will be left in the `ID3v2` tag. They can be converted manually with
+/// [`SynchronizedTextFrame::to_synced_lyrics`].
+///
+/// Lyrics that are stored as LRC lose their language and description, along with any LRC ID tags.
+///
+/// [LRC]: crate::lyrics::Lrc
+/// [`Lyrics`]: crate::id3::v2::SyncTextContentType::Lyrics
+/// [`SynchronizedTextFrame::to_synced_lyrics`]: crate::id3::v2::SynchronizedTextFrame::to_synced_lyrics
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SyncedLyrics {
+	/// The language of the lyrics
+	pub language: Lang,
+	/// A description of the lyrics
+	///
+	/// This is used to tell apart multiple sets of lyrics in the same language.
+	pub description: Option<String>,
+	/// The lines of the lyrics, in order of their timestamps
+	pub lines: Vec<SyncedLyricsLine>,
+}
+
+impl SyncedLyrics {
+	/// Create a new `SyncedLyrics`
+	///
+	/// The language will be [`UNKNOWN_LANGUAGE`], with no description.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::items::{SyncedLyrics, SyncedLyricsLine, UNKNOWN_LANGUAGE};
+	/// use std::time::Duration;
+	///
+	/// let lyrics = SyncedLyrics::new(vec![
+	/// 	SyncedLyricsLine::new(Duration::ZERO, String::from("First line")),
+	/// 	SyncedLyricsLine::new(Duration::from_millis(2500), String::from("Second line")),
+	/// ]);
+	///
+	/// assert_eq!(lyrics.language, UNKNOWN_LANGUAGE);
+	/// assert_eq!(lyrics.lines.len(), 2);
+	/// ```
+	pub fn new(lines: Vec<SyncedLyricsLine>) -> Self {
+		Self {
+			language: UNKNOWN_LANGUAGE,
+			description: None,
+			lines,
+		}
+	}
+}
+
+/// A single line of [`SyncedLyrics`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SyncedLyricsLine {
+	/// The time at which the line starts
+	pub timestamp: Duration,
+	/// The text of the line
+	pub text: String,
+	/// The timing of the individual words (or syllables) of the line
+	///
+	/// If this isn't empty, the words make up the entire [`text`](Self::text).
+	pub words: Vec<SyncedLyricsWord>,
+}
+
+impl SyncedLyricsLine {
+	/// Create a new `SyncedLyricsLine`
+	///
+	/// This will have no word timing.
+	pub fn new(timestamp: Duration, text: String) -> Self {
+		Self {
+			timestamp,
+			text,
+			words: Vec::new(),
+		}
+	}
+
+	/// Create a new `SyncedLyricsLine` from its words
+	///
+	/// The line will start at the first word, and its text will be the concatenation of all words.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::items::{SyncedLyricsLine, SyncedLyricsWord};
+	/// use std::time::Duration;
+	///
+	/// let line = SyncedLyricsLine::from_words(vec![
+	/// 	SyncedLyricsWord::new(Duration::from_millis(1000), String::from("Foo ")),
+	/// 	SyncedLyricsWord::new(Duration::from_millis(1500), String::from("bar")),
+	/// ]);
+	///
+	/// assert_eq!(line.timestamp, Duration::from_millis(1000));
+	/// assert_eq!(line.text, "Foo bar");
+	/// ```
+	pub fn from_words(words: Vec<SyncedLyricsWord>) -> Self {
+		Self {
+			timestamp: words.first().map_or(Duration::ZERO, |word| word.timestamp),
+			text: words.iter().map(|word| word.text.as_str()).collect(),
+			words,
+		}
+	}
+}
+
+/// A single word (or syllable) of a [`SyncedLyricsLine`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SyncedLyricsWord {
+	/// The time at which the word starts
+	pub timestamp: Duration,
+	/// The text of the word, including any surrounding whitespace
+	pub text: String,
+}
+
+impl SyncedLyricsWord {
+	/// Create a new `SyncedLyricsWord`
+	pub fn new(timestamp: Duration, text: String) -> Self {
+		Self { timestamp, text }
+	}
+}
@@ -11,9 +11,11 @@ pub(crate) mod utils;
 
 use crate::config::WriteOptions;
 use crate::error::{LoftyError, Result};
+use crate::lyrics::Lrc;
 use crate::macros::err;
 use crate::picture::{Picture, PictureType};
 use crate::probe::Probe;
+use crate::tag::items::{Chapter, SyncedLyrics};
 use crate::util::io::{FileLike, Length, Truncate};
 
 use std::borrow::Cow;
@@ -112,6 +114,8 @@ pub struct Tag {
 	tag_type: TagType,
 	pub(crate) pictures: Vec<Picture>,
 	pub(crate) items: Vec<TagItem>,
+	pub(crate) chapters: Vec<Chapter>,
+	pub(crate) synced_lyrics: Vec<SyncedLyrics>,
 	pub(crate) companion_tag: Option<companion_tag::CompanionTag>,
 }
 
@@ -229,6 +233,8 @@ impl Tag {
 			tag_type,
 			pictures: Vec::new(),
 			items: Vec::new(),
+			chapters: Vec::new(),
+			synced_lyrics: Vec::new(),
 			companion_tag: None,
 		}
 	}
@@ -634,6 +640,119 @@ impl Tag {
 	pub fn remove_picture(&mut self, index: usize) -> Picture {
 		self.pictures.remove(index)
 	}
+
+	/// Returns the stored [`Chapter`]s as a slice
+	///
+	/// See [`Chapter`] for the formats that support chapters.
+	pub fn chapters(&self) -> &[Chapter] {
+		&self.chapters
+	}
+
+	/// Pushes a [`Chapter`] to the tag
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::items::Chapter;
+	/// use lofty::tag::{Tag, TagType};
+	/// use std::time::Duration;
+	///
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	///
+	/// tag.push_chapter(Chapter::new(Duration::ZERO, Some(String::from("Intro"))));
+	/// tag.push_chapter(Chapter::new(
+	/// 	Duration::from_secs(60),
+	/// 	Some(String::from("Part 1")),
+	/// ));
+	///
+	/// assert_eq!(tag.chapters().len(), 2);
+	/// ```
+	pub fn push_chapter(&mut self, chapter: Chapter) {
+		self.chapters.push(chapter)
+	}
+
+	/// Replaces all [`Chapter`]s
+	///
+	/// NOTE: The chapters are expected to be in order of their start times.
+	pub fn set_chapters(&mut self, chapters: Vec<Chapter>) {
+		self.chapters = chapters;
+	}
+
+	/// Removes all [`Chapter`]s, returning them
+	pub fn remove_chapters(&mut self) -> Vec<Chapter> {
+		std::mem::take(&mut self.chapters)
+	}
+
+	/// Returns the stored [`SyncedLyrics`] as a slice
+	///
+	/// See [`SyncedLyrics`] for the formats that support synchronized lyrics.
+	pub fn synced_lyrics(&self) -> &[SyncedLyrics] {
+		&self.synced_lyrics
+	}
+
+	/// Pushes [`SyncedLyrics`] to the tag
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::tag::items::{SyncedLyrics, SyncedLyricsLine};
+	/// use lofty::tag::{Tag, TagType};
+	/// use std::time::Duration;
+	///
+	/// let mut tag = Tag::new(TagType::Id3v2);
+	///
+	/// tag.push_synced_lyrics(SyncedLyrics::new(vec![SyncedLyricsLine::new(
+	/// 	Duration::ZERO,
+	/// 	String::from("Foo"),
+	/// )]));
+	///
+	/// assert_eq!(tag.synced_lyrics().len(), 1);
+	/// ```
+	pub fn push_synced_lyrics(&mut self, lyrics: SyncedLyrics) {
+		self.synced_lyrics.push(lyrics)
+	}
+
+	/// Removes all [`SyncedLyrics`], returning them
+	pub fn remove_synced_lyrics(&mut self) -> Vec<SyncedLyrics> {
+		std::mem::take(&mut self.synced_lyrics)
+	}
+
+	/// Get the synchronized lyrics as [`Lrc`]
+	///
+	/// This uses the first [`SyncedLyrics`], falling back to any [`ItemKey::Lyrics`] item that
+	/// contains LRC text.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use lofty::lyrics::Lrc;
+	/// use lofty::tag::{Tag, TagType};
+	///
+	/// # fn main() -> lofty::error::Result<()> {
+	/// let mut tag = Tag::new(TagType::VorbisComments);
+	/// assert!(tag.lrc().is_none());
+	///
+	/// let lrc: Lrc = "[00:01.00]Foo\n[00:02.50]Bar".parse()?;
+	/// tag.set_lrc(&lrc);
+	///
+	/// assert_eq!(tag.lrc(), Some(lrc));
+	/// # Ok(()) }
+	/// ```
+	pub fn lrc(&self) -> Option<Lrc> {
+		if let Some(lyrics) = self.synced_lyrics.first() {
+			return Some(Lrc::from_synced_lyrics(lyrics));
+		}
+
+		self.get_strings(&ItemKey::Lyrics)
+			.find_map(Lrc::from_lyrics_text)
+	}
+
+	/// Replaces all [`SyncedLyrics`] with [`Lrc`] lyrics
+	///
+	/// The LRC [`offset`](Lrc::offset) will be applied to the timestamps, see [`Lrc::to_synced_lyrics`].
+	pub fn set_lrc(&mut self, lrc: &Lrc) {
+		self.synced_lyrics = vec![lrc.to_synced_lyrics()];
+	}
 }
 
 impl TagExt for Tag {
@@ -646,7 +765,7 @@ impl TagExt for Tag {
 	}
 
 	fn len(&self) -> usize {
-		self.items.len() + self.pictures.len()
+		self.items.len() + self.pictures.len() + self.chapters.len() + self.synced_lyrics.len()
 	}
 
 	fn contains<'a>(&'a self, key: Self::RefKey<'a>) -> bool {
@@ -654,7 +773,10 @@ impl TagExt for Tag {
 	}
 
 	fn is_empty(&self) -> bool {
-		self.items.is_empty() && self.pictures.is_empty()
+		self.items.is_empty()
+			&& self.pictures.is_empty()
+			&& self.chapters.is_empty()
+			&& self.synced_lyrics.is_empty()
 	}
 
 	/// Save the `Tag` to a [`FileLike`]
@@ -717,6 +839,8 @@ impl TagExt for Tag {
 	fn clear(&mut self) {
 		self.items.clear();
 		self.pictures.clear();
+		self.chapters.clear();
+		self.synced_lyrics.clear();
 	}
 }
 
@@ -70,10 +70,14 @@ pub trait MergeTag: private::Sealed {
 // https://rust-lang.github.io/api-guidelines/future-proofing.html#c-sealed
 mod private {
 	use crate::ape::ApeTag;
+	use crate::asf::AsfTag;
+	use crate::caf::CafInfo;
+	use crate::dsd::dff::DffTextChunks;
 	use crate::id3::v1::Id3v1Tag;
 	use crate::id3::v2::Id3v2Tag;
 	use crate::iff::aiff::AiffTextChunks;
-	use crate::iff::wav::RiffInfoList;
+	use crate::iff::wav::{BextChunk, RiffInfoList};
+	use crate::matroska::MatroskaTag;
 	use crate::ogg::VorbisComments;
 	use crate::tag::Tag;
 
@@ -85,12 +89,27 @@ mod private {
 	impl Sealed for ApeTag {}
 	impl Sealed for crate::ape::tag::SplitTagRemainder {}
 
+	impl Sealed for AsfTag {}
+	impl Sealed for crate::asf::tag::SplitTagRemainder {}
+
+	impl Sealed for BextChunk {}
+	impl Sealed for crate::iff::wav::bext::SplitTagRemainder {}
+
+	impl Sealed for CafInfo {}
+	impl Sealed for crate::caf::tag::SplitTagRemainder {}
+
+	impl Sealed for DffTextChunks {}
+	impl Sealed for crate::dsd::dff::tag::SplitTagRemainder {}
+
 	impl Sealed for Id3v1Tag {}
 	impl Sealed for crate::id3::v1::tag::SplitTagRemainder {}
 
 	impl Sealed for Id3v2Tag {}
 	impl Sealed for crate::id3::v2::tag::SplitTagRemainder {}
 
+	impl Sealed for MatroskaTag {}
+	impl Sealed for crate::matroska::tag::SplitTagRemainder {}
+
 	impl Sealed for crate::mp4::Ilst {}
 	impl Sealed for crate::mp4::ilst::SplitTagRemainder {}
 
@@ -153,10 +153,14 @@ pub trait TagExt: Accessor + Into<Tag> + Sized + private::Sealed {
 // https://rust-lang.github.io/api-guidelines/future-proofing.html#c-sealed
 mod private {
 	use crate::ape::ApeTag;
+	use crate::asf::AsfTag;
+	use crate::caf::CafInfo;
+	use crate::dsd::dff::DffTextChunks;
 	use crate::id3::v1::Id3v1Tag;
 	use crate::id3::v2::Id3v2Tag;
 	use crate::iff::aiff::AiffTextChunks;
-	use crate::iff::wav::RiffInfoList;
+	use crate::iff::wav::{BextChunk, RiffInfoList};
+	use crate::matroska::MatroskaTag;
 	use crate::mp4::Ilst;
 	use crate::ogg::VorbisComments;
 	use crate::tag::Tag;
@@ -165,9 +169,14 @@ mod private {
 
 	impl Sealed for AiffTextChunks {}
 	impl Sealed for ApeTag {}
+	impl Sealed for AsfTag {}
+	impl Sealed for BextChunk {}
+	impl Sealed for CafInfo {}
+	impl Sealed for DffTextChunks {}
 	impl Sealed for Id3v1Tag {}
 	impl Sealed for Id3v2Tag {}
 	impl Sealed for Ilst {}
+	impl Sealed for MatroskaTag {}
 	impl Sealed for RiffInfoList {}
 	impl Sealed for Tag {}
 	impl Sealed for VorbisComments {}
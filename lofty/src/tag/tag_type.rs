@@ -27,6 +27,16 @@ pub enum TagType {
 	RiffInfo,
 	/// Represents AIFF text chunks
 	AiffText,
+	/// Represents DSDIFF text chunks
+	DffText,
+	/// Represents ASF attributes
+	Asf,
+	/// Represents Matroska tags and attachments
+	Matroska,
+	/// Represents a CAF `info` chunk
+	CafInfo,
+	/// Represents a Broadcast Wave Format `bext` chunk
+	Bext,
 }
 
 impl TagType {
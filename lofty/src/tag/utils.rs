@@ -4,14 +4,17 @@ use crate::file::FileType;
 use crate::macros::err;
 use crate::tag::{Tag, TagType};
 use crate::util::io::{FileLike, Length, Truncate};
-use crate::{aac, ape, flac, iff, mpeg, musepack, wavpack};
+use crate::{aac, ape, asf, caf, dsd, flac, iff, matroska, mpeg, musepack, tta, wavpack};
 
 use crate::id3::v1::tag::Id3v1TagRef;
 use crate::id3::v2::tag::Id3v2TagRef;
 use crate::id3::v2::{self, Id3v2TagFlags};
 use crate::mp4::Ilst;
-use crate::ogg::tag::{create_vorbis_comments_ref, VorbisCommentsRef};
+use crate::ogg::tag::{create_vorbis_comments_ref, tag_extra_items, VorbisCommentsRef};
 use ape::tag::ApeTagRef;
+use asf::tag::AsfTagRef;
+use caf::tag::CafInfoRef;
+use dsd::dff::tag::DffTextChunksRef;
 use iff::aiff::tag::AiffTextChunksRef;
 use iff::wav::tag::RIFFInfoListRef;
 
@@ -34,8 +37,13 @@ where
 		FileType::Aac => aac::write::write_to(file, tag, write_options),
 		FileType::Aiff => iff::aiff::write::write_to(file, tag, write_options),
 		FileType::Ape => ape::write::write_to(file, tag, write_options),
+		FileType::Asf => asf::write::write_to(file, tag, write_options),
+		FileType::Caf => caf::write::write_to(file, tag, write_options),
+		FileType::Dff => dsd::dff::write::write_to(file, tag, write_options),
+		FileType::Dsf => dsd::dsf::write::write_to(file, tag, write_options),
 		FileType::Flac => flac::write::write_to(file, tag, write_options),
-		FileType::Opus | FileType::Speex | FileType::Vorbis => {
+		FileType::Matroska => matroska::write::write_to(file, tag, write_options),
+		FileType::OggFlac | FileType::Opus | FileType::Speex | FileType::Vorbis => {
 			crate::ogg::write::write_to(file, tag, file_type, write_options)
 		},
 		FileType::Mpc => musepack::write::write_to(file, tag, write_options),
@@ -45,6 +53,7 @@ where
 			&mut Into::<Ilst>::into(tag.clone()).as_ref(),
 			write_options,
 		),
+		FileType::Tta => tta::write::write_to(file, tag, write_options),
 		FileType::Wav => iff::wav::write::write_to(file, tag, write_options),
 		FileType::WavPack => wavpack::write::write_to(file, tag, write_options),
 		_ => err!(UnsupportedTag),
@@ -66,6 +75,7 @@ pub(crate) fn dump_tag<W: Write>(
 		TagType::Id3v1 => Into::<Id3v1TagRef<'_>>::into(tag).dump_to(writer, write_options),
 		TagType::Id3v2 => Id3v2TagRef {
 			flags: Id3v2TagFlags::default(),
+			original_version: v2::tag::tag_original_version(tag),
 			frames: v2::tag::tag_frames(tag).peekable(),
 		}
 		.dump_to(writer, write_options),
@@ -73,14 +83,16 @@ pub(crate) fn dump_tag<W: Write>(
 			.as_ref()
 			.dump_to(writer, write_options),
 		TagType::VorbisComments => {
-			let (vendor, items, pictures) = create_vorbis_comments_ref(tag);
+			let extra_items = tag_extra_items(tag);
+			let (vendor, items, pictures) = create_vorbis_comments_ref(tag, &extra_items);
 
-			VorbisCommentsRef {
+			let mut comments_ref = VorbisCommentsRef {
 				vendor: Cow::from(vendor),
 				items,
 				pictures,
-			}
-			.dump_to(writer, write_options)
+			};
+
+			comments_ref.dump_to(writer, write_options)
 		},
 		TagType::RiffInfo => RIFFInfoListRef {
 			items: iff::wav::tag::tagitems_into_riff(tag.items()),
@@ -98,6 +110,37 @@ pub(crate) fn dump_tag<W: Write>(
 			}
 		}
 		.dump_to(writer, write_options),
+		TagType::DffText => {
+			use crate::tag::item::ItemKey;
+
+			DffTextChunksRef {
+				artist: tag.get_string(&ItemKey::TrackArtist),
+				title: tag.get_string(&ItemKey::TrackTitle),
+				comments: dsd::dff::tag::tag_comments(tag),
+			}
+		}
+		.dump_to(writer, write_options),
+		TagType::Asf => {
+			let asf_tag: asf::AsfTag = tag.clone().into();
+			AsfTagRef {
+				attributes: asf_tag.attributes.iter(),
+			}
+			.dump_to(writer, write_options)
+		},
+		TagType::Bext => {
+			use crate::tag::TagExt;
+
+			let bext: iff::wav::BextChunk = tag.clone().into();
+			bext.dump_to(writer, write_options)
+		},
+		TagType::CafInfo => CafInfoRef::new(caf::tag::tagitems_into_caf_info(tag.items()))
+			.dump_to(writer, write_options),
+		TagType::Matroska => {
+			use crate::tag::TagExt;
+
+			let matroska_tag: matroska::MatroskaTag = tag.clone().into();
+			matroska_tag.dump_to(writer, write_options)
+		},
 		_ => Ok(()),
 	}
 }
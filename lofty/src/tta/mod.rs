@@ -0,0 +1,36 @@
+//! True Audio (TTA) specific items
+//!
+//! ## File notes
+//!
+//! An `ID3v2` tag is stored at the beginning of the file, while `ID3v1` and `APE` tags are stored
+//! at the end. All three can be read and written.
+mod properties;
+mod read;
+
+use crate::ape::tag::ApeTag;
+use crate::id3::v1::tag::Id3v1Tag;
+use crate::id3::v2::tag::Id3v2Tag;
+
+use lofty_attr::LoftyFile;
+
+// Exports
+
+pub use properties::{TtaFormat, TtaProperties};
+
+/// A TTA file
+#[derive(LoftyFile, Default)]
+#[lofty(read_fn = "read::read_from")]
+#[lofty(internal_write_module_do_not_use_anywhere_else)]
+pub struct TtaFile {
+	/// An ID3v2 tag
+	#[lofty(tag_type = "Id3v2")]
+	pub(crate) id3v2_tag: Option<Id3v2Tag>,
+	/// An ID3v1 tag
+	#[lofty(tag_type = "Id3v1")]
+	pub(crate) id3v1_tag: Option<Id3v1Tag>,
+	/// An APEv1/v2 tag
+	#[lofty(tag_type = "Ape")]
+	pub(crate) ape_tag: Option<ApeTag>,
+	/// The file's audio properties
+	pub(crate) properties: TtaProperties,
+}
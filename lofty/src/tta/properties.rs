@@ -0,0 +1,155 @@
+use crate::config::ParsingMode;
+use crate::error::Result;
+use crate::macros::{decode_err, parse_mode_choice};
+use crate::properties::FileProperties;
+
+use std::io::Read;
+use std::time::Duration;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+
+/// The audio format of a TTA stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum TtaFormat {
+	/// Integer PCM
+	#[default]
+	Simple,
+	/// Integer PCM, encrypted with a password
+	Encrypted,
+}
+
+/// A TTA file's audio properties
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct TtaProperties {
+	pub(crate) format: TtaFormat,
+	pub(crate) duration: Duration,
+	pub(crate) overall_bitrate: u32,
+	pub(crate) audio_bitrate: u32,
+	pub(crate) sample_rate: u32,
+	pub(crate) bit_depth: u8,
+	pub(crate) channels: u8,
+	pub(crate) total_samples: u32,
+}
+
+impl From<TtaProperties> for FileProperties {
+	fn from(input: TtaProperties) -> Self {
+		Self {
+			duration: input.duration,
+			overall_bitrate: Some(input.overall_bitrate),
+			audio_bitrate: Some(input.audio_bitrate),
+			sample_rate: Some(input.sample_rate),
+			bit_depth: Some(input.bit_depth),
+			channels: Some(input.channels),
+			channel_mask: None,
+		}
+	}
+}
+
+impl TtaProperties {
+	/// Audio format
+	pub fn format(&self) -> TtaFormat {
+		self.format
+	}
+
+	/// Duration of the audio
+	pub fn duration(&self) -> Duration {
+		self.duration
+	}
+
+	/// Overall bitrate (kbps)
+	pub fn overall_bitrate(&self) -> u32 {
+		self.overall_bitrate
+	}
+
+	/// Audio bitrate (kbps)
+	pub fn audio_bitrate(&self) -> u32 {
+		self.audio_bitrate
+	}
+
+	/// Sample rate (Hz)
+	pub fn sample_rate(&self) -> u32 {
+		self.sample_rate
+	}
+
+	/// Bits per sample
+	pub fn bit_depth(&self) -> u8 {
+		self.bit_depth
+	}
+
+	/// Channel count
+	pub fn channels(&self) -> u8 {
+		self.channels
+	}
+
+	/// Total number of samples, per channel
+	pub fn total_samples(&self) -> u32 {
+		self.total_samples
+	}
+}
+
+/// Reads the properties from a `TTA1` header, expecting the reader to be positioned after the identifier
+pub(super) fn read_properties<R>(
+	reader: &mut R,
+	stream_length: u64,
+	file_length: u64,
+	parse_mode: ParsingMode,
+) -> Result<TtaProperties>
+where
+	R: Read,
+{
+	// https://web.archive.org/web/20230523180118/http://tausoft.org/wiki/True_Audio_Codec_Format
+	//
+	// Signature ("TTA1") (4)
+	// Audio format (2)
+	// Number of channels (2)
+	// Bits per sample (2)
+	// Sample rate (4)
+	// Data length, in samples (4)
+	// Header CRC32 (4)
+	let format = match reader.read_u16::<LittleEndian>()? {
+		1 => TtaFormat::Simple,
+		2 => TtaFormat::Encrypted,
+		_ => {
+			parse_mode_choice!(
+				parse_mode,
+				STRICT: decode_err!(@BAIL Tta, "Unknown audio format"),
+				DEFAULT: TtaFormat::default()
+			)
+		},
+	};
+
+	let channels = reader.read_u16::<LittleEndian>()? as u8;
+	let bit_depth = reader.read_u16::<LittleEndian>()? as u8;
+	let sample_rate = reader.read_u32::<LittleEndian>()?;
+	let total_samples = reader.read_u32::<LittleEndian>()?;
+
+	let _crc = reader.read_u32::<LittleEndian>()?;
+
+	let mut properties = TtaProperties {
+		format,
+		sample_rate,
+		bit_depth,
+		channels,
+		total_samples,
+		..TtaProperties::default()
+	};
+
+	if sample_rate == 0 {
+		parse_mode_choice!(
+			parse_mode,
+			STRICT: decode_err!(@BAIL Tta, "Sample rate is 0"),
+			DEFAULT: return Ok(properties)
+		);
+	}
+
+	let length = (u64::from(total_samples) * 1000) / u64::from(sample_rate);
+	if length > 0 {
+		properties.duration = Duration::from_millis(length);
+		properties.overall_bitrate = ((file_length * 8) / length) as u32;
+		properties.audio_bitrate = ((stream_length * 8) / length) as u32;
+	}
+
+	Ok(properties)
+}
@@ -0,0 +1,99 @@
+use super::TtaFile;
+use crate::config::ParseOptions;
+use crate::error::Result;
+use crate::id3::v2::read::parse_id3v2;
+use crate::id3::{find_id3v1, find_id3v2, find_lyrics3v2, FindId3v2Config, ID3FindResults};
+use crate::macros::{decode_err, err};
+use crate::util::io::SeekStreamLen;
+
+use std::io::{Read, Seek, SeekFrom};
+
+pub(super) fn read_from<R>(reader: &mut R, parse_options: ParseOptions) -> Result<TtaFile>
+where
+	R: Read + Seek,
+{
+	let mut file = TtaFile::default();
+
+	let file_length = reader.stream_len_hack()?;
+	let mut stream_length = file_length;
+
+	let find_id3v2_config = if parse_options.read_tags {
+		FindId3v2Config::READ_TAG
+	} else {
+		FindId3v2Config::NO_READ_TAG
+	};
+
+	#[allow(unused_variables)]
+	if let ID3FindResults(Some(header), content) = find_id3v2(reader, find_id3v2_config)? {
+		let Some(new_stream_length) = stream_length.checked_sub(u64::from(header.full_tag_size()))
+		else {
+			err!(SizeMismatch);
+		};
+
+		stream_length = new_stream_length;
+
+		if let Some(content) = content {
+			let reader = &mut &*content;
+
+			let id3v2 = parse_id3v2(reader, header, parse_options.clone())?;
+			file.id3v2_tag = Some(id3v2);
+		}
+	}
+
+	// Save the current position, so we can go back and read the properties after the tags
+	let pos_past_id3v2 = reader.stream_position()?;
+
+	let mut signature = [0; 4];
+	reader.read_exact(&mut signature)?;
+
+	if &signature != b"TTA1" {
+		decode_err!(@BAIL Tta, "File missing \"TTA1\" signature");
+	}
+
+	#[allow(unused_variables)]
+	let ID3FindResults(header, id3v1) = find_id3v1(reader, parse_options.read_tags)?;
+
+	if header.is_some() {
+		file.id3v1_tag = id3v1;
+		let Some(new_stream_length) = stream_length.checked_sub(128) else {
+			err!(SizeMismatch);
+		};
+
+		stream_length = new_stream_length;
+	}
+
+	let ID3FindResults(_, lyrics3v2_size) = find_lyrics3v2(reader)?;
+	let Some(new_stream_length) = stream_length.checked_sub(u64::from(lyrics3v2_size)) else {
+		err!(SizeMismatch);
+	};
+
+	stream_length = new_stream_length;
+
+	reader.seek(SeekFrom::Current(-32))?;
+
+	if let (tag, Some(header)) =
+		crate::ape::tag::read::read_ape_tag(reader, true, parse_options.clone())?
+	{
+		file.ape_tag = tag;
+
+		let Some(new_stream_length) = stream_length.checked_sub(u64::from(header.size)) else {
+			err!(SizeMismatch);
+		};
+
+		stream_length = new_stream_length;
+	}
+
+	if parse_options.read_properties {
+		// Skip back over the signature
+		reader.seek(SeekFrom::Start(pos_past_id3v2 + 4))?;
+
+		file.properties = super::properties::read_properties(
+			reader,
+			stream_length,
+			file_length,
+			parse_options.parsing_mode,
+		)?;
+	}
+
+	Ok(file)
+}
@@ -314,7 +314,7 @@ pub(crate) fn trim_end_nulls_str(text: &str) -> &str {
 	text.trim_end_matches('\0')
 }
 
-fn utf16_encode(
+pub(crate) fn utf16_encode(
 	text: &str,
 	endianness: fn(u16) -> [u8; 2],
 	bom: bool,
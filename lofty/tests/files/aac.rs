@@ -106,3 +106,69 @@ fn read_no_properties() {
 fn read_no_tags() {
 	crate::no_tag_test!("tests/files/assets/minimal/full_test.aac");
 }
+
+#[test_log::test]
+fn read_adif() {
+	// Same as `read`, but the stream has an ADIF header rather than ADTS frames
+	let file = Probe::open("tests/files/assets/minimal/full_test_adif.aac")
+		.unwrap()
+		.options(ParseOptions::new().read_properties(false))
+		.read()
+		.unwrap();
+
+	assert_eq!(file.file_type(), FileType::Aac);
+
+	crate::verify_artist!(file, primary_tag, "Foo artist", 1);
+	crate::verify_artist!(file, tag, TagType::Id3v1, "Bar artist", 1);
+}
+
+#[test_log::test]
+fn write_adif() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test_adif.aac");
+
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	assert_eq!(tagged_file.file_type(), FileType::Aac);
+	let properties = tagged_file.properties().clone();
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Foo artist", 1 => file, "Bar artist");
+	crate::set_artist!(tagged_file, tag_mut, TagType::Id3v1, "Bar artist", 1 => file, "Baz artist");
+
+	// Now reread the file, the ADIF header should still be found
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	assert_eq!(tagged_file.properties(), &properties);
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Bar artist", 1 => file, "Foo artist");
+	crate::set_artist!(tagged_file, tag_mut, TagType::Id3v1, "Baz artist", 1 => file, "Bar artist");
+}
+
+#[test_log::test]
+fn remove_id3v2_adif() {
+	crate::remove_tag!(
+		"tests/files/assets/minimal/full_test_adif.aac",
+		TagType::Id3v2
+	);
+}
+
+#[test_log::test]
+fn remove_id3v1_adif() {
+	crate::remove_tag!(
+		"tests/files/assets/minimal/full_test_adif.aac",
+		TagType::Id3v1
+	);
+}
+
+#[test_log::test]
+fn read_no_properties_adif() {
+	crate::no_properties_test!("tests/files/assets/minimal/full_test_adif.aac");
+}
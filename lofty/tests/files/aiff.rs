@@ -1,11 +1,12 @@
 use crate::{set_artist, temp_file, verify_artist};
-use lofty::config::ParseOptions;
+use lofty::config::{ParseOptions, WriteOptions};
 use lofty::file::FileType;
+use lofty::iff::aiff::{AiffFile, InstrumentChunk, LoopPlayMode, Marker};
 use lofty::prelude::*;
 use lofty::probe::Probe;
 use lofty::tag::TagType;
 
-use std::io::Seek;
+use std::io::{Read, Seek};
 
 #[test_log::test]
 fn read() {
@@ -80,3 +81,142 @@ fn read_no_properties() {
 fn read_no_tags() {
 	crate::no_tag_test!("tests/files/assets/minimal/full_test.aiff");
 }
+
+fn chunk_ids(file: &mut std::fs::File) -> Vec<[u8; 4]> {
+	let mut content = Vec::new();
+	file.rewind().unwrap();
+	file.read_to_end(&mut content).unwrap();
+
+	let mut ids = Vec::new();
+	let mut pos = 12;
+	while pos + 8 <= content.len() {
+		let size = u32::from_be_bytes(content[pos + 4..pos + 8].try_into().unwrap()) as usize;
+		ids.push(content[pos..pos + 4].try_into().unwrap());
+		pos += 8 + size + (size % 2);
+	}
+
+	ids
+}
+
+#[test_log::test]
+fn read_markers() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test_markers.aiff");
+	let aiff_file = AiffFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	assert_eq!(
+		aiff_file.markers(),
+		&[
+			Marker::new(1, 0, String::from("Loop start")),
+			Marker::new(2, 47999, String::from("Loop end")),
+		]
+	);
+
+	let instrument = aiff_file.instrument().unwrap();
+	assert_eq!(instrument.base_note, 60);
+	assert_eq!(instrument.detune, -10);
+	assert_eq!(instrument.gain, -3);
+	assert_eq!(instrument.sustain_loop.play_mode, LoopPlayMode::Forward);
+	assert_eq!(instrument.sustain_loop.begin_marker, 1);
+	assert_eq!(instrument.sustain_loop.end_marker, 2);
+	assert_eq!(instrument.release_loop.play_mode, LoopPlayMode::NoLooping);
+
+	let application_chunks = aiff_file.application_chunks();
+	assert_eq!(application_chunks.len(), 1);
+	assert_eq!(&application_chunks[0].signature, b"stoc");
+}
+
+#[test_log::test]
+fn write_markers() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test_markers.aiff");
+	let original_chunk_ids = chunk_ids(&mut file);
+
+	file.rewind().unwrap();
+	let mut aiff_file = AiffFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	aiff_file.insert_marker(Marker::new(2, 95999, String::from("New loop end")));
+	aiff_file.instrument_mut().unwrap().sustain_loop.play_mode = LoopPlayMode::ForwardBackward;
+	aiff_file.application_chunks_mut()[0].data.push(3);
+
+	file.rewind().unwrap();
+	aiff_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	// The chunks should remain in the same order
+	assert_eq!(chunk_ids(&mut file), original_chunk_ids);
+
+	file.rewind().unwrap();
+	let new_aiff_file = AiffFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(new_aiff_file.markers(), aiff_file.markers());
+	assert_eq!(new_aiff_file.instrument(), aiff_file.instrument());
+	assert_eq!(
+		new_aiff_file.application_chunks(),
+		aiff_file.application_chunks()
+	);
+	assert_eq!(new_aiff_file.properties(), aiff_file.properties());
+}
+
+#[test_log::test]
+fn write_new_markers() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.aiff");
+	let mut aiff_file = AiffFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert!(aiff_file.markers().is_empty());
+
+	aiff_file.insert_marker(Marker::new(1, 1024, String::from("Foo")));
+	aiff_file.set_instrument(InstrumentChunk::new(48));
+
+	file.rewind().unwrap();
+	aiff_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let new_aiff_file = AiffFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(new_aiff_file.markers(), aiff_file.markers());
+	assert_eq!(new_aiff_file.instrument(), aiff_file.instrument());
+	assert!(new_aiff_file.application_chunks().is_empty());
+}
+
+#[test_log::test]
+fn remove_markers() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test_markers.aiff");
+	let mut aiff_file = AiffFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	assert_eq!(aiff_file.remove_markers().len(), 2);
+	assert!(aiff_file.remove_instrument().is_some());
+	aiff_file.application_chunks_mut().clear();
+
+	file.rewind().unwrap();
+	aiff_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	let chunk_ids = chunk_ids(&mut file);
+	assert!(!chunk_ids.contains(b"MARK"));
+	assert!(!chunk_ids.contains(b"INST"));
+	assert!(!chunk_ids.contains(b"APPL"));
+}
+
+#[test_log::test]
+fn tag_edit_preserves_markers() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test_markers.aiff");
+	let original = AiffFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	crate::set_artist!(tagged_file, tag_mut, TagType::AiffText, "Bar artist", 1 => file, "Baz artist");
+
+	file.rewind().unwrap();
+	let new_aiff_file = AiffFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(new_aiff_file.markers(), original.markers());
+	assert_eq!(new_aiff_file.instrument(), original.instrument());
+	assert_eq!(
+		new_aiff_file.application_chunks(),
+		original.application_chunks()
+	);
+}
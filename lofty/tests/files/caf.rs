@@ -0,0 +1,133 @@
+use crate::{set_artist, temp_file, verify_artist};
+use lofty::caf::CafFile;
+use lofty::config::{ParseOptions, WriteOptions};
+use lofty::file::FileType;
+use lofty::prelude::*;
+use lofty::probe::Probe;
+use lofty::tag::{Tag, TagType};
+
+use std::io::{Cursor, Seek};
+use std::time::Duration;
+
+#[test_log::test]
+fn read() {
+	// Here we have a CAF file with an artist and title in its `info` chunk
+	let file = Probe::open("tests/files/assets/minimal/full_test.caf")
+		.unwrap()
+		.options(ParseOptions::new().read_properties(false))
+		.read()
+		.unwrap();
+
+	assert_eq!(file.file_type(), FileType::Caf);
+
+	crate::verify_artist!(file, primary_tag, "Foo artist", 2);
+}
+
+#[test_log::test]
+fn write() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.caf");
+
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	assert_eq!(tagged_file.file_type(), FileType::Caf);
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Foo artist", 2 => file, "Bar artist");
+
+	// Now reread the file
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Bar artist", 2 => file, "Foo artist");
+}
+
+macro_rules! write_new_tag_test {
+	($name:ident, $path:literal) => {
+		#[test_log::test]
+		fn $name() {
+			let mut file = temp_file!($path);
+			let tagged_file = Probe::new(&mut file)
+				.guess_file_type()
+				.unwrap()
+				.read()
+				.unwrap();
+			assert!(tagged_file.primary_tag().is_none());
+			let properties = tagged_file.properties().clone();
+
+			let mut tag = Tag::new(TagType::CafInfo);
+			tag.set_artist(String::from("Foo artist"));
+			tag.set_album(String::from("Baz album"));
+
+			file.rewind().unwrap();
+			tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+			file.rewind().unwrap();
+			let tagged_file = Probe::new(&mut file)
+				.guess_file_type()
+				.unwrap()
+				.read()
+				.unwrap();
+
+			let tag = tagged_file.primary_tag().unwrap();
+			assert_eq!(tag.artist().as_deref(), Some("Foo artist"));
+			assert_eq!(tag.album().as_deref(), Some("Baz album"));
+
+			// The `info` chunk has to be placed before the audio data
+			assert_eq!(tagged_file.properties().duration(), properties.duration());
+			assert_eq!(
+				tagged_file.properties().audio_bitrate(),
+				properties.audio_bitrate()
+			);
+		}
+	};
+}
+
+write_new_tag_test!(write_new_tag, "tests/files/assets/minimal/caf_alac.caf");
+
+// This file's `data` chunk has an unknown size, meaning it extends to the end of the file
+write_new_tag_test!(
+	write_new_tag_unknown_data_size,
+	"tests/files/assets/minimal/caf_lpcm.caf"
+);
+
+#[test_log::test]
+fn remove() {
+	crate::remove_tag!("tests/files/assets/minimal/full_test.caf", TagType::CafInfo);
+}
+
+#[test_log::test]
+fn read_no_properties() {
+	crate::no_properties_test!("tests/files/assets/minimal/full_test.caf");
+}
+
+#[test_log::test]
+fn read_no_tags() {
+	crate::no_tag_test!("tests/files/assets/minimal/full_test.caf");
+}
+
+#[test_log::test]
+fn read_huge_frame_count() {
+	let bytes = std::fs::read("tests/files/assets/minimal/full_test.caf").unwrap();
+
+	// The number of packets and valid frames in the `pakt` chunk
+	let mut valid_frames = bytes.clone();
+	valid_frames[128..136].copy_from_slice(&i64::MAX.to_be_bytes());
+
+	let mut packets = bytes;
+	packets[120..128].copy_from_slice(&i64::MAX.to_be_bytes());
+	packets[128..136].copy_from_slice(&0_i64.to_be_bytes());
+
+	for bytes in [valid_frames, packets] {
+		let file = CafFile::read_from(&mut Cursor::new(bytes), ParseOptions::new()).unwrap();
+		assert!(file.properties().duration() > Duration::ZERO);
+	}
+}
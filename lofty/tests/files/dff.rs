@@ -0,0 +1,133 @@
+use crate::{set_artist, temp_file, verify_artist};
+use lofty::config::ParseOptions;
+use lofty::dsd::dff::DffFile;
+use lofty::file::FileType;
+use lofty::prelude::*;
+use lofty::probe::Probe;
+use lofty::tag::TagType;
+
+use std::io::{Cursor, Seek};
+use std::time::Duration;
+
+#[test_log::test]
+fn read() {
+	// Here we have a DSDIFF file with both an ID3v2 chunk and text chunks
+	let file = Probe::open("tests/files/assets/minimal/full_test.dff")
+		.unwrap()
+		.options(ParseOptions::new().read_properties(false))
+		.read()
+		.unwrap();
+
+	assert_eq!(file.file_type(), FileType::Dff);
+
+	// Verify the ID3v2 tag first
+	crate::verify_artist!(file, primary_tag, "Foo artist", 1);
+
+	// Now verify the text chunks
+	crate::verify_artist!(file, tag, TagType::DffText, "Bar artist", 1);
+}
+
+#[test_log::test]
+fn write() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.dff");
+
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	assert_eq!(tagged_file.file_type(), FileType::Dff);
+
+	// ID3v2
+	crate::set_artist!(tagged_file, primary_tag_mut, "Foo artist", 1 => file, "Bar artist");
+
+	// Text chunks
+	crate::set_artist!(tagged_file, tag_mut, TagType::DffText, "Bar artist", 1 => file, "Baz artist");
+
+	// Now reread the file
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Bar artist", 1 => file, "Foo artist");
+
+	crate::set_artist!(tagged_file, tag_mut, TagType::DffText, "Baz artist", 1 => file, "Bar artist");
+}
+
+#[test_log::test]
+fn write_preserves_properties() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.dff");
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let properties = tagged_file.properties().clone();
+
+	let mut tag = tagged_file.tag(TagType::DffText).unwrap().clone();
+	tag.set_title(String::from("Foo title"));
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, lofty::config::WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let tag = tagged_file.tag(TagType::DffText).unwrap();
+	assert_eq!(tag.title().as_deref(), Some("Foo title"));
+	assert_eq!(tag.artist().as_deref(), Some("Bar artist"));
+	assert_eq!(
+		tagged_file.properties().sample_rate(),
+		properties.sample_rate()
+	);
+	assert_eq!(tagged_file.properties().duration(), properties.duration());
+}
+
+#[test_log::test]
+fn remove_text_chunks() {
+	crate::remove_tag!("tests/files/assets/minimal/full_test.dff", TagType::DffText);
+}
+
+#[test_log::test]
+fn remove_id3v2() {
+	crate::remove_tag!("tests/files/assets/minimal/full_test.dff", TagType::Id3v2);
+}
+
+#[test_log::test]
+fn read_no_properties() {
+	crate::no_properties_test!("tests/files/assets/minimal/full_test.dff");
+}
+
+#[test_log::test]
+fn read_no_tags() {
+	crate::no_tag_test!("tests/files/assets/minimal/full_test.dff");
+}
+
+#[test_log::test]
+fn read_huge_dst_frame_count() {
+	let mut bytes = std::fs::read("tests/files/assets/minimal/full_test.dff").unwrap();
+
+	// The sample rate from the "FS  " chunk
+	bytes[60..64].copy_from_slice(&u32::MAX.to_be_bytes());
+
+	// Turn the "DSD " chunk into a "DST " chunk, with a "FRTE" chunk at the start
+	bytes[118..122].copy_from_slice(b"DST ");
+	bytes[130..134].copy_from_slice(b"FRTE");
+	bytes[134..142].copy_from_slice(&6_u64.to_be_bytes());
+	bytes[142..146].copy_from_slice(&u32::MAX.to_be_bytes());
+	bytes[146..148].copy_from_slice(&1_u16.to_be_bytes());
+
+	let file = DffFile::read_from(&mut Cursor::new(bytes), ParseOptions::new()).unwrap();
+	assert!(file.properties().duration() > Duration::ZERO);
+}
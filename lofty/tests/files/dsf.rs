@@ -0,0 +1,122 @@
+use crate::{set_artist, temp_file, verify_artist};
+use lofty::config::ParseOptions;
+use lofty::dsd::dsf::DsfFile;
+use lofty::file::FileType;
+use lofty::prelude::*;
+use lofty::probe::Probe;
+use lofty::tag::TagType;
+
+use std::io::{Cursor, Seek};
+use std::time::Duration;
+
+#[test_log::test]
+fn read() {
+	// Here we have a DSF file with an ID3v2 tag
+	let file = Probe::open("tests/files/assets/minimal/full_test.dsf")
+		.unwrap()
+		.options(ParseOptions::new().read_properties(false))
+		.read()
+		.unwrap();
+
+	assert_eq!(file.file_type(), FileType::Dsf);
+
+	crate::verify_artist!(file, primary_tag, "Foo artist", 1);
+}
+
+#[test_log::test]
+fn write() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.dsf");
+
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	assert_eq!(tagged_file.file_type(), FileType::Dsf);
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Foo artist", 1 => file, "Bar artist");
+
+	// Now reread the file
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Bar artist", 1 => file, "Foo artist");
+}
+
+#[test_log::test]
+fn write_preserves_properties() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.dsf");
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let properties = tagged_file.properties().clone();
+
+	let mut tag = tagged_file.primary_tag().unwrap().clone();
+	tag.set_title(String::from("Foo title"));
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, lofty::config::WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	assert_eq!(
+		tagged_file.primary_tag().unwrap().title().as_deref(),
+		Some("Foo title")
+	);
+	assert_eq!(
+		tagged_file.properties().sample_rate(),
+		properties.sample_rate()
+	);
+	assert_eq!(tagged_file.properties().duration(), properties.duration());
+}
+
+#[test_log::test]
+fn remove_id3v2() {
+	crate::remove_tag!("tests/files/assets/minimal/full_test.dsf", TagType::Id3v2);
+}
+
+#[test_log::test]
+fn read_no_properties() {
+	crate::no_properties_test!("tests/files/assets/minimal/full_test.dsf");
+}
+
+#[test_log::test]
+fn read_no_tags() {
+	crate::no_tag_test!("tests/files/assets/minimal/full_test.dsf");
+}
+
+#[test_log::test]
+fn read_huge_sample_count() {
+	let mut bytes = std::fs::read("tests/files/assets/minimal/full_test.dsf").unwrap();
+
+	// The sample count of the "fmt " chunk
+	bytes[64..72].copy_from_slice(&u64::MAX.to_le_bytes());
+
+	let file = DsfFile::read_from(&mut Cursor::new(bytes), ParseOptions::new()).unwrap();
+	assert!(file.properties().duration() > Duration::ZERO);
+}
+
+#[test_log::test]
+fn read_huge_fmt_chunk_size() {
+	let mut bytes = std::fs::read("tests/files/assets/minimal/full_test.dsf").unwrap();
+
+	// The size of the "fmt " chunk
+	bytes[32..40].copy_from_slice(&u64::MAX.to_le_bytes());
+
+	assert!(DsfFile::read_from(&mut Cursor::new(bytes), ParseOptions::new()).is_err());
+}
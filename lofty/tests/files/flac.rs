@@ -2,11 +2,16 @@ use crate::temp_file;
 
 use std::fs::File;
 use std::io::Seek;
+use std::time::Duration;
 
 use lofty::config::{ParseOptions, ParsingMode, WriteOptions};
 use lofty::flac::FlacFile;
+use lofty::lyrics::Lrc;
 use lofty::ogg::VorbisComments;
 use lofty::prelude::*;
+use lofty::probe::Probe;
+use lofty::tag::items::Chapter;
+use lofty::tag::TagType;
 
 #[test_log::test]
 fn multiple_vorbis_comments() {
@@ -64,3 +69,81 @@ fn retain_vendor_string() {
 	// The vendor string should be retained
 	assert_eq!(f.vorbis_comments().unwrap().vendor(), "Lavf58.76.100");
 }
+
+#[test_log::test]
+fn write_chapters() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.flac");
+
+	let mut intro = Chapter::new(Duration::ZERO, Some(String::from("Intro")));
+	intro.url = Some(String::from("https://example.com"));
+	let outro = Chapter::new(Duration::from_millis(1500), Some(String::from("Outro")));
+
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let tag = tagged_file.tag_mut(TagType::VorbisComments).unwrap();
+	tag.set_chapters(vec![intro.clone(), outro.clone()]);
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	// The chapters are stored as regular comments
+	file.rewind().unwrap();
+	let flac_file = FlacFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	let vorbis_comments = flac_file.vorbis_comments().unwrap();
+	assert_eq!(vorbis_comments.get("CHAPTER000"), Some("00:00:00.000"));
+	assert_eq!(vorbis_comments.get("CHAPTER001NAME"), Some("Outro"));
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let tag = tagged_file.tag(TagType::VorbisComments).unwrap();
+	assert_eq!(tag.chapters(), &[intro, outro]);
+	assert!(tag
+		.get_string(&ItemKey::Unknown(String::from("CHAPTER000")))
+		.is_none());
+}
+
+#[test_log::test]
+fn write_synced_lyrics() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.flac");
+
+	let lrc: Lrc = "[00:01.00]<00:01.00>Foo <00:01.50>bar\n[00:02.50]Baz"
+		.parse()
+		.unwrap();
+
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let tag = tagged_file.tag_mut(TagType::VorbisComments).unwrap();
+	tag.set_lrc(&lrc);
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	// The lyrics are stored as LRC
+	file.rewind().unwrap();
+	let flac_file = FlacFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	let vorbis_comments = flac_file.vorbis_comments().unwrap();
+	assert_eq!(
+		vorbis_comments.get("LYRICS"),
+		Some(lrc.to_string().trim_end())
+	);
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let tag = tagged_file.tag(TagType::VorbisComments).unwrap();
+	assert_eq!(tag.lrc(), Some(lrc));
+	assert!(tag.get_string(&ItemKey::Lyrics).is_none());
+}
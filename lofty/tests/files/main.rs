@@ -1,11 +1,17 @@
 mod aac;
 mod aiff;
 mod ape;
+mod asf;
+mod caf;
+mod dff;
+mod dsf;
 mod flac;
+mod matroska;
 mod mp4;
 mod mpc;
 mod mpeg;
 mod ogg;
+mod tta;
 pub(crate) mod util;
 mod wav;
 mod wavpack;
@@ -0,0 +1,146 @@
+use crate::{set_artist, temp_file, verify_artist};
+use lofty::config::ParseOptions;
+use lofty::file::FileType;
+use lofty::prelude::*;
+use lofty::probe::Probe;
+use lofty::tag::TagType;
+
+use std::io::Seek;
+
+#[test_log::test]
+fn read() {
+	// Here we have a Matroska file with a track level ARTIST
+	let file = Probe::open("tests/files/assets/minimal/full_test.mka")
+		.unwrap()
+		.options(ParseOptions::new().read_properties(false))
+		.read()
+		.unwrap();
+
+	assert_eq!(file.file_type(), FileType::Matroska);
+
+	crate::verify_artist!(file, primary_tag, "Foo artist", 1);
+}
+
+#[test_log::test]
+fn write() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.mka");
+
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	assert_eq!(tagged_file.file_type(), FileType::Matroska);
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Foo artist", 1 => file, "Bar artist");
+
+	// Now reread the file
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	crate::set_artist!(tagged_file, primary_tag_mut, "Bar artist", 1 => file, "Foo artist");
+}
+
+#[test_log::test]
+fn write_preserves_properties() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.mka");
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let properties = tagged_file.properties().clone();
+
+	let mut tag = tagged_file.primary_tag().unwrap().clone();
+	tag.set_title(String::from("Foo title"));
+	tag.set_album(String::from("Baz album"));
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, lofty::config::WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let tag = tagged_file.primary_tag().unwrap();
+	assert_eq!(tag.title().as_deref(), Some("Foo title"));
+	assert_eq!(tag.album().as_deref(), Some("Baz album"));
+	assert_eq!(
+		tagged_file.properties().sample_rate(),
+		properties.sample_rate()
+	);
+	assert_eq!(tagged_file.properties().duration(), properties.duration());
+}
+
+#[test_log::test]
+fn remove() {
+	crate::remove_tag!(
+		"tests/files/assets/minimal/full_test.mka",
+		TagType::Matroska
+	);
+}
+
+#[test_log::test]
+fn read_no_properties() {
+	crate::no_properties_test!("tests/files/assets/minimal/full_test.mka");
+}
+
+#[test_log::test]
+fn read_no_tags() {
+	crate::no_tag_test!("tests/files/assets/minimal/full_test.mka");
+}
+
+#[test_log::test]
+fn write_picture() {
+	use lofty::picture::{MimeType, Picture, PictureType};
+
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.mka");
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let properties = tagged_file.properties().clone();
+
+	// Too large to fit in the existing padding, so the attachment will be appended
+	let picture = Picture::new_unchecked(
+		PictureType::CoverFront,
+		Some(MimeType::Png),
+		None,
+		vec![0; 1024],
+	);
+
+	let mut tag = tagged_file.primary_tag().unwrap().clone();
+	tag.push_picture(picture.clone());
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, lofty::config::WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let tag = tagged_file.primary_tag().unwrap();
+	assert_eq!(tag.artist().as_deref(), Some("Foo artist"));
+	assert_eq!(tag.pictures(), std::slice::from_ref(&picture));
+	assert_eq!(
+		tagged_file.properties().sample_rate(),
+		properties.sample_rate()
+	);
+	assert_eq!(tagged_file.properties().duration(), properties.duration());
+}
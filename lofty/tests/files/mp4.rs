@@ -1,11 +1,14 @@
 use crate::{set_artist, temp_file, verify_artist};
-use lofty::config::ParseOptions;
+use lofty::config::{ParseOptions, WriteOptions};
 use lofty::file::FileType;
+use lofty::lyrics::Lrc;
 use lofty::prelude::*;
 use lofty::probe::Probe;
+use lofty::tag::items::Chapter;
 use lofty::tag::TagType;
 
 use std::io::Seek;
+use std::time::Duration;
 
 #[test_log::test]
 fn read() {
@@ -68,3 +71,177 @@ fn read_no_properties() {
 fn read_no_tags() {
 	crate::no_tag_test!("tests/files/assets/minimal/m4a_codec_aac.m4a");
 }
+
+fn read_chapters(file: &mut std::fs::File) -> Vec<Chapter> {
+	file.rewind().unwrap();
+
+	let tagged_file = Probe::new(file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	tagged_file
+		.tag(TagType::Mp4Ilst)
+		.map(|tag| tag.chapters().to_vec())
+		.unwrap_or_default()
+}
+
+#[test_log::test]
+fn read_chpl_chapters() {
+	let mut file = temp_file!("tests/files/assets/minimal/m4a_codec_aac_chpl.m4a");
+	let chapters = read_chapters(&mut file);
+
+	assert_eq!(
+		chapters,
+		vec![
+			Chapter::new(Duration::ZERO, Some(String::from("Intro"))),
+			Chapter::new(Duration::from_millis(500), Some(String::from("Verse"))),
+			Chapter::new(Duration::from_secs(1), Some(String::from("Outro"))),
+		]
+	);
+}
+
+#[test_log::test]
+fn read_chapter_track() {
+	let mut file = temp_file!("tests/files/assets/minimal/m4a_codec_aac_chapter_track.m4a");
+	let chapters = read_chapters(&mut file);
+
+	let expected = [
+		("Intro", 0, 500),
+		("Verse", 500, 1000),
+		("Outro", 1000, 1300),
+	];
+	assert_eq!(chapters.len(), expected.len());
+
+	for (chapter, (title, start, end)) in chapters.iter().zip(expected) {
+		assert_eq!(chapter.title.as_deref(), Some(title));
+		assert_eq!(chapter.start, Duration::from_millis(start));
+		assert_eq!(chapter.end, Some(Duration::from_millis(end)));
+	}
+}
+
+#[test_log::test]
+fn write_chapters() {
+	let mut file = temp_file!("tests/files/assets/minimal/m4a_codec_aac.m4a");
+	assert!(read_chapters(&mut file).is_empty());
+
+	let chapters = vec![
+		Chapter::new(Duration::ZERO, Some(String::from("Foo chapter"))),
+		Chapter::new(
+			Duration::from_millis(750),
+			Some(String::from("Bar chapter")),
+		),
+	];
+
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let original_properties = tagged_file.properties().clone();
+
+	let tag = tagged_file.tag_mut(TagType::Mp4Ilst).unwrap();
+	tag.set_chapters(chapters.clone());
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	assert_eq!(read_chapters(&mut file), chapters);
+
+	// The other items and the audio properties should be untouched
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	assert_eq!(tagged_file.properties(), &original_properties);
+	crate::verify_artist!(tagged_file, primary_tag, "Foo artist", 1);
+}
+
+#[test_log::test]
+fn write_chapters_over_chapter_track() {
+	let mut file = temp_file!("tests/files/assets/minimal/m4a_codec_aac_chapter_track.m4a");
+
+	let mut chapters = read_chapters(&mut file);
+	chapters[0].title = Some(String::from("Foo chapter"));
+
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let tag = tagged_file.tag_mut(TagType::Mp4Ilst).unwrap();
+	tag.set_chapters(chapters.clone());
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	// The `chpl` atom takes precedence, and it has no end times
+	let new_chapters = read_chapters(&mut file);
+	assert_eq!(new_chapters.len(), chapters.len());
+	for (new, old) in new_chapters.iter().zip(&chapters) {
+		assert_eq!(new.start, old.start);
+		assert_eq!(new.title, old.title);
+		assert!(new.end.is_none());
+	}
+}
+
+#[test_log::test]
+fn remove_chapters() {
+	let mut file = temp_file!("tests/files/assets/minimal/m4a_codec_aac_chpl.m4a");
+
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let tag = tagged_file.tag_mut(TagType::Mp4Ilst).unwrap();
+	assert_eq!(tag.remove_chapters().len(), 3);
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	assert!(read_chapters(&mut file).is_empty());
+}
+
+#[test_log::test]
+fn write_synced_lyrics() {
+	let mut file = temp_file!("tests/files/assets/minimal/m4a_codec_aac.m4a");
+
+	let lrc: Lrc = "[00:01.00]<00:01.00>Foo <00:01.50>bar\n[00:02.50]Baz"
+		.parse()
+		.unwrap();
+
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let tag = tagged_file.tag_mut(TagType::Mp4Ilst).unwrap();
+	tag.insert_text(ItemKey::Lyrics, String::from("Plain lyrics"));
+	tag.set_lrc(&lrc);
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	// The synchronized lyrics replace the plain lyrics
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let tag = tagged_file.tag(TagType::Mp4Ilst).unwrap();
+	assert_eq!(tag.lrc(), Some(lrc));
+	assert!(tag.get_string(&ItemKey::Lyrics).is_none());
+	crate::verify_artist!(tagged_file, primary_tag, "Foo artist", 1);
+}
@@ -1,14 +1,18 @@
 use crate::{set_artist, temp_file, verify_artist};
 use lofty::config::{ParseOptions, WriteOptions};
 use lofty::file::{BoundTaggedFile, FileType};
-use lofty::id3::v2::{Frame, FrameId, Id3v2Tag, KeyValueFrame};
+use lofty::id3::v2::{Frame, FrameId, Id3v2Tag, KeyValueFrame, TimestampFormat};
+use lofty::lyrics::Lrc;
 use lofty::mpeg::MpegFile;
+use lofty::picture::{MimeType, Picture, PictureType};
 use lofty::prelude::*;
 use lofty::probe::Probe;
+use lofty::tag::items::Chapter;
 use lofty::tag::{Tag, TagType};
 
 use std::borrow::Cow;
 use std::io::Seek;
+use std::time::Duration;
 
 #[test_log::test]
 fn read() {
@@ -392,3 +396,106 @@ fn read_no_properties() {
 fn read_no_tags() {
 	crate::no_tag_test!("tests/files/assets/minimal/full_test.mp3");
 }
+
+#[test_log::test]
+fn write_chapters() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.mp3");
+
+	let mut intro = Chapter::new(Duration::ZERO, Some(String::from("Intro")));
+	intro.end = Some(Duration::from_millis(1500));
+	intro.url = Some(String::from("https://example.com"));
+	intro.image = Some(Picture::new_unchecked(
+		PictureType::Other,
+		Some(MimeType::Png),
+		None,
+		vec![0; 256],
+	));
+
+	let mut outro = Chapter::new(Duration::from_millis(1500), Some(String::from("Outro")));
+	outro.end = Some(Duration::from_secs(3));
+
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let tag = tagged_file.tag_mut(TagType::Id3v2).unwrap();
+	tag.set_chapters(vec![intro.clone(), outro.clone()]);
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let tag = tagged_file.tag_mut(TagType::Id3v2).unwrap();
+	assert_eq!(tag.chapters(), &[intro, outro]);
+
+	// The chapters are not items
+	assert_eq!(tag.artist().as_deref(), Some("Foo artist"));
+	assert!(tag.pictures().is_empty());
+
+	tag.remove_chapters();
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	assert!(tagged_file
+		.tag(TagType::Id3v2)
+		.unwrap()
+		.chapters()
+		.is_empty());
+}
+
+#[test_log::test]
+fn write_synced_lyrics() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.mp3");
+
+	let lrc: Lrc = "[00:01.00]<00:01.00>Foo <00:01.50>bar\n[00:02.50]Baz"
+		.parse()
+		.unwrap();
+
+	let mut tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let tag = tagged_file.tag_mut(TagType::Id3v2).unwrap();
+	tag.set_lrc(&lrc);
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	// The lyrics are stored as a `SYLT` frame, with each word as a separate entry
+	file.rewind().unwrap();
+	let mpeg_file = MpegFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	let id3v2 = mpeg_file.id3v2().unwrap();
+
+	let sync_text = id3v2.synchronized_text().next().unwrap();
+	assert_eq!(sync_text.timestamp_format, TimestampFormat::MS);
+	assert_eq!(
+		sync_text.content,
+		vec![
+			(1000, String::from("\nFoo ")),
+			(1500, String::from("bar")),
+			(2500, String::from("\nBaz"))
+		]
+	);
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let tag = tagged_file.tag(TagType::Id3v2).unwrap();
+	assert_eq!(tag.lrc(), Some(lrc));
+}
@@ -1,11 +1,13 @@
 use crate::{set_artist, temp_file, verify_artist};
 use lofty::config::{ParseOptions, WriteOptions};
 use lofty::file::FileType;
+use lofty::ogg::OggFlacFile;
 use lofty::prelude::*;
 use lofty::probe::Probe;
 use lofty::tag::TagType;
 
-use std::io::Seek;
+use std::io::{Cursor, Seek};
+use std::time::Duration;
 
 // The tests for OGG Opus/Vorbis/Speex are nearly identical
 // We have the vendor string and a title stored in the tag
@@ -65,6 +67,78 @@ fn vorbis_remove() {
 	)
 }
 
+#[test_log::test]
+fn ogg_flac_read() {
+	// `.oga` is used for any audio in an OGG container, so the content has to be checked
+	let probe = Probe::open("tests/files/assets/minimal/full_test.oga").unwrap();
+	assert_eq!(probe.file_type(), None);
+
+	let file = probe
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	assert_eq!(file.file_type(), FileType::OggFlac);
+
+	crate::verify_artist!(file, primary_tag, "Foo artist", 2);
+}
+
+#[test_log::test]
+fn ogg_flac_write() {
+	write(
+		"tests/files/assets/minimal/full_test.oga",
+		FileType::OggFlac,
+	)
+}
+
+#[test_log::test]
+fn ogg_flac_remove() {
+	remove(
+		"tests/files/assets/minimal/full_test.oga",
+		TagType::VorbisComments,
+	)
+}
+
+#[test_log::test]
+fn ogg_flac_write_picture() {
+	use lofty::picture::{MimeType, Picture, PictureType};
+
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.oga");
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+	let properties = tagged_file.properties().clone();
+
+	let picture = Picture::new_unchecked(
+		PictureType::CoverFront,
+		Some(MimeType::Png),
+		None,
+		vec![0; 1024],
+	);
+
+	let mut tag = tagged_file.primary_tag().unwrap().clone();
+	tag.push_picture(picture.clone());
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	file.rewind().unwrap();
+	let tagged_file = Probe::new(&mut file)
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let tag = tagged_file.primary_tag().unwrap();
+	assert_eq!(tag.artist().as_deref(), Some("Foo artist"));
+	assert_eq!(tag.pictures(), std::slice::from_ref(&picture));
+	assert_eq!(tagged_file.properties().duration(), properties.duration());
+}
+
 #[test_log::test]
 fn speex_read() {
 	read("tests/files/assets/minimal/full_test.spx", FileType::Speex)
@@ -216,3 +290,29 @@ fn read_no_properties_speex() {
 fn read_no_tags_speex() {
 	crate::no_tag_test!(@MANDATORY_TAG "tests/files/assets/minimal/full_test.spx", expected_len: 1);
 }
+
+#[test_log::test]
+fn read_no_properties_ogg_flac() {
+	crate::no_properties_test!("tests/files/assets/minimal/full_test.oga");
+}
+
+#[test_log::test]
+fn read_no_tags_ogg_flac() {
+	crate::no_tag_test!(@MANDATORY_TAG "tests/files/assets/minimal/full_test.oga", expected_len: 1);
+}
+
+#[test_log::test]
+fn ogg_flac_read_huge_granule_position() {
+	let mut bytes = std::fs::read("tests/files/assets/minimal/full_test.oga").unwrap();
+
+	// Remove the total sample count from STREAMINFO, so the last page has to be used instead
+	bytes[58] &= 0xF0;
+	bytes[59..63].fill(0);
+
+	// The absolute granule position of the last page
+	let last_page_start = bytes.windows(4).rposition(|w| w == b"OggS").unwrap();
+	bytes[last_page_start + 6..last_page_start + 14].copy_from_slice(&u64::MAX.to_le_bytes());
+
+	let file = OggFlacFile::read_from(&mut Cursor::new(bytes), ParseOptions::new()).unwrap();
+	assert!(file.properties().duration() > Duration::ZERO);
+}
@@ -0,0 +1,92 @@
+use crate::{set_artist, temp_file, verify_artist};
+use lofty::config::ParseOptions;
+use lofty::file::FileType;
+use lofty::prelude::*;
+use lofty::probe::Probe;
+use lofty::tag::TagType;
+
+use std::io::Seek;
+
+#[test_log::test]
+fn read() {
+	// Here we have a TTA file with an ID3v2, ID3v1, and an APEv2 tag
+	let file = Probe::open("tests/files/assets/minimal/full_test.tta")
+		.unwrap()
+		.options(ParseOptions::new().read_properties(false))
+		.read()
+		.unwrap();
+
+	assert_eq!(file.file_type(), FileType::Tta);
+
+	// Verify the ID3v2 tag first
+	crate::verify_artist!(file, primary_tag, "Foo artist", 1);
+
+	// Now verify ID3v1
+	crate::verify_artist!(file, tag, TagType::Id3v1, "Bar artist", 1);
+
+	// Finally, verify APEv2
+	crate::verify_artist!(file, tag, TagType::Ape, "Baz artist", 1);
+}
+
+#[test_log::test]
+fn write() {
+	let mut file = temp_file!("tests/files/assets/minimal/full_test.tta");
+
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	assert_eq!(tagged_file.file_type(), FileType::Tta);
+
+	// ID3v2
+	set_artist!(tagged_file, primary_tag_mut, "Foo artist", 1 => file, "Bar artist");
+
+	// ID3v1
+	set_artist!(tagged_file, tag_mut, TagType::Id3v1, "Bar artist", 1 => file, "Baz artist");
+
+	// APEv2
+	set_artist!(tagged_file, tag_mut, TagType::Ape, "Baz artist", 1 => file, "Qux artist");
+
+	// Now reread the file
+	file.rewind().unwrap();
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	set_artist!(tagged_file, primary_tag_mut, "Bar artist", 1 => file, "Foo artist");
+
+	set_artist!(tagged_file, tag_mut, TagType::Id3v1, "Baz artist", 1 => file, "Bar artist");
+
+	set_artist!(tagged_file, tag_mut, TagType::Ape, "Qux artist", 1 => file, "Baz artist");
+}
+
+#[test_log::test]
+fn remove_id3v2() {
+	crate::remove_tag!("tests/files/assets/minimal/full_test.tta", TagType::Id3v2);
+}
+
+#[test_log::test]
+fn remove_id3v1() {
+	crate::remove_tag!("tests/files/assets/minimal/full_test.tta", TagType::Id3v1);
+}
+
+#[test_log::test]
+fn remove_ape() {
+	crate::remove_tag!("tests/files/assets/minimal/full_test.tta", TagType::Ape);
+}
+
+#[test_log::test]
+fn read_no_properties() {
+	crate::no_properties_test!("tests/files/assets/minimal/full_test.tta");
+}
+
+#[test_log::test]
+fn read_no_tags() {
+	crate::no_tag_test!("tests/files/assets/minimal/full_test.tta");
+}
@@ -1,11 +1,15 @@
 use crate::{set_artist, temp_file, verify_artist};
-use lofty::config::ParseOptions;
-use lofty::file::FileType;
+use lofty::config::{ParseOptions, WriteOptions};
+use lofty::file::{FileType, TaggedFile};
+use lofty::iff::wav::{
+	AcidChunk, CuePoint, CueRegion, InstrumentChunk, SampleLoopType, WavContainer, WavFile,
+};
 use lofty::prelude::*;
 use lofty::probe::Probe;
-use lofty::tag::TagType;
+use lofty::tag::{ItemKey, TagType};
 
-use std::io::Seek;
+use std::io::{Cursor, Read, Seek, SeekFrom};
+use std::time::Duration;
 
 #[test_log::test]
 fn read() {
@@ -95,3 +99,597 @@ fn read_no_properties() {
 fn read_no_tags() {
 	crate::no_tag_test!("tests/files/assets/minimal/wav_format_pcm.wav");
 }
+
+#[test_log::test]
+fn read_bext() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_bext.wav");
+	let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	let bext = wav_file.bext().unwrap();
+	assert_eq!(bext.description, "Foo description");
+	assert_eq!(bext.originator, "Bar originator");
+	assert_eq!(bext.originator_reference, "Baz reference");
+	assert_eq!(bext.origination_date, "2024-06-01");
+	assert_eq!(bext.origination_time, "13:45:30");
+	assert_eq!(bext.time_reference, 172_800_000);
+	assert_eq!(bext.version, 2);
+	assert_eq!(bext.umid.unwrap()[63], 63);
+	assert_eq!(bext.loudness_value, Some(-2270));
+	assert_eq!(bext.max_short_term_loudness, None);
+	assert_eq!(
+		bext.coding_history,
+		"A=PCM,F=48000,W=16,M=stereo,T=original\r\n"
+	);
+
+	// The other tags should be unaffected
+	assert!(wav_file.id3v2().is_some());
+	assert!(wav_file.riff_info().is_some());
+}
+
+#[test_log::test]
+fn write_bext() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_bext.wav");
+	let mut wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	let bext = wav_file.bext_mut().unwrap();
+	bext.description = String::from("Qux description");
+	bext.loudness_range = None;
+
+	file.rewind().unwrap();
+	wav_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let new_wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	assert_eq!(new_wav_file.bext(), wav_file.bext());
+	assert_eq!(new_wav_file.properties(), wav_file.properties());
+}
+
+#[test_log::test]
+fn write_bext_through_tag() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_bext.wav");
+
+	let mut tagged_file = Probe::new(&mut file)
+		.options(ParseOptions::new().read_properties(false))
+		.guess_file_type()
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let tag = tagged_file.tag_mut(TagType::Bext).unwrap();
+	assert_eq!(tag.comment().as_deref(), Some("Foo description"));
+	assert_eq!(
+		tag.get_string(&ItemKey::RecordingDate),
+		Some("2024-06-01T13:45:30")
+	);
+
+	tag.set_comment(String::from("Qux description"));
+	tag.insert_text(ItemKey::RecordingDate, String::from("2025-01-02"));
+
+	file.rewind().unwrap();
+	tag.save_to(&mut file, WriteOptions::default()).unwrap();
+
+	file.rewind().unwrap();
+	let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	let bext = wav_file.bext().unwrap();
+	assert_eq!(bext.description, "Qux description");
+	assert_eq!(bext.origination_date, "2025-01-02");
+	assert!(bext.origination_time.is_empty());
+
+	// Fields without an `ItemKey` mapping should survive the conversion
+	assert_eq!(bext.originator_reference, "Baz reference");
+	assert_eq!(bext.time_reference, 172_800_000);
+	assert_eq!(bext.umid.unwrap()[63], 63);
+	assert_eq!(bext.loudness_value, Some(-2270));
+}
+
+#[test_log::test]
+fn remove_bext() {
+	crate::remove_tag!(
+		"tests/files/assets/minimal/wav_format_pcm_bext.wav",
+		TagType::Bext
+	);
+}
+
+fn chunk_ids(file: &mut std::fs::File) -> Vec<[u8; 4]> {
+	let mut content = Vec::new();
+	file.rewind().unwrap();
+	file.read_to_end(&mut content).unwrap();
+
+	let mut ids = Vec::new();
+	let mut pos = 12;
+	while pos + 8 <= content.len() {
+		let size = u32::from_le_bytes(content[pos + 4..pos + 8].try_into().unwrap()) as usize;
+		ids.push(content[pos..pos + 4].try_into().unwrap());
+		pos += 8 + size + (size % 2);
+	}
+
+	ids
+}
+
+fn data_chunk(file: &mut std::fs::File) -> Vec<u8> {
+	let mut content = Vec::new();
+	file.rewind().unwrap();
+	file.read_to_end(&mut content).unwrap();
+
+	let mut pos = 12;
+	while pos + 8 <= content.len() {
+		let size = u32::from_le_bytes(content[pos + 4..pos + 8].try_into().unwrap()) as usize;
+		if &content[pos..pos + 4] == b"data" {
+			return content[pos + 8..pos + 8 + size].to_vec();
+		}
+
+		pos += 8 + size + (size % 2);
+	}
+
+	panic!("No data chunk found");
+}
+
+#[test_log::test]
+fn write_multiple_chunks() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_markers.wav");
+	let original_data = data_chunk(&mut file);
+
+	file.rewind().unwrap();
+	let mut wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	// Chunks growing, shrinking, and being added in the same write
+	wav_file.riff_info_mut().unwrap().insert(
+		String::from("ICMT"),
+		"Foo comment that is much longer than the original".repeat(50),
+	);
+	assert!(wav_file.remove_marker(2).is_some());
+	wav_file.set_instrument(InstrumentChunk::new(60));
+
+	file.rewind().unwrap();
+	wav_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	assert_eq!(data_chunk(&mut file), original_data);
+
+	file.rewind().unwrap();
+	let new_wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(new_wav_file.riff_info(), wav_file.riff_info());
+	assert_eq!(new_wav_file.markers(), wav_file.markers());
+	assert_eq!(new_wav_file.instrument(), wav_file.instrument());
+	assert_eq!(
+		new_wav_file.properties().duration(),
+		wav_file.properties().duration()
+	);
+
+	// The RIFF size should cover the entire file
+	let mut content = Vec::new();
+	file.rewind().unwrap();
+	file.read_to_end(&mut content).unwrap();
+	let riff_size = u32::from_le_bytes(content[4..8].try_into().unwrap()) as usize;
+	assert_eq!(riff_size, content.len() - 8);
+}
+
+#[test_log::test]
+fn read_markers() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_markers.wav");
+	let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	let markers = wav_file.markers();
+	assert_eq!(markers.len(), 2);
+
+	assert_eq!(markers[0].id, 1);
+	assert_eq!(markers[0].sample_offset, 0);
+	assert_eq!(markers[0].label.as_deref(), Some("Intro"));
+	assert_eq!(markers[0].note.as_deref(), Some("Foo note"));
+	assert!(markers[0].region.is_none());
+
+	assert_eq!(markers[1].id, 2);
+	assert_eq!(markers[1].sample_offset, 48000);
+	assert_eq!(markers[1].label.as_deref(), Some("Verse"));
+
+	let region = markers[1].region.as_ref().unwrap();
+	assert_eq!(region.sample_length, 24000);
+	assert_eq!(&region.purpose, b"rgn ");
+	assert_eq!(region.text.as_deref(), Some("Bar region"));
+}
+
+#[test_log::test]
+fn write_markers() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_markers.wav");
+	let original_chunk_ids = chunk_ids(&mut file);
+
+	file.rewind().unwrap();
+	let mut wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	// Remove the first marker, and add a new one with a region
+	assert!(wav_file.remove_marker(1).is_some());
+
+	let mut marker = CuePoint::new(wav_file.next_marker_id(), 60000);
+	marker.label = Some(String::from("Chorus"));
+	marker.region = Some(CueRegion::new(12000));
+	assert_eq!(marker.id, 3);
+	assert!(wav_file.insert_marker(marker).is_none());
+
+	file.rewind().unwrap();
+	wav_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	// The chunks should remain in the same order
+	assert_eq!(chunk_ids(&mut file), original_chunk_ids);
+
+	file.rewind().unwrap();
+	let new_wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(new_wav_file.markers(), wav_file.markers());
+	assert_eq!(new_wav_file.properties(), wav_file.properties());
+}
+
+#[test_log::test]
+fn write_new_markers() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm.wav");
+	let mut wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert!(wav_file.markers().is_empty());
+
+	wav_file.insert_marker(CuePoint::new(1, 1024));
+
+	file.rewind().unwrap();
+	wav_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	// No labels, notes, or regions, so no `adtl` list is needed
+	assert_eq!(
+		chunk_ids(&mut file),
+		[*b"fmt ", *b"data", *b"ID3 ", *b"LIST", *b"cue "]
+	);
+
+	file.rewind().unwrap();
+	let new_wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(new_wav_file.markers(), wav_file.markers());
+}
+
+#[test_log::test]
+fn remove_markers() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_markers.wav");
+	let mut wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	assert_eq!(wav_file.remove_markers().len(), 2);
+
+	file.rewind().unwrap();
+	wav_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	assert_eq!(
+		chunk_ids(&mut file),
+		[*b"fmt ", *b"data", *b"ID3 ", *b"LIST"]
+	);
+
+	file.rewind().unwrap();
+	let new_wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert!(new_wav_file.markers().is_empty());
+	assert!(new_wav_file.riff_info().is_some());
+}
+
+macro_rules! wav_container_tests {
+	($($name:ident => $path:literal, $container:expr);+ $(;)?) => {
+		$(
+			mod $name {
+				use super::*;
+
+				#[test_log::test]
+				fn read() {
+					let file = Probe::open($path)
+						.unwrap()
+						.options(ParseOptions::new().read_properties(false))
+						.read()
+						.unwrap();
+
+					assert_eq!(file.file_type(), FileType::Wav);
+
+					crate::verify_artist!(file, primary_tag, "Foo artist", 1);
+					crate::verify_artist!(file, tag, TagType::RiffInfo, "Bar artist", 1);
+
+					let wav_file =
+						WavFile::read_from(&mut std::fs::File::open($path).unwrap(), ParseOptions::new())
+							.unwrap();
+					assert_eq!(wav_file.container(), $container);
+				}
+
+				#[test_log::test]
+				fn write() {
+					let mut file = temp_file!($path);
+
+					let mut tagged_file = Probe::new(&mut file)
+						.options(ParseOptions::new().read_properties(false))
+						.guess_file_type()
+						.unwrap()
+						.read()
+						.unwrap();
+
+					// ID3v2
+					crate::set_artist!(tagged_file, primary_tag_mut, "Foo artist", 1 => file, "Bar artist");
+
+					// RIFF INFO
+					crate::set_artist!(tagged_file, tag_mut, TagType::RiffInfo, "Bar artist", 1 => file, "Baz artist");
+
+					// Now reread the file
+					file.rewind().unwrap();
+					let mut tagged_file = Probe::new(&mut file)
+						.options(ParseOptions::new().read_properties(false))
+						.guess_file_type()
+						.unwrap()
+						.read()
+						.unwrap();
+
+					crate::set_artist!(tagged_file, primary_tag_mut, "Bar artist", 1 => file, "Foo artist");
+
+					crate::set_artist!(tagged_file, tag_mut, TagType::RiffInfo, "Baz artist", 1 => file, "Bar artist");
+
+					// The properties and container should be unaffected
+					file.rewind().unwrap();
+					let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+					assert_eq!(wav_file.container(), $container);
+					assert_eq!(wav_file.properties().duration().as_millis(), 1428);
+
+					// And the header should still describe the full file
+					let file_len = file.seek(SeekFrom::End(0)).unwrap();
+					let mut header = [0; 28];
+					file.rewind().unwrap();
+					std::io::Read::read_exact(&mut file, &mut header).unwrap();
+
+					let riff_size = match $container {
+						WavContainer::Wave64 => u64::from_le_bytes(header[16..24].try_into().unwrap()),
+						_ => u64::from_le_bytes(header[20..28].try_into().unwrap()) + 8,
+					};
+					assert_eq!(riff_size, file_len);
+				}
+
+				#[test_log::test]
+				fn remove_id3v2() {
+					crate::remove_tag!($path, TagType::Id3v2);
+				}
+
+				#[test_log::test]
+				fn remove_riff_info() {
+					crate::remove_tag!($path, TagType::RiffInfo);
+				}
+
+				#[test_log::test]
+				fn read_no_properties() {
+					crate::no_properties_test!($path);
+				}
+
+				#[test_log::test]
+				fn read_no_tags() {
+					crate::no_tag_test!($path);
+				}
+			}
+		)+
+	};
+}
+
+wav_container_tests! {
+	rf64 => "tests/files/assets/minimal/wav_format_pcm_rf64.wav", WavContainer::Rf64;
+	wave64 => "tests/files/assets/minimal/wav_format_pcm.w64", WavContainer::Wave64;
+}
+
+#[test_log::test]
+fn read_rf64_huge_ds64_sizes() {
+	let mut bytes = std::fs::read("tests/files/assets/minimal/wav_format_pcm_rf64.wav").unwrap();
+
+	// The data size and sample count of the `ds64` chunk
+	bytes[28..36].copy_from_slice(&u64::MAX.to_le_bytes());
+	bytes[36..44].copy_from_slice(&u64::MAX.to_le_bytes());
+
+	let wav_file = WavFile::read_from(&mut Cursor::new(bytes), ParseOptions::new()).unwrap();
+	assert!(wav_file.properties().duration() > Duration::ZERO);
+}
+
+#[test_log::test]
+fn read_sampler_chunks() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_sampler.wav");
+	let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	let sampler = wav_file.sampler().unwrap();
+	assert_eq!(sampler.sample_period, 20833);
+	assert_eq!(sampler.midi_unity_note, 60);
+	assert_eq!(sampler.loops.len(), 2);
+	assert_eq!(sampler.loops[0].loop_type, SampleLoopType::Forward);
+	assert_eq!(sampler.loops[0].end, 47999);
+	assert_eq!(sampler.loops[1].loop_type, SampleLoopType::Alternating);
+	assert_eq!(sampler.loops[1].start, 48000);
+	assert_eq!(sampler.loops[1].play_count, 3);
+
+	let instrument = wav_file.instrument().unwrap();
+	assert_eq!(instrument.unshifted_note, 60);
+	assert_eq!(instrument.fine_tune, -10);
+	assert_eq!(instrument.gain, -3);
+	assert_eq!(instrument.low_note, 36);
+	assert_eq!(instrument.high_note, 84);
+
+	let acid = wav_file.acid().unwrap();
+	assert!((acid.tempo - 128.0).abs() < f32::EPSILON);
+	assert_eq!(acid.beats, 8);
+	assert_eq!(acid.key(), Some("A"));
+	assert!(!acid.is_one_shot());
+}
+
+#[test_log::test]
+fn write_sampler_chunks() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_sampler.wav");
+	let original_chunk_ids = chunk_ids(&mut file);
+
+	file.rewind().unwrap();
+	let mut wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	wav_file.sampler_mut().unwrap().loops.remove(0);
+	wav_file.instrument_mut().unwrap().gain = 6;
+	wav_file.acid_mut().unwrap().tempo = 90.0;
+
+	file.rewind().unwrap();
+	wav_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	// The chunks should remain in the same order
+	assert_eq!(chunk_ids(&mut file), original_chunk_ids);
+
+	file.rewind().unwrap();
+	let new_wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(new_wav_file.sampler(), wav_file.sampler());
+	assert_eq!(new_wav_file.instrument(), wav_file.instrument());
+	assert_eq!(new_wav_file.acid(), wav_file.acid());
+	assert_eq!(new_wav_file.properties(), wav_file.properties());
+}
+
+#[test_log::test]
+fn write_new_sampler_chunks() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm.wav");
+	let mut wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert!(wav_file.acid().is_none());
+
+	wav_file.set_instrument(InstrumentChunk::new(48));
+	wav_file.set_acid(AcidChunk::new(140.0, 16));
+
+	file.rewind().unwrap();
+	wav_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	file.rewind().unwrap();
+	let new_wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert!(new_wav_file.sampler().is_none());
+	assert_eq!(new_wav_file.instrument(), wav_file.instrument());
+	assert_eq!(new_wav_file.acid(), wav_file.acid());
+}
+
+#[test_log::test]
+fn remove_sampler_chunks() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_sampler.wav");
+	let mut wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	assert!(wav_file.remove_sampler().is_some());
+	assert!(wav_file.remove_instrument().is_some());
+	assert!(wav_file.remove_acid().is_some());
+
+	file.rewind().unwrap();
+	wav_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	let chunk_ids = chunk_ids(&mut file);
+	assert!(!chunk_ids.contains(b"smpl"));
+	assert!(!chunk_ids.contains(b"inst"));
+	assert!(!chunk_ids.contains(b"acid"));
+}
+
+#[test_log::test]
+fn acid_into_tagged_file() {
+	let file = Probe::open("tests/files/assets/minimal/wav_format_pcm_sampler.wav")
+		.unwrap()
+		.read()
+		.unwrap();
+
+	let id3v2 = file.tag(TagType::Id3v2).unwrap();
+	assert_eq!(id3v2.get_string(&ItemKey::IntegerBpm), Some("128"));
+	assert_eq!(id3v2.get_string(&ItemKey::InitialKey), Some("A"));
+}
+
+#[test_log::test]
+fn read_xml() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_ixml.wav");
+	let wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	// The trailing nulls should be removed
+	let ixml = wav_file.ixml().unwrap();
+	assert!(ixml.ends_with("</BWFXML>\n"));
+
+	let metadata = wav_file.ixml_metadata().unwrap();
+	assert_eq!(metadata.project.as_deref(), Some("Foo Project"));
+	assert_eq!(metadata.scene.as_deref(), Some("12A"));
+	assert_eq!(metadata.take.as_deref(), Some("3"));
+	assert_eq!(metadata.tape.as_deref(), Some("DAY01"));
+	assert_eq!(metadata.note.as_deref(), Some("Plane overhead"));
+	assert_eq!(metadata.tracks.len(), 1);
+	assert_eq!(metadata.tracks[0].name.as_deref(), Some("Boom"));
+
+	assert!(wav_file.axml().unwrap().contains("<title>Bar</title>"));
+}
+
+#[test_log::test]
+fn write_xml() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_ixml.wav");
+	let original_chunk_ids = chunk_ids(&mut file);
+
+	file.rewind().unwrap();
+	let mut wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	let ixml = wav_file
+		.ixml()
+		.unwrap()
+		.replace("<TAKE>3</TAKE>", "<TAKE>4</TAKE>");
+	wav_file.set_ixml(ixml);
+
+	file.rewind().unwrap();
+	wav_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	// The chunks should remain in the same order
+	assert_eq!(chunk_ids(&mut file), original_chunk_ids);
+
+	file.rewind().unwrap();
+	let new_wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert_eq!(new_wav_file.ixml(), wav_file.ixml());
+	assert_eq!(new_wav_file.axml(), wav_file.axml());
+	assert_eq!(
+		new_wav_file.ixml_metadata().unwrap().take.as_deref(),
+		Some("4")
+	);
+}
+
+#[test_log::test]
+fn remove_xml() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_ixml.wav");
+	let mut wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+
+	assert!(wav_file.remove_ixml().is_some());
+	assert!(wav_file.remove_axml().is_some());
+
+	file.rewind().unwrap();
+	wav_file
+		.save_to(&mut file, WriteOptions::default())
+		.unwrap();
+
+	let chunk_ids = chunk_ids(&mut file);
+	assert!(!chunk_ids.contains(b"iXML"));
+	assert!(!chunk_ids.contains(b"axml"));
+
+	file.rewind().unwrap();
+	let new_wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	assert!(new_wav_file.ixml().is_none());
+	assert!(new_wav_file.id3v2().is_some());
+}
+
+#[test_log::test]
+fn ixml_into_tagged_file() {
+	let mut file = temp_file!("tests/files/assets/minimal/wav_format_pcm_ixml.wav");
+	let mut wav_file = WavFile::read_from(&mut file, ParseOptions::new()).unwrap();
+	wav_file
+		.id3v2_mut()
+		.unwrap()
+		.set_title(String::from("Foo title"));
+
+	let tagged_file = TaggedFile::from(wav_file);
+
+	let id3v2 = tagged_file.tag(TagType::Id3v2).unwrap();
+	assert_eq!(id3v2.get_string(&ItemKey::AlbumTitle), Some("Foo Project"));
+	assert_eq!(id3v2.get_string(&ItemKey::ContentGroup), Some("12A"));
+	assert_eq!(id3v2.get_string(&ItemKey::SetSubtitle), Some("DAY01"));
+	assert_eq!(id3v2.get_string(&ItemKey::Comment), Some("Plane overhead"));
+	assert_eq!(id3v2.get_string(&ItemKey::TrackSubtitle), Some("1: Boom"));
+
+	// Existing items aren't replaced
+	assert_eq!(id3v2.get_string(&ItemKey::TrackTitle), Some("Foo title"));
+}
@@ -9,9 +9,9 @@ use quote::quote;
 pub(crate) fn opt_internal_file_type(
 	struct_name: String,
 ) -> Option<(proc_macro2::TokenStream, bool)> {
-	const LOFTY_FILE_TYPES: [&str; 12] = [
-		"Aac", "Aiff", "Ape", "Flac", "Mpeg", "Mp4", "Mpc", "Opus", "Vorbis", "Speex", "Wav",
-		"WavPack",
+	const LOFTY_FILE_TYPES: [&str; 19] = [
+		"Aac", "Aiff", "Ape", "Asf", "Caf", "Dff", "Dsf", "Flac", "Matroska", "Mpeg", "Mp4", "Mpc",
+		"OggFlac", "Opus", "Vorbis", "Speex", "Tta", "Wav", "WavPack",
 	];
 
 	const ID3V2_STRIPPABLE: [&str; 2] = ["Flac", "Ape"];
@@ -63,6 +63,7 @@ pub(crate) fn init_write_lookup(
 		insert!(map, Id3v2, {
 			lofty::id3::v2::tag::Id3v2TagRef {
 				flags: lofty::id3::v2::Id3v2TagFlags::default(),
+				original_version: lofty::id3::v2::tag::tag_original_version(tag),
 				frames: lofty::id3::v2::tag::tag_frames(tag).peekable(),
 			}
 			.write_to(file, write_options)
@@ -87,6 +88,23 @@ pub(crate) fn init_write_lookup(
 		.write_to(file, write_options)
 	});
 
+	insert!(map, Bext, {
+		lofty::tag::TagExt::save_to(
+			&Into::<lofty::iff::wav::BextChunk>::into(tag.clone()),
+			file,
+			write_options,
+		)
+	});
+
+	insert!(map, DffText, {
+		lofty::dsd::dff::tag::DffTextChunksRef {
+			artist: tag.get_string(&lofty::prelude::ItemKey::TrackArtist),
+			title: tag.get_string(&lofty::prelude::ItemKey::TrackTitle),
+			comments: lofty::dsd::dff::tag::tag_comments(tag),
+		}
+		.write_to(file, write_options)
+	});
+
 	map
 }
 
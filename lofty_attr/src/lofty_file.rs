@@ -495,13 +495,13 @@ fn get_save_to_body(
 			quote! {
 				if let Some(ref tag) = self.#name {
 					file.rewind()?;
-					tag.save_to(file, write_options)?;
+					tag.save_to(file, write_options.clone())?;
 				}
 			}
 		} else {
 			quote! {
 				file.rewind()?;
-				self.#name.save_to(file, write_options)?;
+				self.#name.save_to(file, write_options.clone())?;
 			}
 		}
 	});